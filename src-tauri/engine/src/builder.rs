@@ -0,0 +1,649 @@
+use crate::{
+    entities::{
+        fuzzy_output_value::FuzzyOutputValue, input_parameter::InputParameter,
+        input_value::InputValue, output_parameter::OutputParameter, output_value::OutputValue,
+        parameter_kind::ParameterKind, parameter_metadata::ParameterMetadata,
+        partition_mode::PartitionMode, problem::Problem,
+    },
+    error::{DomainError, EntityKind, FieldError},
+    evaluation::{evaluate, FuzzyEvaluationInput, FuzzyEvaluationResult},
+    services::{
+        defuzzification::DefuzzificationMethod,
+        fuzzification::category_index,
+        hedge::{format_antecedent_hedges, Hedge},
+    },
+};
+
+/// Term of a parameter being built: a trapezoid (a, b, c, d), or a triangle with b = c
+#[derive(Debug, Clone)]
+struct TermShape {
+    name: String,
+    a: f32,
+    b: f32,
+    c: f32,
+    d: f32,
+    is_triangle: bool,
+}
+
+/// Input or output parameter of a [`FuzzySystemBuilder`]
+#[derive(Debug, Clone)]
+pub struct ParameterBuilder {
+    name: String,
+    start: f32,
+    end: f32,
+    kind: ParameterKind,
+    metadata: ParameterMetadata,
+    terms: Vec<TermShape>,
+}
+
+impl ParameterBuilder {
+    /// A numeric parameter over [`start`, `end`] without terms
+    pub fn new(name: &str, start: f32, end: f32) -> Self {
+        Self {
+            name: name.to_string(),
+            start,
+            end,
+            kind: ParameterKind::Continuous,
+            metadata: ParameterMetadata::default(),
+            terms: Vec::new(),
+        }
+    }
+
+    /// A categorical input whose terms are `categories`; inputs select them by index
+    pub fn categorical(name: &str, categories: &[&str]) -> Self {
+        let mut builder = Self::new(name, 0.0, categories.len().saturating_sub(1) as f32);
+        builder.kind = ParameterKind::Categorical;
+        for (i, category) in categories.iter().enumerate() {
+            let x = i as f32;
+            builder = builder.triangle(category, x, x, x);
+        }
+        builder
+    }
+
+    pub fn trapezoid(mut self, name: &str, a: f32, b: f32, c: f32, d: f32) -> Self {
+        self.terms.push(TermShape {
+            name: name.to_string(),
+            a,
+            b,
+            c,
+            d,
+            is_triangle: false,
+        });
+        self
+    }
+
+    pub fn triangle(mut self, name: &str, a: f32, peak: f32, d: f32) -> Self {
+        self.terms.push(TermShape {
+            name: name.to_string(),
+            a,
+            b: peak,
+            c: peak,
+            d,
+            is_triangle: true,
+        });
+        self
+    }
+
+    pub fn unit(mut self, unit: &str) -> Self {
+        self.metadata.unit = unit.to_string();
+        self
+    }
+
+    /// Number of decimals values of the parameter are displayed with
+    pub fn precision(mut self, precision: u8) -> Self {
+        self.metadata.precision = Some(precision);
+        self
+    }
+
+    /// Checks the parameter, built as an `entity` of the kind given
+    fn check(&self, entity: EntityKind) -> Result<(), DomainError> {
+        let invalid = |field: &str, message: String| {
+            DomainError::invalid_field(entity, None, FieldError::new(field, message))
+        };
+        if self.name.trim().is_empty() {
+            return Err(invalid("name", "Parameter name cannot be empty".to_string()));
+        }
+        if self.start >= self.end && self.kind == ParameterKind::Continuous {
+            return Err(invalid(
+                "end",
+                format!(
+                    "Range of parameter '{}' is empty: [{}, {}]",
+                    self.name, self.start, self.end
+                ),
+            ));
+        }
+        if self.terms.is_empty() {
+            return Err(invalid("terms", format!("Parameter '{}' has no terms", self.name)));
+        }
+        for (i, term) in self.terms.iter().enumerate() {
+            if self.terms[..i].iter().any(|t| t.name == term.name) {
+                return Err(invalid(
+                    &format!("terms[{}].name", i),
+                    format!("Parameter '{}' has two terms named '{}'", self.name, term.name),
+                ));
+            }
+            if !(term.a <= term.b && term.b <= term.c && term.c <= term.d) {
+                return Err(invalid(
+                    &format!("terms[{}].shape", i),
+                    format!(
+                        "Term '{}' of parameter '{}' must satisfy a <= b <= c <= d",
+                        term.name, self.name
+                    ),
+                ));
+            }
+        }
+        self.metadata
+            .check(self.start, self.end)
+            .map_err(|e| invalid(&e.field, format!("Parameter '{}': {}", self.name, e.message)))
+    }
+}
+
+/// One clause of a rule: a term of a parameter, optionally hedged
+#[derive(Debug, Clone)]
+struct Clause {
+    parameter: String,
+    term: String,
+    hedge: Option<Hedge>,
+}
+
+/// Rule "if `when` and ... then `then`" of a [`FuzzySystemBuilder`], naming
+/// parameters and terms
+#[derive(Debug, Clone)]
+pub struct RuleBuilder {
+    antecedents: Vec<Clause>,
+    consequent: Option<Clause>,
+}
+
+impl RuleBuilder {
+    pub fn when(parameter: &str, term: &str) -> Self {
+        Self {
+            antecedents: Vec::new(),
+            consequent: None,
+        }
+        .and(parameter, term)
+    }
+
+    pub fn and(mut self, parameter: &str, term: &str) -> Self {
+        self.antecedents.push(Clause {
+            parameter: parameter.to_string(),
+            term: term.to_string(),
+            hedge: None,
+        });
+        self
+    }
+
+    pub fn then(mut self, parameter: &str, term: &str) -> Self {
+        self.consequent = Some(Clause {
+            parameter: parameter.to_string(),
+            term: term.to_string(),
+            hedge: None,
+        });
+        self
+    }
+
+    /// Puts a hedge on the clause added last, e.g. "very hot"
+    pub fn hedged(mut self, hedge: Hedge) -> Self {
+        let clause = match self.consequent.as_mut() {
+            Some(consequent) => Some(consequent),
+            None => self.antecedents.last_mut(),
+        };
+        if let Some(clause) = clause {
+            clause.hedge = Some(hedge);
+        }
+        self
+    }
+}
+
+/// Builds a fuzzy system in code, without a database.
+///
+/// ```
+/// use fuzzy_engine::builder::{FuzzySystemBuilder, ParameterBuilder, RuleBuilder};
+/// use fuzzy_engine::services::defuzzification::DefuzzificationMethod;
+///
+/// let system = FuzzySystemBuilder::new("Tipping")
+///     .input(
+///         ParameterBuilder::new("service", 0.0, 10.0)
+///             .trapezoid("poor", 0.0, 0.0, 2.0, 5.0)
+///             .trapezoid("good", 2.0, 5.0, 10.0, 10.0),
+///     )
+///     .output(
+///         ParameterBuilder::new("tip", 0.0, 30.0)
+///             .triangle("low", 0.0, 5.0, 15.0)
+///             .triangle("high", 15.0, 25.0, 30.0),
+///     )
+///     .rule(RuleBuilder::when("service", "poor").then("tip", "low"))
+///     .rule(RuleBuilder::when("service", "good").then("tip", "high"))
+///     .build()
+///     .unwrap();
+///
+/// let result = system
+///     .evaluate(&[("service", 8.0)], DefuzzificationMethod::Centroid, 500)
+///     .unwrap();
+/// assert!(result.outputs[0].crisp_value > 15.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FuzzySystemBuilder {
+    name: String,
+    description: String,
+    inputs: Vec<ParameterBuilder>,
+    outputs: Vec<ParameterBuilder>,
+    rules: Vec<RuleBuilder>,
+}
+
+impl FuzzySystemBuilder {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            description: String::new(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            rules: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    pub fn input(mut self, parameter: ParameterBuilder) -> Self {
+        self.inputs.push(parameter);
+        self
+    }
+
+    /// Adds an output parameter; outputs are always numeric
+    pub fn output(mut self, parameter: ParameterBuilder) -> Self {
+        self.outputs.push(parameter);
+        self
+    }
+
+    pub fn rule(mut self, rule: RuleBuilder) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Checks the definition and assigns ids to everything the way storage would
+    pub fn build(self) -> Result<FuzzySystem, DomainError> {
+        if self.name.trim().is_empty() {
+            return Err(DomainError::invalid_field(
+                EntityKind::Problem,
+                None,
+                FieldError::new("name", "Name cannot be empty"),
+            ));
+        }
+        let names: Vec<&str> = self
+            .inputs
+            .iter()
+            .chain(&self.outputs)
+            .map(|p| p.name.as_str())
+            .collect();
+        for (i, name) in names.iter().enumerate() {
+            if names[..i].contains(name) {
+                let entity = match i < self.inputs.len() {
+                    true => EntityKind::InputParameter,
+                    false => EntityKind::OutputParameter,
+                };
+                return Err(DomainError::invalid_field(
+                    entity,
+                    None,
+                    FieldError::new("name", format!("Two parameters are named '{}'", name)),
+                ));
+            }
+        }
+
+        let problem_id = 1;
+        let mut next_id = 0;
+        let mut new_id = || {
+            next_id += 1;
+            next_id
+        };
+
+        let mut input_parameters = Vec::new();
+        for input in &self.inputs {
+            input.check(EntityKind::InputParameter)?;
+            let id = new_id();
+            input_parameters.push(InputParameter {
+                id,
+                problem_id,
+                name: input.name.clone(),
+                start: input.start,
+                end: input.end,
+                partition_mode: PartitionMode::FreeForm,
+                kind: input.kind,
+                metadata: input.metadata.clone(),
+                input_values: input
+                    .terms
+                    .iter()
+                    .map(|term| InputValue {
+                        id: new_id(),
+                        input_parameter_id: id,
+                        value: term.name.clone(),
+                        a: term.a,
+                        b: term.b,
+                        c: term.c,
+                        d: term.d,
+                        is_triangle: term.is_triangle,
+                        lower: None,
+                    })
+                    .collect(),
+            });
+        }
+
+        let mut output_parameters = Vec::new();
+        for output in &self.outputs {
+            output.check(EntityKind::OutputParameter)?;
+            if output.kind == ParameterKind::Categorical {
+                return Err(DomainError::invalid_field(
+                    EntityKind::OutputParameter,
+                    None,
+                    FieldError::new(
+                        "kind",
+                        format!("Output parameter '{}' can not be categorical", output.name),
+                    ),
+                ));
+            }
+            let id = new_id();
+            output_parameters.push(OutputParameter {
+                id,
+                problem_id,
+                name: output.name.clone(),
+                start: output.start,
+                end: output.end,
+                partition_mode: PartitionMode::FreeForm,
+                metadata: output.metadata.clone(),
+                fuzzy_output_values: output
+                    .terms
+                    .iter()
+                    .map(|term| FuzzyOutputValue {
+                        id: new_id(),
+                        output_parameter_id: id,
+                        value: term.name.clone(),
+                        a: term.a,
+                        b: term.b,
+                        c: term.c,
+                        d: term.d,
+                        is_triangle: term.is_triangle,
+                        lower: None,
+                    })
+                    .collect(),
+            });
+        }
+
+        let mut output_values = Vec::new();
+        for (i, rule) in self.rules.iter().enumerate() {
+            let consequent = rule.consequent.as_ref().ok_or_else(|| {
+                invalid_rule("consequent", format!("Rule {} has no consequent", i + 1))
+            })?;
+            let output_parameter = output_parameters
+                .iter()
+                .find(|op| op.name == consequent.parameter)
+                .ok_or_else(|| unknown_parameter(i, "output", &consequent.parameter))?;
+            let fuzzy_output_value = output_parameter
+                .fuzzy_output_values
+                .iter()
+                .find(|fov| fov.value == consequent.term)
+                .ok_or_else(|| {
+                    unknown_term(i, "consequent", &consequent.parameter, &consequent.term)
+                })?;
+
+            let mut input_value_ids = Vec::new();
+            let mut hedges = Vec::new();
+            for antecedent in &rule.antecedents {
+                let input_parameter = input_parameters
+                    .iter()
+                    .find(|ip| ip.name == antecedent.parameter)
+                    .ok_or_else(|| unknown_parameter(i, "input", &antecedent.parameter))?;
+                let input_value = input_parameter
+                    .input_values
+                    .iter()
+                    .find(|iv| iv.value == antecedent.term)
+                    .ok_or_else(|| {
+                        unknown_term(i, "antecedents", &antecedent.parameter, &antecedent.term)
+                    })?;
+                input_value_ids.push(format!("|{}|", input_value.id));
+                if let Some(hedge) = antecedent.hedge {
+                    hedges.push((input_value.id, hedge));
+                }
+            }
+
+            output_values.push(OutputValue {
+                id: new_id(),
+                output_parameter_id: output_parameter.id,
+                fuzzy_output_value_id: Some(fuzzy_output_value.id),
+                input_value_ids: input_value_ids.concat(),
+                antecedent_hedges: format_antecedent_hedges(&hedges),
+                consequent_hedge: consequent.hedge.map(|h| h.as_str().to_string()),
+            });
+        }
+
+        Ok(FuzzySystem {
+            problem: Problem {
+                id: problem_id,
+                prev_problem_id: None,
+                is_final: true,
+                name: self.name,
+                description: self.description,
+                image_id: None,
+                created_at: String::new(),
+                updated_at: None,
+                input_parameters,
+                output_parameters,
+                output_values,
+            },
+        })
+    }
+}
+
+/// Rejected clause of a rule, which is built as an output value
+fn invalid_rule(field: &str, message: String) -> DomainError {
+    DomainError::invalid_field(EntityKind::OutputValue, None, FieldError::new(field, message))
+}
+
+fn unknown_parameter(rule: usize, direction: &str, name: &str) -> DomainError {
+    let field = match direction {
+        "input" => "antecedents",
+        _ => "consequent",
+    };
+    invalid_rule(
+        field,
+        format!("Rule {} refers to unknown {} parameter '{}'", rule + 1, direction, name),
+    )
+}
+
+fn unknown_term(rule: usize, field: &str, parameter: &str, term: &str) -> DomainError {
+    invalid_rule(
+        field,
+        format!("Rule {} refers to unknown term '{}' of '{}'", rule + 1, term, parameter),
+    )
+}
+
+/// A problem held in memory together with its rules, ready to evaluate
+#[derive(Debug, Clone)]
+pub struct FuzzySystem {
+    problem: Problem,
+}
+
+impl FuzzySystem {
+    /// Wraps a problem loaded elsewhere, e.g. deserialized from a stored definition
+    pub fn from_problem(problem: Problem) -> Self {
+        Self { problem }
+    }
+
+    pub fn problem(&self) -> &Problem {
+        &self.problem
+    }
+
+    pub fn into_problem(self) -> Problem {
+        self.problem
+    }
+
+    /// Id of the input parameter named `name`
+    pub fn input_id(&self, name: &str) -> Option<i64> {
+        self.problem
+            .input_parameters
+            .iter()
+            .find(|ip| ip.name == name)
+            .map(|ip| ip.id)
+    }
+
+    /// Evaluates crisp inputs given by parameter name; categorical inputs take
+    /// the index of their category, in the order the categories were given
+    pub fn evaluate(
+        &self,
+        inputs: &[(&str, f32)],
+        method: DefuzzificationMethod,
+        resolution: usize,
+    ) -> Result<FuzzyEvaluationResult, DomainError> {
+        let inputs = inputs
+            .iter()
+            .map(|(name, value)| {
+                let input_param = self
+                    .problem
+                    .input_parameters
+                    .iter()
+                    .find(|ip| ip.name == *name)
+                    .ok_or_else(|| {
+                        DomainError::invalid_field(
+                            EntityKind::InputParameter,
+                            None,
+                            FieldError::new("name", format!("Unknown input parameter '{}'", name)),
+                        )
+                    })?;
+                let input_value_id = match input_param.kind {
+                    ParameterKind::Continuous => None,
+                    ParameterKind::Categorical => {
+                        category_index(*value, input_param.input_values.len())
+                            .map(|index| input_param.input_values[index].id)
+                    }
+                };
+                Ok(FuzzyEvaluationInput {
+                    input_parameter_id: input_param.id,
+                    crisp_value: *value,
+                    uncertainty: None,
+                    input_value_id,
+                    category_degrees: None,
+                })
+            })
+            .collect::<Result<Vec<_>, DomainError>>()?;
+
+        self.evaluate_inputs(&inputs, method, resolution)
+    }
+
+    /// Evaluates inputs addressed by parameter id, with uncertainty or category degrees
+    pub fn evaluate_inputs(
+        &self,
+        inputs: &[FuzzyEvaluationInput],
+        method: DefuzzificationMethod,
+        resolution: usize,
+    ) -> Result<FuzzyEvaluationResult, DomainError> {
+        evaluate(&self.problem, inputs, method, resolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tipping() -> FuzzySystemBuilder {
+        FuzzySystemBuilder::new("Tipping")
+            .input(
+                ParameterBuilder::new("service", 0.0, 10.0)
+                    .trapezoid("poor", 0.0, 0.0, 2.0, 5.0)
+                    .triangle("good", 2.0, 5.0, 8.0)
+                    .trapezoid("excellent", 5.0, 8.0, 10.0, 10.0),
+            )
+            .input(ParameterBuilder::categorical("food", &["rancid", "delicious"]))
+            .output(
+                ParameterBuilder::new("tip", 0.0, 30.0)
+                    .unit("%")
+                    .precision(1)
+                    .triangle("cheap", 0.0, 5.0, 10.0)
+                    .triangle("average", 10.0, 15.0, 20.0)
+                    .triangle("generous", 20.0, 25.0, 30.0),
+            )
+            .rule(RuleBuilder::when("service", "poor").and("food", "rancid").then("tip", "cheap"))
+            .rule(RuleBuilder::when("service", "good").then("tip", "average"))
+            .rule(
+                RuleBuilder::when("service", "excellent")
+                    .and("food", "delicious")
+                    .then("tip", "generous"),
+            )
+    }
+
+    #[test]
+    fn builds_problem_with_rules_referring_to_term_ids() {
+        let system = tipping().build().unwrap();
+        let problem = system.problem();
+
+        assert_eq!(problem.input_parameters.len(), 2);
+        assert_eq!(problem.output_values.len(), 3);
+        let poor = problem.input_parameters[0].input_values[0].id;
+        let rancid = problem.input_parameters[1].input_values[0].id;
+        assert_eq!(
+            problem.output_values[0].input_value_ids,
+            format!("|{}||{}|", poor, rancid)
+        );
+        assert_eq!(problem.input_parameters[1].kind, ParameterKind::Categorical);
+    }
+
+    #[test]
+    fn evaluates_by_parameter_name() {
+        let system = tipping().build().unwrap();
+
+        let generous = system
+            .evaluate(&[("service", 9.5), ("food", 1.0)], DefuzzificationMethod::Centroid, 500)
+            .unwrap();
+        let cheap = system
+            .evaluate(&[("service", 0.5), ("food", 0.0)], DefuzzificationMethod::Centroid, 500)
+            .unwrap();
+
+        assert!((generous.outputs[0].crisp_value - 25.0).abs() < 0.5);
+        assert!((cheap.outputs[0].crisp_value - 5.0).abs() < 0.5);
+        assert!(generous.outputs[0].display_value.ends_with(" %"));
+    }
+
+    #[test]
+    fn hedges_are_stored_on_the_rule() {
+        let system = tipping()
+            .rule(
+                RuleBuilder::when("service", "poor")
+                    .hedged(Hedge::Very)
+                    .then("tip", "cheap")
+                    .hedged(Hedge::Somewhat),
+            )
+            .build()
+            .unwrap();
+
+        let rule = system.problem().output_values.last().unwrap();
+        let poor = system.problem().input_parameters[0].input_values[0].id;
+        assert_eq!(rule.antecedent_hedges, format!("|{}:very|", poor));
+        assert_eq!(rule.consequent_hedge.as_deref(), Some("somewhat"));
+    }
+
+    #[test]
+    fn rejects_rules_with_unknown_terms() {
+        let result = tipping()
+            .rule(RuleBuilder::when("service", "bad").then("tip", "cheap"))
+            .build();
+
+        match result {
+            Err(DomainError::InvalidFields { entity, fields, .. }) => {
+                assert_eq!(entity, EntityKind::OutputValue);
+                assert_eq!(fields[0].field, "antecedents");
+            }
+            other => panic!("{:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn rejects_missing_inputs_and_unknown_names() {
+        let system = tipping().build().unwrap();
+
+        assert!(system
+            .evaluate(&[("service", 3.0)], DefuzzificationMethod::Centroid, 100)
+            .is_err());
+        assert!(system
+            .evaluate(&[("service", 3.0), ("drinks", 1.0)], DefuzzificationMethod::Centroid, 100)
+            .is_err());
+    }
+}
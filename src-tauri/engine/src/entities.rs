@@ -0,0 +1,11 @@
+pub mod fuzzy_output_value;
+pub mod input_parameter;
+pub mod input_value;
+pub mod lower_membership;
+pub mod output_parameter;
+pub mod output_value;
+pub mod parameter_kind;
+pub mod parameter_link;
+pub mod parameter_metadata;
+pub mod partition_mode;
+pub mod problem;
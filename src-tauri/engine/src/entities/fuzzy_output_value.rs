@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::entities::lower_membership::LowerMembership;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FuzzyOutputValue {
     pub id: i64,
@@ -10,4 +12,7 @@ pub struct FuzzyOutputValue {
     pub c: f32,
     pub d: f32,
     pub is_triangle: bool,
+    /// Lower membership function making this an interval type-2 term
+    #[serde(default)]
+    pub lower: Option<LowerMembership>,
 }
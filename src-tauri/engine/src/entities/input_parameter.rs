@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{
+    input_value::InputValue, parameter_kind::ParameterKind, parameter_metadata::ParameterMetadata,
+    partition_mode::PartitionMode,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputParameter {
+    pub id: i64,
+    pub problem_id: i64,
+    pub name: String,
+    pub start: f32,
+    pub end: f32,
+    /// Whether term edits keep a Ruspini partition of the range
+    #[serde(default)]
+    pub partition_mode: PartitionMode,
+    /// Whether the parameter is numeric or takes one of its terms as a category
+    #[serde(default)]
+    pub kind: ParameterKind,
+    /// Unit, description and display settings
+    #[serde(default)]
+    pub metadata: ParameterMetadata,
+    pub input_values: Vec<InputValue>,
+}
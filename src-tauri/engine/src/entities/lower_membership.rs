@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Lower membership function of an interval type-2 term. The term's own
+/// trapezoid is the upper membership function; the region between the two
+/// is the footprint of uncertainty.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LowerMembership {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub is_triangle: bool,
+    /// Peak degree of the lower function, in (0, 1]
+    pub height: f32,
+}
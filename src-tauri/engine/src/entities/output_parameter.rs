@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{
+    fuzzy_output_value::FuzzyOutputValue, parameter_metadata::ParameterMetadata, partition_mode::PartitionMode,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputParameter {
+    pub id: i64,
+    pub problem_id: i64,
+    pub name: String,
+    pub start: f32,
+    pub end: f32,
+    /// Whether term edits keep a Ruspini partition of the range
+    #[serde(default)]
+    pub partition_mode: PartitionMode,
+    /// Unit, description and display settings
+    #[serde(default)]
+    pub metadata: ParameterMetadata,
+    pub fuzzy_output_values: Vec<FuzzyOutputValue>,
+}
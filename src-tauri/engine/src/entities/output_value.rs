@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputValue {
+    pub id: i64,
+    pub output_parameter_id: i64,
+    pub fuzzy_output_value_id: Option<i64>,
+    pub input_value_ids: String,
+    /// Hedges on antecedent terms as "|input_value_id:hedge|" pairs; terms without one are used plain
+    #[serde(default)]
+    pub antecedent_hedges: String,
+    /// Hedge applied to the consequent term's shape before aggregation
+    #[serde(default)]
+    pub consequent_hedge: Option<String>,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// What values an input parameter takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParameterKind {
+    /// A number in the parameter's range, fuzzified against the term shapes
+    #[default]
+    Continuous,
+    /// One of a fixed set of categories; the terms are the categories themselves
+    /// and their shapes are not used
+    Categorical,
+}
+
+impl ParameterKind {
+    /// Name as used by the frontend and in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParameterKind::Continuous => "continuous",
+            ParameterKind::Categorical => "categorical",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "continuous" | "numeric" => Some(ParameterKind::Continuous),
+            "categorical" | "discrete" => Some(ParameterKind::Categorical),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Feeds the crisp value of an output parameter of one problem into an input
+/// parameter of another, chaining problems into a hierarchical system.
+/// An input parameter is fed by at most one link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterLink {
+    pub id: i64,
+    pub output_parameter_id: i64,
+    pub input_parameter_id: i64,
+    /// Problem owning the output parameter; filled on read
+    pub source_problem_id: i64,
+    /// Problem owning the input parameter; filled on read
+    pub target_problem_id: i64,
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::FieldError;
+
+/// Descriptive data of a parameter that does not take part in inference
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ParameterMetadata {
+    /// Physical unit, e.g. "°C" or "kPa"; empty for dimensionless parameters
+    #[serde(default)]
+    pub unit: String,
+    #[serde(default)]
+    pub description: String,
+    /// Number of decimals values are displayed with; `None` leaves them unrounded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<u8>,
+    /// Value an evaluation starts from; only input parameters have one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<f32>,
+}
+
+impl ParameterMetadata {
+    /// Most decimals a value can be displayed with
+    pub const MAX_PRECISION: u8 = 10;
+
+    /// Checks the display settings and that the default value lies in [`start`, `end`]
+    pub fn check(&self, start: f32, end: f32) -> Result<(), FieldError> {
+        if let Some(precision) = self.precision {
+            if precision > Self::MAX_PRECISION {
+                return Err(FieldError::new(
+                    "precision",
+                    format!("Precision must be at most {} decimals, got {}", Self::MAX_PRECISION, precision),
+                ));
+            }
+        }
+        if let Some(value) = self.default_value {
+            if !value.is_finite() || value < start || value > end {
+                return Err(FieldError::new(
+                    "default_value",
+                    format!("Default value {} lies outside of the range [{}, {}]", value, start, end),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Formats `value` with the parameter's precision and unit, e.g. "21.5 °C"
+    pub fn format_value(&self, value: f32) -> String {
+        let number = match self.precision {
+            Some(precision) => format!("{:.*}", precision as usize, value),
+            None => value.to_string(),
+        };
+        if self.unit.is_empty() {
+            number
+        } else {
+            format!("{} {}", number, self.unit)
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// How the terms of a parameter relate to each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionMode {
+    /// Terms form a Ruspini partition of the range; creating, editing or removing a
+    /// term reshapes its neighbours to keep it
+    #[default]
+    Ruspini,
+    /// Terms are stored exactly as given and may overlap, leave gaps or nest
+    FreeForm,
+}
+
+impl PartitionMode {
+    /// Name as used by the frontend and in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PartitionMode::Ruspini => "ruspini",
+            PartitionMode::FreeForm => "free_form",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace(['-', ' '], "_").as_str() {
+            "ruspini" => Some(PartitionMode::Ruspini),
+            "free_form" | "free" => Some(PartitionMode::FreeForm),
+            _ => None,
+        }
+    }
+}
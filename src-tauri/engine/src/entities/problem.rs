@@ -1,8 +1,7 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::{
-    image::Image, input_parameter::InputParameter, output_parameter::OutputParameter,
-    output_value::OutputValue,
+use crate::entities::{
+    input_parameter::InputParameter, output_parameter::OutputParameter, output_value::OutputValue,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,5 +17,4 @@ pub struct Problem {
     pub input_parameters: Vec<InputParameter>,
     pub output_parameters: Vec<OutputParameter>,
     pub output_values: Vec<OutputValue>,
-    pub image: Option<Image>,
 }
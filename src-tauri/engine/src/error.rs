@@ -0,0 +1,105 @@
+use std::error::Error as StdError;
+use std::fmt;
+
+use serde::Serialize;
+
+/// Kind of engine entity an error is about; applications map it onto their own kinds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKind {
+    Problem,
+    InputParameter,
+    InputValue,
+    OutputParameter,
+    FuzzyOutputValue,
+    OutputValue,
+    ParameterLink,
+}
+
+impl EntityKind {
+    /// Name of the kind as used in messages, e.g. "Input parameter"
+    pub fn label(&self) -> &'static str {
+        match self {
+            EntityKind::Problem => "Problem",
+            EntityKind::InputParameter => "Input parameter",
+            EntityKind::InputValue => "Input value",
+            EntityKind::OutputParameter => "Output parameter",
+            EntityKind::FuzzyOutputValue => "Fuzzy output value",
+            EntityKind::OutputValue => "Output value",
+            EntityKind::ParameterLink => "Parameter link",
+        }
+    }
+}
+
+/// A rejected value of a single field
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    /// Name of the field as in requests, e.g. "name" or "default_value"
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DomainError {
+    NotFound {
+        entity: EntityKind,
+        id: i64,
+    },
+    Validation(String),
+    /// Values of particular fields of an entity were rejected; `id` is `None` for
+    /// entities that are being created
+    InvalidFields {
+        entity: EntityKind,
+        id: Option<i64>,
+        fields: Vec<FieldError>,
+    },
+    Data(String),
+    Internal(String),
+    /// The operation was stopped on request before it finished
+    Cancelled(String),
+}
+
+impl DomainError {
+    pub fn not_found(entity: EntityKind, id: i64) -> Self {
+        DomainError::NotFound { entity, id }
+    }
+
+    /// A single rejected field
+    pub fn invalid_field(entity: EntityKind, id: Option<i64>, error: FieldError) -> Self {
+        DomainError::InvalidFields {
+            entity,
+            id,
+            fields: vec![error],
+        }
+    }
+}
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DomainError::NotFound { entity, id } => write!(f, "Not found: {} {}", entity.label(), id),
+            DomainError::Validation(msg) => write!(f, "Validation error: {}", msg),
+            DomainError::InvalidFields { fields, .. } => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|e| format!("{}: {}", e.field, e.message))
+                    .collect();
+                write!(f, "Validation error: {}", fields.join("; "))
+            }
+            DomainError::Data(msg) => write!(f, "Data error: {}", msg),
+            DomainError::Internal(msg) => write!(f, "Internal error: {}", msg),
+            DomainError::Cancelled(msg) => write!(f, "Cancelled: {}", msg),
+        }
+    }
+}
+
+impl StdError for DomainError {}
@@ -0,0 +1,410 @@
+use crate::{
+    entities::{input_parameter::InputParameter, parameter_kind::ParameterKind, problem::Problem},
+    error::{DomainError, EntityKind, FieldError},
+    services::{
+        defuzzification::{build_clipped_sets, defuzzify, DefuzzificationMethod},
+        fuzzification::{
+            fuzzify_categorical_input, fuzzify_uncertain_input, CategoryDegree,
+            FuzzifiedParameter, InputUncertainty,
+        },
+        definition_hash::problem_definition_hash,
+        inference::{aggregate_fired_rules, evaluate_rules},
+        linguistic_approximation::{
+            approximate_output, refuzzify_output, LinguisticApproximation, TermDegree,
+        },
+    },
+};
+
+/// Crisp input of one input parameter
+#[derive(Debug, Clone)]
+pub struct FuzzyEvaluationInput {
+    pub input_parameter_id: i64,
+    pub crisp_value: f32,
+    /// Fuzzy number around `crisp_value`; `None` treats the value as exact
+    pub uncertainty: Option<InputUncertainty>,
+    /// Selected category of a categorical parameter, whose `crisp_value` is unused
+    pub input_value_id: Option<i64>,
+    /// Degree per category of a categorical parameter; `None` selects the
+    /// category `input_value_id`
+    pub category_degrees: Option<Vec<CategoryDegree>>,
+}
+
+/// Complete result of fuzzy inference for one output parameter
+#[derive(Debug, Clone)]
+pub struct FuzzyEvaluationOutputResult {
+    pub output_parameter_id: i64,
+    pub output_parameter_name: String,
+    /// Unit of the output parameter, empty if it has none
+    pub unit: String,
+    pub crisp_value: f32,
+    /// Crisp value with the parameter's precision and unit, e.g. "21.5 °C"
+    pub display_value: String,
+    pub fuzzified_inputs: Vec<FuzzifiedInputInfo>,
+    pub fired_rules_count: usize,
+    /// Degrees of the crisp value in the output terms, highest first
+    pub linguistic_terms: Vec<TermDegree>,
+    /// Term best describing the aggregated output set; `None` if no rule fired
+    pub linguistic_approximation: Option<LinguisticApproximation>,
+}
+
+/// Information about a fuzzified input for debugging/display
+#[derive(Debug, Clone)]
+pub struct FuzzifiedInputInfo {
+    pub input_parameter_id: i64,
+    pub input_parameter_name: String,
+    pub unit: String,
+    pub crisp_value: f32,
+    pub display_value: String,
+    pub membership_degrees: Vec<(String, f32)>, // (linguistic_term_name, degree)
+}
+
+/// Complete result of the fuzzy evaluation
+#[derive(Debug, Clone)]
+pub struct FuzzyEvaluationResult {
+    pub problem_id: i64,
+    pub problem_name: String,
+    pub outputs: Vec<FuzzyEvaluationOutputResult>,
+    /// Fingerprint of the problem definition the result was computed from
+    pub definition_hash: String,
+}
+
+/// Runs the full fuzzy inference cycle on a problem:
+/// 1. Fuzzification - convert crisp inputs to membership degrees
+/// 2. Rule Evaluation - evaluate all rules using min T-norm
+/// 3. Aggregation - combine fired rules using max S-norm
+/// 4. Defuzzification - convert aggregated output to crisp value
+/// 5. Interpretation - describe the crisp value and the aggregated output in terms
+///
+/// # Arguments
+/// * `problem` - The problem with all its parameters and rules
+/// * `inputs` - Crisp input values for each input parameter
+/// * `method` - The defuzzification method to use
+/// * `resolution` - Number of discrete points for numerical integration
+pub fn evaluate(
+    problem: &Problem,
+    inputs: &[FuzzyEvaluationInput],
+    method: DefuzzificationMethod,
+    resolution: usize,
+) -> Result<FuzzyEvaluationResult, DomainError> {
+    // Validate that all input parameters are provided
+    validate_inputs(problem, inputs)?;
+
+    // Step 1: Fuzzification
+    let fuzzified_params = fuzzify_all(problem, inputs);
+
+    // Build fuzzified input info for the result
+    let fuzzified_inputs_info = build_fuzzified_info(problem, inputs, &fuzzified_params);
+
+    // Step 2-4: Evaluate each output parameter
+    let outputs: Vec<FuzzyEvaluationOutputResult> = problem
+        .output_parameters
+        .iter()
+        .map(|output_param| {
+            // Step 2: Rule Evaluation
+            let inference_result =
+                evaluate_rules(&fuzzified_params, &problem.output_values, output_param.id);
+
+            // Step 3: Aggregation
+            let aggregated = aggregate_fired_rules(&inference_result);
+
+            // Step 4: Defuzzification
+            let defuzz_result = defuzzify(
+                &aggregated,
+                &output_param.fuzzy_output_values,
+                output_param.id,
+                output_param.start,
+                output_param.end,
+                method,
+                resolution,
+            );
+
+            // Linguistic interpretation of the crisp value and the aggregated set
+            let linguistic_terms = refuzzify_output(
+                defuzz_result.crisp_value,
+                &output_param.fuzzy_output_values,
+            );
+            let linguistic_approximation = approximate_output(
+                &build_clipped_sets(&aggregated, &output_param.fuzzy_output_values),
+                &output_param.fuzzy_output_values,
+                output_param.start,
+                output_param.end,
+                resolution,
+            );
+
+            FuzzyEvaluationOutputResult {
+                output_parameter_id: output_param.id,
+                output_parameter_name: output_param.name.clone(),
+                unit: output_param.metadata.unit.clone(),
+                crisp_value: defuzz_result.crisp_value,
+                display_value: output_param.metadata.format_value(defuzz_result.crisp_value),
+                fuzzified_inputs: fuzzified_inputs_info.clone(),
+                fired_rules_count: inference_result.fired_rules.len(),
+                linguistic_terms,
+                linguistic_approximation,
+            }
+        })
+        .collect();
+
+    Ok(FuzzyEvaluationResult {
+        problem_id: problem.id,
+        definition_hash: problem_definition_hash(problem),
+        problem_name: problem.name.clone(),
+        outputs,
+    })
+}
+
+/// Fuzzifies all input values
+fn fuzzify_all(
+    problem: &Problem,
+    inputs: &[FuzzyEvaluationInput],
+) -> Vec<FuzzifiedParameter> {
+    inputs
+        .iter()
+        .filter_map(|input| {
+            problem
+                .input_parameters
+                .iter()
+                .find(|ip| ip.id == input.input_parameter_id)
+                .map(|input_param| fuzzify_parameter_input(input_param, input))
+        })
+        .collect()
+}
+
+/// Builds fuzzified input info for the result
+fn build_fuzzified_info(
+    problem: &Problem,
+    inputs: &[FuzzyEvaluationInput],
+    fuzzified_params: &[FuzzifiedParameter],
+) -> Vec<FuzzifiedInputInfo> {
+    fuzzified_params
+        .iter()
+        .filter_map(|fp| {
+            let input_param = problem
+                .input_parameters
+                .iter()
+                .find(|ip| ip.id == fp.input_parameter_id)?;
+
+            let input = inputs
+                .iter()
+                .find(|i| i.input_parameter_id == fp.input_parameter_id)?;
+
+            let membership_degrees: Vec<(String, f32)> = fp
+                .fuzzified_values
+                .iter()
+                .filter_map(|fv| {
+                    input_param
+                        .input_values
+                        .iter()
+                        .find(|iv| iv.id == fv.input_value_id)
+                        .map(|iv| (iv.value.clone(), fv.membership_degree))
+                })
+                .collect();
+
+            Some(FuzzifiedInputInfo {
+                input_parameter_id: fp.input_parameter_id,
+                input_parameter_name: input_param.name.clone(),
+                unit: input_param.metadata.unit.clone(),
+                crisp_value: input.crisp_value,
+                display_value: input_display_value(input_param, input),
+                membership_degrees,
+            })
+        })
+        .collect()
+}
+
+/// Crisp value of every output parameter, in the problem's order, for inputs already
+/// checked by [`validate_inputs`].
+///
+/// The bare inference cycle without the explanations `execute` adds, for evaluating
+/// many points against one snapshot of a problem.
+pub fn infer_crisp_outputs(
+    problem: &Problem,
+    inputs: &[FuzzyEvaluationInput],
+    method: DefuzzificationMethod,
+    resolution: usize,
+) -> Vec<f32> {
+    let fuzzified_params = fuzzify_all(problem, inputs);
+
+    problem
+        .output_parameters
+        .iter()
+        .map(|output_param| {
+            let inference_result =
+                evaluate_rules(&fuzzified_params, &problem.output_values, output_param.id);
+            let aggregated = aggregate_fired_rules(&inference_result);
+            defuzzify(
+                &aggregated,
+                &output_param.fuzzy_output_values,
+                output_param.id,
+                output_param.start,
+                output_param.end,
+                method,
+                resolution,
+            )
+            .crisp_value
+        })
+        .collect()
+}
+
+/// Type-1 fuzzification of an input, by category for categorical parameters
+pub fn fuzzify_parameter_input(
+    input_param: &InputParameter,
+    input: &FuzzyEvaluationInput,
+) -> FuzzifiedParameter {
+    match input_param.kind {
+        ParameterKind::Continuous => fuzzify_uncertain_input(
+            input.crisp_value,
+            input.uncertainty,
+            input_param.id,
+            &input_param.input_values,
+        ),
+        ParameterKind::Categorical => fuzzify_categorical_input(
+            input.input_value_id,
+            input.category_degrees.as_deref(),
+            input_param.id,
+            &input_param.input_values,
+        ),
+    }
+}
+
+/// Input as shown to the user: the formatted number, the selected category or
+/// the categories with their supplied degrees
+pub fn input_display_value(input_param: &InputParameter, input: &FuzzyEvaluationInput) -> String {
+    if input_param.kind == ParameterKind::Continuous {
+        return input_param.metadata.format_value(input.crisp_value);
+    }
+    match &input.category_degrees {
+        Some(degrees) => input_param
+            .input_values
+            .iter()
+            .filter_map(|iv| {
+                let degree = degrees.iter().find(|d| d.input_value_id == iv.id)?.degree;
+                (degree > 0.0).then(|| format!("{} ({})", iv.value, degree))
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        None => input_param
+            .input_values
+            .iter()
+            .find(|iv| Some(iv.id) == input.input_value_id)
+            .map(|iv| iv.value.clone())
+            .unwrap_or_default(),
+    }
+}
+
+/// Validates that all required input parameters are provided
+pub fn validate_inputs(
+    problem: &Problem,
+    inputs: &[FuzzyEvaluationInput],
+) -> Result<(), DomainError> {
+    for input_param in &problem.input_parameters {
+        if !inputs.iter().any(|i| i.input_parameter_id == input_param.id) {
+            return Err(invalid_input(
+                input_param.id,
+                "crisp_value",
+                format!("Missing input value for parameter '{}'", input_param.name),
+            ));
+        }
+    }
+    for input in inputs {
+        let input_param = problem
+            .input_parameters
+            .iter()
+            .find(|ip| ip.id == input.input_parameter_id);
+        if let Some(input_param) = input_param {
+            validate_category(input_param, input)?;
+        }
+        if let Some(uncertainty) = input.uncertainty {
+            let width = uncertainty.width();
+            if !width.is_finite() || width < 0.0 {
+                return Err(invalid_input(
+                    input.input_parameter_id,
+                    "uncertainty",
+                    format!("Uncertainty must be a non-negative number, got {}", width),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Rejected field of the input of input parameter `input_parameter_id`
+fn invalid_input(input_parameter_id: i64, field: &str, message: String) -> DomainError {
+    DomainError::invalid_field(
+        EntityKind::InputParameter,
+        Some(input_parameter_id),
+        FieldError::new(field, message),
+    )
+}
+
+/// Checks that categorical parameters select one of their own categories or give
+/// them degrees, and that only they do
+fn validate_category(input_param: &InputParameter, input: &FuzzyEvaluationInput) -> Result<(), DomainError> {
+    if input_param.kind == ParameterKind::Continuous {
+        if input.input_value_id.is_some() || input.category_degrees.is_some() {
+            let field = match input.input_value_id {
+                Some(_) => "input_value_id",
+                None => "category_degrees",
+            };
+            return Err(invalid_input(
+                input_param.id,
+                field,
+                format!(
+                    "Parameter '{}' is not categorical and takes no category",
+                    input_param.name
+                ),
+            ));
+        }
+        return Ok(());
+    }
+
+    if input.uncertainty.is_some() {
+        return Err(invalid_input(
+            input_param.id,
+            "uncertainty",
+            format!("Categorical parameter '{}' takes no uncertainty", input_param.name),
+        ));
+    }
+    match &input.category_degrees {
+        Some(degrees) => {
+            for degree in degrees {
+                if !input_param.input_values.iter().any(|iv| iv.id == degree.input_value_id) {
+                    return Err(invalid_input(
+                        input_param.id,
+                        "category_degrees",
+                        format!(
+                            "Term {} is not a category of '{}'",
+                            degree.input_value_id, input_param.name
+                        ),
+                    ));
+                }
+                if !(0.0..=1.0).contains(&degree.degree) {
+                    return Err(invalid_input(
+                        input_param.id,
+                        "category_degrees",
+                        format!(
+                            "Category degrees of '{}' must lie in [0, 1], got {}",
+                            input_param.name, degree.degree
+                        ),
+                    ));
+                }
+            }
+        }
+        None => {
+            let selected = input
+                .input_value_id
+                .is_some_and(|id| input_param.input_values.iter().any(|iv| iv.id == id));
+            if !selected {
+                return Err(invalid_input(
+                    input_param.id,
+                    "input_value_id",
+                    format!(
+                        "Input of categorical parameter '{}' must select one of its categories, got {:?}",
+                        input_param.name, input.input_value_id
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
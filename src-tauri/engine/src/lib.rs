@@ -0,0 +1,5 @@
+pub mod builder;
+pub mod entities;
+pub mod error;
+pub mod evaluation;
+pub mod services;
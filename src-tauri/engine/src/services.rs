@@ -0,0 +1,14 @@
+pub mod membership_function;
+pub mod fuzzification;
+pub mod inference;
+pub mod defuzzification;
+pub mod fuzzy_partition;
+pub mod definition_hash;
+pub mod problem_diff;
+pub mod problem_merge;
+pub mod problem_chain;
+pub mod hedge;
+pub mod linguistic_approximation;
+pub mod interval_type2;
+pub mod range_change;
+pub mod parallel_evaluation;
@@ -0,0 +1,312 @@
+use crate::{
+    entities::{lower_membership::LowerMembership, parameter_kind::ParameterKind, problem::Problem},
+    services::hedge::parse_antecedent_hedges,
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Incremental FNV-1a hasher.
+///
+/// `std::collections::hash_map::DefaultHasher` is not guaranteed to be stable
+/// between Rust releases, while stored hashes must stay comparable forever.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_i64(&mut self, value: i64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_f32(&mut self, value: f32) {
+        self.write(&value.to_bits().to_le_bytes());
+    }
+
+    fn write_str(&mut self, value: &str) {
+        // Length prefix keeps ("ab", "c") and ("a", "bc") apart
+        self.write_i64(value.len() as i64);
+        self.write(value.as_bytes());
+    }
+
+    fn write_bool(&mut self, value: bool) {
+        self.write(&[value as u8]);
+    }
+
+    /// Only interval type-2 terms contribute, so hashes of type-1 problems stay unchanged
+    fn write_lower(&mut self, lower: Option<&LowerMembership>) {
+        if let Some(lower) = lower {
+            self.write_f32(lower.a);
+            self.write_f32(lower.b);
+            self.write_f32(lower.c);
+            self.write_f32(lower.d);
+            self.write_bool(lower.is_triangle);
+            self.write_f32(lower.height);
+        }
+    }
+}
+
+/// Computes a stable fingerprint of everything that influences inference results:
+/// parameters with their ranges, linguistic terms and the rule base.
+///
+/// Problem name, description, image and timestamps are not part of the definition.
+/// Items are hashed in id order, so the fingerprint does not depend on the order
+/// in which the repository returned them.
+///
+/// # Returns
+/// 16 hex digits of the 64-bit FNV-1a hash
+pub fn problem_definition_hash(problem: &Problem) -> String {
+    let mut hasher = Fnv1a::new();
+
+    let mut input_parameters: Vec<_> = problem.input_parameters.iter().collect();
+    input_parameters.sort_by_key(|ip| ip.id);
+    hasher.write_i64(input_parameters.len() as i64);
+    for ip in input_parameters {
+        hasher.write_i64(ip.id);
+        hasher.write_str(&ip.name);
+        hasher.write_f32(ip.start);
+        hasher.write_f32(ip.end);
+
+        let mut terms: Vec<_> = ip.input_values.iter().collect();
+        terms.sort_by_key(|iv| iv.id);
+        hasher.write_i64(terms.len() as i64);
+        for iv in terms {
+            hasher.write_i64(iv.id);
+            hasher.write_str(&iv.value);
+            hasher.write_f32(iv.a);
+            hasher.write_f32(iv.b);
+            hasher.write_f32(iv.c);
+            hasher.write_f32(iv.d);
+            hasher.write_bool(iv.is_triangle);
+            hasher.write_lower(iv.lower.as_ref());
+        }
+
+        // A categorical parameter is evaluated by category instead of by shape, so
+        // it contributes its kind; numeric ones keep their old hashes
+        if ip.kind == ParameterKind::Categorical {
+            hasher.write_str(ip.kind.as_str());
+        }
+    }
+
+    let mut output_parameters: Vec<_> = problem.output_parameters.iter().collect();
+    output_parameters.sort_by_key(|op| op.id);
+    hasher.write_i64(output_parameters.len() as i64);
+    for op in output_parameters {
+        hasher.write_i64(op.id);
+        hasher.write_str(&op.name);
+        hasher.write_f32(op.start);
+        hasher.write_f32(op.end);
+
+        let mut terms: Vec<_> = op.fuzzy_output_values.iter().collect();
+        terms.sort_by_key(|fov| fov.id);
+        hasher.write_i64(terms.len() as i64);
+        for fov in terms {
+            hasher.write_i64(fov.id);
+            hasher.write_str(&fov.value);
+            hasher.write_f32(fov.a);
+            hasher.write_f32(fov.b);
+            hasher.write_f32(fov.c);
+            hasher.write_f32(fov.d);
+            hasher.write_bool(fov.is_triangle);
+            hasher.write_lower(fov.lower.as_ref());
+        }
+    }
+
+    let mut rules: Vec<_> = problem.output_values.iter().collect();
+    rules.sort_by_key(|ov| ov.id);
+    hasher.write_i64(rules.len() as i64);
+    for ov in rules {
+        hasher.write_i64(ov.id);
+        hasher.write_i64(ov.output_parameter_id);
+        hasher.write_i64(ov.fuzzy_output_value_id.unwrap_or(-1));
+
+        let mut input_value_ids: Vec<i64> = ov
+            .input_value_ids
+            .split('|')
+            .filter_map(|s| s.parse::<i64>().ok())
+            .collect();
+        input_value_ids.sort();
+        hasher.write_i64(input_value_ids.len() as i64);
+        for id in input_value_ids {
+            hasher.write_i64(id);
+        }
+
+        // Only hedged rules contribute, so hashes of unhedged problems stay unchanged
+        let mut antecedent_hedges = parse_antecedent_hedges(&ov.antecedent_hedges);
+        antecedent_hedges.sort_by_key(|(id, _)| *id);
+        if !antecedent_hedges.is_empty() || ov.consequent_hedge.is_some() {
+            hasher.write_i64(antecedent_hedges.len() as i64);
+            for (id, hedge) in antecedent_hedges {
+                hasher.write_i64(id);
+                hasher.write_str(hedge.as_str());
+            }
+            hasher.write_str(ov.consequent_hedge.as_deref().unwrap_or(""));
+        }
+    }
+
+    format!("{:016x}", hasher.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{
+        fuzzy_output_value::FuzzyOutputValue, input_parameter::InputParameter,
+        input_value::InputValue, output_parameter::OutputParameter, output_value::OutputValue,
+        parameter_kind::ParameterKind, parameter_metadata::ParameterMetadata,
+        partition_mode::PartitionMode,
+    };
+
+    fn create_test_problem() -> Problem {
+        Problem {
+            id: 1,
+            prev_problem_id: None,
+            is_final: true,
+            name: "Test".to_string(),
+            description: String::new(),
+            image_id: None,
+            created_at: String::new(),
+            updated_at: None,
+            input_parameters: vec![InputParameter {
+                id: 10,
+                problem_id: 1,
+                name: "x".to_string(),
+                start: 0.0,
+                end: 10.0,
+                partition_mode: PartitionMode::Ruspini,
+                kind: ParameterKind::Continuous,
+                metadata: ParameterMetadata::default(),
+                input_values: vec![
+                    InputValue {
+                        id: 100,
+                        input_parameter_id: 10,
+                        value: "Low".to_string(),
+                        a: 0.0,
+                        b: 0.0,
+                        c: 2.0,
+                        d: 8.0,
+                        is_triangle: false,
+                        lower: None,
+                    },
+                    InputValue {
+                        id: 101,
+                        input_parameter_id: 10,
+                        value: "High".to_string(),
+                        a: 2.0,
+                        b: 8.0,
+                        c: 10.0,
+                        d: 10.0,
+                        is_triangle: false,
+                        lower: None,
+                    },
+                ],
+            }],
+            output_parameters: vec![OutputParameter {
+                id: 20,
+                problem_id: 1,
+                name: "y".to_string(),
+                start: 0.0,
+                end: 1.0,
+                partition_mode: PartitionMode::Ruspini,
+                metadata: ParameterMetadata::default(),
+                fuzzy_output_values: vec![FuzzyOutputValue {
+                    id: 200,
+                    output_parameter_id: 20,
+                    value: "Any".to_string(),
+                    a: 0.0,
+                    b: 0.0,
+                    c: 1.0,
+                    d: 1.0,
+                    is_triangle: false,
+                    lower: None,
+                }],
+            }],
+            output_values: vec![
+                OutputValue {
+                    id: 1000,
+                    output_parameter_id: 20,
+                    fuzzy_output_value_id: Some(200),
+                    input_value_ids: "|100|".to_string(),
+                    antecedent_hedges: String::new(),
+                    consequent_hedge: None,
+                },
+                OutputValue {
+                    id: 1001,
+                    output_parameter_id: 20,
+                    fuzzy_output_value_id: None,
+                    input_value_ids: "|101|".to_string(),
+                    antecedent_hedges: String::new(),
+                    consequent_hedge: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_hash_is_deterministic() {
+        let problem = create_test_problem();
+        let hash = problem_definition_hash(&problem);
+        assert_eq!(hash.len(), 16);
+        assert_eq!(hash, problem_definition_hash(&problem.clone()));
+    }
+
+    #[test]
+    fn test_hash_ignores_metadata_and_order() {
+        let problem = create_test_problem();
+        let mut changed = problem.clone();
+        changed.name = "Renamed".to_string();
+        changed.description = "Other".to_string();
+        changed.updated_at = Some("2024-01-01T00:00:00+00:00".to_string());
+        changed.input_parameters[0].input_values.reverse();
+        changed.output_values.reverse();
+
+        assert_eq!(
+            problem_definition_hash(&problem),
+            problem_definition_hash(&changed)
+        );
+    }
+
+    #[test]
+    fn test_hash_detects_definition_changes() {
+        let problem = create_test_problem();
+        let original = problem_definition_hash(&problem);
+
+        let mut breakpoint = problem.clone();
+        breakpoint.input_parameters[0].input_values[0].c = 2.5;
+        assert_ne!(original, problem_definition_hash(&breakpoint));
+
+        let mut consequent = problem.clone();
+        consequent.output_values[1].fuzzy_output_value_id = Some(200);
+        assert_ne!(original, problem_definition_hash(&consequent));
+
+        let mut range = problem.clone();
+        range.output_parameters[0].end = 2.0;
+        assert_ne!(original, problem_definition_hash(&range));
+
+        let mut hedge = problem.clone();
+        hedge.output_values[0].antecedent_hedges = "|100:very|".to_string();
+        assert_ne!(original, problem_definition_hash(&hedge));
+    }
+
+    #[test]
+    fn test_hash_of_categorical_parameter_ignores_category_order() {
+        let mut problem = create_test_problem();
+        let numeric = problem_definition_hash(&problem);
+        problem.input_parameters[0].kind = ParameterKind::Categorical;
+        let categorical = problem_definition_hash(&problem);
+        assert_ne!(numeric, categorical);
+
+        // Inputs select categories by id, so reordering them changes no result
+        problem.input_parameters[0].input_values.reverse();
+        assert_eq!(categorical, problem_definition_hash(&problem));
+    }
+}
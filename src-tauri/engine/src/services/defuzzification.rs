@@ -1,5 +1,8 @@
-use crate::domain::entities::fuzzy_output_value::FuzzyOutputValue;
-use crate::domain::services::membership_function::calculate_membership;
+use crate::entities::fuzzy_output_value::FuzzyOutputValue;
+use crate::services::{
+    hedge::{apply_hedge, Hedge},
+    membership_function::calculate_membership,
+};
 
 /// Available defuzzification methods
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -16,6 +19,31 @@ pub enum DefuzzificationMethod {
     LargestOfMaximum,
 }
 
+impl DefuzzificationMethod {
+    /// Short name of the method as used by the frontend and in stored records
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DefuzzificationMethod::Centroid => "centroid",
+            DefuzzificationMethod::Bisector => "bisector",
+            DefuzzificationMethod::MeanOfMaximum => "mom",
+            DefuzzificationMethod::SmallestOfMaximum => "som",
+            DefuzzificationMethod::LargestOfMaximum => "lom",
+        }
+    }
+
+    /// Parses a method name, accepting the short names as well as common aliases
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "centroid" | "cog" | "coa" => Some(DefuzzificationMethod::Centroid),
+            "bisector" | "boa" => Some(DefuzzificationMethod::Bisector),
+            "mom" | "mean_of_maximum" => Some(DefuzzificationMethod::MeanOfMaximum),
+            "som" | "smallest_of_maximum" => Some(DefuzzificationMethod::SmallestOfMaximum),
+            "lom" | "largest_of_maximum" => Some(DefuzzificationMethod::LargestOfMaximum),
+            _ => None,
+        }
+    }
+}
+
 /// Result of defuzzification for a single output parameter
 #[derive(Debug, Clone)]
 pub struct DefuzzificationResult {
@@ -25,20 +53,23 @@ pub struct DefuzzificationResult {
 }
 
 /// Calculates the aggregated membership value at a point x
-/// using MAX aggregation (S-norm) of all clipped fuzzy sets
+/// using MAX aggregation (S-norm) of all clipped, optionally hedged, fuzzy sets
 ///
 /// # Arguments
 /// * `x` - The point to evaluate
-/// * `clipped_sets` - Vector of (FuzzyOutputValue, clipping_level) pairs
+/// * `clipped_sets` - Vector of (FuzzyOutputValue, hedge, clipping_level) triples
 ///
 /// # Returns
 /// The maximum membership value at point x after clipping
-fn aggregated_membership_at(x: f32, clipped_sets: &[(FuzzyOutputValue, f32)]) -> f32 {
+pub fn aggregated_membership_at(x: f32, clipped_sets: &[(FuzzyOutputValue, Option<Hedge>, f32)]) -> f32 {
     clipped_sets
         .iter()
-        .map(|(fov, clip_level)| {
-            let raw_membership = calculate_membership(x, fov.a, fov.b, fov.c, fov.d, fov.is_triangle);
-            // Clip the membership function at the firing strength
+        .map(|(fov, hedge, clip_level)| {
+            let raw_membership = apply_hedge(
+                *hedge,
+                calculate_membership(x, fov.a, fov.b, fov.c, fov.d, fov.is_triangle),
+            );
+            // Clip the (hedged) membership function at the firing strength
             raw_membership.min(*clip_level)
         })
         .fold(0.0_f32, f32::max)
@@ -51,7 +82,7 @@ fn aggregated_membership_at(x: f32, clipped_sets: &[(FuzzyOutputValue, f32)]) ->
 /// Uses numerical integration with discrete sampling
 ///
 /// # Arguments
-/// * `clipped_sets` - Vector of (FuzzyOutputValue, clipping_level) pairs
+/// * `clipped_sets` - Vector of (FuzzyOutputValue, hedge, clipping_level) triples
 /// * `start` - Start of the universe of discourse
 /// * `end` - End of the universe of discourse
 /// * `resolution` - Number of discrete points for integration
@@ -59,7 +90,7 @@ fn aggregated_membership_at(x: f32, clipped_sets: &[(FuzzyOutputValue, f32)]) ->
 /// # Returns
 /// The defuzzified crisp value
 pub fn defuzzify_centroid(
-    clipped_sets: &[(FuzzyOutputValue, f32)],
+    clipped_sets: &[(FuzzyOutputValue, Option<Hedge>, f32)],
     start: f32,
     end: f32,
     resolution: usize,
@@ -92,7 +123,7 @@ pub fn defuzzify_centroid(
 /// Finds the point x where the area under the curve is divided into two equal parts
 ///
 /// # Arguments
-/// * `clipped_sets` - Vector of (FuzzyOutputValue, clipping_level) pairs
+/// * `clipped_sets` - Vector of (FuzzyOutputValue, hedge, clipping_level) triples
 /// * `start` - Start of the universe of discourse
 /// * `end` - End of the universe of discourse
 /// * `resolution` - Number of discrete points for integration
@@ -100,7 +131,7 @@ pub fn defuzzify_centroid(
 /// # Returns
 /// The defuzzified crisp value
 pub fn defuzzify_bisector(
-    clipped_sets: &[(FuzzyOutputValue, f32)],
+    clipped_sets: &[(FuzzyOutputValue, Option<Hedge>, f32)],
     start: f32,
     end: f32,
     resolution: usize,
@@ -140,7 +171,7 @@ pub fn defuzzify_bisector(
 
 /// Finds all maximum points in the aggregated fuzzy set
 fn find_maximum_points(
-    clipped_sets: &[(FuzzyOutputValue, f32)],
+    clipped_sets: &[(FuzzyOutputValue, Option<Hedge>, f32)],
     start: f32,
     end: f32,
     resolution: usize,
@@ -169,7 +200,7 @@ fn find_maximum_points(
 ///
 /// Returns the mean of all points where the membership function reaches its maximum
 pub fn defuzzify_mean_of_maximum(
-    clipped_sets: &[(FuzzyOutputValue, f32)],
+    clipped_sets: &[(FuzzyOutputValue, Option<Hedge>, f32)],
     start: f32,
     end: f32,
     resolution: usize,
@@ -191,7 +222,7 @@ pub fn defuzzify_mean_of_maximum(
 ///
 /// Returns the smallest point where the membership function reaches its maximum
 pub fn defuzzify_smallest_of_maximum(
-    clipped_sets: &[(FuzzyOutputValue, f32)],
+    clipped_sets: &[(FuzzyOutputValue, Option<Hedge>, f32)],
     start: f32,
     end: f32,
     resolution: usize,
@@ -209,7 +240,7 @@ pub fn defuzzify_smallest_of_maximum(
 ///
 /// Returns the largest point where the membership function reaches its maximum
 pub fn defuzzify_largest_of_maximum(
-    clipped_sets: &[(FuzzyOutputValue, f32)],
+    clipped_sets: &[(FuzzyOutputValue, Option<Hedge>, f32)],
     start: f32,
     end: f32,
     resolution: usize,
@@ -223,10 +254,27 @@ pub fn defuzzify_largest_of_maximum(
     max_points.last().copied().unwrap_or(end)
 }
 
+/// Pairs each aggregated (fuzzy_output_value_id, hedge, firing_strength) with its term,
+/// i.e. the clipped sets whose union is the aggregated output set
+pub fn build_clipped_sets(
+    aggregated_rules: &[(i64, Option<Hedge>, f32)],
+    fuzzy_output_values: &[FuzzyOutputValue],
+) -> Vec<(FuzzyOutputValue, Option<Hedge>, f32)> {
+    aggregated_rules
+        .iter()
+        .filter_map(|(fov_id, hedge, strength)| {
+            fuzzy_output_values
+                .iter()
+                .find(|fov| fov.id == *fov_id)
+                .map(|fov| (fov.clone(), *hedge, *strength))
+        })
+        .collect()
+}
+
 /// Main defuzzification function that dispatches to the appropriate method
 ///
 /// # Arguments
-/// * `aggregated_rules` - Vector of (fuzzy_output_value_id, firing_strength) pairs
+/// * `aggregated_rules` - Vector of (fuzzy_output_value_id, hedge, firing_strength) triples
 /// * `fuzzy_output_values` - All fuzzy output values for this parameter
 /// * `output_parameter_id` - The ID of the output parameter
 /// * `start` - Start of the universe of discourse
@@ -237,7 +285,7 @@ pub fn defuzzify_largest_of_maximum(
 /// # Returns
 /// DefuzzificationResult containing the crisp output value
 pub fn defuzzify(
-    aggregated_rules: &[(i64, f32)],
+    aggregated_rules: &[(i64, Option<Hedge>, f32)],
     fuzzy_output_values: &[FuzzyOutputValue],
     output_parameter_id: i64,
     start: f32,
@@ -245,16 +293,7 @@ pub fn defuzzify(
     method: DefuzzificationMethod,
     resolution: usize,
 ) -> DefuzzificationResult {
-    // Build clipped sets from aggregated rules
-    let clipped_sets: Vec<(FuzzyOutputValue, f32)> = aggregated_rules
-        .iter()
-        .filter_map(|(fov_id, strength)| {
-            fuzzy_output_values
-                .iter()
-                .find(|fov| fov.id == *fov_id)
-                .map(|fov| (fov.clone(), *strength))
-        })
-        .collect();
+    let clipped_sets = build_clipped_sets(aggregated_rules, fuzzy_output_values);
 
     let crisp_value = match method {
         DefuzzificationMethod::Centroid => {
@@ -296,6 +335,7 @@ mod tests {
                 c: 2.0,
                 d: 4.0,
                 is_triangle: false,
+                lower: None,
             },
             FuzzyOutputValue {
                 id: 2,
@@ -306,6 +346,7 @@ mod tests {
                 c: 6.0,
                 d: 8.0,
                 is_triangle: false,
+                lower: None,
             },
             FuzzyOutputValue {
                 id: 3,
@@ -316,6 +357,7 @@ mod tests {
                 c: 10.0,
                 d: 10.0,
                 is_triangle: false,
+                lower: None,
             },
         ]
     }
@@ -323,7 +365,7 @@ mod tests {
     #[test]
     fn test_defuzzify_centroid_single_set() {
         let fovs = create_test_fuzzy_output_values();
-        let aggregated = vec![(1, 1.0)]; // Full activation of "Low"
+        let aggregated = vec![(1, None, 1.0)]; // Full activation of "Low"
 
         let result = defuzzify(
             &aggregated,
@@ -343,7 +385,7 @@ mod tests {
     #[test]
     fn test_defuzzify_centroid_multiple_sets() {
         let fovs = create_test_fuzzy_output_values();
-        let aggregated = vec![(1, 0.5), (3, 0.5)]; // Equal activation of "Low" and "High"
+        let aggregated = vec![(1, None, 0.5), (3, None, 0.5)]; // Equal activation of "Low" and "High"
 
         let result = defuzzify(
             &aggregated,
@@ -363,7 +405,7 @@ mod tests {
     #[test]
     fn test_defuzzify_mean_of_maximum() {
         let fovs = create_test_fuzzy_output_values();
-        let aggregated = vec![(2, 1.0)]; // Full activation of "Medium"
+        let aggregated = vec![(2, None, 1.0)]; // Full activation of "Medium"
 
         let result = defuzzify(
             &aggregated,
@@ -380,10 +422,28 @@ mod tests {
         assert!(result.crisp_value <= 6.0);
     }
 
+    #[test]
+    fn test_method_names_round_trip() {
+        for method in [
+            DefuzzificationMethod::Centroid,
+            DefuzzificationMethod::Bisector,
+            DefuzzificationMethod::MeanOfMaximum,
+            DefuzzificationMethod::SmallestOfMaximum,
+            DefuzzificationMethod::LargestOfMaximum,
+        ] {
+            assert_eq!(DefuzzificationMethod::from_name(method.as_str()), Some(method));
+        }
+        assert_eq!(
+            DefuzzificationMethod::from_name("COG"),
+            Some(DefuzzificationMethod::Centroid)
+        );
+        assert_eq!(DefuzzificationMethod::from_name("unknown"), None);
+    }
+
     #[test]
     fn test_defuzzify_empty_aggregation() {
         let fovs = create_test_fuzzy_output_values();
-        let aggregated: Vec<(i64, f32)> = vec![];
+        let aggregated: Vec<(i64, Option<Hedge>, f32)> = vec![];
 
         let result = defuzzify(
             &aggregated,
@@ -398,4 +458,17 @@ mod tests {
         // Should return midpoint
         assert_eq!(result.crisp_value, 5.0);
     }
+
+    #[test]
+    fn test_consequent_hedge_reshapes_term() {
+        let fovs = create_test_fuzzy_output_values();
+        let plain = vec![(fovs[1].clone(), None, 1.0)];
+        let very = vec![(fovs[1].clone(), Some(Hedge::Very), 1.0)];
+
+        // On the slope of "Medium" at x = 3, μ = 0.5
+        assert!((aggregated_membership_at(3.0, &plain) - 0.5).abs() < 1e-6);
+        assert!((aggregated_membership_at(3.0, &very) - 0.25).abs() < 1e-6);
+        // The plateau is unchanged
+        assert_eq!(aggregated_membership_at(5.0, &very), 1.0);
+    }
 }
@@ -0,0 +1,392 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::input_value::InputValue;
+use crate::services::membership_function::calculate_membership;
+
+/// Sample points on each side of the value when fuzzifying an uncertain input
+const UNCERTAINTY_SAMPLES: usize = 200;
+
+/// Gaussian inputs are sampled up to this many standard deviations from the value
+const GAUSSIAN_EXTENT: f32 = 4.0;
+
+/// Fuzzy number around a measured value describing its uncertainty
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "shape", rename_all = "lowercase")]
+pub enum InputUncertainty {
+    /// Triangle peaking at the value with feet at value ± spread
+    Triangular { spread: f32 },
+    /// Gaussian exp(-(x - value)² / 2σ²)
+    Gaussian { sigma: f32 },
+}
+
+impl InputUncertainty {
+    /// Width parameter of the shape (spread or σ)
+    pub fn width(&self) -> f32 {
+        match self {
+            InputUncertainty::Triangular { spread } => *spread,
+            InputUncertainty::Gaussian { sigma } => *sigma,
+        }
+    }
+
+    /// Half-width of the interval outside of which the input set is treated as zero
+    fn extent(&self) -> f32 {
+        match self {
+            InputUncertainty::Triangular { spread } => *spread,
+            InputUncertainty::Gaussian { sigma } => GAUSSIAN_EXTENT * sigma,
+        }
+    }
+
+    /// Samples the fuzzy number centered on `value` as (x, μ(x)) pairs,
+    /// `2 * UNCERTAINTY_SAMPLES + 1` points spanning its support
+    pub fn samples(&self, value: f32) -> Vec<(f32, f32)> {
+        let extent = self.extent();
+        let step = extent / UNCERTAINTY_SAMPLES as f32;
+        (0..=2 * UNCERTAINTY_SAMPLES)
+            .map(|i| {
+                let x = value - extent + (i as f32) * step;
+                (x, self.membership(value, x))
+            })
+            .collect()
+    }
+
+    /// Membership of `x` in the fuzzy number centered on `value`
+    pub fn membership(&self, value: f32, x: f32) -> f32 {
+        let distance = (x - value).abs();
+        match self {
+            InputUncertainty::Triangular { spread } => (1.0 - distance / spread).max(0.0),
+            InputUncertainty::Gaussian { sigma } => {
+                (-(distance * distance) / (2.0 * sigma * sigma)).exp()
+            }
+        }
+    }
+}
+
+/// Degree supplied for one category of a categorical parameter
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CategoryDegree {
+    pub input_value_id: i64,
+    pub degree: f32,
+}
+
+/// Represents a fuzzified input value with its membership degree
+#[derive(Debug, Clone)]
+pub struct FuzzifiedValue {
+    pub input_value_id: i64,
+    pub membership_degree: f32,
+}
+
+/// Represents all fuzzified values for a single input parameter
+#[derive(Debug, Clone)]
+pub struct FuzzifiedParameter {
+    pub input_parameter_id: i64,
+    pub fuzzified_values: Vec<FuzzifiedValue>,
+}
+
+/// Fuzzifies a crisp input value against all input values (linguistic terms) of a parameter
+///
+/// # Arguments
+/// * `crisp_value` - The crisp input value to fuzzify
+/// * `input_parameter_id` - The ID of the input parameter
+/// * `input_values` - All linguistic terms (fuzzy sets) for this parameter
+///
+/// # Returns
+/// A FuzzifiedParameter containing membership degrees for each linguistic term
+pub fn fuzzify_input(
+    crisp_value: f32,
+    input_parameter_id: i64,
+    input_values: &[InputValue],
+) -> FuzzifiedParameter {
+    let fuzzified_values: Vec<FuzzifiedValue> = input_values
+        .iter()
+        .map(|iv| {
+            let membership = calculate_membership(
+                crisp_value,
+                iv.a,
+                iv.b,
+                iv.c,
+                iv.d,
+                iv.is_triangle,
+            );
+            FuzzifiedValue {
+                input_value_id: iv.id,
+                membership_degree: membership,
+            }
+        })
+        .collect();
+
+    FuzzifiedParameter {
+        input_parameter_id,
+        fuzzified_values,
+    }
+}
+
+/// Fuzzifies an input supplied as a fuzzy number around `value`.
+///
+/// The degree of each term is the possibility sup_x min(μ_input(x), μ_term(x)),
+/// approximated on the points of [`InputUncertainty::samples`].
+/// Without uncertainty (or with a zero width) this is the same as [`fuzzify_input`].
+pub fn fuzzify_uncertain_input(
+    value: f32,
+    uncertainty: Option<InputUncertainty>,
+    input_parameter_id: i64,
+    input_values: &[InputValue],
+) -> FuzzifiedParameter {
+    let uncertainty = match uncertainty {
+        Some(u) if u.width() > 0.0 => u,
+        _ => return fuzzify_input(value, input_parameter_id, input_values),
+    };
+
+    let samples = uncertainty.samples(value);
+
+    let fuzzified_values: Vec<FuzzifiedValue> = input_values
+        .iter()
+        .map(|iv| {
+            let membership = possibility(&samples, |x| {
+                calculate_membership(x, iv.a, iv.b, iv.c, iv.d, iv.is_triangle)
+            });
+            FuzzifiedValue {
+                input_value_id: iv.id,
+                membership_degree: membership,
+            }
+        })
+        .collect();
+
+    FuzzifiedParameter {
+        input_parameter_id,
+        fuzzified_values,
+    }
+}
+
+/// Index of the category selected by a crisp `value`, if it is a whole number
+/// below `count`; used where a category is given by position, like a parameter's
+/// default value
+pub fn category_index(value: f32, count: usize) -> Option<usize> {
+    if value >= 0.0 && value.fract() == 0.0 && (value as usize) < count {
+        Some(value as usize)
+    } else {
+        None
+    }
+}
+
+/// Fuzzifies an input of a categorical parameter, whose terms are its categories.
+///
+/// With `degrees` every category takes its supplied degree, 0 if it is not listed.
+/// Otherwise the term `selected` is the single selected category, which gets degree 1.
+pub fn fuzzify_categorical_input(
+    selected: Option<i64>,
+    degrees: Option<&[CategoryDegree]>,
+    input_parameter_id: i64,
+    input_values: &[InputValue],
+) -> FuzzifiedParameter {
+    let fuzzified_values: Vec<FuzzifiedValue> = input_values
+        .iter()
+        .map(|iv| {
+            let membership = match degrees {
+                Some(degrees) => degrees
+                    .iter()
+                    .find(|d| d.input_value_id == iv.id)
+                    .map_or(0.0, |d| d.degree.clamp(0.0, 1.0)),
+                None if selected == Some(iv.id) => 1.0,
+                None => 0.0,
+            };
+            FuzzifiedValue {
+                input_value_id: iv.id,
+                membership_degree: membership,
+            }
+        })
+        .collect();
+
+    FuzzifiedParameter {
+        input_parameter_id,
+        fuzzified_values,
+    }
+}
+
+/// sup_x min(μ_input(x), μ_term(x)) over the sampled input set
+pub fn possibility(samples: &[(f32, f32)], membership: impl Fn(f32) -> f32) -> f32 {
+    samples
+        .iter()
+        .map(|(x, mu_input)| mu_input.min(membership(*x)))
+        .fold(0.0_f32, f32::max)
+}
+
+/// Fuzzifies multiple crisp inputs against their respective input parameters
+///
+/// # Arguments
+/// * `crisp_inputs` - Vector of (input_parameter_id, crisp_value) pairs
+/// * `all_input_values` - All input values grouped by their parameters
+///
+/// # Returns
+/// Vector of FuzzifiedParameter for each input
+pub fn fuzzify_all_inputs(
+    crisp_inputs: &[(i64, f32)],
+    all_input_values: &[(i64, Vec<InputValue>)],
+) -> Vec<FuzzifiedParameter> {
+    crisp_inputs
+        .iter()
+        .filter_map(|(param_id, crisp_value)| {
+            all_input_values
+                .iter()
+                .find(|(id, _)| id == param_id)
+                .map(|(_, input_values)| fuzzify_input(*crisp_value, *param_id, input_values))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_input_values() -> Vec<InputValue> {
+        vec![
+            InputValue {
+                id: 1,
+                input_parameter_id: 100,
+                value: "Low".to_string(),
+                a: 0.0,
+                b: 0.0,
+                c: 2.0,
+                d: 4.0,
+                is_triangle: false,
+                lower: None,
+            },
+            InputValue {
+                id: 2,
+                input_parameter_id: 100,
+                value: "Medium".to_string(),
+                a: 2.0,
+                b: 4.0,
+                c: 6.0,
+                d: 8.0,
+                is_triangle: false,
+                lower: None,
+            },
+            InputValue {
+                id: 3,
+                input_parameter_id: 100,
+                value: "High".to_string(),
+                a: 6.0,
+                b: 8.0,
+                c: 10.0,
+                d: 10.0,
+                is_triangle: false,
+                lower: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_fuzzify_low_value() {
+        let input_values = create_test_input_values();
+        let result = fuzzify_input(1.0, 100, &input_values);
+
+        assert_eq!(result.input_parameter_id, 100);
+        assert_eq!(result.fuzzified_values.len(), 3);
+
+        // Value 1.0 should be fully in "Low"
+        assert_eq!(result.fuzzified_values[0].membership_degree, 1.0);
+        assert_eq!(result.fuzzified_values[1].membership_degree, 0.0);
+        assert_eq!(result.fuzzified_values[2].membership_degree, 0.0);
+    }
+
+    #[test]
+    fn test_fuzzify_overlap_value() {
+        let input_values = create_test_input_values();
+        let result = fuzzify_input(3.0, 100, &input_values);
+
+        // Value 3.0 should be partially in "Low" (falling) and "Medium" (rising)
+        assert!(result.fuzzified_values[0].membership_degree > 0.0);
+        assert!(result.fuzzified_values[0].membership_degree < 1.0);
+        assert!(result.fuzzified_values[1].membership_degree > 0.0);
+        assert!(result.fuzzified_values[1].membership_degree < 1.0);
+        assert_eq!(result.fuzzified_values[2].membership_degree, 0.0);
+    }
+
+    #[test]
+    fn test_fuzzify_categorical_id_selects_one_category() {
+        let categories = create_test_input_values();
+        let result = fuzzify_categorical_input(Some(2), None, 100, &categories);
+
+        let degrees: Vec<f32> = result.fuzzified_values.iter().map(|v| v.membership_degree).collect();
+        assert_eq!(degrees, vec![0.0, 1.0, 0.0]);
+
+        // The selection follows the term, not its position
+        let mut reordered = categories.clone();
+        reordered.swap(0, 1);
+        let result = fuzzify_categorical_input(Some(2), None, 100, &reordered);
+        let degrees: Vec<f32> = result.fuzzified_values.iter().map(|v| v.membership_degree).collect();
+        assert_eq!(degrees, vec![1.0, 0.0, 0.0]);
+
+        // Not a category of the parameter: nothing is selected
+        for selected in [None, Some(4)] {
+            let result = fuzzify_categorical_input(selected, None, 100, &categories);
+            assert!(result.fuzzified_values.iter().all(|v| v.membership_degree == 0.0));
+        }
+    }
+
+    #[test]
+    fn test_fuzzify_categorical_degrees_override_index() {
+        let categories = create_test_input_values();
+        let degrees = [
+            CategoryDegree { input_value_id: 3, degree: 0.3 },
+            CategoryDegree { input_value_id: 1, degree: 0.7 },
+        ];
+        let result = fuzzify_categorical_input(Some(2), Some(&degrees), 100, &categories);
+
+        let degrees: Vec<f32> = result.fuzzified_values.iter().map(|v| v.membership_degree).collect();
+        assert_eq!(degrees, vec![0.7, 0.0, 0.3]);
+    }
+
+    #[test]
+    fn test_category_index() {
+        assert_eq!(category_index(0.0, 3), Some(0));
+        assert_eq!(category_index(2.0, 3), Some(2));
+        assert_eq!(category_index(3.0, 3), None);
+        assert_eq!(category_index(0.5, 3), None);
+        assert_eq!(category_index(f32::NAN, 3), None);
+    }
+
+    #[test]
+    fn test_fuzzify_uncertain_input_without_width_is_crisp() {
+        let input_values = create_test_input_values();
+        let crisp = fuzzify_input(3.0, 100, &input_values);
+
+        for uncertainty in [None, Some(InputUncertainty::Triangular { spread: 0.0 })] {
+            let result = fuzzify_uncertain_input(3.0, uncertainty, 100, &input_values);
+            for (a, b) in result.fuzzified_values.iter().zip(&crisp.fuzzified_values) {
+                assert_eq!(a.membership_degree, b.membership_degree);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fuzzify_triangular_input_sup_min() {
+        let input_values = create_test_input_values();
+        // Input triangle 1..5 peaking at 3, where Low and Medium cross at 0.5
+        let uncertainty = Some(InputUncertainty::Triangular { spread: 2.0 });
+        let result = fuzzify_uncertain_input(3.0, uncertainty, 100, &input_values);
+
+        // Input rising (x - 1) / 2 meets Low falling (4 - x) / 2 at x = 2.5
+        assert!((result.fuzzified_values[0].membership_degree - 0.75).abs() < 1e-2);
+        // Medium rising (x - 2) / 2 meets the input falling (5 - x) / 2 at x = 3.5
+        assert!((result.fuzzified_values[1].membership_degree - 0.75).abs() < 1e-2);
+        // High starts at 6, beyond the input support
+        assert_eq!(result.fuzzified_values[2].membership_degree, 0.0);
+    }
+
+    #[test]
+    fn test_fuzzify_gaussian_input_reaches_nearby_term() {
+        let input_values = create_test_input_values();
+        // Crisp 5.5 is not in High at all, but a wide enough Gaussian overlaps it
+        let crisp = fuzzify_input(5.5, 100, &input_values);
+        let uncertainty = Some(InputUncertainty::Gaussian { sigma: 1.0 });
+        let result = fuzzify_uncertain_input(5.5, uncertainty, 100, &input_values);
+
+        assert_eq!(crisp.fuzzified_values[2].membership_degree, 0.0);
+        assert!(result.fuzzified_values[2].membership_degree > 0.0);
+        // Never below the singleton degree, since the input set peaks at the value
+        for (a, b) in result.fuzzified_values.iter().zip(&crisp.fuzzified_values) {
+            assert!(a.membership_degree >= b.membership_degree);
+        }
+    }
+}
@@ -1,13 +1,13 @@
-/// Fuzzy Partition utilities for overlapping trapezoidal membership functions (Ruspini partition)
-///
-/// For adjacent terms A, B, C with points (a, b, c, d):
-/// - A.c = B.a, A.d = B.b (overlap region)
-/// - B.c = C.a, B.d = C.b
-/// - Constraint: a < b <= c < d (a = c only for triangular terms)
-///
-/// This ensures sum of memberships = 1 at every point because:
-/// - In overlap region: falling edge of left term + rising edge of right term = 1
-/// - In plateau: exactly one term has membership 1
+//! Fuzzy Partition utilities for overlapping trapezoidal membership functions (Ruspini partition)
+//!
+//! For adjacent terms A, B, C with points (a, b, c, d):
+//! - A.c = B.a, A.d = B.b (overlap region)
+//! - B.c = C.a, B.d = C.b
+//! - Constraint: a < b <= c < d (a = c only for triangular terms)
+//!
+//! This ensures sum of memberships = 1 at every point because:
+//! - In overlap region: falling edge of left term + rising edge of right term = 1
+//! - In plateau: exactly one term has membership 1
 
 /// Calculate membership value for a trapezoidal fuzzy set at a given point x
 /// 
@@ -209,6 +209,22 @@ pub fn validate_fuzzy_partition(
     Ok(())
 }
 
+/// Tolerance used when checking stored terms against a partition
+pub const PARTITION_TOLERANCE: f32 = 1e-3;
+
+/// Check if terms given in any order form a valid fuzzy partition of [start, end],
+/// ordering them by `a` (then `d`) first
+pub fn check_partition(
+    terms: &[(f32, f32, f32, f32)],
+    start: f32,
+    end: f32,
+    tolerance: f32,
+) -> Result<(), String> {
+    let mut sorted = terms.to_vec();
+    sorted.sort_by(|x, y| x.0.total_cmp(&y.0).then(x.3.total_cmp(&y.3)));
+    validate_fuzzy_partition(&sorted, start, end, tolerance)
+}
+
 /// Create default partition with n overlapping terms
 pub fn create_default_partition(num_terms: usize, start: f32, end: f32) -> Vec<(f32, f32, f32, f32)> {
     if num_terms == 0 {
@@ -433,6 +449,15 @@ mod tests {
         assert!(result.unwrap_err().contains("No terms"));
     }
 
+    #[test]
+    fn test_check_partition_ignores_order() {
+        let terms = vec![(5.0, 10.0, 10.0, 10.0), (0.0, 0.0, 5.0, 10.0)];
+        assert!(check_partition(&terms, 0.0, 10.0, 0.001).is_ok());
+
+        let nested = vec![(0.0, 0.0, 10.0, 10.0), (4.0, 5.0, 5.0, 6.0)];
+        assert!(check_partition(&nested, 0.0, 10.0, 0.001).is_err());
+    }
+
     #[test]
     fn test_create_default_partition_single() {
         let terms = create_default_partition(1, 0.0, 1.0);
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+/// Linguistic hedges modifying a term's membership function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Hedge {
+    /// Concentration, μ²
+    Very,
+    /// Strong concentration, μ³
+    Extremely,
+    /// Dilation, √μ
+    Somewhat,
+    /// Mild dilation, μ^0.75
+    Slightly,
+}
+
+impl Hedge {
+    pub const ALL: [Hedge; 4] = [Hedge::Very, Hedge::Extremely, Hedge::Somewhat, Hedge::Slightly];
+
+    /// Name as used by the frontend and in stored rules
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Hedge::Very => "very",
+            Hedge::Extremely => "extremely",
+            Hedge::Somewhat => "somewhat",
+            Hedge::Slightly => "slightly",
+        }
+    }
+
+    /// Parses a hedge name, accepting "more or less" for "somewhat"
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace(' ', "_").as_str() {
+            "very" => Some(Hedge::Very),
+            "extremely" => Some(Hedge::Extremely),
+            "somewhat" | "more_or_less" => Some(Hedge::Somewhat),
+            "slightly" => Some(Hedge::Slightly),
+            _ => None,
+        }
+    }
+
+    /// Applies the hedge to a membership degree
+    pub fn apply(&self, degree: f32) -> f32 {
+        match self {
+            Hedge::Very => degree.powi(2),
+            Hedge::Extremely => degree.powi(3),
+            Hedge::Somewhat => degree.sqrt(),
+            Hedge::Slightly => degree.powf(0.75),
+        }
+    }
+}
+
+/// Applies an optional hedge to a membership degree
+pub fn apply_hedge(hedge: Option<Hedge>, degree: f32) -> f32 {
+    hedge.map_or(degree, |h| h.apply(degree))
+}
+
+/// Parses the antecedent hedges of a rule, stored as "|id1:very||id2:somewhat|".
+/// Unknown hedge names and malformed entries are skipped.
+pub fn parse_antecedent_hedges(antecedent_hedges: &str) -> Vec<(i64, Hedge)> {
+    antecedent_hedges
+        .split('|')
+        .filter_map(|entry| {
+            let (id, hedge) = entry.split_once(':')?;
+            Some((id.parse::<i64>().ok()?, Hedge::from_name(hedge)?))
+        })
+        .collect()
+}
+
+/// Formats antecedent hedges in the stored "|id:hedge|" form
+pub fn format_antecedent_hedges(hedges: &[(i64, Hedge)]) -> String {
+    hedges
+        .iter()
+        .map(|(id, hedge)| format!("|{}:{}|", id, hedge.as_str()))
+        .collect()
+}
+
+/// Parses a stored consequent hedge; unknown names are treated as no hedge
+pub fn parse_consequent_hedge(consequent_hedge: Option<&str>) -> Option<Hedge> {
+    consequent_hedge.and_then(Hedge::from_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-4;
+
+    #[test]
+    fn test_hedges() {
+        assert!((Hedge::Very.apply(0.5) - 0.25).abs() < TOLERANCE);
+        assert!((Hedge::Extremely.apply(0.5) - 0.125).abs() < TOLERANCE);
+        assert!((Hedge::Somewhat.apply(0.25) - 0.5).abs() < TOLERANCE);
+        assert!(Hedge::Slightly.apply(0.5) > 0.5 && Hedge::Slightly.apply(0.5) < 0.5_f32.sqrt());
+        assert_eq!(apply_hedge(None, 0.3), 0.3);
+    }
+
+    #[test]
+    fn test_hedge_names_round_trip() {
+        for hedge in Hedge::ALL {
+            assert_eq!(Hedge::from_name(hedge.as_str()), Some(hedge));
+        }
+        assert_eq!(Hedge::from_name("More or less"), Some(Hedge::Somewhat));
+        assert_eq!(Hedge::from_name("hardly"), None);
+    }
+
+    #[test]
+    fn test_antecedent_hedges_round_trip() {
+        let hedges = vec![(12, Hedge::Very), (15, Hedge::Somewhat)];
+
+        let formatted = format_antecedent_hedges(&hedges);
+
+        assert_eq!(formatted, "|12:very||15:somewhat|");
+        assert_eq!(parse_antecedent_hedges(&formatted), hedges);
+        assert!(parse_antecedent_hedges("").is_empty());
+        assert_eq!(parse_antecedent_hedges("|x:very||3:hardly||4:slightly|"), vec![(4, Hedge::Slightly)]);
+    }
+}
@@ -1,5 +1,8 @@
-use crate::domain::entities::output_value::OutputValue;
-use crate::domain::services::fuzzification::FuzzifiedParameter;
+use crate::entities::output_value::OutputValue;
+use crate::services::fuzzification::FuzzifiedParameter;
+use crate::services::hedge::{
+    apply_hedge, parse_antecedent_hedges, parse_consequent_hedge, Hedge,
+};
 use std::collections::HashMap;
 
 /// Represents a fired rule with its activation strength
@@ -7,6 +10,8 @@ use std::collections::HashMap;
 pub struct FiredRule {
     pub output_value_id: i64,
     pub fuzzy_output_value_id: i64,
+    /// Hedge applied to the consequent term's shape before aggregation
+    pub consequent_hedge: Option<Hedge>,
     pub firing_strength: f32,
 }
 
@@ -36,7 +41,8 @@ fn parse_input_value_ids(input_value_ids: &str) -> Vec<i64> {
 /// Evaluates fuzzy rules using Mamdani inference (min T-norm for AND)
 ///
 /// For each rule, the firing strength is calculated as the minimum
-/// of all input membership degrees (AND operation).
+/// of all input membership degrees (AND operation), each modified by
+/// the hedge the rule puts on that term.
 ///
 /// # Arguments
 /// * `fuzzified_inputs` - The fuzzified input parameters
@@ -75,11 +81,17 @@ pub fn evaluate_rules(
                 return None;
             }
 
-            // Calculate firing strength as MIN of all input membership degrees (AND)
+            let hedges: HashMap<i64, Hedge> =
+                parse_antecedent_hedges(&rule.antecedent_hedges).into_iter().collect();
+
+            // Calculate firing strength as MIN of all (hedged) input membership degrees (AND)
             let firing_strength = input_value_ids
                 .iter()
-                .filter_map(|id| membership_map.get(id))
-                .copied()
+                .filter_map(|id| {
+                    membership_map
+                        .get(id)
+                        .map(|mu| apply_hedge(hedges.get(id).copied(), *mu))
+                })
                 .fold(f32::MAX, f32::min);
 
             // Only include rules that actually fire (strength > 0)
@@ -87,6 +99,7 @@ pub fn evaluate_rules(
                 Some(FiredRule {
                     output_value_id: rule.id,
                     fuzzy_output_value_id: fuzzy_output_id,
+                    consequent_hedge: parse_consequent_hedge(rule.consequent_hedge.as_deref()),
                     firing_strength,
                 })
             } else {
@@ -121,31 +134,34 @@ pub fn evaluate_all_rules(
         .collect()
 }
 
-/// Aggregates fired rules by fuzzy output value, taking the maximum firing strength
-/// for each unique fuzzy output value (MAX aggregation / S-norm)
+/// Aggregates fired rules by fuzzy output value and consequent hedge, taking the
+/// maximum firing strength for each unique pair (MAX aggregation / S-norm)
 ///
 /// # Arguments
 /// * `inference_result` - The result of rule evaluation
 ///
 /// # Returns
-/// Vector of (fuzzy_output_value_id, max_firing_strength) pairs
-pub fn aggregate_fired_rules(inference_result: &InferenceResult) -> Vec<(i64, f32)> {
-    let mut aggregated: HashMap<i64, f32> = HashMap::new();
+/// Vector of (fuzzy_output_value_id, consequent_hedge, max_firing_strength) triples
+pub fn aggregate_fired_rules(inference_result: &InferenceResult) -> Vec<(i64, Option<Hedge>, f32)> {
+    let mut aggregated: HashMap<(i64, Option<Hedge>), f32> = HashMap::new();
 
     for rule in &inference_result.fired_rules {
         let entry = aggregated
-            .entry(rule.fuzzy_output_value_id)
+            .entry((rule.fuzzy_output_value_id, rule.consequent_hedge))
             .or_insert(0.0);
         *entry = entry.max(rule.firing_strength);
     }
 
-    aggregated.into_iter().collect()
+    aggregated
+        .into_iter()
+        .map(|((fov_id, hedge), strength)| (fov_id, hedge, strength))
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::services::fuzzification::{FuzzifiedParameter, FuzzifiedValue};
+    use crate::services::fuzzification::{FuzzifiedParameter, FuzzifiedValue};
 
     #[test]
     fn test_parse_input_value_ids() {
@@ -196,12 +212,16 @@ mod tests {
                 output_parameter_id: 100,
                 fuzzy_output_value_id: Some(1000),
                 input_value_ids: "|10||20|".to_string(),
+                antecedent_hedges: String::new(),
+                consequent_hedge: None,
             },
             OutputValue {
                 id: 2,
                 output_parameter_id: 100,
                 fuzzy_output_value_id: Some(1001),
                 input_value_ids: "|11||21|".to_string(),
+                antecedent_hedges: String::new(),
+                consequent_hedge: None,
             },
         ];
 
@@ -227,16 +247,19 @@ mod tests {
                 FiredRule {
                     output_value_id: 1,
                     fuzzy_output_value_id: 1000,
+                    consequent_hedge: None,
                     firing_strength: 0.6,
                 },
                 FiredRule {
                     output_value_id: 2,
                     fuzzy_output_value_id: 1000,
+                    consequent_hedge: None,
                     firing_strength: 0.4,
                 },
                 FiredRule {
                     output_value_id: 3,
                     fuzzy_output_value_id: 1001,
+                    consequent_hedge: None,
                     firing_strength: 0.3,
                 },
             ],
@@ -245,11 +268,61 @@ mod tests {
         let aggregated = aggregate_fired_rules(&inference_result);
 
         // fuzzy_output_value 1000: max(0.6, 0.4) = 0.6
-        let val_1000 = aggregated.iter().find(|(id, _)| *id == 1000).unwrap();
-        assert_eq!(val_1000.1, 0.6);
+        let val_1000 = aggregated.iter().find(|(id, _, _)| *id == 1000).unwrap();
+        assert_eq!(val_1000.2, 0.6);
 
         // fuzzy_output_value 1001: 0.3
-        let val_1001 = aggregated.iter().find(|(id, _)| *id == 1001).unwrap();
-        assert_eq!(val_1001.1, 0.3);
+        let val_1001 = aggregated.iter().find(|(id, _, _)| *id == 1001).unwrap();
+        assert_eq!(val_1001.2, 0.3);
+    }
+
+    #[test]
+    fn test_evaluate_rules_with_hedges() {
+        let fuzzified_inputs = vec![FuzzifiedParameter {
+            input_parameter_id: 1,
+            fuzzified_values: vec![
+                FuzzifiedValue {
+                    input_value_id: 10,
+                    membership_degree: 0.5,
+                },
+                FuzzifiedValue {
+                    input_value_id: 11,
+                    membership_degree: 0.25,
+                },
+            ],
+        }];
+
+        let rules = vec![
+            OutputValue {
+                id: 1,
+                output_parameter_id: 100,
+                fuzzy_output_value_id: Some(1000),
+                input_value_ids: "|10|".to_string(),
+                antecedent_hedges: "|10:very|".to_string(),
+                consequent_hedge: Some("somewhat".to_string()),
+            },
+            OutputValue {
+                id: 2,
+                output_parameter_id: 100,
+                fuzzy_output_value_id: Some(1000),
+                input_value_ids: "|11|".to_string(),
+                antecedent_hedges: "|11:more_or_less|".to_string(),
+                consequent_hedge: None,
+            },
+        ];
+
+        let result = evaluate_rules(&fuzzified_inputs, &rules, 100);
+
+        // Rule 1: very(0.5) = 0.25
+        let rule1 = result.fired_rules.iter().find(|r| r.output_value_id == 1).unwrap();
+        assert!((rule1.firing_strength - 0.25).abs() < 1e-6);
+        assert_eq!(rule1.consequent_hedge, Some(Hedge::Somewhat));
+
+        // Rule 2: somewhat(0.25) = 0.5
+        let rule2 = result.fired_rules.iter().find(|r| r.output_value_id == 2).unwrap();
+        assert!((rule2.firing_strength - 0.5).abs() < 1e-6);
+
+        // Same term with different consequent hedges is aggregated separately
+        assert_eq!(aggregate_fired_rules(&result).len(), 2);
     }
 }
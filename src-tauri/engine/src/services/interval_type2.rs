@@ -0,0 +1,499 @@
+use std::collections::HashMap;
+
+use crate::entities::{
+    fuzzy_output_value::FuzzyOutputValue, input_value::InputValue,
+    lower_membership::LowerMembership, output_value::OutputValue,
+};
+use crate::services::{
+    fuzzification::{
+        fuzzify_uncertain_input, possibility, FuzzifiedParameter, FuzzifiedValue,
+        InputUncertainty,
+    },
+    hedge::{apply_hedge, Hedge},
+    inference::{aggregate_fired_rules, evaluate_rules},
+    membership_function::calculate_membership,
+};
+
+/// Tolerance when checking that a lower membership function stays under the upper one
+const FOOTPRINT_TOLERANCE: f32 = 1e-4;
+
+/// Lower membership degree of `x` in a term whose upper membership function is the
+/// trapezoid (a, b, c, d). Type-1 terms (no lower function) have equal lower and
+/// upper degrees, and the lower degree is capped by the upper one so that a shape
+/// edited after its footprint was set cannot invert the interval.
+pub fn lower_membership_at(
+    x: f32,
+    (a, b, c, d): (f32, f32, f32, f32),
+    is_triangle: bool,
+    lower: Option<&LowerMembership>,
+) -> f32 {
+    let upper = calculate_membership(x, a, b, c, d, is_triangle);
+    match lower {
+        Some(l) => (l.height * calculate_membership(x, l.a, l.b, l.c, l.d, l.is_triangle)).min(upper),
+        None => upper,
+    }
+}
+
+/// Checks that `lower` is a well-formed lower membership function for the term
+/// (a, b, c, d), i.e. that the footprint of uncertainty it bounds is non-empty
+/// and lies under the term's own shape.
+pub fn validate_lower_membership(
+    (a, b, c, d): (f32, f32, f32, f32),
+    is_triangle: bool,
+    lower: &LowerMembership,
+) -> Result<(), String> {
+    let points = [lower.a, lower.b, lower.c, lower.d, lower.height];
+    if points.iter().any(|p| !p.is_finite()) {
+        return Err("Lower membership function must only contain finite numbers".to_string());
+    }
+    if lower.height <= 0.0 || lower.height > 1.0 {
+        return Err(format!("Lower membership height must be in (0, 1], got {}", lower.height));
+    }
+    let (lower_b, lower_c) = if lower.is_triangle { (lower.b, lower.b) } else { (lower.b, lower.c) };
+    if !(lower.a <= lower_b && lower_b <= lower_c && lower_c <= lower.d) {
+        return Err(format!(
+            "Lower membership points must satisfy a <= b <= c <= d, got ({}, {}, {}, {})",
+            lower.a, lower.b, lower.c, lower.d
+        ));
+    }
+
+    // Both functions are piecewise linear, so comparing them at every breakpoint
+    // and halfway between neighbouring breakpoints covers all segments.
+    let mut breakpoints = [a, b, c, d, lower.a, lower_b, lower_c, lower.d];
+    breakpoints.sort_by(f32::total_cmp);
+    let midpoints: Vec<f32> = breakpoints.windows(2).map(|w| (w[0] + w[1]) / 2.0).collect();
+    for x in breakpoints.iter().chain(&midpoints) {
+        let upper = calculate_membership(*x, a, b, c, d, is_triangle);
+        let lower_degree =
+            lower.height * calculate_membership(*x, lower.a, lower.b, lower.c, lower.d, lower.is_triangle);
+        if lower_degree > upper + FOOTPRINT_TOLERANCE {
+            return Err(format!(
+                "Lower membership function exceeds the term's shape at {} ({} > {})",
+                x, lower_degree, upper
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Lower and upper degrees of an input in the terms of its parameter,
+/// each kept as an ordinary type-1 fuzzification
+#[derive(Debug, Clone)]
+pub struct IntervalFuzzifiedParameter {
+    pub input_parameter_id: i64,
+    pub lower: FuzzifiedParameter,
+    pub upper: FuzzifiedParameter,
+}
+
+impl IntervalFuzzifiedParameter {
+    /// Interval with equal lower and upper degrees, as for categorical inputs
+    pub fn from_type1(fuzzified: FuzzifiedParameter) -> Self {
+        Self {
+            input_parameter_id: fuzzified.input_parameter_id,
+            lower: fuzzified.clone(),
+            upper: fuzzified,
+        }
+    }
+}
+
+/// Fuzzifies a (possibly uncertain) input against interval type-2 terms.
+///
+/// Upper degrees come from the terms' own shapes exactly as in type-1 fuzzification;
+/// lower degrees use the same possibility measure on the lower membership functions.
+pub fn fuzzify_interval_input(
+    value: f32,
+    uncertainty: Option<InputUncertainty>,
+    input_parameter_id: i64,
+    input_values: &[InputValue],
+) -> IntervalFuzzifiedParameter {
+    let upper = fuzzify_uncertain_input(value, uncertainty, input_parameter_id, input_values);
+
+    let samples = uncertainty
+        .filter(|u| u.width() > 0.0)
+        .map(|u| u.samples(value));
+    let fuzzified_values = input_values
+        .iter()
+        .map(|iv| {
+            let membership = |x: f32| {
+                lower_membership_at(x, (iv.a, iv.b, iv.c, iv.d), iv.is_triangle, iv.lower.as_ref())
+            };
+            FuzzifiedValue {
+                input_value_id: iv.id,
+                membership_degree: match &samples {
+                    Some(samples) => possibility(samples, membership),
+                    None => membership(value),
+                },
+            }
+        })
+        .collect();
+
+    IntervalFuzzifiedParameter {
+        input_parameter_id,
+        lower: FuzzifiedParameter {
+            input_parameter_id,
+            fuzzified_values,
+        },
+        upper,
+    }
+}
+
+/// Firing interval of all rules sharing a consequent term and hedge
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiringInterval {
+    pub fuzzy_output_value_id: i64,
+    pub consequent_hedge: Option<Hedge>,
+    pub lower: f32,
+    pub upper: f32,
+}
+
+/// Result of interval type-2 rule evaluation for a single output parameter
+#[derive(Debug, Clone)]
+pub struct IntervalInferenceResult {
+    pub output_parameter_id: i64,
+    /// Rules with a non-zero upper firing strength
+    pub fired_rules_count: usize,
+    pub firing_intervals: Vec<FiringInterval>,
+}
+
+/// Evaluates the rules of an output parameter on interval fuzzified inputs.
+///
+/// The min T-norm, the hedges and the max S-norm are all monotone, so the
+/// firing interval of each consequent is [type-1 inference on the lower degrees,
+/// type-1 inference on the upper degrees].
+pub fn evaluate_interval_rules(
+    fuzzified_inputs: &[IntervalFuzzifiedParameter],
+    rules: &[OutputValue],
+    output_parameter_id: i64,
+) -> IntervalInferenceResult {
+    let lower_inputs: Vec<FuzzifiedParameter> =
+        fuzzified_inputs.iter().map(|fp| fp.lower.clone()).collect();
+    let upper_inputs: Vec<FuzzifiedParameter> =
+        fuzzified_inputs.iter().map(|fp| fp.upper.clone()).collect();
+
+    let lower: HashMap<(i64, Option<Hedge>), f32> =
+        aggregate_fired_rules(&evaluate_rules(&lower_inputs, rules, output_parameter_id))
+            .into_iter()
+            .map(|(fov_id, hedge, strength)| ((fov_id, hedge), strength))
+            .collect();
+    let upper_result = evaluate_rules(&upper_inputs, rules, output_parameter_id);
+
+    let firing_intervals = aggregate_fired_rules(&upper_result)
+        .into_iter()
+        .map(|(fov_id, hedge, upper)| FiringInterval {
+            fuzzy_output_value_id: fov_id,
+            consequent_hedge: hedge,
+            lower: lower.get(&(fov_id, hedge)).copied().unwrap_or(0.0).min(upper),
+            upper,
+        })
+        .collect();
+
+    IntervalInferenceResult {
+        output_parameter_id,
+        fired_rules_count: upper_result.fired_rules.len(),
+        firing_intervals,
+    }
+}
+
+/// Type-reduced centroid of the aggregated interval type-2 output set
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TypeReducedOutput {
+    /// Left end c_l of the type-reduced interval
+    pub left: f32,
+    /// Right end c_r of the type-reduced interval
+    pub right: f32,
+    /// Defuzzified value, the midpoint of the interval
+    pub crisp_value: f32,
+}
+
+/// Aggregates the clipped lower and upper output sets on `resolution + 1` points of
+/// the universe and reduces them to the centroid interval with the Karnik–Mendel
+/// algorithm. Without fired rules the interval collapses onto the middle of the
+/// universe, like type-1 centroid defuzzification.
+pub fn type_reduce(
+    firing_intervals: &[FiringInterval],
+    fuzzy_output_values: &[FuzzyOutputValue],
+    start: f32,
+    end: f32,
+    resolution: usize,
+) -> TypeReducedOutput {
+    let midpoint = TypeReducedOutput {
+        left: (start + end) / 2.0,
+        right: (start + end) / 2.0,
+        crisp_value: (start + end) / 2.0,
+    };
+    if firing_intervals.is_empty() || resolution == 0 {
+        return midpoint;
+    }
+
+    let clipped: Vec<(&FuzzyOutputValue, &FiringInterval)> = firing_intervals
+        .iter()
+        .filter_map(|fi| {
+            fuzzy_output_values
+                .iter()
+                .find(|fov| fov.id == fi.fuzzy_output_value_id)
+                .map(|fov| (fov, fi))
+        })
+        .collect();
+
+    let step = (end - start) / (resolution as f32);
+    let mut ys = Vec::with_capacity(resolution + 1);
+    let mut lower = Vec::with_capacity(resolution + 1);
+    let mut upper = Vec::with_capacity(resolution + 1);
+    for i in 0..=resolution {
+        let y = start + (i as f32) * step;
+        let (mut lower_y, mut upper_y) = (0.0_f32, 0.0_f32);
+        for (fov, fi) in &clipped {
+            let shape = (fov.a, fov.b, fov.c, fov.d);
+            let upper_mu = calculate_membership(y, fov.a, fov.b, fov.c, fov.d, fov.is_triangle);
+            let lower_mu = lower_membership_at(y, shape, fov.is_triangle, fov.lower.as_ref());
+            lower_y = lower_y.max(apply_hedge(fi.consequent_hedge, lower_mu).min(fi.lower));
+            upper_y = upper_y.max(apply_hedge(fi.consequent_hedge, upper_mu).min(fi.upper));
+        }
+        ys.push(y);
+        lower.push(lower_y);
+        upper.push(upper_y);
+    }
+
+    match (
+        karnik_mendel(&ys, &lower, &upper, true),
+        karnik_mendel(&ys, &lower, &upper, false),
+    ) {
+        (Some(left), Some(right)) => TypeReducedOutput {
+            left,
+            right,
+            crisp_value: (left + right) / 2.0,
+        },
+        _ => midpoint,
+    }
+}
+
+/// Karnik–Mendel iteration for one end of the centroid interval of a sampled
+/// interval type-2 set with ascending points `ys` and degrees [lower, upper].
+///
+/// The left end weights points left of the switch point by their upper degree and
+/// the rest by their lower degree; the right end does the opposite. Starting from
+/// the centroid of the mid-degrees, the switch point is moved to the current
+/// estimate until it stops changing, which takes at most `ys.len()` rounds.
+fn karnik_mendel(ys: &[f32], lower: &[f32], upper: &[f32], left: bool) -> Option<f32> {
+    let mut weights: Vec<f32> = lower.iter().zip(upper).map(|(l, u)| (l + u) / 2.0).collect();
+    let mut estimate = weighted_mean(ys, &weights)?;
+    let mut previous_switch = None;
+
+    for _ in 0..ys.len() {
+        let switch = ys.iter().rposition(|y| *y <= estimate).unwrap_or(0);
+        if previous_switch == Some(switch) {
+            break;
+        }
+        for (i, weight) in weights.iter_mut().enumerate() {
+            *weight = if (i <= switch) == left { upper[i] } else { lower[i] };
+        }
+        estimate = weighted_mean(ys, &weights)?;
+        previous_switch = Some(switch);
+    }
+
+    Some(estimate)
+}
+
+fn weighted_mean(ys: &[f32], weights: &[f32]) -> Option<f32> {
+    let denominator: f32 = weights.iter().sum();
+    if denominator.abs() < f32::EPSILON {
+        return None;
+    }
+    Some(ys.iter().zip(weights).map(|(y, w)| y * w).sum::<f32>() / denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::defuzzification::{build_clipped_sets, defuzzify_centroid};
+
+    fn footprint(a: f32, b: f32, c: f32, d: f32, height: f32) -> LowerMembership {
+        LowerMembership {
+            a,
+            b,
+            c,
+            d,
+            is_triangle: false,
+            height,
+        }
+    }
+
+    fn input_terms(lower: Option<LowerMembership>) -> Vec<InputValue> {
+        vec![
+            InputValue {
+                id: 1,
+                input_parameter_id: 1,
+                value: "Low".to_string(),
+                a: 0.0,
+                b: 0.0,
+                c: 3.0,
+                d: 7.0,
+                is_triangle: false,
+                lower,
+            },
+            InputValue {
+                id: 2,
+                input_parameter_id: 1,
+                value: "High".to_string(),
+                a: 3.0,
+                b: 7.0,
+                c: 10.0,
+                d: 10.0,
+                is_triangle: false,
+                lower: None,
+            },
+        ]
+    }
+
+    fn output_terms(lower: Option<LowerMembership>) -> Vec<FuzzyOutputValue> {
+        vec![
+            FuzzyOutputValue {
+                id: 10,
+                output_parameter_id: 1,
+                value: "Small".to_string(),
+                a: 0.0,
+                b: 2.0,
+                c: 3.0,
+                d: 5.0,
+                is_triangle: false,
+                lower,
+            },
+            FuzzyOutputValue {
+                id: 11,
+                output_parameter_id: 1,
+                value: "Large".to_string(),
+                a: 5.0,
+                b: 7.0,
+                c: 8.0,
+                d: 10.0,
+                is_triangle: false,
+                lower,
+            },
+        ]
+    }
+
+    fn rules() -> Vec<OutputValue> {
+        [(100, 1, 10), (101, 2, 11)]
+            .into_iter()
+            .map(|(id, input_value_id, fuzzy_output_value_id)| OutputValue {
+                id,
+                output_parameter_id: 1,
+                fuzzy_output_value_id: Some(fuzzy_output_value_id),
+                input_value_ids: format!("|{}|", input_value_id),
+                antecedent_hedges: String::new(),
+                consequent_hedge: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_lower_membership() {
+        let shape = (2.0, 4.0, 6.0, 8.0);
+
+        assert!(validate_lower_membership(shape, false, &footprint(3.0, 5.0, 5.0, 7.0, 0.6)).is_ok());
+        assert!(validate_lower_membership(shape, false, &footprint(2.0, 4.0, 6.0, 8.0, 1.0)).is_ok());
+        // Rises faster than the term itself
+        assert!(validate_lower_membership(shape, false, &footprint(2.0, 2.5, 6.0, 8.0, 1.0)).is_err());
+        // Sticks out of the support
+        assert!(validate_lower_membership(shape, false, &footprint(1.0, 4.0, 6.0, 8.0, 0.5)).is_err());
+        assert!(validate_lower_membership(shape, false, &footprint(3.0, 5.0, 5.0, 7.0, 0.0)).is_err());
+        assert!(validate_lower_membership(shape, false, &footprint(5.0, 4.0, 6.0, 7.0, 0.5)).is_err());
+    }
+
+    #[test]
+    fn test_fuzzify_interval_input_bounds() {
+        let terms = input_terms(Some(footprint(0.0, 0.0, 2.0, 6.0, 0.8)));
+
+        let fuzzified = fuzzify_interval_input(4.0, None, 1, &terms);
+
+        // Upper: (7 - 4) / 4; lower: 0.8 * (6 - 4) / 4
+        assert!((fuzzified.upper.fuzzified_values[0].membership_degree - 0.75).abs() < 1e-4);
+        assert!((fuzzified.lower.fuzzified_values[0].membership_degree - 0.4).abs() < 1e-4);
+        // High is type-1, both degrees coincide
+        assert_eq!(
+            fuzzified.lower.fuzzified_values[1].membership_degree,
+            fuzzified.upper.fuzzified_values[1].membership_degree
+        );
+    }
+
+    #[test]
+    fn test_firing_intervals() {
+        let terms = input_terms(Some(footprint(0.0, 0.0, 2.0, 6.0, 0.8)));
+        let fuzzified = vec![fuzzify_interval_input(4.0, None, 1, &terms)];
+
+        let result = evaluate_interval_rules(&fuzzified, &rules(), 1);
+
+        assert_eq!(result.fired_rules_count, 2);
+        let small = result
+            .firing_intervals
+            .iter()
+            .find(|fi| fi.fuzzy_output_value_id == 10)
+            .unwrap();
+        assert!((small.lower - 0.4).abs() < 1e-4);
+        assert!((small.upper - 0.75).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_type1_terms_reduce_to_type1_centroid() {
+        let inputs = input_terms(None);
+        let outputs = output_terms(None);
+        let fuzzified = vec![fuzzify_interval_input(4.0, None, 1, &inputs)];
+
+        let intervals = evaluate_interval_rules(&fuzzified, &rules(), 1).firing_intervals;
+        let reduced = type_reduce(&intervals, &outputs, 0.0, 10.0, 200);
+
+        let type1 = evaluate_rules(&[fuzzified[0].upper.clone()], &rules(), 1);
+        let centroid = defuzzify_centroid(
+            &build_clipped_sets(&aggregate_fired_rules(&type1), &outputs),
+            0.0,
+            10.0,
+            200,
+        );
+        assert!((reduced.left - centroid).abs() < 1e-3);
+        assert!((reduced.right - centroid).abs() < 1e-3);
+        assert!((reduced.crisp_value - centroid).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_footprint_widens_symmetric_output() {
+        // Both consequents fire equally on a symmetric output partition
+        let outputs = output_terms(Some(footprint(1.0, 2.5, 2.5, 4.0, 0.6)));
+        let outputs = vec![
+            outputs[0].clone(),
+            FuzzyOutputValue {
+                lower: Some(footprint(6.0, 7.5, 7.5, 9.0, 0.6)),
+                ..outputs[1].clone()
+            },
+        ];
+        let intervals = vec![
+            FiringInterval {
+                fuzzy_output_value_id: 10,
+                consequent_hedge: None,
+                lower: 0.3,
+                upper: 0.5,
+            },
+            FiringInterval {
+                fuzzy_output_value_id: 11,
+                consequent_hedge: None,
+                lower: 0.3,
+                upper: 0.5,
+            },
+        ];
+
+        let reduced = type_reduce(&intervals, &outputs, 0.0, 10.0, 200);
+
+        assert!(reduced.left < 5.0 - 0.1);
+        assert!(reduced.right > 5.0 + 0.1);
+        assert!((reduced.crisp_value - 5.0).abs() < 1e-2);
+        assert!(((5.0 - reduced.left) - (reduced.right - 5.0)).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_type_reduce_without_fired_rules() {
+        let reduced = type_reduce(&[], &output_terms(None), 0.0, 10.0, 100);
+
+        assert_eq!(reduced.left, 5.0);
+        assert_eq!(reduced.right, 5.0);
+        assert_eq!(reduced.crisp_value, 5.0);
+    }
+}
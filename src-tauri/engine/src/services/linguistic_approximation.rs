@@ -0,0 +1,189 @@
+use crate::entities::fuzzy_output_value::FuzzyOutputValue;
+use crate::services::{
+    defuzzification::aggregated_membership_at, hedge::Hedge,
+    membership_function::calculate_membership,
+};
+
+/// Membership of a crisp output value in one output term
+#[derive(Debug, Clone)]
+pub struct TermDegree {
+    pub fuzzy_output_value_id: i64,
+    pub term: String,
+    pub degree: f32,
+}
+
+/// Term, optionally hedged, that best describes an aggregated output set
+#[derive(Debug, Clone)]
+pub struct LinguisticApproximation {
+    pub fuzzy_output_value_id: i64,
+    pub term: String,
+    pub hedge: Option<Hedge>,
+    /// Jaccard similarity between the aggregated set and the (hedged) term, in [0, 1]
+    pub similarity: f32,
+}
+
+impl LinguisticApproximation {
+    /// Human readable label, e.g. "very High"
+    pub fn label(&self) -> String {
+        match self.hedge {
+            Some(hedge) => format!("{} {}", hedge.as_str(), self.term),
+            None => self.term.clone(),
+        }
+    }
+}
+
+/// Re-fuzzifies a crisp output value against the output terms.
+///
+/// Returns the terms with a non-zero degree, highest degree first.
+pub fn refuzzify_output(crisp_value: f32, terms: &[FuzzyOutputValue]) -> Vec<TermDegree> {
+    let mut degrees: Vec<TermDegree> = terms
+        .iter()
+        .map(|fov| TermDegree {
+            fuzzy_output_value_id: fov.id,
+            term: fov.value.clone(),
+            degree: calculate_membership(crisp_value, fov.a, fov.b, fov.c, fov.d, fov.is_triangle),
+        })
+        .filter(|td| td.degree > 0.0)
+        .collect();
+
+    degrees.sort_by(|a, b| b.degree.total_cmp(&a.degree));
+    degrees
+}
+
+/// Finds the term, plain or with one of the hedges, most similar to the aggregated
+/// output set (the union of `clipped_sets`).
+///
+/// Similarity is the Jaccard index Σmin(μA, μT) / Σmax(μA, μT) over `resolution + 1`
+/// sample points. On ties the plain term wins. Returns `None` if no rule fired.
+pub fn approximate_output(
+    clipped_sets: &[(FuzzyOutputValue, Option<Hedge>, f32)],
+    terms: &[FuzzyOutputValue],
+    start: f32,
+    end: f32,
+    resolution: usize,
+) -> Option<LinguisticApproximation> {
+    if clipped_sets.is_empty() || terms.is_empty() || resolution == 0 {
+        return None;
+    }
+
+    let step = (end - start) / (resolution as f32);
+    let xs: Vec<f32> = (0..=resolution).map(|i| start + (i as f32) * step).collect();
+    let aggregated: Vec<f32> = xs
+        .iter()
+        .map(|x| aggregated_membership_at(*x, clipped_sets))
+        .collect();
+
+    if aggregated.iter().all(|mu| *mu <= 0.0) {
+        return None;
+    }
+
+    let mut best: Option<LinguisticApproximation> = None;
+
+    for fov in terms {
+        let term: Vec<f32> = xs
+            .iter()
+            .map(|x| calculate_membership(*x, fov.a, fov.b, fov.c, fov.d, fov.is_triangle))
+            .collect();
+
+        let candidates = std::iter::once(None).chain(Hedge::ALL.iter().copied().map(Some));
+        for hedge in candidates {
+            let (intersection, union) = aggregated.iter().zip(&term).fold(
+                (0.0_f32, 0.0_f32),
+                |(intersection, union), (mu_a, mu_t)| {
+                    let mu_t = hedge.map_or(*mu_t, |h| h.apply(*mu_t));
+                    (intersection + mu_a.min(mu_t), union + mu_a.max(mu_t))
+                },
+            );
+            let similarity = if union > 0.0 { intersection / union } else { 0.0 };
+
+            if best.as_ref().is_none_or(|b| similarity > b.similarity) {
+                best = Some(LinguisticApproximation {
+                    fuzzy_output_value_id: fov.id,
+                    term: fov.value.clone(),
+                    hedge,
+                    similarity,
+                });
+            }
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-4;
+
+    fn term(id: i64, value: &str, a: f32, b: f32, c: f32, d: f32) -> FuzzyOutputValue {
+        FuzzyOutputValue {
+            id,
+            output_parameter_id: 100,
+            value: value.to_string(),
+            a,
+            b,
+            c,
+            d,
+            is_triangle: false,
+            lower: None,
+        }
+    }
+
+    fn create_test_terms() -> Vec<FuzzyOutputValue> {
+        vec![
+            term(1, "Low", -1.0, 0.0, 2.0, 5.0),
+            term(2, "High", 2.0, 5.0, 10.0, 11.0),
+        ]
+    }
+
+    #[test]
+    fn test_refuzzify_output_sorts_by_degree() {
+        // At 4: Low = 1/3, High = 2/3
+        let degrees = refuzzify_output(4.0, &create_test_terms());
+
+        assert_eq!(degrees.len(), 2);
+        assert_eq!(degrees[0].term, "High");
+        assert!((degrees[0].degree - 2.0 / 3.0).abs() < TOLERANCE);
+        assert!((degrees[1].degree - 1.0 / 3.0).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn test_refuzzify_output_skips_zero_degrees() {
+        let degrees = refuzzify_output(8.0, &create_test_terms());
+
+        assert_eq!(degrees.len(), 1);
+        assert_eq!(degrees[0].term, "High");
+    }
+
+    #[test]
+    fn test_approximate_unclipped_term_matches_itself() {
+        let terms = create_test_terms();
+        let clipped = vec![(terms[1].clone(), None, 1.0)];
+
+        let approximation = approximate_output(&clipped, &terms, 0.0, 10.0, 100).unwrap();
+
+        assert_eq!(approximation.term, "High");
+        assert_eq!(approximation.hedge, None);
+        assert!((approximation.similarity - 1.0).abs() < TOLERANCE);
+        assert_eq!(approximation.label(), "High");
+    }
+
+    #[test]
+    fn test_approximate_uses_hedge_for_wider_set() {
+        // Falls off slower than Low, which a dilated ("somewhat") Low follows best
+        let terms = create_test_terms();
+        let clipped = vec![(term(3, "Wide", -1.0, 0.0, 2.0, 8.0), None, 1.0)];
+
+        let approximation = approximate_output(&clipped, &terms, 0.0, 10.0, 100).unwrap();
+
+        assert_eq!(approximation.term, "Low");
+        assert_eq!(approximation.hedge, Some(Hedge::Somewhat));
+        assert_eq!(approximation.label(), "somewhat Low");
+    }
+
+    #[test]
+    fn test_approximate_without_fired_rules() {
+        assert!(approximate_output(&[], &create_test_terms(), 0.0, 10.0, 100).is_none());
+    }
+}
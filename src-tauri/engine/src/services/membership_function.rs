@@ -56,6 +56,7 @@ pub fn calculate_membership(x: f32, a: f32, b: f32, c: f32, d: f32, is_triangle:
 ///
 /// # Returns
 /// Membership degree in range [0.0, 1.0]
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_membership_with_edges(
     x: f32,
     a: f32,
@@ -68,9 +69,7 @@ pub fn calculate_membership_with_edges(
 ) -> f32 {
     let (b, c) = if is_triangle { (b, b) } else { (b, c) };
 
-    if is_left_edge && x <= c {
-        1.0
-    } else if is_right_edge && x >= b {
+    if (is_left_edge && x <= c) || (is_right_edge && x >= b) {
         1.0
     } else {
         calculate_membership(x, a, b, c, d, false)
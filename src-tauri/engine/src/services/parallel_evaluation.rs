@@ -0,0 +1,145 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Items a worker claims at once: large enough to keep the shared counter out of
+/// the hot path, small enough for even load and timely cancellation
+const CHUNK_SIZE: usize = 64;
+
+/// Cancellation flag and progress of a running job, shared between the workers
+/// and whoever observes or cancels the job
+#[derive(Debug, Default)]
+pub struct JobControl {
+    cancelled: AtomicBool,
+    completed: AtomicUsize,
+}
+
+impl JobControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks the workers to stop; items already being evaluated are finished first
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Number of items evaluated so far
+    pub fn completed(&self) -> usize {
+        self.completed.load(Ordering::Relaxed)
+    }
+}
+
+/// Number of worker threads to use by default: one per available core
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+/// Maps `f` over `items` on up to `threads` worker threads, keeping the order of
+/// the items. Workers claim chunks of [`CHUNK_SIZE`] items and call
+/// `on_progress(completed, total)` after each one.
+///
+/// # Returns
+/// The results, or `None` if the job was cancelled before every item was done
+pub fn parallel_map<T, R, F, P>(
+    items: &[T],
+    threads: usize,
+    control: &JobControl,
+    f: F,
+    on_progress: P,
+) -> Option<Vec<R>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    P: Fn(usize, usize) + Sync,
+{
+    let total = items.len();
+    let next = AtomicUsize::new(0);
+    let chunks: Mutex<Vec<(usize, Vec<R>)>> = Mutex::new(Vec::new());
+    let workers = threads.max(1).min(total.div_ceil(CHUNK_SIZE).max(1));
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                if control.is_cancelled() {
+                    break;
+                }
+                let start = next.fetch_add(CHUNK_SIZE, Ordering::Relaxed);
+                if start >= total {
+                    break;
+                }
+                let end = (start + CHUNK_SIZE).min(total);
+                let results: Vec<R> = items[start..end].iter().map(&f).collect();
+
+                chunks
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .push((start, results));
+                let completed = control.completed.fetch_add(end - start, Ordering::Relaxed) + end - start;
+                on_progress(completed, total);
+            });
+        }
+    });
+
+    let mut chunks = chunks.into_inner().unwrap_or_else(|e| e.into_inner());
+    if chunks.iter().map(|(_, results)| results.len()).sum::<usize>() < total {
+        return None;
+    }
+    chunks.sort_by_key(|(start, _)| *start);
+    Some(chunks.into_iter().flat_map(|(_, results)| results).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parallel_map_keeps_order() {
+        let items: Vec<u32> = (0..1000).collect();
+        let control = JobControl::new();
+        let result = parallel_map(&items, 4, &control, |x| x * 2, |_, _| {}).unwrap();
+
+        assert_eq!(result, items.iter().map(|x| x * 2).collect::<Vec<_>>());
+        assert_eq!(control.completed(), 1000);
+    }
+
+    #[test]
+    fn test_parallel_map_reports_progress_up_to_total() {
+        let items: Vec<u32> = (0..300).collect();
+        let control = JobControl::new();
+        let last = AtomicUsize::new(0);
+        parallel_map(&items, 3, &control, |x| *x, |completed, total| {
+            assert_eq!(total, 300);
+            last.fetch_max(completed, Ordering::Relaxed);
+        })
+        .unwrap();
+
+        assert_eq!(last.load(Ordering::Relaxed), 300);
+    }
+
+    #[test]
+    fn test_parallel_map_stops_when_cancelled() {
+        let items: Vec<u32> = (0..10_000).collect();
+        let control = JobControl::new();
+        let result = parallel_map(&items, 2, &control, |x| *x, |completed, _| {
+            if completed >= 256 {
+                control.cancel();
+            }
+        });
+
+        assert!(result.is_none());
+        assert!(control.completed() < items.len());
+    }
+
+    #[test]
+    fn test_parallel_map_of_nothing() {
+        let control = JobControl::new();
+        let result = parallel_map(&[] as &[u32], 4, &control, |x| *x, |_, _| {});
+        assert_eq!(result, Some(vec![]));
+    }
+}
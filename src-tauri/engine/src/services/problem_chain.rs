@@ -0,0 +1,158 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    entities::parameter_link::ParameterLink,
+    error::{DomainError, EntityKind, FieldError},
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Visit {
+    InProgress,
+    Done,
+}
+
+/// Depth-first walk from `problem_id` against the link direction, pushing every
+/// problem after the problems feeding it. Returns the problems of a cycle if one is met.
+fn visit(
+    problem_id: i64,
+    sources: &BTreeMap<i64, BTreeSet<i64>>,
+    state: &mut BTreeMap<i64, Visit>,
+    path: &mut Vec<i64>,
+    order: &mut Vec<i64>,
+) -> Result<(), Vec<i64>> {
+    match state.get(&problem_id) {
+        Some(Visit::Done) => return Ok(()),
+        Some(Visit::InProgress) => {
+            let start = path.iter().position(|id| *id == problem_id).unwrap_or(0);
+            return Err(path[start..].to_vec());
+        }
+        None => {}
+    }
+
+    state.insert(problem_id, Visit::InProgress);
+    path.push(problem_id);
+    for source in sources.get(&problem_id).into_iter().flatten() {
+        visit(*source, sources, state, path, order)?;
+    }
+    path.pop();
+    state.insert(problem_id, Visit::Done);
+    order.push(problem_id);
+
+    Ok(())
+}
+
+fn sources_by_target(links: &[ParameterLink]) -> BTreeMap<i64, BTreeSet<i64>> {
+    let mut sources: BTreeMap<i64, BTreeSet<i64>> = BTreeMap::new();
+    for link in links {
+        sources
+            .entry(link.target_problem_id)
+            .or_default()
+            .insert(link.source_problem_id);
+    }
+    sources
+}
+
+fn cycle_message(cycle: &[i64]) -> String {
+    let problems: Vec<String> = cycle.iter().map(|id| id.to_string()).collect();
+    format!("Parameter links form a cycle between problems {}", problems.join(" -> "))
+}
+
+/// Returns the problems of a cycle in the link graph, if there is one
+pub fn find_cycle(links: &[ParameterLink]) -> Option<Vec<i64>> {
+    let sources = sources_by_target(links);
+    let mut state = BTreeMap::new();
+    let mut order = Vec::new();
+
+    sources.keys().find_map(|target| {
+        visit(*target, &sources, &mut state, &mut Vec::new(), &mut order).err()
+    })
+}
+
+/// Returns `problem_id` and every problem feeding it, directly or through other
+/// problems, in evaluation order: each problem comes after all of its sources,
+/// so `problem_id` is last.
+pub fn evaluation_order(problem_id: i64, links: &[ParameterLink]) -> Result<Vec<i64>, DomainError> {
+    let sources = sources_by_target(links);
+    let mut state = BTreeMap::new();
+    let mut order = Vec::new();
+
+    visit(problem_id, &sources, &mut state, &mut Vec::new(), &mut order)
+        .map_err(|cycle| DomainError::Validation(cycle_message(&cycle)))?;
+
+    Ok(order)
+}
+
+/// Checks that adding `link` to `links` keeps the graph acyclic
+pub fn validate_new_link(link: &ParameterLink, links: &[ParameterLink]) -> Result<(), DomainError> {
+    let invalid = |message: String| {
+        DomainError::invalid_field(
+            EntityKind::ParameterLink,
+            None,
+            FieldError::new("input_parameter_id", message),
+        )
+    };
+    if link.source_problem_id == link.target_problem_id {
+        return Err(invalid(
+            "An output parameter can not feed an input parameter of the same problem".to_string(),
+        ));
+    }
+
+    let mut all = links.to_vec();
+    all.push(link.clone());
+    match find_cycle(&all) {
+        Some(cycle) => Err(invalid(cycle_message(&cycle))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(source_problem_id: i64, target_problem_id: i64) -> ParameterLink {
+        ParameterLink {
+            id: 0,
+            output_parameter_id: source_problem_id * 10,
+            input_parameter_id: target_problem_id * 10 + 1,
+            source_problem_id,
+            target_problem_id,
+        }
+    }
+
+    #[test]
+    fn test_evaluation_order_puts_sources_first() {
+        // 1 and 2 feed 3, 3 feeds 4; 5 feeds nothing relevant
+        let links = vec![link(1, 3), link(2, 3), link(3, 4), link(4, 5)];
+
+        let order = evaluation_order(4, &links).unwrap();
+
+        assert_eq!(order.len(), 4);
+        assert_eq!(*order.last().unwrap(), 4);
+        let position = |id| order.iter().position(|x| *x == id).unwrap();
+        assert!(position(1) < position(3));
+        assert!(position(2) < position(3));
+        assert!(!order.contains(&5));
+    }
+
+    #[test]
+    fn test_evaluation_order_of_unlinked_problem() {
+        assert_eq!(evaluation_order(7, &[link(1, 2)]).unwrap(), vec![7]);
+    }
+
+    #[test]
+    fn test_diamond_is_not_a_cycle() {
+        let links = vec![link(1, 2), link(1, 3), link(2, 4), link(3, 4)];
+
+        assert!(find_cycle(&links).is_none());
+        assert_eq!(evaluation_order(4, &links).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_validate_new_link_rejects_cycles() {
+        let links = vec![link(1, 2), link(2, 3)];
+
+        assert!(validate_new_link(&link(3, 1), &links).is_err());
+        assert!(validate_new_link(&link(2, 2), &links).is_err());
+        assert!(validate_new_link(&link(1, 3), &links).is_ok());
+    }
+}
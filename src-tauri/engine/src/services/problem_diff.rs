@@ -0,0 +1,437 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    entities::{lower_membership::LowerMembership, problem::Problem},
+    services::hedge::{parse_antecedent_hedges, parse_consequent_hedge, Hedge},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// Breakpoints of a linguistic term
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TermShape {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub is_triangle: bool,
+    /// Lower membership function of an interval type-2 term
+    pub lower: Option<LowerMembership>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParameterChange {
+    pub kind: ChangeKind,
+    pub is_input: bool,
+    pub name: String,
+    pub old_range: Option<(f32, f32)>,
+    pub new_range: Option<(f32, f32)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TermChange {
+    pub kind: ChangeKind,
+    pub is_input: bool,
+    pub parameter_name: String,
+    pub term_name: String,
+    pub old_shape: Option<TermShape>,
+    pub new_shape: Option<TermShape>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RuleChange {
+    pub kind: ChangeKind,
+    pub output_parameter_name: String,
+    /// `(input parameter name, term name)` pairs, sorted; hedged terms read e.g. "very Low"
+    pub antecedents: Vec<(String, String)>,
+    pub old_consequent: Option<String>,
+    pub new_consequent: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ProblemDiff {
+    pub parameters: Vec<ParameterChange>,
+    pub terms: Vec<TermChange>,
+    pub rules: Vec<RuleChange>,
+}
+
+impl ProblemDiff {
+    pub fn is_empty(&self) -> bool {
+        self.parameters.is_empty() && self.terms.is_empty() && self.rules.is_empty()
+    }
+}
+
+/// Output parameter name and sorted antecedents
+type RuleKey = (String, Vec<(String, String)>);
+
+/// Flattened, name-keyed view of a problem definition.
+///
+/// Ids are not stable between a snapshot and its restored copy, so everything
+/// is matched by name instead.
+struct DefinitionView {
+    parameters: BTreeMap<(bool, String), (f32, f32)>,
+    terms: BTreeMap<(bool, String, String), TermShape>,
+    rules: BTreeMap<RuleKey, Option<String>>,
+}
+
+impl DefinitionView {
+    fn from(problem: &Problem) -> Self {
+        let mut parameters = BTreeMap::new();
+        let mut terms = BTreeMap::new();
+        let mut input_terms = BTreeMap::new();
+        let mut output_terms = BTreeMap::new();
+
+        for ip in &problem.input_parameters {
+            parameters.insert((true, ip.name.clone()), (ip.start, ip.end));
+            for iv in &ip.input_values {
+                terms.insert(
+                    (true, ip.name.clone(), iv.value.clone()),
+                    TermShape {
+                        a: iv.a,
+                        b: iv.b,
+                        c: iv.c,
+                        d: iv.d,
+                        is_triangle: iv.is_triangle,
+                        lower: iv.lower,
+                    },
+                );
+                input_terms.insert(iv.id, (ip.name.clone(), iv.value.clone()));
+            }
+        }
+        for op in &problem.output_parameters {
+            parameters.insert((false, op.name.clone()), (op.start, op.end));
+            for fov in &op.fuzzy_output_values {
+                terms.insert(
+                    (false, op.name.clone(), fov.value.clone()),
+                    TermShape {
+                        a: fov.a,
+                        b: fov.b,
+                        c: fov.c,
+                        d: fov.d,
+                        is_triangle: fov.is_triangle,
+                        lower: fov.lower,
+                    },
+                );
+                output_terms.insert(fov.id, fov.value.clone());
+            }
+        }
+
+        let mut rules = BTreeMap::new();
+        for ov in &problem.output_values {
+            let Some(op) = problem
+                .output_parameters
+                .iter()
+                .find(|op| op.id == ov.output_parameter_id)
+            else {
+                continue;
+            };
+            let hedges: BTreeMap<i64, Hedge> =
+                parse_antecedent_hedges(&ov.antecedent_hedges).into_iter().collect();
+            let mut antecedents: Vec<(String, String)> = ov
+                .input_value_ids
+                .split('|')
+                .filter_map(|s| s.parse::<i64>().ok())
+                .filter_map(|id| {
+                    let (parameter, term) = input_terms.get(&id)?;
+                    Some((parameter.clone(), hedged_term(hedges.get(&id).copied(), term)))
+                })
+                .collect();
+            antecedents.sort();
+            let consequent_hedge = parse_consequent_hedge(ov.consequent_hedge.as_deref());
+            let consequent = ov
+                .fuzzy_output_value_id
+                .and_then(|id| output_terms.get(&id))
+                .map(|term| hedged_term(consequent_hedge, term));
+            rules.insert((op.name.clone(), antecedents), consequent);
+        }
+
+        Self {
+            parameters,
+            terms,
+            rules,
+        }
+    }
+}
+
+fn hedged_term(hedge: Option<Hedge>, term: &str) -> String {
+    match hedge {
+        Some(hedge) => format!("{} {}", hedge.as_str(), term),
+        None => term.to_string(),
+    }
+}
+
+/// Kind of change, key, old value and new value
+type MapChange<K, V> = (ChangeKind, K, Option<V>, Option<V>);
+
+/// Walks two sorted maps side by side and reports keys only on the left,
+/// only on the right, or present on both sides with different values
+fn diff_maps<K: Ord + Clone, V: Clone + PartialEq>(
+    old: &BTreeMap<K, V>,
+    new: &BTreeMap<K, V>,
+) -> Vec<MapChange<K, V>> {
+    let mut changes = Vec::new();
+    for (key, old_value) in old {
+        match new.get(key) {
+            None => changes.push((ChangeKind::Removed, key.clone(), Some(old_value.clone()), None)),
+            Some(new_value) if new_value != old_value => changes.push((
+                ChangeKind::Changed,
+                key.clone(),
+                Some(old_value.clone()),
+                Some(new_value.clone()),
+            )),
+            Some(_) => {}
+        }
+    }
+    for (key, new_value) in new {
+        if !old.contains_key(key) {
+            changes.push((ChangeKind::Added, key.clone(), None, Some(new_value.clone())));
+        }
+    }
+    changes
+}
+
+/// Computes a structural diff between two definitions of a problem:
+/// added/removed parameters and range changes, added/removed terms and changed
+/// breakpoints, added/removed rules and changed consequents.
+pub fn diff_problems(old: &Problem, new: &Problem) -> ProblemDiff {
+    let old_view = DefinitionView::from(old);
+    let new_view = DefinitionView::from(new);
+
+    let parameters = diff_maps(&old_view.parameters, &new_view.parameters)
+        .into_iter()
+        .map(|(kind, (is_input, name), old_range, new_range)| ParameterChange {
+            kind,
+            is_input,
+            name,
+            old_range,
+            new_range,
+        })
+        .collect();
+
+    let terms = diff_maps(&old_view.terms, &new_view.terms)
+        .into_iter()
+        .map(
+            |(kind, (is_input, parameter_name, term_name), old_shape, new_shape)| TermChange {
+                kind,
+                is_input,
+                parameter_name,
+                term_name,
+                old_shape,
+                new_shape,
+            },
+        )
+        .collect();
+
+    let rules = diff_maps(&old_view.rules, &new_view.rules)
+        .into_iter()
+        .map(
+            |(kind, (output_parameter_name, antecedents), old_consequent, new_consequent)| {
+                RuleChange {
+                    kind,
+                    output_parameter_name,
+                    antecedents,
+                    old_consequent: old_consequent.flatten(),
+                    new_consequent: new_consequent.flatten(),
+                }
+            },
+        )
+        .collect();
+
+    ProblemDiff {
+        parameters,
+        terms,
+        rules,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{
+        fuzzy_output_value::FuzzyOutputValue, input_parameter::InputParameter,
+        input_value::InputValue, output_parameter::OutputParameter, output_value::OutputValue,
+        parameter_kind::ParameterKind, parameter_metadata::ParameterMetadata,
+        partition_mode::PartitionMode,
+    };
+
+    fn create_test_problem(id_offset: i64) -> Problem {
+        Problem {
+            id: 1,
+            prev_problem_id: None,
+            is_final: true,
+            name: "Test".to_string(),
+            description: String::new(),
+            image_id: None,
+            created_at: String::new(),
+            updated_at: None,
+            input_parameters: vec![InputParameter {
+                id: 10 + id_offset,
+                problem_id: 1,
+                name: "x".to_string(),
+                start: 0.0,
+                end: 10.0,
+                partition_mode: PartitionMode::Ruspini,
+                kind: ParameterKind::Continuous,
+                metadata: ParameterMetadata::default(),
+                input_values: vec![
+                    InputValue {
+                        id: 100 + id_offset,
+                        input_parameter_id: 10 + id_offset,
+                        value: "Low".to_string(),
+                        a: 0.0,
+                        b: 0.0,
+                        c: 2.0,
+                        d: 8.0,
+                        is_triangle: false,
+                        lower: None,
+                    },
+                    InputValue {
+                        id: 101 + id_offset,
+                        input_parameter_id: 10 + id_offset,
+                        value: "High".to_string(),
+                        a: 2.0,
+                        b: 8.0,
+                        c: 10.0,
+                        d: 10.0,
+                        is_triangle: false,
+                        lower: None,
+                    },
+                ],
+            }],
+            output_parameters: vec![OutputParameter {
+                id: 20 + id_offset,
+                problem_id: 1,
+                name: "y".to_string(),
+                start: 0.0,
+                end: 1.0,
+                partition_mode: PartitionMode::Ruspini,
+                metadata: ParameterMetadata::default(),
+                fuzzy_output_values: vec![FuzzyOutputValue {
+                    id: 200 + id_offset,
+                    output_parameter_id: 20 + id_offset,
+                    value: "Any".to_string(),
+                    a: 0.0,
+                    b: 0.0,
+                    c: 1.0,
+                    d: 1.0,
+                    is_triangle: false,
+                    lower: None,
+                }],
+            }],
+            output_values: vec![
+                OutputValue {
+                    id: 1000 + id_offset,
+                    output_parameter_id: 20 + id_offset,
+                    fuzzy_output_value_id: Some(200 + id_offset),
+                    input_value_ids: format!("|{}|", 100 + id_offset),
+                    antecedent_hedges: String::new(),
+                    consequent_hedge: None,
+                },
+                OutputValue {
+                    id: 1001 + id_offset,
+                    output_parameter_id: 20 + id_offset,
+                    fuzzy_output_value_id: None,
+                    input_value_ids: format!("|{}|", 101 + id_offset),
+                    antecedent_hedges: String::new(),
+                    consequent_hedge: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_diff_ignores_ids() {
+        let diff = diff_problems(&create_test_problem(0), &create_test_problem(5000));
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_parameter_changes() {
+        let old = create_test_problem(0);
+        let mut new = create_test_problem(0);
+        new.output_parameters[0].end = 2.0;
+        new.input_parameters.push(InputParameter {
+            id: 11,
+            problem_id: 1,
+            name: "z".to_string(),
+            start: 0.0,
+            end: 1.0,
+            partition_mode: PartitionMode::Ruspini,
+            kind: ParameterKind::Continuous,
+            metadata: ParameterMetadata::default(),
+            input_values: vec![],
+        });
+
+        let diff = diff_problems(&old, &new);
+        assert_eq!(diff.parameters.len(), 2);
+
+        let range = diff.parameters.iter().find(|p| p.name == "y").unwrap();
+        assert_eq!(range.kind, ChangeKind::Changed);
+        assert!(!range.is_input);
+        assert_eq!(range.old_range, Some((0.0, 1.0)));
+        assert_eq!(range.new_range, Some((0.0, 2.0)));
+
+        let added = diff.parameters.iter().find(|p| p.name == "z").unwrap();
+        assert_eq!(added.kind, ChangeKind::Added);
+        assert!(added.is_input);
+    }
+
+    #[test]
+    fn test_diff_reports_term_and_rule_changes() {
+        let old = create_test_problem(0);
+        let mut new = create_test_problem(0);
+        new.input_parameters[0].input_values[0].c = 3.0;
+        new.output_values[1].fuzzy_output_value_id = Some(200);
+
+        let diff = diff_problems(&old, &new);
+        assert!(diff.parameters.is_empty());
+
+        assert_eq!(diff.terms.len(), 1);
+        assert_eq!(diff.terms[0].kind, ChangeKind::Changed);
+        assert_eq!(diff.terms[0].term_name, "Low");
+        assert_eq!(diff.terms[0].old_shape.unwrap().c, 2.0);
+        assert_eq!(diff.terms[0].new_shape.unwrap().c, 3.0);
+
+        assert_eq!(diff.rules.len(), 1);
+        assert_eq!(diff.rules[0].kind, ChangeKind::Changed);
+        assert_eq!(
+            diff.rules[0].antecedents,
+            vec![("x".to_string(), "High".to_string())]
+        );
+        assert_eq!(diff.rules[0].old_consequent, None);
+        assert_eq!(diff.rules[0].new_consequent, Some("Any".to_string()));
+    }
+
+    #[test]
+    fn test_diff_shows_consequent_hedge() {
+        let old = create_test_problem(0);
+        let mut new = create_test_problem(0);
+        new.output_values[0].consequent_hedge = Some("very".to_string());
+
+        let diff = diff_problems(&old, &new);
+
+        assert_eq!(diff.rules.len(), 1);
+        assert_eq!(diff.rules[0].old_consequent, Some("Any".to_string()));
+        assert_eq!(diff.rules[0].new_consequent, Some("very Any".to_string()));
+    }
+
+    #[test]
+    fn test_diff_reports_removed_terms() {
+        let old = create_test_problem(0);
+        let mut new = create_test_problem(0);
+        new.input_parameters[0].input_values.pop();
+        new.output_values.pop();
+
+        let diff = diff_problems(&old, &new);
+        assert_eq!(diff.terms.len(), 1);
+        assert_eq!(diff.terms[0].kind, ChangeKind::Removed);
+        assert!(diff.terms[0].new_shape.is_none());
+        assert_eq!(diff.rules.len(), 1);
+        assert_eq!(diff.rules[0].kind, ChangeKind::Removed);
+    }
+}
@@ -0,0 +1,612 @@
+use std::collections::HashMap;
+
+use crate::{
+    entities::{
+        fuzzy_output_value::FuzzyOutputValue, input_parameter::InputParameter,
+        input_value::InputValue, lower_membership::LowerMembership,
+        output_parameter::OutputParameter, output_value::OutputValue,
+        partition_mode::PartitionMode, problem::Problem,
+    },
+    services::{
+        fuzzy_partition::{validate_fuzzy_partition, PARTITION_TOLERANCE},
+        hedge::{format_antecedent_hedges, parse_antecedent_hedges},
+    },
+};
+
+/// Something the merge could not apply as the incoming definition asked for
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeConflict {
+    /// What the conflict is about, e.g. `input parameter 'x'`
+    pub subject: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProblemMerge {
+    /// Merged definition. Parameters, terms and rules that already existed keep
+    /// their ids; added ones get negative placeholder ids.
+    pub problem: Problem,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// Term fields shared by input and output terms
+#[derive(Debug, Clone)]
+struct Term {
+    id: i64,
+    name: String,
+    shape: (f32, f32, f32, f32),
+    is_triangle: bool,
+    lower: Option<LowerMembership>,
+}
+
+impl From<&InputValue> for Term {
+    fn from(iv: &InputValue) -> Self {
+        Self {
+            id: iv.id,
+            name: iv.value.clone(),
+            shape: (iv.a, iv.b, iv.c, iv.d),
+            is_triangle: iv.is_triangle,
+            lower: iv.lower,
+        }
+    }
+}
+
+impl From<&FuzzyOutputValue> for Term {
+    fn from(fov: &FuzzyOutputValue) -> Self {
+        Self {
+            id: fov.id,
+            name: fov.value.clone(),
+            shape: (fov.a, fov.b, fov.c, fov.d),
+            is_triangle: fov.is_triangle,
+            lower: fov.lower,
+        }
+    }
+}
+
+struct Merger {
+    next_id: i64,
+    conflicts: Vec<MergeConflict>,
+}
+
+impl Merger {
+    fn new_id(&mut self) -> i64 {
+        self.next_id -= 1;
+        self.next_id
+    }
+
+    fn conflict(&mut self, subject: String, message: String) {
+        self.conflicts.push(MergeConflict { subject, message });
+    }
+
+    /// Merges `incoming` terms into `current` by name and records the id each
+    /// incoming term ends up with in `ids`
+    fn merge_terms(
+        &mut self,
+        subject: &str,
+        range: (f32, f32),
+        mode: PartitionMode,
+        current: Vec<Term>,
+        incoming: Vec<Term>,
+        ids: &mut HashMap<i64, i64>,
+    ) -> Vec<Term> {
+        let mut merged = current;
+        let mut matched = vec![false; merged.len()];
+
+        for term in incoming {
+            match merged.iter().position(|t| t.name == term.name) {
+                Some(i) if matched[i] => {
+                    self.conflict(
+                        subject.to_string(),
+                        format!("Term '{}' appears more than once in the imported file", term.name),
+                    );
+                }
+                Some(i) => {
+                    matched[i] = true;
+                    merged[i].shape = term.shape;
+                    merged[i].is_triangle = term.is_triangle;
+                    merged[i].lower = term.lower;
+                    ids.insert(term.id, merged[i].id);
+                }
+                None => {
+                    let id = self.new_id();
+                    ids.insert(term.id, id);
+                    matched.push(true);
+                    merged.push(Term { id, ..term });
+                }
+            }
+        }
+
+        // Only worth mentioning when the file defines terms for this parameter at all
+        if matched.iter().any(|m| *m) {
+            for (term, is_matched) in merged.iter().zip(&matched) {
+                if !is_matched {
+                    self.conflict(
+                        subject.to_string(),
+                        format!("Term '{}' is not in the imported file and was kept", term.name),
+                    );
+                }
+            }
+        }
+
+        merged.sort_by(|x, y| x.shape.0.total_cmp(&y.shape.0));
+        if !merged.is_empty() && mode == PartitionMode::Ruspini {
+            let shapes: Vec<_> = merged.iter().map(|t| t.shape).collect();
+            if let Err(message) = validate_fuzzy_partition(&shapes, range.0, range.1, PARTITION_TOLERANCE) {
+                self.conflict(
+                    subject.to_string(),
+                    format!("Merged terms do not form a fuzzy partition: {}", message),
+                );
+            }
+        }
+
+        merged
+    }
+}
+
+fn parse_rule_ids(input_value_ids: &str) -> Vec<i64> {
+    let mut ids: Vec<i64> = input_value_ids
+        .split('|')
+        .filter_map(|s| s.parse::<i64>().ok())
+        .collect();
+    ids.sort();
+    ids
+}
+
+/// Every combination of one term id per input parameter, in parameter order
+fn term_combinations(parameters: &[InputParameter]) -> Vec<Vec<i64>> {
+    if parameters.is_empty() {
+        return vec![];
+    }
+    parameters.iter().fold(vec![vec![]], |combinations, ip| {
+        combinations
+            .iter()
+            .flat_map(|prefix| {
+                ip.input_values.iter().map(move |iv| {
+                    let mut combination = prefix.clone();
+                    combination.push(iv.id);
+                    combination
+                })
+            })
+            .collect()
+    })
+}
+
+/// Merges the definition of `incoming` into `current`, matching parameters
+/// and terms by name.
+///
+/// Matched parameters take the incoming range and partition mode, matched terms the incoming
+/// breakpoints, and missing parameters and terms are added. Nothing is
+/// removed. The rule table is rebuilt for every combination of input terms;
+/// each rule takes the incoming consequent if the file sets one, otherwise
+/// the current one. Name, description and everything outside the definition
+/// are kept from `current`.
+pub fn merge_problems(current: &Problem, incoming: &Problem) -> ProblemMerge {
+    let mut merger = Merger {
+        next_id: 0,
+        conflicts: vec![],
+    };
+
+    // Incoming id -> merged id
+    let mut input_term_ids = HashMap::<i64, i64>::new();
+    let mut output_param_ids = HashMap::<i64, i64>::new();
+    let mut output_term_ids = HashMap::<i64, i64>::new();
+
+    let mut input_parameters = current.input_parameters.clone();
+    for (i, inc) in incoming.input_parameters.iter().enumerate() {
+        let subject = format!("input parameter '{}'", inc.name);
+        if incoming.input_parameters[..i].iter().any(|p| p.name == inc.name) {
+            merger.conflict(subject, "Parameter appears more than once in the imported file".to_string());
+            continue;
+        }
+
+        let index = match input_parameters.iter().position(|p| p.name == inc.name) {
+            Some(index) => {
+                input_parameters[index].start = inc.start;
+                input_parameters[index].end = inc.end;
+                input_parameters[index].partition_mode = inc.partition_mode;
+                input_parameters[index].kind = inc.kind;
+                input_parameters[index].metadata = inc.metadata.clone();
+                index
+            }
+            None => {
+                input_parameters.push(InputParameter {
+                    id: merger.new_id(),
+                    problem_id: current.id,
+                    name: inc.name.clone(),
+                    start: inc.start,
+                    end: inc.end,
+                    partition_mode: inc.partition_mode,
+                    kind: inc.kind,
+                    metadata: inc.metadata.clone(),
+                    input_values: vec![],
+                });
+                input_parameters.len() - 1
+            }
+        };
+
+        let parameter = &mut input_parameters[index];
+        let terms = merger.merge_terms(
+            &subject,
+            (parameter.start, parameter.end),
+            parameter.partition_mode,
+            parameter.input_values.iter().map(Term::from).collect(),
+            inc.input_values.iter().map(Term::from).collect(),
+            &mut input_term_ids,
+        );
+        let parameter_id = parameter.id;
+        parameter.input_values = terms
+            .into_iter()
+            .map(|t| InputValue {
+                id: t.id,
+                input_parameter_id: parameter_id,
+                value: t.name,
+                a: t.shape.0,
+                b: t.shape.1,
+                c: t.shape.2,
+                d: t.shape.3,
+                is_triangle: t.is_triangle,
+                lower: t.lower,
+            })
+            .collect();
+    }
+
+    let mut output_parameters = current.output_parameters.clone();
+    for (i, inc) in incoming.output_parameters.iter().enumerate() {
+        let subject = format!("output parameter '{}'", inc.name);
+        if incoming.output_parameters[..i].iter().any(|p| p.name == inc.name) {
+            merger.conflict(subject, "Parameter appears more than once in the imported file".to_string());
+            continue;
+        }
+
+        let index = match output_parameters.iter().position(|p| p.name == inc.name) {
+            Some(index) => {
+                output_parameters[index].start = inc.start;
+                output_parameters[index].end = inc.end;
+                output_parameters[index].partition_mode = inc.partition_mode;
+                output_parameters[index].metadata = inc.metadata.clone();
+                index
+            }
+            None => {
+                output_parameters.push(OutputParameter {
+                    id: merger.new_id(),
+                    problem_id: current.id,
+                    name: inc.name.clone(),
+                    start: inc.start,
+                    end: inc.end,
+                    partition_mode: inc.partition_mode,
+                    metadata: inc.metadata.clone(),
+                    fuzzy_output_values: vec![],
+                });
+                output_parameters.len() - 1
+            }
+        };
+        output_param_ids.insert(inc.id, output_parameters[index].id);
+
+        let parameter = &mut output_parameters[index];
+        let terms = merger.merge_terms(
+            &subject,
+            (parameter.start, parameter.end),
+            parameter.partition_mode,
+            parameter.fuzzy_output_values.iter().map(Term::from).collect(),
+            inc.fuzzy_output_values.iter().map(Term::from).collect(),
+            &mut output_term_ids,
+        );
+        let parameter_id = parameter.id;
+        parameter.fuzzy_output_values = terms
+            .into_iter()
+            .map(|t| FuzzyOutputValue {
+                id: t.id,
+                output_parameter_id: parameter_id,
+                value: t.name,
+                a: t.shape.0,
+                b: t.shape.1,
+                c: t.shape.2,
+                d: t.shape.3,
+                is_triangle: t.is_triangle,
+                lower: t.lower,
+            })
+            .collect();
+    }
+
+    // Existing rules keyed by output parameter and sorted antecedent ids
+    let mut current_rules = HashMap::<(i64, Vec<i64>), &OutputValue>::new();
+    for ov in &current.output_values {
+        current_rules.insert((ov.output_parameter_id, parse_rule_ids(&ov.input_value_ids)), ov);
+    }
+
+    // Incoming consequents and hedges translated to merged ids
+    let mut incoming_consequents = HashMap::<(i64, Vec<i64>), (i64, String, Option<String>)>::new();
+    for ov in &incoming.output_values {
+        let Some(fid) = ov.fuzzy_output_value_id else {
+            continue;
+        };
+        let output_parameter_name = incoming
+            .output_parameters
+            .iter()
+            .find(|op| op.id == ov.output_parameter_id)
+            .map(|op| op.name.clone())
+            .unwrap_or_else(|| ov.output_parameter_id.to_string());
+        let subject = format!("rule of '{}' ({})", output_parameter_name, ov.input_value_ids);
+
+        let antecedents: Option<Vec<i64>> = parse_rule_ids(&ov.input_value_ids)
+            .iter()
+            .map(|id| input_term_ids.get(id).copied())
+            .collect();
+        match (
+            output_param_ids.get(&ov.output_parameter_id),
+            antecedents,
+            output_term_ids.get(&fid),
+        ) {
+            (Some(op_id), Some(mut antecedents), Some(consequent)) => {
+                antecedents.sort();
+                let antecedent_hedges = format_antecedent_hedges(
+                    &parse_antecedent_hedges(&ov.antecedent_hedges)
+                        .into_iter()
+                        .filter_map(|(id, hedge)| input_term_ids.get(&id).map(|id| (*id, hedge)))
+                        .collect::<Vec<_>>(),
+                );
+                incoming_consequents.insert(
+                    (*op_id, antecedents),
+                    (*consequent, antecedent_hedges, ov.consequent_hedge.clone()),
+                );
+            }
+            _ => merger.conflict(
+                subject,
+                "Rule refers to parameters or terms that are not in the imported file and was skipped"
+                    .to_string(),
+            ),
+        }
+    }
+
+    let combinations = term_combinations(&input_parameters);
+    let mut output_values = vec![];
+    for op in &output_parameters {
+        for combination in &combinations {
+            let mut key_ids = combination.clone();
+            key_ids.sort();
+            let key = (op.id, key_ids);
+            let existing = current_rules.get(&key);
+
+            // An incoming consequent replaces the rule's hedges along with its term
+            let (fuzzy_output_value_id, antecedent_hedges, consequent_hedge) =
+                match incoming_consequents.get(&key) {
+                    Some((consequent, antecedent_hedges, consequent_hedge)) => (
+                        Some(*consequent),
+                        antecedent_hedges.clone(),
+                        consequent_hedge.clone(),
+                    ),
+                    None => (
+                        existing.and_then(|ov| ov.fuzzy_output_value_id),
+                        existing.map(|ov| ov.antecedent_hedges.clone()).unwrap_or_default(),
+                        existing.and_then(|ov| ov.consequent_hedge.clone()),
+                    ),
+                };
+            output_values.push(OutputValue {
+                id: existing.map_or_else(|| merger.new_id(), |ov| ov.id),
+                output_parameter_id: op.id,
+                fuzzy_output_value_id,
+                input_value_ids: key.1.iter().map(|id| format!("|{}|", id)).collect(),
+                antecedent_hedges,
+                consequent_hedge,
+            });
+        }
+    }
+
+    ProblemMerge {
+        problem: Problem {
+            input_parameters,
+            output_parameters,
+            output_values,
+            ..current.clone()
+        },
+        conflicts: merger.conflicts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{parameter_kind::ParameterKind, parameter_metadata::ParameterMetadata};
+
+    fn input_value(id: i64, parameter_id: i64, name: &str, shape: (f32, f32, f32, f32)) -> InputValue {
+        InputValue {
+            id,
+            input_parameter_id: parameter_id,
+            value: name.to_string(),
+            a: shape.0,
+            b: shape.1,
+            c: shape.2,
+            d: shape.3,
+            is_triangle: false,
+            lower: None,
+        }
+    }
+
+    fn output_value(id: i64, parameter_id: i64, name: &str, shape: (f32, f32, f32, f32)) -> FuzzyOutputValue {
+        FuzzyOutputValue {
+            id,
+            output_parameter_id: parameter_id,
+            value: name.to_string(),
+            a: shape.0,
+            b: shape.1,
+            c: shape.2,
+            d: shape.3,
+            is_triangle: false,
+            lower: None,
+        }
+    }
+
+    /// One input `x` (Low, High) and one output `y` (Bad, Good) with ids offset by `o`
+    fn create_test_problem(o: i64, high_is_good: bool) -> Problem {
+        Problem {
+            id: 1,
+            prev_problem_id: None,
+            is_final: true,
+            name: "Test".to_string(),
+            description: String::new(),
+            image_id: None,
+            created_at: String::new(),
+            updated_at: None,
+            input_parameters: vec![InputParameter {
+                id: 10 + o,
+                problem_id: 1,
+                name: "x".to_string(),
+                start: 0.0,
+                end: 10.0,
+                partition_mode: PartitionMode::Ruspini,
+                kind: ParameterKind::Continuous,
+                metadata: ParameterMetadata::default(),
+                input_values: vec![
+                    input_value(100 + o, 10 + o, "Low", (0.0, 0.0, 2.0, 8.0)),
+                    input_value(101 + o, 10 + o, "High", (2.0, 8.0, 10.0, 10.0)),
+                ],
+            }],
+            output_parameters: vec![OutputParameter {
+                id: 20 + o,
+                problem_id: 1,
+                name: "y".to_string(),
+                start: 0.0,
+                end: 1.0,
+                partition_mode: PartitionMode::Ruspini,
+                metadata: ParameterMetadata::default(),
+                fuzzy_output_values: vec![
+                    output_value(200 + o, 20 + o, "Bad", (0.0, 0.0, 0.2, 0.8)),
+                    output_value(201 + o, 20 + o, "Good", (0.2, 0.8, 1.0, 1.0)),
+                ],
+            }],
+            output_values: vec![
+                OutputValue {
+                    id: 300 + o,
+                    output_parameter_id: 20 + o,
+                    fuzzy_output_value_id: Some(200 + o),
+                    input_value_ids: format!("|{}|", 100 + o),
+                    antecedent_hedges: String::new(),
+                    consequent_hedge: None,
+                },
+                OutputValue {
+                    id: 301 + o,
+                    output_parameter_id: 20 + o,
+                    fuzzy_output_value_id: Some(if high_is_good { 201 + o } else { 200 + o }),
+                    input_value_ids: format!("|{}|", 101 + o),
+                    antecedent_hedges: String::new(),
+                    consequent_hedge: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_merge_identical_keeps_ids_and_has_no_conflicts() {
+        let current = create_test_problem(0, true);
+        let merge = merge_problems(&current, &create_test_problem(1000, true));
+
+        assert!(merge.conflicts.is_empty());
+        assert_eq!(merge.problem.input_parameters[0].id, 10);
+        assert_eq!(merge.problem.input_parameters[0].input_values[1].id, 101);
+        assert_eq!(merge.problem.output_values.len(), 2);
+        assert!(merge.problem.output_values.iter().all(|ov| ov.id >= 300));
+    }
+
+    #[test]
+    fn test_merge_updates_breakpoints_and_consequents() {
+        let current = create_test_problem(0, false);
+        let mut incoming = create_test_problem(1000, true);
+        incoming.input_parameters[0].input_values[0].c = 3.0;
+        incoming.input_parameters[0].input_values[1].a = 3.0;
+
+        let merge = merge_problems(&current, &incoming);
+
+        assert!(merge.conflicts.is_empty(), "{:?}", merge.conflicts);
+        assert_eq!(merge.problem.input_parameters[0].input_values[0].c, 3.0);
+        let high_rule = merge
+            .problem
+            .output_values
+            .iter()
+            .find(|ov| ov.input_value_ids == "|101|")
+            .unwrap();
+        assert_eq!(high_rule.fuzzy_output_value_id, Some(201));
+    }
+
+    #[test]
+    fn test_merge_adds_missing_parameter_and_expands_rules() {
+        let current = create_test_problem(0, true);
+        let mut incoming = create_test_problem(1000, true);
+        incoming.input_parameters.push(InputParameter {
+            id: 1011,
+            problem_id: 1,
+            name: "z".to_string(),
+            start: 0.0,
+            end: 1.0,
+            partition_mode: PartitionMode::Ruspini,
+            kind: ParameterKind::Continuous,
+            metadata: ParameterMetadata::default(),
+            input_values: vec![
+                input_value(1110, 1011, "Off", (0.0, 0.0, 0.4, 0.6)),
+                input_value(1111, 1011, "On", (0.4, 0.6, 1.0, 1.0)),
+            ],
+        });
+
+        let merge = merge_problems(&current, &incoming);
+
+        assert_eq!(merge.problem.input_parameters.len(), 2);
+        assert!(merge.problem.input_parameters[1].id < 0);
+        // 2 x 2 combinations for the single output parameter
+        assert_eq!(merge.problem.output_values.len(), 4);
+        // Rules of the old one-input table no longer match any combination
+        assert!(merge.problem.output_values.iter().all(|ov| ov.id < 0));
+    }
+
+    #[test]
+    fn test_merge_reports_kept_terms_and_broken_partition() {
+        let current = create_test_problem(0, true);
+        let mut incoming = create_test_problem(1000, true);
+        incoming.input_parameters[0].input_values.remove(1);
+        incoming.input_parameters[0].input_values[0].c = 4.0;
+
+        let merge = merge_problems(&current, &incoming);
+
+        assert!(merge
+            .conflicts
+            .iter()
+            .any(|c| c.message.contains("'High' is not in the imported file")));
+        assert!(merge
+            .conflicts
+            .iter()
+            .any(|c| c.message.contains("do not form a fuzzy partition")));
+        assert_eq!(merge.problem.input_parameters[0].input_values.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_free_form_terms_are_not_a_partition_conflict() {
+        let current = create_test_problem(0, true);
+        let mut incoming = create_test_problem(1000, true);
+        incoming.input_parameters[0].partition_mode = PartitionMode::FreeForm;
+        incoming.input_parameters[0].input_values[0].c = 4.0;
+
+        let merge = merge_problems(&current, &incoming);
+
+        assert_eq!(merge.problem.input_parameters[0].partition_mode, PartitionMode::FreeForm);
+        assert!(!merge
+            .conflicts
+            .iter()
+            .any(|c| c.message.contains("do not form a fuzzy partition")));
+    }
+
+    #[test]
+    fn test_merge_takes_incoming_metadata() {
+        let current = create_test_problem(0, true);
+        let mut incoming = create_test_problem(1000, true);
+        incoming.output_parameters[0].metadata = ParameterMetadata {
+            unit: "kPa".to_string(),
+            precision: Some(1),
+            ..ParameterMetadata::default()
+        };
+
+        let merge = merge_problems(&current, &incoming);
+
+        assert!(merge.conflicts.is_empty());
+        let metadata = &merge.problem.output_parameters[0].metadata;
+        assert_eq!(metadata.unit, "kPa");
+        assert_eq!(metadata.format_value(101.325), "101.3 kPa");
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{
+    entities::lower_membership::LowerMembership,
+    error::FieldError,
+    services::fuzzy_partition::{validate_fuzzy_partition, PARTITION_TOLERANCE},
+};
+
+/// What happens to the terms of a parameter whose universe of discourse changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeChangePolicy {
+    /// Maps every breakpoint linearly from the old range onto the new one
+    Rescale,
+    /// Keeps breakpoints where they are, moving those outside of the new range onto its
+    /// bounds and those on the old bounds onto the new ones
+    Clip,
+    /// Changes the range only if the terms still fit it unchanged
+    Reject,
+}
+
+impl RangeChangePolicy {
+    /// Name as used by the frontend
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RangeChangePolicy::Rescale => "rescale",
+            RangeChangePolicy::Clip => "clip",
+            RangeChangePolicy::Reject => "reject",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "rescale" | "scale" => Some(RangeChangePolicy::Rescale),
+            "clip" => Some(RangeChangePolicy::Clip),
+            "reject" => Some(RangeChangePolicy::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// Geometry of a term as far as a range change is concerned
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangedTerm {
+    pub name: String,
+    /// Breakpoints (a, b, c, d)
+    pub shape: (f32, f32, f32, f32),
+    pub lower: Option<LowerMembership>,
+}
+
+/// Computes the terms of a parameter after its range changes from `old` to `new`.
+///
+/// Returns the moved terms in the order they were given, or the bound of the new
+/// range ("start" or "end") that makes the change impossible under `policy`.
+pub fn change_range(
+    terms: &[RangedTerm],
+    old: (f32, f32),
+    new: (f32, f32),
+    policy: RangeChangePolicy,
+) -> Result<Vec<RangedTerm>, FieldError> {
+    if !new.0.is_finite() || !new.1.is_finite() || new.0 >= new.1 {
+        let field = if new.0.is_finite() { "end" } else { "start" };
+        return Err(FieldError::new(
+            field,
+            format!("Range start ({}) must be less than its end ({})", new.0, new.1),
+        ));
+    }
+    // Bound of the new range a breakpoint lies beyond
+    let bound = |x: f32| if x < new.0 { "start" } else { "end" };
+
+    match policy {
+        RangeChangePolicy::Rescale => {
+            if old.1 - old.0 <= 0.0 {
+                return Err(FieldError::new(
+                    "policy",
+                    format!(
+                        "The current range [{}, {}] is empty and can not be rescaled",
+                        old.0, old.1
+                    ),
+                ));
+            }
+            let scale = (new.1 - new.0) / (old.1 - old.0);
+            Ok(move_terms(terms, |x| new.0 + (x - old.0) * scale))
+        }
+        RangeChangePolicy::Clip => {
+            let epsilon = (old.1 - old.0).abs() * 0.001;
+            let moved = move_terms(terms, |x| {
+                if (x - old.0).abs() <= epsilon {
+                    new.0
+                } else if (x - old.1).abs() <= epsilon {
+                    new.1
+                } else {
+                    x.clamp(new.0, new.1)
+                }
+            });
+            for (term, moved) in terms.iter().zip(&moved) {
+                if term.shape.0 < term.shape.3 && moved.shape.0 >= moved.shape.3 {
+                    return Err(FieldError::new(
+                        bound(term.shape.0),
+                        format!(
+                            "Term '{}' ({}, {}, {}, {}) lies outside of the new range [{}, {}]; rescale the terms or remove it first",
+                            term.name, term.shape.0, term.shape.1, term.shape.2, term.shape.3, new.0, new.1
+                        ),
+                    ));
+                }
+            }
+            Ok(moved)
+        }
+        RangeChangePolicy::Reject => {
+            for term in terms {
+                let (a, b, c, d) = term.shape;
+                if let Some(x) = [a, b, c, d].into_iter().find(|x| *x < new.0 || *x > new.1) {
+                    return Err(FieldError::new(
+                        bound(x),
+                        format!(
+                            "Term '{}' ({}, {}, {}, {}) lies outside of the new range [{}, {}]",
+                            term.name, a, b, c, d, new.0, new.1
+                        ),
+                    ));
+                }
+            }
+            // A partition of the old range that does not cover the new one would silently
+            // leave inputs without any term; partitions that were already broken are left alone
+            let mut shapes: Vec<_> = terms.iter().map(|t| t.shape).collect();
+            shapes.sort_by(|x, y| x.0.total_cmp(&y.0));
+            if !shapes.is_empty()
+                && validate_fuzzy_partition(&shapes, old.0, old.1, PARTITION_TOLERANCE).is_ok()
+            {
+                if let Err(message) = validate_fuzzy_partition(&shapes, new.0, new.1, PARTITION_TOLERANCE) {
+                    return Err(FieldError::new(
+                        if new.0 != old.0 { "start" } else { "end" },
+                        format!(
+                            "Terms would no longer form a fuzzy partition of [{}, {}]: {}",
+                            new.0, new.1, message
+                        ),
+                    ));
+                }
+            }
+            Ok(terms.to_vec())
+        }
+    }
+}
+
+/// Applies the monotone map `f` to the breakpoints of every term and of its lower
+/// membership function
+fn move_terms(terms: &[RangedTerm], f: impl Fn(f32) -> f32) -> Vec<RangedTerm> {
+    terms
+        .iter()
+        .map(|term| {
+            let (a, b, c, d) = term.shape;
+            RangedTerm {
+                name: term.name.clone(),
+                shape: (f(a), f(b), f(c), f(d)),
+                lower: term.lower.map(|l| LowerMembership {
+                    a: f(l.a),
+                    b: f(l.b),
+                    c: f(l.c),
+                    d: f(l.d),
+                    ..l
+                }),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn term(name: &str, shape: (f32, f32, f32, f32)) -> RangedTerm {
+        RangedTerm {
+            name: name.to_string(),
+            shape,
+            lower: None,
+        }
+    }
+
+    /// Low and High partitioning [0, 10]
+    fn partition() -> Vec<RangedTerm> {
+        vec![term("Low", (0.0, 0.0, 3.0, 7.0)), term("High", (3.0, 7.0, 10.0, 10.0))]
+    }
+
+    #[test]
+    fn test_rescale_maps_breakpoints_linearly() {
+        let mut terms = partition();
+        terms[0].lower = Some(LowerMembership {
+            a: 0.0,
+            b: 0.0,
+            c: 2.0,
+            d: 6.0,
+            is_triangle: false,
+            height: 0.5,
+        });
+
+        let moved = change_range(&terms, (0.0, 10.0), (10.0, 30.0), RangeChangePolicy::Rescale).unwrap();
+
+        assert_eq!(moved[0].shape, (10.0, 10.0, 16.0, 24.0));
+        assert_eq!(moved[1].shape, (16.0, 24.0, 30.0, 30.0));
+        let lower = moved[0].lower.unwrap();
+        assert_eq!((lower.c, lower.d, lower.height), (14.0, 22.0, 0.5));
+        let shapes: Vec<_> = moved.iter().map(|t| t.shape).collect();
+        assert!(validate_fuzzy_partition(&shapes, 10.0, 30.0, PARTITION_TOLERANCE).is_ok());
+    }
+
+    #[test]
+    fn test_clip_shrinking_keeps_partition() {
+        let moved = change_range(&partition(), (0.0, 10.0), (0.0, 5.0), RangeChangePolicy::Clip).unwrap();
+
+        assert_eq!(moved[0].shape, (0.0, 0.0, 3.0, 5.0));
+        assert_eq!(moved[1].shape, (3.0, 5.0, 5.0, 5.0));
+        let shapes: Vec<_> = moved.iter().map(|t| t.shape).collect();
+        assert!(validate_fuzzy_partition(&shapes, 0.0, 5.0, PARTITION_TOLERANCE).is_ok());
+    }
+
+    #[test]
+    fn test_clip_extending_moves_edge_terms() {
+        let moved = change_range(&partition(), (0.0, 10.0), (-5.0, 20.0), RangeChangePolicy::Clip).unwrap();
+
+        assert_eq!(moved[0].shape, (-5.0, -5.0, 3.0, 7.0));
+        assert_eq!(moved[1].shape, (3.0, 7.0, 20.0, 20.0));
+    }
+
+    #[test]
+    fn test_clip_rejects_vanishing_term() {
+        let result = change_range(&partition(), (0.0, 10.0), (0.0, 2.0), RangeChangePolicy::Clip);
+
+        let error = result.unwrap_err();
+        assert_eq!(error.field, "end");
+        assert!(error.message.contains("'High'"));
+    }
+
+    #[test]
+    fn test_reject_policy() {
+        let outside = change_range(&partition(), (0.0, 10.0), (0.0, 8.0), RangeChangePolicy::Reject);
+        let outside = outside.unwrap_err();
+        assert_eq!(outside.field, "end");
+        assert!(outside.message.contains("'High'"));
+
+        // Still inside, but the partition would no longer reach the new end
+        let uncovered = change_range(&partition(), (0.0, 10.0), (0.0, 12.0), RangeChangePolicy::Reject);
+        let uncovered = uncovered.unwrap_err();
+        assert_eq!(uncovered.field, "end");
+        assert!(uncovered.message.contains("fuzzy partition"));
+
+        assert!(change_range(&[], (0.0, 10.0), (0.0, 12.0), RangeChangePolicy::Reject).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_new_range() {
+        let empty = change_range(&partition(), (0.0, 10.0), (5.0, 5.0), RangeChangePolicy::Rescale);
+        assert_eq!(empty.unwrap_err().field, "end");
+        let nan = change_range(&partition(), (0.0, 10.0), (f32::NAN, 5.0), RangeChangePolicy::Clip);
+        assert_eq!(nan.unwrap_err().field, "start");
+    }
+}
@@ -1 +1,2 @@
+pub mod exported_problem;
 pub mod use_cases;
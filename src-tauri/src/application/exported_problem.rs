@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::{
+    lower_membership::LowerMembership, parameter_kind::ParameterKind,
+    parameter_metadata::ParameterMetadata, partition_mode::PartitionMode,
+};
+
+/// Self-contained problem tree as written to `.fuzzy` files and snapshots, with
+/// ids replaced by file-local `temp_id`s
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedProblem {
+    pub name: String,
+    pub description: String,
+    pub is_final: bool,
+    pub image: Option<ExportedImage>,
+    pub input_parameters: Vec<ExportedInputParameter>,
+    pub output_parameters: Vec<ExportedOutputParameter>,
+    pub output_values: Vec<ExportedOutputValue>,
+    #[serde(default)]
+    pub test_cases: Vec<ExportedTestCase>,
+    pub children: Vec<ExportedProblem>,
+}
+
+/// Version of the `.fuzzy` format written by this build.
+///
+/// 1. Bare `ExportedProblem`, image bytes as an array of numbers
+/// 2. `FuzzyFile` envelope, image bytes as base64
+/// 3. Rule hedges, interval type-2 lower memberships, partition mode, parameter
+///    metadata and kind; older files read them as their defaults
+pub const FUZZY_FORMAT_VERSION: u32 = 3;
+
+/// Top-level object of a `.fuzzy` file
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FuzzyFile {
+    pub format_version: u32,
+    /// Application name and version that wrote the file
+    pub generator: String,
+    pub exported_at: String,
+    pub problem: ExportedProblem,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedImage {
+    #[serde(with = "image_data")]
+    pub data: Vec<u8>,
+    pub format: String,
+}
+
+/// Image bytes are written as base64 and read from base64 or,
+/// as in unversioned files, from an array of numbers
+mod image_data {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Encoded {
+        Base64(String),
+        Bytes(Vec<u8>),
+    }
+
+    pub fn serialize<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(data))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        match Encoded::deserialize(deserializer)? {
+            Encoded::Base64(text) => STANDARD.decode(text).map_err(D::Error::custom),
+            Encoded::Bytes(bytes) => Ok(bytes),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedInputParameter {
+    pub temp_id: i64,
+    pub name: String,
+    pub start: f32,
+    pub end: f32,
+    #[serde(default)]
+    pub partition_mode: PartitionMode,
+    #[serde(default)]
+    pub kind: ParameterKind,
+    /// Unit, description, precision and default value, stored inline
+    #[serde(default, flatten)]
+    pub metadata: ParameterMetadata,
+    pub values: Vec<ExportedInputValue>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedInputValue {
+    pub temp_id: i64,
+    pub value: String,
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub is_triangle: bool,
+    /// Lower membership function of an interval type-2 term
+    #[serde(default)]
+    pub lower: Option<LowerMembership>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedOutputParameter {
+    pub temp_id: i64,
+    pub name: String,
+    pub start: f32,
+    pub end: f32,
+    #[serde(default)]
+    pub partition_mode: PartitionMode,
+    /// Unit, description, precision and default value, stored inline
+    #[serde(default, flatten)]
+    pub metadata: ParameterMetadata,
+    pub values: Vec<ExportedFuzzyOutputValue>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedFuzzyOutputValue {
+    pub temp_id: i64,
+    pub value: String,
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub is_triangle: bool,
+    /// Lower membership function of an interval type-2 term
+    #[serde(default)]
+    pub lower: Option<LowerMembership>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedOutputValue {
+    pub output_parameter_temp_id: i64,
+    #[serde(alias = "fuzzy_output_value_id", rename = "fuzzy_output_value_temp_id")]
+    pub fuzzy_output_value_temp_id: Option<i64>,
+    pub input_value_temp_ids: Vec<i64>,
+    #[serde(default)]
+    pub antecedent_hedges: Vec<ExportedAntecedentHedge>,
+    /// "very", "extremely", "somewhat" or "slightly"
+    #[serde(default)]
+    pub consequent_hedge: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedAntecedentHedge {
+    pub input_value_temp_id: i64,
+    pub hedge: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedTestCase {
+    pub name: String,
+    pub inputs: Vec<ExportedTestCaseInput>,
+    pub expectations: Vec<ExportedTestCaseExpectation>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedTestCaseInput {
+    pub input_parameter_temp_id: i64,
+    pub crisp_value: f32,
+    /// Selected category of a categorical parameter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_value_temp_id: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedTestCaseExpectation {
+    pub output_parameter_temp_id: i64,
+    pub expected_value: Option<f32>,
+    pub tolerance: f32,
+    pub expected_fuzzy_output_value_temp_id: Option<i64>,
+}
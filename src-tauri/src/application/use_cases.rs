@@ -1,8 +1,13 @@
+pub mod evaluation_history;
 pub mod fuzzy_inference;
 pub mod fuzzy_output_value;
 pub mod image;
 pub mod input_parameter;
 pub mod input_value;
+pub mod journal;
 pub mod output_parameter;
 pub mod output_value;
+pub mod parameter_link;
 pub mod problem;
+pub mod problem_snapshot;
+pub mod test_case;
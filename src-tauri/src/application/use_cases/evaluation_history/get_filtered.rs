@@ -0,0 +1,25 @@
+use crate::domain::{
+    entities::evaluation_record::{EvaluationHistoryFilter, EvaluationRecord},
+    error::DomainError,
+    repository::EvaluationHistoryRepository,
+};
+
+pub struct GetEvaluationHistoryUseCase<'a> {
+    evaluation_history_repository: &'a dyn EvaluationHistoryRepository,
+}
+
+impl<'a> GetEvaluationHistoryUseCase<'a> {
+    pub fn new(evaluation_history_repository: &'a dyn EvaluationHistoryRepository) -> Self {
+        Self {
+            evaluation_history_repository,
+        }
+    }
+
+    /// Returns matching records, newest first
+    pub fn execute(
+        &self,
+        filter: &EvaluationHistoryFilter,
+    ) -> Result<Vec<EvaluationRecord>, DomainError> {
+        self.evaluation_history_repository.get_filtered(filter)
+    }
+}
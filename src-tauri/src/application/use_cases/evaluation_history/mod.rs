@@ -0,0 +1,5 @@
+pub mod get_filtered;
+pub mod record;
+pub mod remove_by_id;
+pub mod remove_by_problem_id;
+pub mod replay;
@@ -0,0 +1,65 @@
+use crate::{
+    application::use_cases::fuzzy_inference::{FuzzyEvaluationInput, FuzzyEvaluationResult},
+    domain::{
+        entities::evaluation_record::{
+            EvaluationRecord, EvaluationRecordInput, EvaluationRecordOutput,
+        },
+        error::DomainError,
+        repository::EvaluationHistoryRepository,
+        services::defuzzification::DefuzzificationMethod,
+    },
+};
+
+/// Use case for appending a finished evaluation to the history log
+pub struct RecordEvaluationUseCase<'a> {
+    evaluation_history_repository: &'a dyn EvaluationHistoryRepository,
+}
+
+impl<'a> RecordEvaluationUseCase<'a> {
+    pub fn new(evaluation_history_repository: &'a dyn EvaluationHistoryRepository) -> Self {
+        Self {
+            evaluation_history_repository,
+        }
+    }
+
+    /// # Returns
+    /// Id of the new history record
+    pub fn execute(
+        &self,
+        inputs: &[FuzzyEvaluationInput],
+        method: DefuzzificationMethod,
+        resolution: usize,
+        result: &FuzzyEvaluationResult,
+    ) -> Result<i64, DomainError> {
+        let record = EvaluationRecord {
+            id: 0,
+            problem_id: result.problem_id,
+            problem_name: result.problem_name.clone(),
+            inputs: inputs
+                .iter()
+                .map(|i| EvaluationRecordInput {
+                    input_parameter_id: i.input_parameter_id,
+                    crisp_value: i.crisp_value,
+                    uncertainty: i.uncertainty,
+                    input_value_id: i.input_value_id,
+                    category_degrees: i.category_degrees.clone(),
+                })
+                .collect(),
+            method: method.as_str().to_string(),
+            resolution,
+            outputs: result
+                .outputs
+                .iter()
+                .map(|o| EvaluationRecordOutput {
+                    output_parameter_id: o.output_parameter_id,
+                    output_parameter_name: o.output_parameter_name.clone(),
+                    crisp_value: o.crisp_value,
+                })
+                .collect(),
+            definition_hash: result.definition_hash.clone(),
+            created_at: String::new(),
+        };
+
+        self.evaluation_history_repository.create(&record)
+    }
+}
@@ -0,0 +1,17 @@
+use crate::domain::{error::DomainError, repository::EvaluationHistoryRepository};
+
+pub struct RemoveEvaluationRecordByIdUseCase<'a> {
+    evaluation_history_repository: &'a dyn EvaluationHistoryRepository,
+}
+
+impl<'a> RemoveEvaluationRecordByIdUseCase<'a> {
+    pub fn new(evaluation_history_repository: &'a dyn EvaluationHistoryRepository) -> Self {
+        Self {
+            evaluation_history_repository,
+        }
+    }
+
+    pub fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.evaluation_history_repository.remove_by_id(id)
+    }
+}
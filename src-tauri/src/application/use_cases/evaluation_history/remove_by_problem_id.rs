@@ -0,0 +1,20 @@
+use crate::domain::{error::DomainError, repository::EvaluationHistoryRepository};
+
+pub struct RemoveEvaluationHistoryByProblemIdUseCase<'a> {
+    evaluation_history_repository: &'a dyn EvaluationHistoryRepository,
+}
+
+impl<'a> RemoveEvaluationHistoryByProblemIdUseCase<'a> {
+    pub fn new(evaluation_history_repository: &'a dyn EvaluationHistoryRepository) -> Self {
+        Self {
+            evaluation_history_repository,
+        }
+    }
+
+    /// # Returns
+    /// Number of removed records
+    pub fn execute(&self, problem_id: i64) -> Result<usize, DomainError> {
+        self.evaluation_history_repository
+            .remove_by_problem_id(problem_id)
+    }
+}
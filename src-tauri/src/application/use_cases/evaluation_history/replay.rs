@@ -0,0 +1,122 @@
+use crate::{
+    application::use_cases::fuzzy_inference::{EvaluateFuzzySystemUseCase, FuzzyEvaluationInput},
+    domain::{
+        entities::evaluation_record::EvaluationRecord,
+        error::DomainError,
+        repository::{EvaluationHistoryRepository, ProblemRepository},
+        services::defuzzification::DefuzzificationMethod,
+    },
+};
+
+/// Recorded and current crisp value of one output parameter
+#[derive(Debug, Clone)]
+pub struct ReplayOutputComparison {
+    pub output_parameter_id: i64,
+    pub output_parameter_name: String,
+    /// `None` if the output parameter did not exist when the record was made
+    pub recorded_value: Option<f32>,
+    /// `None` if the output parameter has been removed since
+    pub current_value: Option<f32>,
+    /// `current_value - recorded_value`, when both are known
+    pub difference: Option<f32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplayResult {
+    pub record: EvaluationRecord,
+    pub current_definition_hash: String,
+    pub definition_changed: bool,
+    pub outputs: Vec<ReplayOutputComparison>,
+}
+
+/// Use case for re-evaluating a history record against the current problem definition
+pub struct ReplayEvaluationUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+    evaluation_history_repository: &'a dyn EvaluationHistoryRepository,
+}
+
+impl<'a> ReplayEvaluationUseCase<'a> {
+    pub fn new(
+        problem_repository: &'a dyn ProblemRepository,
+        evaluation_history_repository: &'a dyn EvaluationHistoryRepository,
+    ) -> Self {
+        Self {
+            problem_repository,
+            evaluation_history_repository,
+        }
+    }
+
+    /// Runs the recorded inputs through the engine with the recorded method and
+    /// resolution. The replay itself is not written to the history.
+    pub fn execute(&self, id: i64) -> Result<ReplayResult, DomainError> {
+        let record = self.evaluation_history_repository.get_by_id(id)?;
+
+        let method = DefuzzificationMethod::from_name(&record.method).ok_or_else(|| {
+            DomainError::Data(format!(
+                "Unknown defuzzification method '{}' in evaluation record {}",
+                record.method, record.id
+            ))
+        })?;
+        let inputs: Vec<FuzzyEvaluationInput> = record
+            .inputs
+            .iter()
+            .map(|i| FuzzyEvaluationInput {
+                input_parameter_id: i.input_parameter_id,
+                crisp_value: i.crisp_value,
+                uncertainty: i.uncertainty,
+                input_value_id: i.input_value_id,
+                category_degrees: i.category_degrees.clone(),
+            })
+            .collect();
+
+        let current = EvaluateFuzzySystemUseCase::new(self.problem_repository).execute(
+            record.problem_id,
+            inputs,
+            method,
+            record.resolution,
+        )?;
+
+        let mut outputs: Vec<ReplayOutputComparison> = record
+            .outputs
+            .iter()
+            .map(|recorded| {
+                let current_value = current
+                    .outputs
+                    .iter()
+                    .find(|o| o.output_parameter_id == recorded.output_parameter_id)
+                    .map(|o| o.crisp_value);
+                ReplayOutputComparison {
+                    output_parameter_id: recorded.output_parameter_id,
+                    output_parameter_name: recorded.output_parameter_name.clone(),
+                    recorded_value: Some(recorded.crisp_value),
+                    current_value,
+                    difference: current_value.map(|v| v - recorded.crisp_value),
+                }
+            })
+            .collect();
+
+        // Output parameters added after the record was made
+        for o in &current.outputs {
+            if !record
+                .outputs
+                .iter()
+                .any(|r| r.output_parameter_id == o.output_parameter_id)
+            {
+                outputs.push(ReplayOutputComparison {
+                    output_parameter_id: o.output_parameter_id,
+                    output_parameter_name: o.output_parameter_name.clone(),
+                    recorded_value: None,
+                    current_value: Some(o.crisp_value),
+                    difference: None,
+                });
+            }
+        }
+
+        Ok(ReplayResult {
+            definition_changed: current.definition_hash != record.definition_hash,
+            current_definition_hash: current.definition_hash,
+            record,
+            outputs,
+        })
+    }
+}
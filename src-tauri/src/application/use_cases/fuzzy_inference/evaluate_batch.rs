@@ -0,0 +1,191 @@
+use crate::{
+    application::use_cases::fuzzy_inference::evaluate::{
+        infer_crisp_outputs, validate_inputs, FuzzyEvaluationInput,
+    },
+    domain::{
+        entities::{input_parameter::InputParameter, parameter_kind::ParameterKind, problem::Problem},
+        error::{DomainError, EntityKind, FieldError},
+        repository::ProblemRepository,
+        services::{
+            defuzzification::DefuzzificationMethod,
+            definition_hash::problem_definition_hash,
+            fuzzification::category_index,
+            parallel_evaluation::{default_threads, parallel_map, JobControl},
+        },
+    },
+};
+
+/// One evaluated row of a batch
+#[derive(Debug, Clone)]
+pub struct BatchRowResult {
+    /// Cells of the row as given
+    pub cells: Vec<String>,
+    /// Crisp value of every output parameter; `None` if the row could not be evaluated
+    pub outputs: Option<Vec<f32>>,
+    pub error: Option<String>,
+}
+
+/// Result of evaluating a table of inputs
+#[derive(Debug, Clone)]
+pub struct BatchEvaluationResult {
+    pub problem_id: i64,
+    /// Input column names as given
+    pub columns: Vec<String>,
+    pub output_parameter_ids: Vec<i64>,
+    pub output_parameter_names: Vec<String>,
+    pub rows: Vec<BatchRowResult>,
+    /// Fingerprint of the problem definition all rows were computed from
+    pub definition_hash: String,
+}
+
+/// Use case for evaluating many input rows, e.g. from a CSV file, in parallel
+/// against one snapshot of a problem
+pub struct EvaluateBatchUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+}
+
+impl<'a> EvaluateBatchUseCase<'a> {
+    pub fn new(problem_repository: &'a dyn ProblemRepository) -> Self {
+        Self { problem_repository }
+    }
+
+    /// Evaluates every row of a table whose `columns` name the input parameters.
+    ///
+    /// Cells are numbers; for categorical parameters they may also be category names.
+    /// A row that cannot be evaluated gets an error of its own, while unknown,
+    /// repeated or missing columns fail the whole batch. The problem is loaded once,
+    /// so edits made while the batch runs do not affect it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute(
+        &self,
+        problem_id: i64,
+        columns: Vec<String>,
+        rows: Vec<Vec<String>>,
+        method: DefuzzificationMethod,
+        resolution: usize,
+        control: &JobControl,
+        on_progress: impl Fn(usize, usize) + Sync,
+    ) -> Result<BatchEvaluationResult, DomainError> {
+        let problem = self.problem_repository.get_full_by_id(problem_id)?;
+        let parameters = resolve_columns(&problem, &columns)?;
+
+        let outputs = parallel_map(
+            &rows,
+            default_threads(),
+            control,
+            |cells| evaluate_row(&problem, &parameters, cells, method, resolution),
+            on_progress,
+        )
+        .ok_or_else(|| DomainError::Cancelled("Batch evaluation was cancelled".to_string()))?;
+
+        Ok(BatchEvaluationResult {
+            problem_id: problem.id,
+            output_parameter_ids: problem.output_parameters.iter().map(|op| op.id).collect(),
+            output_parameter_names: problem.output_parameters.iter().map(|op| op.name.clone()).collect(),
+            rows: rows
+                .into_iter()
+                .zip(outputs)
+                .map(|(cells, outputs)| match outputs {
+                    Ok(outputs) => BatchRowResult {
+                        cells,
+                        outputs: Some(outputs),
+                        error: None,
+                    },
+                    Err(error) => BatchRowResult {
+                        cells,
+                        outputs: None,
+                        error: Some(error),
+                    },
+                })
+                .collect(),
+            definition_hash: problem_definition_hash(&problem),
+            columns,
+        })
+    }
+}
+
+/// Input parameter of every column, which together must cover all of them exactly once
+fn resolve_columns<'p>(problem: &'p Problem, columns: &[String]) -> Result<Vec<&'p InputParameter>, DomainError> {
+    let invalid = |message: String| {
+        DomainError::invalid_field(
+            EntityKind::Problem,
+            Some(problem.id),
+            FieldError::new("csv", message),
+        )
+    };
+    let mut parameters = Vec::with_capacity(columns.len());
+    for column in columns {
+        let parameter = problem
+            .input_parameters
+            .iter()
+            .find(|ip| ip.name.trim() == column.trim())
+            .ok_or_else(|| invalid(format!("Column '{}' is not an input parameter", column)))?;
+        if parameters.iter().any(|p: &&InputParameter| p.id == parameter.id) {
+            return Err(invalid(format!("Column '{}' appears more than once", column)));
+        }
+        parameters.push(parameter);
+    }
+    if let Some(missing) = problem
+        .input_parameters
+        .iter()
+        .find(|ip| !parameters.iter().any(|p| p.id == ip.id))
+    {
+        return Err(invalid(format!("No column for input parameter '{}'", missing.name)));
+    }
+    Ok(parameters)
+}
+
+fn evaluate_row(
+    problem: &Problem,
+    parameters: &[&InputParameter],
+    cells: &[String],
+    method: DefuzzificationMethod,
+    resolution: usize,
+) -> Result<Vec<f32>, String> {
+    if cells.len() != parameters.len() {
+        return Err(format!("Expected {} values, got {}", parameters.len(), cells.len()));
+    }
+    let inputs = parameters
+        .iter()
+        .zip(cells)
+        .map(|(parameter, cell)| {
+            let (crisp_value, input_value_id) = parse_cell(parameter, cell)?;
+            Ok(FuzzyEvaluationInput {
+                input_parameter_id: parameter.id,
+                crisp_value,
+                uncertainty: None,
+                input_value_id,
+                category_degrees: None,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    validate_inputs(problem, &inputs).map_err(|e| e.to_string())?;
+    Ok(infer_crisp_outputs(problem, &inputs, method, resolution))
+}
+
+/// A number, with a decimal comma allowed, or the name of a category, with the
+/// id of the category selected by name or index
+fn parse_cell(parameter: &InputParameter, cell: &str) -> Result<(f32, Option<i64>), String> {
+    let cell = cell.trim();
+    if parameter.kind == ParameterKind::Categorical {
+        if let Some((index, iv)) = parameter
+            .input_values
+            .iter()
+            .enumerate()
+            .find(|(_, iv)| iv.value.trim().eq_ignore_ascii_case(cell))
+        {
+            return Ok((index as f32, Some(iv.id)));
+        }
+    }
+    let value = cell
+        .replace(',', ".")
+        .parse::<f32>()
+        .map_err(|_| format!("'{}' is not a valid value of '{}'", cell, parameter.name))?;
+    let input_value_id = match parameter.kind {
+        ParameterKind::Continuous => None,
+        ParameterKind::Categorical => category_index(value, parameter.input_values.len())
+            .map(|index| parameter.input_values[index].id),
+    };
+    Ok((value, input_value_id))
+}
@@ -0,0 +1,283 @@
+use crate::{
+    application::use_cases::fuzzy_inference::evaluate::{
+        infer_crisp_outputs, validate_inputs, FuzzyEvaluationInput,
+    },
+    domain::{
+        entities::{input_parameter::InputParameter, parameter_kind::ParameterKind, problem::Problem},
+        error::{DomainError, EntityKind, FieldError},
+        repository::ProblemRepository,
+        services::{
+            defuzzification::DefuzzificationMethod,
+            definition_hash::problem_definition_hash,
+            parallel_evaluation::{default_threads, parallel_map, JobControl},
+        },
+    },
+};
+
+/// Largest number of points a single sweep may evaluate
+pub const MAX_SWEEP_POINTS: usize = 1_000_000;
+
+/// An input parameter swept over its range
+#[derive(Debug, Clone, Copy)]
+pub struct SweepAxis {
+    pub input_parameter_id: i64,
+    /// Number of evenly spaced points from start to end; ignored for categorical
+    /// parameters, which are swept over their categories
+    pub steps: usize,
+}
+
+/// Values of one output parameter over the swept grid
+#[derive(Debug, Clone)]
+pub struct SweepSurface {
+    pub output_parameter_id: i64,
+    pub output_parameter_name: String,
+    pub unit: String,
+    /// Row-major: the value at `(x[i], y[j])` is `values[j * x.len() + i]`
+    pub values: Vec<f32>,
+}
+
+/// Result of a control-surface sweep
+#[derive(Debug, Clone)]
+pub struct SweepResult {
+    pub problem_id: i64,
+    pub x: Vec<f32>,
+    /// Points of the second axis; empty for a curve over one input
+    pub y: Vec<f32>,
+    pub surfaces: Vec<SweepSurface>,
+    /// Fingerprint of the problem definition the sweep was computed from
+    pub definition_hash: String,
+}
+
+/// Use case for computing control curves and surfaces: one or two inputs swept over
+/// their ranges with the others held fixed, evaluated in parallel
+pub struct EvaluateSweepUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+}
+
+impl<'a> EvaluateSweepUseCase<'a> {
+    pub fn new(problem_repository: &'a dyn ProblemRepository) -> Self {
+        Self { problem_repository }
+    }
+
+    /// Evaluates the problem on the grid spanned by `x` and, if given, `y`.
+    ///
+    /// `fixed_inputs` must cover every input parameter that is not swept. The problem
+    /// is loaded once, so edits made while the sweep runs do not affect it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute(
+        &self,
+        problem_id: i64,
+        x: SweepAxis,
+        y: Option<SweepAxis>,
+        fixed_inputs: Vec<FuzzyEvaluationInput>,
+        method: DefuzzificationMethod,
+        resolution: usize,
+        control: &JobControl,
+        on_progress: impl Fn(usize, usize) + Sync,
+    ) -> Result<SweepResult, DomainError> {
+        let problem = self.problem_repository.get_full_by_id(problem_id)?;
+
+        let x_param = swept_parameter(&problem, x.input_parameter_id)?;
+        let x_len = axis_len(x_param, x.steps, "x")?;
+        let (y_param, y_len) = match y {
+            Some(y) if y.input_parameter_id == x.input_parameter_id => {
+                return Err(invalid_field(
+                    problem_id,
+                    "y.input_parameter_id",
+                    "Both sweep axes use the same input parameter".to_string(),
+                ))
+            }
+            Some(y) => {
+                let y_param = swept_parameter(&problem, y.input_parameter_id)?;
+                (Some(y_param), axis_len(y_param, y.steps, "y")?)
+            }
+            None => (None, 0),
+        };
+
+        // Sized before any point is allocated, so huge step counts fail right away
+        let total = x_len
+            .checked_mul(y_len.max(1))
+            .filter(|&total| total <= MAX_SWEEP_POINTS)
+            .ok_or_else(|| {
+                invalid_field(
+                    problem_id,
+                    "y.steps",
+                    format!(
+                        "A sweep may evaluate at most {} points, got {} x {}",
+                        MAX_SWEEP_POINTS,
+                        x_len,
+                        y_len.max(1)
+                    ),
+                )
+            })?;
+        let x_points = axis_points(x_param, x_len);
+        let y_points = y_param.map_or_else(Vec::new, |y_param| axis_points(y_param, y_len));
+
+        let fixed_inputs: Vec<FuzzyEvaluationInput> = fixed_inputs
+            .into_iter()
+            .filter(|input| {
+                input.input_parameter_id != x.input_parameter_id
+                    && Some(input.input_parameter_id) != y_param.map(|p| p.id)
+            })
+            .collect();
+        let point_inputs = |index: usize| {
+            let mut inputs = fixed_inputs.clone();
+            inputs.push(swept_input(x_param, x_points[index % x_points.len()]));
+            if let Some(y_param) = y_param {
+                inputs.push(swept_input(y_param, y_points[index / x_points.len()]));
+            }
+            inputs
+        };
+        // Checked once here, so a bad fixed input fails the sweep instead of every point
+        validate_inputs(&problem, &point_inputs(0))?;
+
+        let indices: Vec<usize> = (0..total).collect();
+        let points = parallel_map(
+            &indices,
+            default_threads(),
+            control,
+            |&index| infer_crisp_outputs(&problem, &point_inputs(index), method, resolution),
+            on_progress,
+        )
+        .ok_or_else(|| DomainError::Cancelled("Sweep was cancelled".to_string()))?;
+
+        let surfaces = problem
+            .output_parameters
+            .iter()
+            .enumerate()
+            .map(|(i, output_param)| SweepSurface {
+                output_parameter_id: output_param.id,
+                output_parameter_name: output_param.name.clone(),
+                unit: output_param.metadata.unit.clone(),
+                values: points.iter().map(|outputs| outputs[i]).collect(),
+            })
+            .collect();
+
+        Ok(SweepResult {
+            problem_id: problem.id,
+            x: x_points,
+            y: y_points,
+            surfaces,
+            definition_hash: problem_definition_hash(&problem),
+        })
+    }
+}
+
+fn swept_parameter(problem: &Problem, input_parameter_id: i64) -> Result<&InputParameter, DomainError> {
+    problem
+        .input_parameters
+        .iter()
+        .find(|ip| ip.id == input_parameter_id)
+        .ok_or(DomainError::not_found(EntityKind::InputParameter, input_parameter_id))
+}
+
+/// Rejected field of a sweep request on problem `problem_id`, e.g. "x.steps"
+fn invalid_field(problem_id: i64, field: &str, message: String) -> DomainError {
+    DomainError::invalid_field(
+        EntityKind::Problem,
+        Some(problem_id),
+        FieldError::new(field, message),
+    )
+}
+
+/// Number of points on the `axis` ("x" or "y") of a parameter: its categories, or
+/// `steps` for a continuous one
+fn axis_len(parameter: &InputParameter, steps: usize, axis: &str) -> Result<usize, DomainError> {
+    let invalid = |field: &str, message: String| {
+        invalid_field(parameter.problem_id, &format!("{}.{}", axis, field), message)
+    };
+    if parameter.kind == ParameterKind::Categorical {
+        if parameter.input_values.is_empty() {
+            return Err(invalid(
+                "input_parameter_id",
+                format!("Parameter '{}' has no categories to sweep", parameter.name),
+            ));
+        }
+        return Ok(parameter.input_values.len());
+    }
+    if steps < 2 {
+        return Err(invalid(
+            "steps",
+            format!("A sweep of '{}' needs at least 2 steps, got {}", parameter.name, steps),
+        ));
+    }
+    if steps > MAX_SWEEP_POINTS {
+        return Err(invalid(
+            "steps",
+            format!(
+                "A sweep may evaluate at most {} points, got {} steps of '{}'",
+                MAX_SWEEP_POINTS, steps, parameter.name
+            ),
+        ));
+    }
+    Ok(steps)
+}
+
+/// Category indices of a categorical parameter, or `len` evenly spaced points
+/// from the start to the end of a continuous one; `len` comes from [`axis_len`]
+fn axis_points(parameter: &InputParameter, len: usize) -> Vec<f32> {
+    if parameter.kind == ParameterKind::Categorical {
+        return (0..len).map(|i| i as f32).collect();
+    }
+    let step = (parameter.end - parameter.start) / (len - 1) as f32;
+    (0..len)
+        .map(|i| if i == len - 1 { parameter.end } else { parameter.start + step * i as f32 })
+        .collect()
+}
+
+/// Input at an axis point; a categorical point selects the category at that index
+fn swept_input(parameter: &InputParameter, crisp_value: f32) -> FuzzyEvaluationInput {
+    let input_value_id = match parameter.kind {
+        ParameterKind::Continuous => None,
+        ParameterKind::Categorical => Some(parameter.input_values[crisp_value as usize].id),
+    };
+    FuzzyEvaluationInput {
+        input_parameter_id: parameter.id,
+        crisp_value,
+        uncertainty: None,
+        input_value_id,
+        category_degrees: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{parameter_metadata::ParameterMetadata, partition_mode::PartitionMode};
+
+    fn parameter() -> InputParameter {
+        InputParameter {
+            id: 10,
+            problem_id: 1,
+            name: "temperature".to_string(),
+            start: 0.0,
+            end: 10.0,
+            partition_mode: PartitionMode::Ruspini,
+            kind: ParameterKind::Continuous,
+            metadata: ParameterMetadata::default(),
+            input_values: vec![],
+        }
+    }
+
+    #[test]
+    fn test_axis_len_rejects_steps_over_the_limit() {
+        let parameter = parameter();
+
+        assert_eq!(axis_len(&parameter, 11, "x").unwrap(), 11);
+        assert!(axis_len(&parameter, 1, "x").is_err());
+        assert!(axis_len(&parameter, MAX_SWEEP_POINTS + 1, "x").is_err());
+        match axis_len(&parameter, usize::MAX, "y") {
+            Err(DomainError::InvalidFields { fields, .. }) => {
+                assert_eq!(fields[0].field, "y.steps")
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_axis_points_end_on_the_range() {
+        let points = axis_points(&parameter(), 5);
+
+        assert_eq!(points, vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+    }
+}
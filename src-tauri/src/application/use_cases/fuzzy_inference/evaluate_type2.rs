@@ -0,0 +1,185 @@
+use crate::application::use_cases::fuzzy_inference::evaluate::{
+    fuzzify_parameter_input, input_display_value, validate_inputs, FuzzyEvaluationInput,
+};
+use crate::domain::{
+    entities::parameter_kind::ParameterKind,
+    error::DomainError,
+    repository::ProblemRepository,
+    services::{
+        definition_hash::problem_definition_hash,
+        interval_type2::{
+            evaluate_interval_rules, fuzzify_interval_input, type_reduce, FiringInterval,
+            IntervalFuzzifiedParameter,
+        },
+    },
+};
+
+/// Result of interval type-2 inference for one output parameter
+#[derive(Debug, Clone)]
+pub struct Type2EvaluationOutputResult {
+    pub output_parameter_id: i64,
+    pub output_parameter_name: String,
+    /// Unit of the output parameter, empty if it has none
+    pub unit: String,
+    /// Left end of the type-reduced (centroid) interval
+    pub left: f32,
+    /// Right end of the type-reduced (centroid) interval
+    pub right: f32,
+    /// Midpoint of the type-reduced interval
+    pub crisp_value: f32,
+    /// Crisp value with the parameter's precision and unit, e.g. "21.5 °C"
+    pub display_value: String,
+    pub fired_rules_count: usize,
+    pub firing_intervals: Vec<FiringInterval>,
+}
+
+/// Lower and upper degrees of an input in its terms, for debugging/display
+#[derive(Debug, Clone)]
+pub struct IntervalFuzzifiedInputInfo {
+    pub input_parameter_id: i64,
+    pub input_parameter_name: String,
+    pub unit: String,
+    pub crisp_value: f32,
+    pub display_value: String,
+    pub membership_intervals: Vec<(String, f32, f32)>, // (linguistic_term_name, lower, upper)
+}
+
+/// Complete result of an interval type-2 evaluation
+#[derive(Debug, Clone)]
+pub struct Type2EvaluationResult {
+    pub problem_id: i64,
+    pub problem_name: String,
+    pub fuzzified_inputs: Vec<IntervalFuzzifiedInputInfo>,
+    pub outputs: Vec<Type2EvaluationOutputResult>,
+    /// Fingerprint of the problem definition the result was computed from
+    pub definition_hash: String,
+}
+
+/// Use case for evaluating a problem as an interval type-2 fuzzy system.
+///
+/// Terms with a lower membership function contribute their footprint of uncertainty;
+/// the others behave as type-1 terms, so a problem without footprints yields a
+/// degenerate interval at its type-1 centroid.
+pub struct EvaluateType2SystemUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+}
+
+impl<'a> EvaluateType2SystemUseCase<'a> {
+    pub fn new(problem_repository: &'a dyn ProblemRepository) -> Self {
+        Self { problem_repository }
+    }
+
+    /// Executes the interval type-2 inference cycle:
+    /// 1. Fuzzification - lower and upper membership degrees of each input
+    /// 2. Rule Evaluation - firing intervals using min T-norm
+    /// 3. Aggregation - max S-norm over the clipped lower and upper sets
+    /// 4. Type Reduction - Karnik–Mendel centroid interval
+    /// 5. Defuzzification - midpoint of the type-reduced interval
+    pub fn execute(
+        &self,
+        problem_id: i64,
+        inputs: Vec<FuzzyEvaluationInput>,
+        resolution: usize,
+    ) -> Result<Type2EvaluationResult, DomainError> {
+        let problem = self.problem_repository.get_full_by_id(problem_id)?;
+
+        validate_inputs(&problem, &inputs)?;
+
+        // Step 1: Fuzzification
+        let fuzzified: Vec<(&FuzzyEvaluationInput, IntervalFuzzifiedParameter)> = inputs
+            .iter()
+            .filter_map(|input| {
+                problem
+                    .input_parameters
+                    .iter()
+                    .find(|ip| ip.id == input.input_parameter_id)
+                    .map(|input_param| {
+                        let fuzzified = match input_param.kind {
+                            ParameterKind::Continuous => fuzzify_interval_input(
+                                input.crisp_value,
+                                input.uncertainty,
+                                input_param.id,
+                                &input_param.input_values,
+                            ),
+                            // Categories have no footprint of uncertainty
+                            ParameterKind::Categorical => IntervalFuzzifiedParameter::from_type1(
+                                fuzzify_parameter_input(input_param, input),
+                            ),
+                        };
+                        (input, fuzzified)
+                    })
+            })
+            .collect();
+
+        let fuzzified_inputs = fuzzified
+            .iter()
+            .filter_map(|(input, fp)| {
+                let input_param = problem
+                    .input_parameters
+                    .iter()
+                    .find(|ip| ip.id == fp.input_parameter_id)?;
+                let membership_intervals = fp
+                    .lower
+                    .fuzzified_values
+                    .iter()
+                    .zip(&fp.upper.fuzzified_values)
+                    .filter_map(|(lower, upper)| {
+                        input_param
+                            .input_values
+                            .iter()
+                            .find(|iv| iv.id == upper.input_value_id)
+                            .map(|iv| (iv.value.clone(), lower.membership_degree, upper.membership_degree))
+                    })
+                    .collect();
+                Some(IntervalFuzzifiedInputInfo {
+                    input_parameter_id: fp.input_parameter_id,
+                    input_parameter_name: input_param.name.clone(),
+                    unit: input_param.metadata.unit.clone(),
+                    crisp_value: input.crisp_value,
+                    display_value: input_display_value(input_param, input),
+                    membership_intervals,
+                })
+            })
+            .collect();
+
+        let fuzzified_params: Vec<IntervalFuzzifiedParameter> =
+            fuzzified.into_iter().map(|(_, fp)| fp).collect();
+
+        // Steps 2-5 for each output parameter
+        let outputs = problem
+            .output_parameters
+            .iter()
+            .map(|output_param| {
+                let inference =
+                    evaluate_interval_rules(&fuzzified_params, &problem.output_values, output_param.id);
+                let reduced = type_reduce(
+                    &inference.firing_intervals,
+                    &output_param.fuzzy_output_values,
+                    output_param.start,
+                    output_param.end,
+                    resolution,
+                );
+
+                Type2EvaluationOutputResult {
+                    output_parameter_id: output_param.id,
+                    output_parameter_name: output_param.name.clone(),
+                    unit: output_param.metadata.unit.clone(),
+                    left: reduced.left,
+                    right: reduced.right,
+                    crisp_value: reduced.crisp_value,
+                    display_value: output_param.metadata.format_value(reduced.crisp_value),
+                    fired_rules_count: inference.fired_rules_count,
+                    firing_intervals: inference.firing_intervals,
+                }
+            })
+            .collect();
+
+        Ok(Type2EvaluationResult {
+            problem_id: problem.id,
+            definition_hash: problem_definition_hash(&problem),
+            problem_name: problem.name,
+            fuzzified_inputs,
+            outputs,
+        })
+    }
+}
@@ -1,3 +1,11 @@
 pub mod evaluate;
+pub mod evaluate_batch;
+pub mod evaluate_chain;
+pub mod evaluate_sweep;
+pub mod evaluate_type2;
 
 pub use evaluate::*;
+pub use evaluate_batch::*;
+pub use evaluate_chain::*;
+pub use evaluate_sweep::*;
+pub use evaluate_type2::*;
@@ -14,7 +14,12 @@ impl<'a> CreateFuzzyOutputValueUseCase<'a> {
         }
     }
 
-    pub fn execute(&self, fuzzy_output_value: &FuzzyOutputValue) -> Result<i64, DomainError> {
-        self.fuzzy_output_value_repository.create(fuzzy_output_value)
+    /// `shape` places the term in a free-form parameter, see `FuzzyOutputValueRepository::create`
+    pub fn execute(
+        &self,
+        fuzzy_output_value: &FuzzyOutputValue,
+        shape: Option<[f32; 4]>,
+    ) -> Result<i64, DomainError> {
+        self.fuzzy_output_value_repository.create(fuzzy_output_value, shape)
     }
 }
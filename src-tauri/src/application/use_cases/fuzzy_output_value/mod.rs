@@ -1,4 +1,4 @@
 pub mod create;
 pub mod remove_by_id;
 pub mod update_by_id;
-
+pub mod update_lower;
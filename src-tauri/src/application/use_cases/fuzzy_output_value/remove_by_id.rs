@@ -1,17 +1,58 @@
-use crate::domain::{error::DomainError, repository::FuzzyOutputValueRepository};
+use crate::{
+    application::use_cases::{
+        problem_snapshot::create::CreateProblemSnapshotUseCase,
+        test_case::prune::{PruneTestCasesUseCase, RemovedReference},
+    },
+    domain::{
+        error::DomainError,
+        repository::{
+            FuzzyOutputValueRepository, OutputParameterRepository, TestCaseRepository, UnitOfWork,
+        },
+    },
+};
 
 pub struct RemoveFuzzyOutputValueByIdUseCase<'a> {
     fuzzy_output_value_repository: &'a dyn FuzzyOutputValueRepository,
+    output_parameter_repository: &'a dyn OutputParameterRepository,
+    test_case_repository: &'a dyn TestCaseRepository,
+    create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+    unit_of_work: &'a dyn UnitOfWork,
 }
 
 impl<'a> RemoveFuzzyOutputValueByIdUseCase<'a> {
-    pub fn new(fuzzy_output_value_repository: &'a dyn FuzzyOutputValueRepository) -> Self {
+    pub fn new(
+        fuzzy_output_value_repository: &'a dyn FuzzyOutputValueRepository,
+        output_parameter_repository: &'a dyn OutputParameterRepository,
+        test_case_repository: &'a dyn TestCaseRepository,
+        create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
         Self {
             fuzzy_output_value_repository,
+            output_parameter_repository,
+            test_case_repository,
+            create_snapshot,
+            unit_of_work,
         }
     }
 
+    /// Removes the term and clears it from the expectations of the test cases of its problem.
+    ///
+    /// The problem is snapshotted automatically first, in the same unit of work.
     pub fn execute(&self, id: i64) -> Result<(), DomainError> {
-        self.fuzzy_output_value_repository.remove_by_id(id)
+        let term = self.fuzzy_output_value_repository.get_by_id(id)?;
+        let parameter = self
+            .output_parameter_repository
+            .get_by_id(term.output_parameter_id)?;
+
+        self.unit_of_work.run(|| {
+            self.create_snapshot.execute_automatic(
+                parameter.problem_id,
+                &format!("Before deleting term '{}' of '{}'", term.value, parameter.name),
+            )?;
+            PruneTestCasesUseCase::new(self.test_case_repository)
+                .execute(parameter.problem_id, RemovedReference::FuzzyOutputValue(id))?;
+            self.fuzzy_output_value_repository.remove_by_id(id)
+        })
     }
 }
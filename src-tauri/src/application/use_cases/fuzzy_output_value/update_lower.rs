@@ -0,0 +1,29 @@
+use crate::domain::{
+    entities::lower_membership::LowerMembership,
+    error::DomainError,
+    repository::FuzzyOutputValueRepository,
+    services::interval_type2::validate_lower_membership,
+};
+
+pub struct UpdateFuzzyOutputValueLowerUseCase<'a> {
+    fuzzy_output_value_repository: &'a dyn FuzzyOutputValueRepository,
+}
+
+impl<'a> UpdateFuzzyOutputValueLowerUseCase<'a> {
+    pub fn new(fuzzy_output_value_repository: &'a dyn FuzzyOutputValueRepository) -> Self {
+        Self {
+            fuzzy_output_value_repository,
+        }
+    }
+
+    /// Sets the lower membership function of a term, turning it into an interval
+    /// type-2 term, or clears it with `None`
+    pub fn execute(&self, id: i64, lower: Option<&LowerMembership>) -> Result<(), DomainError> {
+        let term = self.fuzzy_output_value_repository.get_by_id(id)?;
+        if let Some(lower) = lower {
+            validate_lower_membership((term.a, term.b, term.c, term.d), term.is_triangle, lower)
+                .map_err(DomainError::Validation)?;
+        }
+        self.fuzzy_output_value_repository.update_lower(id, lower)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::domain::{
+    error::{DomainError, EntityKind},
+    repository::{InputParameterRepository, InputValueRepository, UnitOfWork},
+    services::range_change::{change_range, RangeChangePolicy, RangedTerm},
+};
+
+/// Use case for changing the universe of discourse of an input parameter together
+/// with the breakpoints of its terms
+pub struct ChangeInputParameterRangeUseCase<'a> {
+    input_parameter_repository: &'a dyn InputParameterRepository,
+    input_value_repository: &'a dyn InputValueRepository,
+    unit_of_work: &'a dyn UnitOfWork,
+}
+
+impl<'a> ChangeInputParameterRangeUseCase<'a> {
+    pub fn new(
+        input_parameter_repository: &'a dyn InputParameterRepository,
+        input_value_repository: &'a dyn InputValueRepository,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
+        Self {
+            input_parameter_repository,
+            input_value_repository,
+            unit_of_work,
+        }
+    }
+
+    /// Sets the range of the parameter to `[start, end]`, moving its terms according
+    /// to `policy`; either everything is written or nothing is
+    pub fn execute(
+        &self,
+        id: i64,
+        start: f32,
+        end: f32,
+        policy: RangeChangePolicy,
+    ) -> Result<(), DomainError> {
+        let mut parameter = self.input_parameter_repository.get_by_id(id)?;
+        let terms = self.input_value_repository.get_by_input_parameter_id(id)?;
+
+        let ranged: Vec<RangedTerm> = terms
+            .iter()
+            .map(|term| RangedTerm {
+                name: term.value.clone(),
+                shape: (term.a, term.b, term.c, term.d),
+                lower: term.lower,
+            })
+            .collect();
+        let moved = change_range(&ranged, (parameter.start, parameter.end), (start, end), policy)
+            .map_err(|error| {
+                DomainError::invalid_field(EntityKind::InputParameter, Some(id), error)
+            })?;
+
+        // The default value follows the terms when rescaling and otherwise stays
+        // where it is as far as the new range allows
+        let default_value = parameter.metadata.default_value.map(|value| match policy {
+            RangeChangePolicy::Rescale => {
+                start + (value - parameter.start) * (end - start) / (parameter.end - parameter.start)
+            }
+            RangeChangePolicy::Clip | RangeChangePolicy::Reject => value.clamp(start, end),
+        });
+
+        parameter.start = start;
+        parameter.end = end;
+        self.unit_of_work.run(|| {
+            self.input_parameter_repository.update_by_id(id, &parameter)?;
+            if default_value != parameter.metadata.default_value {
+                parameter.metadata.default_value = default_value;
+                self.input_parameter_repository.update_metadata(id, &parameter.metadata)?;
+            }
+            for (mut term, moved) in terms.into_iter().zip(moved) {
+                (term.a, term.b, term.c, term.d) = moved.shape;
+                term.lower = moved.lower;
+                self.input_value_repository.update_shape(term.id, &term)?;
+            }
+            Ok(())
+        })
+    }
+}
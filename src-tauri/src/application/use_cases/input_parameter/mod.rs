@@ -1,3 +1,7 @@
+pub mod change_range;
 pub mod create;
 pub mod remove_by_id;
-pub mod update_by_id;
\ No newline at end of file
+pub mod update_by_id;
+pub mod update_kind;
+pub mod update_metadata;
+pub mod update_partition_mode;
@@ -1,17 +1,50 @@
-use crate::domain::{error::DomainError, repository::InputParameterRepository};
+use crate::{
+    application::use_cases::{
+        problem_snapshot::create::CreateProblemSnapshotUseCase,
+        test_case::prune::{PruneTestCasesUseCase, RemovedReference},
+    },
+    domain::{
+        error::DomainError,
+        repository::{InputParameterRepository, TestCaseRepository, UnitOfWork},
+    },
+};
 
 pub struct RemoveInputParameterByIdUseCase<'a> {
     input_parameter_repository: &'a dyn InputParameterRepository,
+    test_case_repository: &'a dyn TestCaseRepository,
+    create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+    unit_of_work: &'a dyn UnitOfWork,
 }
 
 impl<'a> RemoveInputParameterByIdUseCase<'a> {
-    pub fn new(input_parameter_repository: &'a dyn InputParameterRepository) -> Self {
+    pub fn new(
+        input_parameter_repository: &'a dyn InputParameterRepository,
+        test_case_repository: &'a dyn TestCaseRepository,
+        create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
         Self {
             input_parameter_repository: input_parameter_repository,
+            test_case_repository,
+            create_snapshot,
+            unit_of_work,
         }
     }
 
+    /// Removes the parameter and its inputs from the test cases of its problem.
+    ///
+    /// The problem is snapshotted automatically first, in the same unit of work.
     pub fn execute(&self, id: i64) -> Result<(), DomainError> {
-        self.input_parameter_repository.remove_by_id(id)
+        let parameter = self.input_parameter_repository.get_by_id(id)?;
+
+        self.unit_of_work.run(|| {
+            self.create_snapshot.execute_automatic(
+                parameter.problem_id,
+                &format!("Before deleting input parameter '{}'", parameter.name),
+            )?;
+            PruneTestCasesUseCase::new(self.test_case_repository)
+                .execute(parameter.problem_id, RemovedReference::InputParameter(id))?;
+            self.input_parameter_repository.remove_by_id(id)
+        })
     }
 }
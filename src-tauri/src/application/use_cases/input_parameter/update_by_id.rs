@@ -1,17 +1,45 @@
-use crate::domain::{entities::input_parameter::InputParameter, error::DomainError, repository::InputParameterRepository};
+use crate::{
+    application::use_cases::input_parameter::change_range::ChangeInputParameterRangeUseCase,
+    domain::{
+        entities::input_parameter::InputParameter,
+        error::DomainError,
+        repository::{InputParameterRepository, UnitOfWork},
+        services::range_change::RangeChangePolicy,
+    },
+};
 
 pub struct UpdateInputParameterByIdUseCase<'a> {
     input_parameter_repository: &'a dyn InputParameterRepository,
+    change_range: &'a ChangeInputParameterRangeUseCase<'a>,
+    unit_of_work: &'a dyn UnitOfWork,
 }
 
 impl<'a> UpdateInputParameterByIdUseCase<'a> {
-    pub fn new(input_parameter_repository: &'a dyn InputParameterRepository) -> Self {
+    pub fn new(
+        input_parameter_repository: &'a dyn InputParameterRepository,
+        change_range: &'a ChangeInputParameterRangeUseCase<'a>,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
         Self {
-            input_parameter_repository: input_parameter_repository,
+            input_parameter_repository,
+            change_range,
+            unit_of_work,
         }
     }
 
+    /// Renames the parameter. A different range is changed with the reject policy of
+    /// `ChangeInputParameterRangeUseCase`, so it fails rather than strand the terms.
     pub fn execute(&self, id: i64, model: &InputParameter) -> Result<(), DomainError> {
-        self.input_parameter_repository.update_by_id(id, model)
+        self.unit_of_work.run(|| {
+            let mut parameter = self.input_parameter_repository.get_by_id(id)?;
+            if (model.start, model.end) != (parameter.start, parameter.end) {
+                self.change_range
+                    .execute(id, model.start, model.end, RangeChangePolicy::Reject)?;
+                parameter = self.input_parameter_repository.get_by_id(id)?;
+            }
+
+            parameter.name = model.name.clone();
+            self.input_parameter_repository.update_by_id(id, &parameter)
+        })
     }
-}
\ No newline at end of file
+}
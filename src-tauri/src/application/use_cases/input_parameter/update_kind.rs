@@ -0,0 +1,75 @@
+use crate::domain::{
+    entities::{
+        parameter_kind::ParameterKind, parameter_metadata::ParameterMetadata,
+        partition_mode::PartitionMode,
+    },
+    error::{DomainError, EntityKind, FieldError},
+    repository::{InputParameterRepository, ParameterLinkRepository, UnitOfWork},
+};
+
+pub struct UpdateInputParameterKindUseCase<'a> {
+    input_parameter_repository: &'a dyn InputParameterRepository,
+    parameter_link_repository: &'a dyn ParameterLinkRepository,
+    unit_of_work: &'a dyn UnitOfWork,
+}
+
+impl<'a> UpdateInputParameterKindUseCase<'a> {
+    pub fn new(
+        input_parameter_repository: &'a dyn InputParameterRepository,
+        parameter_link_repository: &'a dyn ParameterLinkRepository,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
+        Self {
+            input_parameter_repository,
+            parameter_link_repository,
+            unit_of_work,
+        }
+    }
+
+    /// Switches between a numeric and a categorical parameter. The terms are kept
+    /// either way; as categories they are edited free-form, since reshaping
+    /// neighbours means nothing for them. A default value is dropped, as a number
+    /// and a category index do not translate into each other.
+    pub fn execute(&self, id: i64, kind: ParameterKind) -> Result<(), DomainError> {
+        let parameter = self.input_parameter_repository.get_by_id(id)?;
+        if parameter.kind == kind {
+            return Ok(());
+        }
+        if kind == ParameterKind::Categorical
+            && self
+                .parameter_link_repository
+                .get_all()?
+                .iter()
+                .any(|l| l.input_parameter_id == id)
+        {
+            return Err(DomainError::invalid_field(
+                EntityKind::InputParameter,
+                Some(id),
+                FieldError::new(
+                    "kind",
+                    format!(
+                        "Input parameter '{}' is fed by a crisp output and cannot be categorical",
+                        parameter.name
+                    ),
+                ),
+            ));
+        }
+
+        self.unit_of_work.run(|| {
+            if kind == ParameterKind::Categorical {
+                self.input_parameter_repository
+                    .update_partition_mode(id, PartitionMode::FreeForm)?;
+            }
+            if parameter.metadata.default_value.is_some() {
+                self.input_parameter_repository.update_metadata(
+                    id,
+                    &ParameterMetadata {
+                        default_value: None,
+                        ..parameter.metadata.clone()
+                    },
+                )?;
+            }
+            self.input_parameter_repository.update_kind(id, kind)
+        })
+    }
+}
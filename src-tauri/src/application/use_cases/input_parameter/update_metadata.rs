@@ -0,0 +1,49 @@
+use crate::domain::{
+    entities::{parameter_kind::ParameterKind, parameter_metadata::ParameterMetadata},
+    error::{DomainError, EntityKind, FieldError},
+    repository::{InputParameterRepository, InputValueRepository},
+    services::fuzzification::category_index,
+};
+
+pub struct UpdateInputParameterMetadataUseCase<'a> {
+    input_parameter_repository: &'a dyn InputParameterRepository,
+    input_value_repository: &'a dyn InputValueRepository,
+}
+
+impl<'a> UpdateInputParameterMetadataUseCase<'a> {
+    pub fn new(
+        input_parameter_repository: &'a dyn InputParameterRepository,
+        input_value_repository: &'a dyn InputValueRepository,
+    ) -> Self {
+        Self {
+            input_parameter_repository,
+            input_value_repository,
+        }
+    }
+
+    /// The default value of a categorical parameter is the index of a category
+    pub fn execute(&self, id: i64, metadata: &ParameterMetadata) -> Result<(), DomainError> {
+        let parameter = self.input_parameter_repository.get_by_id(id)?;
+        let checked = match parameter.kind {
+            ParameterKind::Continuous => metadata.check(parameter.start, parameter.end),
+            ParameterKind::Categorical => {
+                let count = self.input_value_repository.get_by_input_parameter_id(id)?.len();
+                match metadata.default_value {
+                    Some(value) if category_index(value, count).is_none() => {
+                        Err(FieldError::new(
+                            "default_value",
+                            format!("Default value {} is not a category index", value),
+                        ))
+                    }
+                    _ => ParameterMetadata {
+                        default_value: None,
+                        ..metadata.clone()
+                    }
+                    .check(parameter.start, parameter.end),
+                }
+            }
+        };
+        checked.map_err(|error| DomainError::invalid_field(EntityKind::InputParameter, Some(id), error))?;
+        self.input_parameter_repository.update_metadata(id, metadata)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::domain::{
+    entities::{parameter_kind::ParameterKind, partition_mode::PartitionMode},
+    error::{DomainError, EntityKind, FieldError},
+    repository::{InputParameterRepository, InputValueRepository},
+    services::fuzzy_partition::{check_partition, PARTITION_TOLERANCE},
+};
+
+pub struct UpdateInputParameterPartitionModeUseCase<'a> {
+    input_parameter_repository: &'a dyn InputParameterRepository,
+    input_value_repository: &'a dyn InputValueRepository,
+}
+
+impl<'a> UpdateInputParameterPartitionModeUseCase<'a> {
+    pub fn new(
+        input_parameter_repository: &'a dyn InputParameterRepository,
+        input_value_repository: &'a dyn InputValueRepository,
+    ) -> Self {
+        Self {
+            input_parameter_repository,
+            input_value_repository,
+        }
+    }
+
+    /// Switches between Ruspini and free-form term editing. Going back to Ruspini
+    /// requires the terms to form a partition of the range again, since later edits
+    /// reshape neighbours assuming they do. Categories of a categorical parameter
+    /// always stay free-form.
+    pub fn execute(&self, id: i64, mode: PartitionMode) -> Result<(), DomainError> {
+        let parameter = self.input_parameter_repository.get_by_id(id)?;
+        if mode == PartitionMode::Ruspini && parameter.kind == ParameterKind::Categorical {
+            return Err(DomainError::invalid_field(
+                EntityKind::InputParameter,
+                Some(id),
+                FieldError::new(
+                    "partition_mode",
+                    format!("Categories of '{}' do not partition a range", parameter.name),
+                ),
+            ));
+        }
+        if mode == PartitionMode::Ruspini {
+            let shapes: Vec<_> = self
+                .input_value_repository
+                .get_by_input_parameter_id(id)?
+                .iter()
+                .map(|term| (term.a, term.b, term.c, term.d))
+                .collect();
+            if !shapes.is_empty() {
+                check_partition(&shapes, parameter.start, parameter.end, PARTITION_TOLERANCE).map_err(
+                    |message| {
+                        DomainError::invalid_field(
+                            EntityKind::InputParameter,
+                            Some(id),
+                            FieldError::new(
+                                "partition_mode",
+                                format!(
+                                    "Terms of '{}' do not form a fuzzy partition: {}",
+                                    parameter.name, message
+                                ),
+                            ),
+                        )
+                    },
+                )?;
+            }
+        }
+        self.input_parameter_repository.update_partition_mode(id, mode)
+    }
+}
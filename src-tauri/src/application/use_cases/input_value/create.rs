@@ -11,7 +11,8 @@ impl<'a> CreateInputValueUseCase<'a> {
         }
     }
 
-    pub fn execute(&self, input_value: &InputValue) -> Result<i64, DomainError> {
-        self.input_value_repository.create(input_value)
+    /// `shape` places the term in a free-form parameter, see `InputValueRepository::create`
+    pub fn execute(&self, input_value: &InputValue, shape: Option<[f32; 4]>) -> Result<i64, DomainError> {
+        self.input_value_repository.create(input_value, shape)
     }
 }
\ No newline at end of file
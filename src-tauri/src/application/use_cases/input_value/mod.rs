@@ -1,3 +1,4 @@
 pub mod create;
 pub mod remove_by_id;
-pub mod update_by_id;
\ No newline at end of file
+pub mod update_by_id;
+pub mod update_lower;
@@ -1,17 +1,46 @@
-use crate::domain::{error::DomainError, repository::InputValueRepository};
+use crate::{
+    application::use_cases::problem_snapshot::create::CreateProblemSnapshotUseCase,
+    domain::{
+        error::DomainError,
+        repository::{InputParameterRepository, InputValueRepository, UnitOfWork},
+    },
+};
 
 pub struct RemoveInputValueByIdUseCase<'a> {
     input_value_repository: &'a dyn InputValueRepository,
+    input_parameter_repository: &'a dyn InputParameterRepository,
+    create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+    unit_of_work: &'a dyn UnitOfWork,
 }
 
 impl<'a> RemoveInputValueByIdUseCase<'a> {
-    pub fn new(input_value_repository: &'a dyn InputValueRepository) -> Self {
+    pub fn new(
+        input_value_repository: &'a dyn InputValueRepository,
+        input_parameter_repository: &'a dyn InputParameterRepository,
+        create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
         Self {
             input_value_repository,
+            input_parameter_repository,
+            create_snapshot,
+            unit_of_work,
         }
     }
 
+    /// Removes the term. The problem is snapshotted automatically first, in the same unit of work.
     pub fn execute(&self, id: i64) -> Result<(), DomainError> {
-        self.input_value_repository.remove_by_id(id)
+        let term = self.input_value_repository.get_by_id(id)?;
+        let parameter = self
+            .input_parameter_repository
+            .get_by_id(term.input_parameter_id)?;
+
+        self.unit_of_work.run(|| {
+            self.create_snapshot.execute_automatic(
+                parameter.problem_id,
+                &format!("Before deleting term '{}' of '{}'", term.value, parameter.name),
+            )?;
+            self.input_value_repository.remove_by_id(id)
+        })
     }
 }
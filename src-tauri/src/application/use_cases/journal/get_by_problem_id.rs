@@ -0,0 +1,18 @@
+use crate::domain::{
+    entities::journal_entry::JournalEntry, error::DomainError, repository::JournalRepository,
+};
+
+pub struct GetJournalByProblemIdUseCase<'a> {
+    journal_repository: &'a dyn JournalRepository,
+}
+
+impl<'a> GetJournalByProblemIdUseCase<'a> {
+    pub fn new(journal_repository: &'a dyn JournalRepository) -> Self {
+        Self { journal_repository }
+    }
+
+    /// Returns the journal of a problem, newest first
+    pub fn execute(&self, problem_id: i64) -> Result<Vec<JournalEntry>, DomainError> {
+        self.journal_repository.get_by_problem_id(problem_id)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod get_by_problem_id;
+pub mod record;
+pub mod redo;
+pub mod undo;
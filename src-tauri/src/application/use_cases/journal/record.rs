@@ -0,0 +1,81 @@
+use crate::domain::{
+    entities::journal_entry::JournalEntry,
+    error::DomainError,
+    repository::{JournalRepository, ProblemRepository, UnitOfWork},
+};
+
+/// Number of journal entries kept per problem
+pub const JOURNAL_LIMIT: usize = 50;
+
+/// Loads the journaled part of a problem: name, description, parameters, terms and rules.
+///
+/// Timestamps, image and tree position are cleared so that two captures of an
+/// unchanged definition serialize identically.
+pub(super) fn capture_definition(
+    problem_repository: &dyn ProblemRepository,
+    problem_id: i64,
+) -> Result<String, DomainError> {
+    let mut problem = problem_repository.get_full_by_id(problem_id)?;
+    problem.prev_problem_id = None;
+    problem.image_id = None;
+    problem.created_at = String::new();
+    problem.updated_at = None;
+
+    serde_json::to_string(&problem).map_err(|e| DomainError::Internal(e.to_string()))
+}
+
+/// Use case wrapping a model-editing operation so that it can be undone.
+///
+/// The definition is captured before and after the operation; the pair is the
+/// journal entry. A new entry discards everything that was undone before it.
+pub struct RecordMutationUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+    journal_repository: &'a dyn JournalRepository,
+    unit_of_work: &'a dyn UnitOfWork,
+}
+
+impl<'a> RecordMutationUseCase<'a> {
+    pub fn new(
+        problem_repository: &'a dyn ProblemRepository,
+        journal_repository: &'a dyn JournalRepository,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
+        Self {
+            problem_repository,
+            journal_repository,
+            unit_of_work,
+        }
+    }
+
+    /// Runs `mutation` and records it. The mutation and its journal entry are
+    /// committed together; if the mutation fails, none of its writes are kept.
+    pub fn execute<T>(
+        &self,
+        problem_id: i64,
+        description: &str,
+        mutation: impl FnOnce() -> Result<T, DomainError>,
+    ) -> Result<T, DomainError> {
+        self.unit_of_work.run(|| {
+            let before = capture_definition(self.problem_repository, problem_id)?;
+            let result = mutation()?;
+            let after = capture_definition(self.problem_repository, problem_id)?;
+
+            if before != after {
+                self.journal_repository
+                    .remove_undone_by_problem_id(problem_id)?;
+                self.journal_repository.create(&JournalEntry {
+                    id: 0,
+                    problem_id,
+                    description: description.to_string(),
+                    before,
+                    after,
+                    is_undone: false,
+                    created_at: String::new(),
+                })?;
+                self.journal_repository.prune(problem_id, JOURNAL_LIMIT)?;
+            }
+
+            Ok(result)
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    application::use_cases::journal::record::capture_definition,
+    domain::{
+        entities::{journal_entry::JournalEntry, problem::Problem},
+        error::DomainError,
+        repository::{JournalRepository, ProblemRepository, UnitOfWork},
+    },
+};
+
+pub struct RedoUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+    journal_repository: &'a dyn JournalRepository,
+    unit_of_work: &'a dyn UnitOfWork,
+}
+
+impl<'a> RedoUseCase<'a> {
+    pub fn new(
+        problem_repository: &'a dyn ProblemRepository,
+        journal_repository: &'a dyn JournalRepository,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
+        Self {
+            problem_repository,
+            journal_repository,
+            unit_of_work,
+        }
+    }
+
+    /// Re-applies the most recently undone mutation of the problem.
+    ///
+    /// # Returns
+    /// The re-applied entry, or `None` when there is nothing to redo
+    pub fn execute(&self, problem_id: i64) -> Result<Option<JournalEntry>, DomainError> {
+        let Some(entry) = self.journal_repository.get_first_undone(problem_id)? else {
+            return Ok(None);
+        };
+
+        let current = capture_definition(self.problem_repository, problem_id)?;
+        if current != entry.before {
+            self.journal_repository.prune(problem_id, 0)?;
+            return Err(DomainError::Validation(format!(
+                "Problem {} was changed outside of the undo history, the history has been cleared",
+                problem_id
+            )));
+        }
+
+        let after: Problem =
+            serde_json::from_str(&entry.after).map_err(|e| DomainError::Data(e.to_string()))?;
+        self.unit_of_work.run(|| {
+            self.problem_repository.replace_definition(&after)?;
+            self.journal_repository.set_undone(entry.id, false)
+        })?;
+
+        Ok(Some(entry))
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{
+    application::use_cases::journal::record::capture_definition,
+    domain::{
+        entities::{journal_entry::JournalEntry, problem::Problem},
+        error::DomainError,
+        repository::{JournalRepository, ProblemRepository, UnitOfWork},
+    },
+};
+
+pub struct UndoUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+    journal_repository: &'a dyn JournalRepository,
+    unit_of_work: &'a dyn UnitOfWork,
+}
+
+impl<'a> UndoUseCase<'a> {
+    pub fn new(
+        problem_repository: &'a dyn ProblemRepository,
+        journal_repository: &'a dyn JournalRepository,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
+        Self {
+            problem_repository,
+            journal_repository,
+            unit_of_work,
+        }
+    }
+
+    /// Reverts the newest not yet undone mutation of the problem.
+    ///
+    /// # Returns
+    /// The reverted entry, or `None` when there is nothing to undo
+    pub fn execute(&self, problem_id: i64) -> Result<Option<JournalEntry>, DomainError> {
+        let Some(entry) = self.journal_repository.get_last_done(problem_id)? else {
+            return Ok(None);
+        };
+
+        let current = capture_definition(self.problem_repository, problem_id)?;
+        if current != entry.after {
+            // Something edited the problem without going through the journal;
+            // replaying old states on top of it would lose that change
+            self.journal_repository.prune(problem_id, 0)?;
+            return Err(DomainError::Validation(format!(
+                "Problem {} was changed outside of the undo history, the history has been cleared",
+                problem_id
+            )));
+        }
+
+        let before: Problem =
+            serde_json::from_str(&entry.before).map_err(|e| DomainError::Data(e.to_string()))?;
+        self.unit_of_work.run(|| {
+            self.problem_repository.replace_definition(&before)?;
+            self.journal_repository.set_undone(entry.id, true)
+        })?;
+
+        Ok(Some(entry))
+    }
+}
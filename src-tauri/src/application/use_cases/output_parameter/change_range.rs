@@ -0,0 +1,65 @@
+use crate::domain::{
+    error::{DomainError, EntityKind},
+    repository::{OutputParameterRepository, FuzzyOutputValueRepository, UnitOfWork},
+    services::range_change::{change_range, RangeChangePolicy, RangedTerm},
+};
+
+/// Use case for changing the universe of discourse of an output parameter together
+/// with the breakpoints of its terms
+pub struct ChangeOutputParameterRangeUseCase<'a> {
+    output_parameter_repository: &'a dyn OutputParameterRepository,
+    fuzzy_output_value_repository: &'a dyn FuzzyOutputValueRepository,
+    unit_of_work: &'a dyn UnitOfWork,
+}
+
+impl<'a> ChangeOutputParameterRangeUseCase<'a> {
+    pub fn new(
+        output_parameter_repository: &'a dyn OutputParameterRepository,
+        fuzzy_output_value_repository: &'a dyn FuzzyOutputValueRepository,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
+        Self {
+            output_parameter_repository,
+            fuzzy_output_value_repository,
+            unit_of_work,
+        }
+    }
+
+    /// Sets the range of the parameter to `[start, end]`, moving its terms according
+    /// to `policy`; either everything is written or nothing is
+    pub fn execute(
+        &self,
+        id: i64,
+        start: f32,
+        end: f32,
+        policy: RangeChangePolicy,
+    ) -> Result<(), DomainError> {
+        let mut parameter = self.output_parameter_repository.get_by_id(id)?;
+        let terms = self.fuzzy_output_value_repository.get_by_output_parameter_id(id)?;
+
+        let ranged: Vec<RangedTerm> = terms
+            .iter()
+            .map(|term| RangedTerm {
+                name: term.value.clone(),
+                shape: (term.a, term.b, term.c, term.d),
+                lower: term.lower,
+            })
+            .collect();
+        let moved = change_range(&ranged, (parameter.start, parameter.end), (start, end), policy)
+            .map_err(|error| {
+                DomainError::invalid_field(EntityKind::OutputParameter, Some(id), error)
+            })?;
+
+        parameter.start = start;
+        parameter.end = end;
+        self.unit_of_work.run(|| {
+            self.output_parameter_repository.update_by_id(id, &parameter)?;
+            for (mut term, moved) in terms.into_iter().zip(moved) {
+                (term.a, term.b, term.c, term.d) = moved.shape;
+                term.lower = moved.lower;
+                self.fuzzy_output_value_repository.update_shape(term.id, &term)?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -1,4 +1,6 @@
+pub mod change_range;
 pub mod create;
 pub mod remove_by_id;
 pub mod update_by_id;
-
+pub mod update_metadata;
+pub mod update_partition_mode;
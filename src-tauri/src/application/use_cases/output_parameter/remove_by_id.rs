@@ -1,17 +1,50 @@
-use crate::domain::{error::DomainError, repository::OutputParameterRepository};
+use crate::{
+    application::use_cases::{
+        problem_snapshot::create::CreateProblemSnapshotUseCase,
+        test_case::prune::{PruneTestCasesUseCase, RemovedReference},
+    },
+    domain::{
+        error::DomainError,
+        repository::{OutputParameterRepository, TestCaseRepository, UnitOfWork},
+    },
+};
 
 pub struct RemoveOutputParameterByIdUseCase<'a> {
     output_parameter_repository: &'a dyn OutputParameterRepository,
+    test_case_repository: &'a dyn TestCaseRepository,
+    create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+    unit_of_work: &'a dyn UnitOfWork,
 }
 
 impl<'a> RemoveOutputParameterByIdUseCase<'a> {
-    pub fn new(output_parameter_repository: &'a dyn OutputParameterRepository) -> Self {
+    pub fn new(
+        output_parameter_repository: &'a dyn OutputParameterRepository,
+        test_case_repository: &'a dyn TestCaseRepository,
+        create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
         Self {
             output_parameter_repository,
+            test_case_repository,
+            create_snapshot,
+            unit_of_work,
         }
     }
 
+    /// Removes the parameter and its expectations from the test cases of its problem.
+    ///
+    /// The problem is snapshotted automatically first, in the same unit of work.
     pub fn execute(&self, id: i64) -> Result<(), DomainError> {
-        self.output_parameter_repository.remove_by_id(id)
+        let parameter = self.output_parameter_repository.get_by_id(id)?;
+
+        self.unit_of_work.run(|| {
+            self.create_snapshot.execute_automatic(
+                parameter.problem_id,
+                &format!("Before deleting output parameter '{}'", parameter.name),
+            )?;
+            PruneTestCasesUseCase::new(self.test_case_repository)
+                .execute(parameter.problem_id, RemovedReference::OutputParameter(id))?;
+            self.output_parameter_repository.remove_by_id(id)
+        })
     }
 }
@@ -1,20 +1,45 @@
-use crate::domain::{
-    entities::output_parameter::OutputParameter, error::DomainError,
-    repository::OutputParameterRepository,
+use crate::{
+    application::use_cases::output_parameter::change_range::ChangeOutputParameterRangeUseCase,
+    domain::{
+        entities::output_parameter::OutputParameter,
+        error::DomainError,
+        repository::{OutputParameterRepository, UnitOfWork},
+        services::range_change::RangeChangePolicy,
+    },
 };
 
 pub struct UpdateOutputParameterByIdUseCase<'a> {
     output_parameter_repository: &'a dyn OutputParameterRepository,
+    change_range: &'a ChangeOutputParameterRangeUseCase<'a>,
+    unit_of_work: &'a dyn UnitOfWork,
 }
 
 impl<'a> UpdateOutputParameterByIdUseCase<'a> {
-    pub fn new(output_parameter_repository: &'a dyn OutputParameterRepository) -> Self {
+    pub fn new(
+        output_parameter_repository: &'a dyn OutputParameterRepository,
+        change_range: &'a ChangeOutputParameterRangeUseCase<'a>,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
         Self {
             output_parameter_repository,
+            change_range,
+            unit_of_work,
         }
     }
 
+    /// Renames the parameter. A different range is changed with the reject policy of
+    /// `ChangeOutputParameterRangeUseCase`, so it fails rather than strand the terms.
     pub fn execute(&self, id: i64, model: &OutputParameter) -> Result<(), DomainError> {
-        self.output_parameter_repository.update_by_id(id, model)
+        self.unit_of_work.run(|| {
+            let mut parameter = self.output_parameter_repository.get_by_id(id)?;
+            if (model.start, model.end) != (parameter.start, parameter.end) {
+                self.change_range
+                    .execute(id, model.start, model.end, RangeChangePolicy::Reject)?;
+                parameter = self.output_parameter_repository.get_by_id(id)?;
+            }
+
+            parameter.name = model.name.clone();
+            self.output_parameter_repository.update_by_id(id, &parameter)
+        })
     }
 }
@@ -0,0 +1,35 @@
+use crate::domain::{
+    entities::parameter_metadata::ParameterMetadata,
+    error::{DomainError, EntityKind, FieldError},
+    repository::OutputParameterRepository,
+};
+
+pub struct UpdateOutputParameterMetadataUseCase<'a> {
+    output_parameter_repository: &'a dyn OutputParameterRepository,
+}
+
+impl<'a> UpdateOutputParameterMetadataUseCase<'a> {
+    pub fn new(output_parameter_repository: &'a dyn OutputParameterRepository) -> Self {
+        Self {
+            output_parameter_repository,
+        }
+    }
+
+    pub fn execute(&self, id: i64, metadata: &ParameterMetadata) -> Result<(), DomainError> {
+        let parameter = self.output_parameter_repository.get_by_id(id)?;
+        if metadata.default_value.is_some() {
+            return Err(DomainError::invalid_field(
+                EntityKind::OutputParameter,
+                Some(id),
+                FieldError::new(
+                    "default_value",
+                    format!("Output parameter '{}' can not have a default value", parameter.name),
+                ),
+            ));
+        }
+        metadata
+            .check(parameter.start, parameter.end)
+            .map_err(|error| DomainError::invalid_field(EntityKind::OutputParameter, Some(id), error))?;
+        self.output_parameter_repository.update_metadata(id, metadata)
+    }
+}
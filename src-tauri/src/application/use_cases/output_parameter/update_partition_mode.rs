@@ -0,0 +1,56 @@
+use crate::domain::{
+    entities::partition_mode::PartitionMode,
+    error::{DomainError, EntityKind, FieldError},
+    repository::{OutputParameterRepository, FuzzyOutputValueRepository},
+    services::fuzzy_partition::{check_partition, PARTITION_TOLERANCE},
+};
+
+pub struct UpdateOutputParameterPartitionModeUseCase<'a> {
+    output_parameter_repository: &'a dyn OutputParameterRepository,
+    fuzzy_output_value_repository: &'a dyn FuzzyOutputValueRepository,
+}
+
+impl<'a> UpdateOutputParameterPartitionModeUseCase<'a> {
+    pub fn new(
+        output_parameter_repository: &'a dyn OutputParameterRepository,
+        fuzzy_output_value_repository: &'a dyn FuzzyOutputValueRepository,
+    ) -> Self {
+        Self {
+            output_parameter_repository,
+            fuzzy_output_value_repository,
+        }
+    }
+
+    /// Switches between Ruspini and free-form term editing. Going back to Ruspini
+    /// requires the terms to form a partition of the range again, since later edits
+    /// reshape neighbours assuming they do.
+    pub fn execute(&self, id: i64, mode: PartitionMode) -> Result<(), DomainError> {
+        let parameter = self.output_parameter_repository.get_by_id(id)?;
+        if mode == PartitionMode::Ruspini {
+            let shapes: Vec<_> = self
+                .fuzzy_output_value_repository
+                .get_by_output_parameter_id(id)?
+                .iter()
+                .map(|term| (term.a, term.b, term.c, term.d))
+                .collect();
+            if !shapes.is_empty() {
+                check_partition(&shapes, parameter.start, parameter.end, PARTITION_TOLERANCE).map_err(
+                    |message| {
+                        DomainError::invalid_field(
+                            EntityKind::OutputParameter,
+                            Some(id),
+                            FieldError::new(
+                                "partition_mode",
+                                format!(
+                                    "Terms of '{}' do not form a fuzzy partition: {}",
+                                    parameter.name, message
+                                ),
+                            ),
+                        )
+                    },
+                )?;
+            }
+        }
+        self.output_parameter_repository.update_partition_mode(id, mode)
+    }
+}
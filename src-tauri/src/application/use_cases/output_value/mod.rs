@@ -1,3 +1,4 @@
 pub mod create;
 pub mod get_by_problem_id;
 pub mod update_by_id;
+pub mod update_hedges;
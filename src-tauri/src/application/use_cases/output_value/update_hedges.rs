@@ -0,0 +1,72 @@
+use crate::domain::{
+    error::{DomainError, EntityKind, FieldError},
+    repository::OutputValueRepository,
+    services::hedge::{format_antecedent_hedges, Hedge},
+};
+
+pub struct UpdateOutputValueHedgesUseCase<'a> {
+    output_value_repository: &'a dyn OutputValueRepository,
+}
+
+impl<'a> UpdateOutputValueHedgesUseCase<'a> {
+    pub fn new(output_value_repository: &'a dyn OutputValueRepository) -> Self {
+        Self {
+            output_value_repository,
+        }
+    }
+
+    /// Replaces the hedges of a rule. `antecedent_hedges` pairs antecedent term ids
+    /// with hedge names; terms left out are used plain.
+    pub fn execute(
+        &self,
+        id: i64,
+        antecedent_hedges: &[(i64, String)],
+        consequent_hedge: Option<&str>,
+    ) -> Result<(), DomainError> {
+        let rule = self.output_value_repository.get_by_id(id)?;
+
+        let invalid = |field: &str, message: String| {
+            DomainError::invalid_field(
+                EntityKind::OutputValue,
+                Some(id),
+                FieldError::new(field, message),
+            )
+        };
+        let mut hedges = Vec::with_capacity(antecedent_hedges.len());
+        for (i, (input_value_id, name)) in antecedent_hedges.iter().enumerate() {
+            if !rule.input_value_ids.contains(&format!("|{}|", input_value_id)) {
+                return Err(invalid(
+                    &format!("antecedent_hedges[{}].input_value_id", i),
+                    format!("Term {} is not an antecedent of rule {}", input_value_id, id),
+                ));
+            }
+            if hedges.iter().any(|(seen, _)| seen == input_value_id) {
+                return Err(invalid(
+                    &format!("antecedent_hedges[{}].input_value_id", i),
+                    format!("Term {} has more than one hedge", input_value_id),
+                ));
+            }
+            let hedge = Hedge::from_name(name).ok_or_else(|| {
+                invalid(&format!("antecedent_hedges[{}].hedge", i), unknown_hedge(name))
+            })?;
+            hedges.push((*input_value_id, hedge));
+        }
+        let consequent_hedge = match consequent_hedge {
+            Some(name) => Some(
+                Hedge::from_name(name)
+                    .ok_or_else(|| invalid("consequent_hedge", unknown_hedge(name)))?,
+            ),
+            None => None,
+        };
+
+        self.output_value_repository.update_hedges(
+            id,
+            &format_antecedent_hedges(&hedges),
+            consequent_hedge.map(|h| h.as_str()),
+        )
+    }
+}
+
+fn unknown_hedge(name: &str) -> String {
+    format!("Unknown hedge '{}'", name)
+}
@@ -0,0 +1,71 @@
+use crate::domain::{
+    entities::{parameter_kind::ParameterKind, parameter_link::ParameterLink},
+    error::{DomainError, EntityKind, FieldError},
+    repository::{InputParameterRepository, OutputParameterRepository, ParameterLinkRepository},
+    services::problem_chain::validate_new_link,
+};
+
+pub struct CreateParameterLinkUseCase<'a> {
+    parameter_link_repository: &'a dyn ParameterLinkRepository,
+    input_parameter_repository: &'a dyn InputParameterRepository,
+    output_parameter_repository: &'a dyn OutputParameterRepository,
+}
+
+impl<'a> CreateParameterLinkUseCase<'a> {
+    pub fn new(
+        parameter_link_repository: &'a dyn ParameterLinkRepository,
+        input_parameter_repository: &'a dyn InputParameterRepository,
+        output_parameter_repository: &'a dyn OutputParameterRepository,
+    ) -> Self {
+        Self {
+            parameter_link_repository,
+            input_parameter_repository,
+            output_parameter_repository,
+        }
+    }
+
+    /// Links output parameter `output_parameter_id` to input parameter `input_parameter_id`
+    /// of another problem. Rejected if the input is already fed, is categorical, or the
+    /// problems would form a cycle.
+    pub fn execute(
+        &self,
+        output_parameter_id: i64,
+        input_parameter_id: i64,
+    ) -> Result<i64, DomainError> {
+        let output_parameter = self.output_parameter_repository.get_by_id(output_parameter_id)?;
+        let input_parameter = self.input_parameter_repository.get_by_id(input_parameter_id)?;
+
+        let link = ParameterLink {
+            id: 0,
+            output_parameter_id,
+            input_parameter_id,
+            source_problem_id: output_parameter.problem_id,
+            target_problem_id: input_parameter.problem_id,
+        };
+
+        let invalid = |message: String| {
+            DomainError::invalid_field(
+                EntityKind::ParameterLink,
+                None,
+                FieldError::new("input_parameter_id", message),
+            )
+        };
+        if input_parameter.kind == ParameterKind::Categorical {
+            return Err(invalid(format!(
+                "Categorical input parameter '{}' cannot take a crisp output",
+                input_parameter.name
+            )));
+        }
+
+        let links = self.parameter_link_repository.get_all()?;
+        if links.iter().any(|l| l.input_parameter_id == input_parameter_id) {
+            return Err(invalid(format!(
+                "Input parameter '{}' is already linked",
+                input_parameter.name
+            )));
+        }
+        validate_new_link(&link, &links)?;
+
+        self.parameter_link_repository.create(&link)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::domain::{
+    entities::parameter_link::ParameterLink, error::DomainError,
+    repository::ParameterLinkRepository,
+};
+
+pub struct GetParameterLinksByProblemIdUseCase<'a> {
+    parameter_link_repository: &'a dyn ParameterLinkRepository,
+}
+
+impl<'a> GetParameterLinksByProblemIdUseCase<'a> {
+    pub fn new(parameter_link_repository: &'a dyn ParameterLinkRepository) -> Self {
+        Self {
+            parameter_link_repository,
+        }
+    }
+
+    /// Returns the links feeding the problem and the links it feeds
+    pub fn execute(&self, problem_id: i64) -> Result<Vec<ParameterLink>, DomainError> {
+        let links = self.parameter_link_repository.get_all()?;
+
+        Ok(links
+            .into_iter()
+            .filter(|l| l.source_problem_id == problem_id || l.target_problem_id == problem_id)
+            .collect())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create;
+pub mod get_by_problem_id;
+pub mod remove_by_id;
@@ -0,0 +1,17 @@
+use crate::domain::{error::DomainError, repository::ParameterLinkRepository};
+
+pub struct RemoveParameterLinkByIdUseCase<'a> {
+    parameter_link_repository: &'a dyn ParameterLinkRepository,
+}
+
+impl<'a> RemoveParameterLinkByIdUseCase<'a> {
+    pub fn new(parameter_link_repository: &'a dyn ParameterLinkRepository) -> Self {
+        Self {
+            parameter_link_repository,
+        }
+    }
+
+    pub fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.parameter_link_repository.remove_by_id(id)
+    }
+}
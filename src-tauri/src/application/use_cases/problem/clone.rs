@@ -0,0 +1,96 @@
+use crate::{
+    application::use_cases::problem::{
+        export_problem::ExportProblemUseCase, import_problem::ImportProblemUseCase,
+    },
+    domain::{
+        error::{DomainError, EntityKind, FieldError},
+        repository::{ProblemRepository, UnitOfWork},
+    },
+};
+
+pub struct CloneProblemUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+    export_problem: &'a ExportProblemUseCase<'a>,
+    import_problem: &'a ImportProblemUseCase<'a>,
+    unit_of_work: &'a dyn UnitOfWork,
+}
+
+impl<'a> CloneProblemUseCase<'a> {
+    pub fn new(
+        problem_repository: &'a dyn ProblemRepository,
+        export_problem: &'a ExportProblemUseCase<'a>,
+        import_problem: &'a ImportProblemUseCase<'a>,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
+        Self {
+            problem_repository,
+            export_problem,
+            import_problem,
+            unit_of_work,
+        }
+    }
+
+    /// Deep-copies problem `id` under `parent_id` and returns the id of the copy.
+    /// With `with_children` the whole subtree is copied as well.
+    ///
+    /// The copy is an export of the problem imported again, so image, parameters,
+    /// terms, rules and test cases come along with their ids remapped.
+    pub fn execute(
+        &self,
+        id: i64,
+        parent_id: Option<i64>,
+        name: Option<&str>,
+        with_children: bool,
+    ) -> Result<i64, DomainError> {
+        if let Some(name) = name {
+            if name.is_empty() {
+                return Err(DomainError::invalid_field(
+                    EntityKind::Problem,
+                    None,
+                    FieldError::new("name", "Name cannot be empty"),
+                ));
+            }
+        }
+
+        if let Some(parent_id) = parent_id {
+            if parent_id == id || self.problem_repository.get_ancestor_ids(parent_id)?.contains(&id) {
+                return Err(DomainError::invalid_field(
+                    EntityKind::Problem,
+                    Some(id),
+                    FieldError::new(
+                        "prev_problem_id",
+                        "A problem can not be copied into itself or one of its descendants",
+                    ),
+                ));
+            }
+
+            let parent = self.problem_repository.get_full_by_id(parent_id)?;
+            if parent.is_final {
+                return Err(DomainError::invalid_field(
+                    EntityKind::Problem,
+                    Some(id),
+                    FieldError::new(
+                        "prev_problem_id",
+                        format!(
+                            "Problem {} is final and can not contain other problems",
+                            parent_id
+                        ),
+                    ),
+                ));
+            }
+        }
+
+        self.unit_of_work.run(|| {
+            let mut dto = if with_children {
+                self.export_problem.execute(id)?
+            } else {
+                self.export_problem.execute_without_children(id)?
+            };
+            if let Some(name) = name {
+                dto.name = name.to_string();
+            }
+
+            self.import_problem.execute(parent_id, dto)
+        })
+    }
+}
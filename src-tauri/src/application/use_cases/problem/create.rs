@@ -1,5 +1,7 @@
 use crate::domain::{
-    entities::problem::Problem, error::DomainError, repository::ProblemRepository,
+    entities::{image::Image, problem::Problem},
+    error::{DomainError, EntityKind, FieldError},
+    repository::ProblemRepository,
 };
 
 pub struct CreateProblemUseCase<'a> {
@@ -13,11 +15,15 @@ impl<'a> CreateProblemUseCase<'a> {
         }
     }
 
-    pub fn execute(&self, problem: &Problem) -> Result<(i64, Option<i64>), DomainError> {
+    pub fn execute(&self, problem: &Problem, image: Option<&Image>) -> Result<(i64, Option<i64>), DomainError> {
         if problem.name.is_empty() {
-            return Err(DomainError::Validation("Name cannot be empty".to_string()));
+            return Err(DomainError::invalid_field(
+                EntityKind::Problem,
+                None,
+                FieldError::new("name", "Name cannot be empty"),
+            ));
         }
 
-        self.problem_repository.create(problem)
+        self.problem_repository.create(problem, image)
     }
 }
@@ -1,53 +1,59 @@
 use crate::domain::{
+    entities::problem::Problem,
     error::DomainError,
     repository::{
-        ImageRepository, ProblemRepository,
+        ImageRepository, ProblemRepository, TestCaseRepository,
     },
+    services::hedge::parse_antecedent_hedges,
 };
-use crate::infrastructure::tauri::dtos::export_import_dtos::*;
+use crate::application::exported_problem::*;
 
 pub struct ExportProblemUseCase<'a> {
     problem_repo: &'a dyn ProblemRepository,
     image_repo: &'a dyn ImageRepository,
+    test_case_repo: &'a dyn TestCaseRepository,
 }
 
 impl<'a> ExportProblemUseCase<'a> {
     pub fn new(
         problem_repo: &'a dyn ProblemRepository,
         image_repo: &'a dyn ImageRepository,
+        test_case_repo: &'a dyn TestCaseRepository,
     ) -> Self {
         Self {
             problem_repo,
             image_repo,
+            test_case_repo,
         }
     }
 
     pub fn execute(&self, id: i64) -> Result<ExportedProblem, DomainError> {
-        self.export_recursive(id)
+        self.export(id, true)
     }
 
-    fn export_recursive(&self, id: i64) -> Result<ExportedProblem, DomainError> {
+    /// Exports only the problem itself, leaving `children` empty
+    pub fn execute_without_children(&self, id: i64) -> Result<ExportedProblem, DomainError> {
+        self.export(id, false)
+    }
+
+    fn export(&self, id: i64, with_children: bool) -> Result<ExportedProblem, DomainError> {
         let p = self.problem_repo.get_full_by_id(id)?;
 
         // Recursive children
-        let children_entities = self
-            .problem_repo
-            .get_all_by_prev_problem_id(Some(id))
-            .unwrap_or(vec![]);
-        
         let mut children_dtos = Vec::new();
-        for child in children_entities {
-            children_dtos.push(self.export_recursive(child.id)?);
+        if with_children {
+            let children_entities = self
+                .problem_repo
+                .get_all_by_prev_problem_id(Some(id))
+                .unwrap_or(vec![]);
+
+            for child in children_entities {
+                children_dtos.push(self.export(child.id, true)?);
+            }
         }
 
         // Image
-        // If p.image is populated, use it. If not, try by image_id
-        let image_dto = if let Some(img_ref) = &p.image {
-             Some(ExportedImage {
-                 data: img_ref.image_data.clone(),
-                 format: img_ref.image_format.clone(),
-             })
-        } else if let Some(img_id) = p.image_id {
+        let image_dto = if let Some(img_id) = p.image_id {
             if let Ok(img) = self.image_repo.get_by_id(img_id) {
                 Some(ExportedImage {
                     data: img.image_data,
@@ -60,83 +66,138 @@ impl<'a> ExportProblemUseCase<'a> {
             None
         };
 
-        // Inputs
-        let inputs = p
-            .input_parameters
-            .iter()
-            .map(|ip| ExportedInputParameter {
-                temp_id: ip.id,
-                name: ip.name.clone(),
-                start: ip.start,
-                end: ip.end,
-                values: ip
-                    .input_values
+        // Test cases
+        let test_cases = self
+            .test_case_repo
+            .get_by_problem_id(id)?
+            .into_iter()
+            .map(|tc| ExportedTestCase {
+                name: tc.name,
+                inputs: tc
+                    .inputs
                     .iter()
-                    .map(|iv| ExportedInputValue {
-                        temp_id: iv.id,
-                        value: iv.value.clone(),
-                        a: iv.a,
-                        b: iv.b,
-                        c: iv.c,
-                        d: iv.d,
-                        is_triangle: iv.is_triangle,
+                    .map(|i| ExportedTestCaseInput {
+                        input_parameter_temp_id: i.input_parameter_id,
+                        crisp_value: i.crisp_value,
+                        input_value_temp_id: i.input_value_id,
                     })
                     .collect(),
-            })
-            .collect();
-
-        // Outputs
-        let outputs = p
-            .output_parameters
-            .iter()
-            .map(|op| ExportedOutputParameter {
-                temp_id: op.id,
-                name: op.name.clone(),
-                start: op.start,
-                end: op.end,
-                values: op
-                    .fuzzy_output_values
+                expectations: tc
+                    .expectations
                     .iter()
-                    .map(|fov| ExportedFuzzyOutputValue {
-                        temp_id: fov.id,
-                        value: fov.value.clone(),
-                        a: fov.a,
-                        b: fov.b,
-                        c: fov.c,
-                        d: fov.d,
-                        is_triangle: fov.is_triangle,
+                    .map(|e| ExportedTestCaseExpectation {
+                        output_parameter_temp_id: e.output_parameter_id,
+                        expected_value: e.expected_value,
+                        tolerance: e.tolerance,
+                        expected_fuzzy_output_value_temp_id: e.expected_fuzzy_output_value_id,
                     })
                     .collect(),
             })
             .collect();
 
-        // Rules
-        let rules = p
-            .output_values
-            .iter()
-            .map(|ov| ExportedOutputValue {
-                output_parameter_temp_id: ov.output_parameter_id,
-                fuzzy_output_value_temp_id: ov.fuzzy_output_value_id,
-                input_value_temp_ids: ov
-                    .input_value_ids
-                    .replace('|', ",")
-                    .split(',')
-                    .map(|s| s.trim())
-                    .filter(|s| !s.is_empty())
-                    .filter_map(|s| s.parse::<i64>().ok())
-                    .collect(),
-            })
-            .collect();
+        let mut dto = to_exported(&p, test_cases);
+        dto.image = image_dto;
+        dto.children = children_dtos;
 
-        Ok(ExportedProblem {
-            name: p.name,
-            description: p.description,
-            is_final: p.is_final,
-            image: image_dto,
-            input_parameters: inputs,
-            output_parameters: outputs,
-            output_values: rules,
-            children: children_dtos,
+        Ok(dto)
+    }
+}
+
+/// Builds the exported form of a problem definition, using ids as temp ids.
+/// `image` and `children` are left empty.
+pub(crate) fn to_exported(p: &Problem, test_cases: Vec<ExportedTestCase>) -> ExportedProblem {
+    // Inputs
+    let inputs = p
+        .input_parameters
+        .iter()
+        .map(|ip| ExportedInputParameter {
+            temp_id: ip.id,
+            name: ip.name.clone(),
+            start: ip.start,
+            end: ip.end,
+            partition_mode: ip.partition_mode,
+            kind: ip.kind,
+            metadata: ip.metadata.clone(),
+            values: ip
+                .input_values
+                .iter()
+                .map(|iv| ExportedInputValue {
+                    temp_id: iv.id,
+                    value: iv.value.clone(),
+                    a: iv.a,
+                    b: iv.b,
+                    c: iv.c,
+                    d: iv.d,
+                    is_triangle: iv.is_triangle,
+                    lower: iv.lower,
+                })
+                .collect(),
+        })
+        .collect();
+
+    // Outputs
+    let outputs = p
+        .output_parameters
+        .iter()
+        .map(|op| ExportedOutputParameter {
+            temp_id: op.id,
+            name: op.name.clone(),
+            start: op.start,
+            end: op.end,
+            partition_mode: op.partition_mode,
+            metadata: op.metadata.clone(),
+            values: op
+                .fuzzy_output_values
+                .iter()
+                .map(|fov| ExportedFuzzyOutputValue {
+                    temp_id: fov.id,
+                    value: fov.value.clone(),
+                    a: fov.a,
+                    b: fov.b,
+                    c: fov.c,
+                    d: fov.d,
+                    is_triangle: fov.is_triangle,
+                    lower: fov.lower,
+                })
+                .collect(),
         })
+        .collect();
+
+    // Rules
+    let rules = p
+        .output_values
+        .iter()
+        .map(|ov| ExportedOutputValue {
+            output_parameter_temp_id: ov.output_parameter_id,
+            fuzzy_output_value_temp_id: ov.fuzzy_output_value_id,
+            input_value_temp_ids: ov
+                .input_value_ids
+                .replace('|', ",")
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .filter_map(|s| s.parse::<i64>().ok())
+                .collect(),
+            antecedent_hedges: parse_antecedent_hedges(&ov.antecedent_hedges)
+                .into_iter()
+                .map(|(id, hedge)| ExportedAntecedentHedge {
+                    input_value_temp_id: id,
+                    hedge: hedge.as_str().to_string(),
+                })
+                .collect(),
+            consequent_hedge: ov.consequent_hedge.clone(),
+        })
+        .collect();
+
+    ExportedProblem {
+        name: p.name.clone(),
+        description: p.description.clone(),
+        is_final: p.is_final,
+        image: None,
+        input_parameters: inputs,
+        output_parameters: outputs,
+        output_values: rules,
+        test_cases,
+        children: vec![],
     }
 }
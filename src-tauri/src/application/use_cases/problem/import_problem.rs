@@ -4,15 +4,28 @@ use crate::domain::{
     entities::{
         fuzzy_output_value::FuzzyOutputValue, image::Image, input_parameter::InputParameter,
         input_value::InputValue, output_parameter::OutputParameter, output_value::OutputValue,
-        problem::Problem,
+        parameter_kind::ParameterKind, parameter_metadata::ParameterMetadata,
+        partition_mode::PartitionMode, problem::Problem,
+        test_case::{TestCase, TestCaseExpectation, TestCaseInput},
     },
-    error::DomainError,
+    error::{DomainError, EntityKind, FieldError},
     repository::{
         FuzzyOutputValueRepository, InputParameterRepository, InputValueRepository,
-        OutputParameterRepository, OutputValueRepository, ProblemRepository,
+        OutputParameterRepository, OutputValueRepository, ProblemRepository, TestCaseRepository,
+        UnitOfWork,
     },
+    services::hedge::{format_antecedent_hedges, Hedge},
 };
-use crate::infrastructure::tauri::dtos::export_import_dtos::*;
+use crate::application::exported_problem::*;
+
+/// Export temp ids mapped to the ids of the newly created rows
+#[derive(Default)]
+struct IdMaps {
+    input_params: HashMap<i64, i64>,
+    input_values: HashMap<i64, i64>,
+    output_params: HashMap<i64, i64>,
+    fuzzy_values: HashMap<i64, i64>,
+}
 
 pub struct ImportProblemUseCase<'a> {
     problem_repo: &'a dyn ProblemRepository,
@@ -21,9 +34,12 @@ pub struct ImportProblemUseCase<'a> {
     output_param_repo: &'a dyn OutputParameterRepository,
     fuzzy_output_value_repo: &'a dyn FuzzyOutputValueRepository,
     output_value_repo: &'a dyn OutputValueRepository,
+    test_case_repo: &'a dyn TestCaseRepository,
+    unit_of_work: &'a dyn UnitOfWork,
 }
 
 impl<'a> ImportProblemUseCase<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         problem_repo: &'a dyn ProblemRepository,
         input_param_repo: &'a dyn InputParameterRepository,
@@ -31,6 +47,8 @@ impl<'a> ImportProblemUseCase<'a> {
         output_param_repo: &'a dyn OutputParameterRepository,
         fuzzy_output_value_repo: &'a dyn FuzzyOutputValueRepository,
         output_value_repo: &'a dyn OutputValueRepository,
+        test_case_repo: &'a dyn TestCaseRepository,
+        unit_of_work: &'a dyn UnitOfWork,
     ) -> Self {
         Self {
             problem_repo,
@@ -39,35 +57,122 @@ impl<'a> ImportProblemUseCase<'a> {
             output_param_repo,
             fuzzy_output_value_repo,
             output_value_repo,
+            test_case_repo,
+            unit_of_work,
         }
     }
 
+    /// Imports `dto` with its children under `parent_id` and returns the id of the
+    /// imported problem. Either the whole tree is imported or, if any part of it is
+    /// invalid, nothing is.
     pub fn execute(
         &self,
         parent_id: Option<i64>,
         dto: ExportedProblem,
-    ) -> Result<(), DomainError> {
-        let mut map_input_values = HashMap::new();
-        let mut map_output_params = HashMap::new();
-        let mut map_fuzzy_values = HashMap::new();
-        
-        self.import_internal(
-            parent_id, 
-            dto, 
-            &mut map_input_values, 
-            &mut map_output_params, 
-            &mut map_fuzzy_values
-        )
+    ) -> Result<i64, DomainError> {
+        let mut maps = IdMaps::default();
+
+        self.unit_of_work
+            .run(|| self.import_internal(parent_id, dto, &mut maps))
+    }
+
+    /// Replaces parameters, terms, rules and test cases of an existing problem
+    /// with the ones in `dto`. Name and description are taken over as well;
+    /// image, `is_final` and `children` of `dto` are ignored.
+    ///
+    /// Parameters and terms whose `temp_id` is the id of one the problem already
+    /// has keep that id, and parameters their links to other problems. So do
+    /// rules with the same output parameter and terms as an existing one.
+    pub fn execute_into(&self, problem_id: i64, dto: ExportedProblem) -> Result<(), DomainError> {
+        self.unit_of_work.run(|| self.replace_internal(problem_id, dto))
+    }
+
+    fn replace_internal(&self, problem_id: i64, dto: ExportedProblem) -> Result<(), DomainError> {
+        let current = self.problem_repo.get_full_by_id(problem_id)?;
+        let mut maps = IdMaps::default();
+        let mut problem = Problem {
+            name: dto.name,
+            description: dto.description,
+            input_parameters: vec![],
+            output_parameters: vec![],
+            output_values: vec![],
+            ..current.clone()
+        };
+
+        // Parameters and terms the problem does not have yet are created first, so
+        // every row has its id when the whole definition is written in order below
+        for ip in dto.input_parameters {
+            let existing = current.input_parameters.iter().find(|p| p.id == ip.temp_id);
+            let mut model = input_parameter_model(problem_id, &ip);
+            model.id = match existing {
+                Some(p) => p.id,
+                None => self.input_param_repo.create_raw(problem_id, &model)?,
+            };
+            maps.input_params.insert(ip.temp_id, model.id);
+
+            for iv in &ip.values {
+                let mut value = input_value_model(model.id, iv);
+                value.id = match existing.and_then(|p| p.input_values.iter().find(|v| v.id == iv.temp_id)) {
+                    Some(v) => v.id,
+                    None => self.input_value_repo.create_raw(&value)?,
+                };
+                maps.input_values.insert(iv.temp_id, value.id);
+                model.input_values.push(value);
+            }
+            problem.input_parameters.push(model);
+        }
+
+        for op in dto.output_parameters {
+            let existing = current.output_parameters.iter().find(|p| p.id == op.temp_id);
+            let mut model = output_parameter_model(problem_id, &op);
+            model.id = match existing {
+                Some(p) => p.id,
+                None => self.output_param_repo.create_raw(problem_id, &model)?,
+            };
+            maps.output_params.insert(op.temp_id, model.id);
+
+            for fov in &op.values {
+                let mut value = fuzzy_output_value_model(model.id, fov);
+                value.id = match existing.and_then(|p| p.fuzzy_output_values.iter().find(|v| v.id == fov.temp_id)) {
+                    Some(v) => v.id,
+                    None => self.fuzzy_output_value_repo.create_raw(&value)?,
+                };
+                maps.fuzzy_values.insert(fov.temp_id, value.id);
+                model.fuzzy_output_values.push(value);
+            }
+            problem.output_parameters.push(model);
+        }
+
+        let mut new_rules = vec![];
+        for ov in &dto.output_values {
+            let rule = rule_model(ov, &maps)?;
+            let existing = current.output_values.iter().find(|r| {
+                r.output_parameter_id == rule.output_parameter_id
+                    && antecedent_ids(&r.input_value_ids) == antecedent_ids(&rule.input_value_ids)
+            });
+            match existing {
+                Some(r) => problem.output_values.push(OutputValue { id: r.id, ..rule }),
+                None => new_rules.push(rule),
+            }
+        }
+
+        self.problem_repo.replace_definition(&problem)?;
+        for rule in &new_rules {
+            self.output_value_repo.create(rule)?;
+        }
+
+        for test_case in self.test_case_repo.get_by_problem_id(problem_id)? {
+            self.test_case_repo.remove_by_id(test_case.id)?;
+        }
+        self.import_test_cases(problem_id, dto.test_cases, &maps)
     }
 
     fn import_internal(
         &self,
         parent_id: Option<i64>,
         dto: ExportedProblem,
-        map_input_values: &mut HashMap<i64, i64>,
-        map_output_params: &mut HashMap<i64, i64>,
-        map_fuzzy_values: &mut HashMap<i64, i64>,
-    ) -> Result<(), DomainError> {
+        maps: &mut IdMaps,
+    ) -> Result<i64, DomainError> {
         // 1. Create Problem
         // Model for create
         let image = if let Some(img_dto) = dto.image {
@@ -92,125 +197,307 @@ impl<'a> ImportProblemUseCase<'a> {
             input_parameters: vec![],
             output_parameters: vec![],
             output_values: vec![],
-            image: image,
         };
 
-        let (new_problem_id, _new_image_id) = self.problem_repo.create(&problem_model)?;
+        let (new_problem_id, _new_image_id) = self.problem_repo.create(&problem_model, image.as_ref())?;
 
         // Maps are passed in arguments
 
+        // 2-5. Parameters, terms, rules and test cases
+        self.import_definition(
+            new_problem_id,
+            dto.input_parameters,
+            dto.output_parameters,
+            dto.output_values,
+            dto.test_cases,
+            maps,
+        )?;
+
+        // 6. Recursion for children
+        for child in dto.children {
+            self.import_internal(Some(new_problem_id), child, maps)?;
+        }
+
+        Ok(new_problem_id)
+    }
+
+    fn import_definition(
+        &self,
+        new_problem_id: i64,
+        input_parameters: Vec<ExportedInputParameter>,
+        output_parameters: Vec<ExportedOutputParameter>,
+        output_values: Vec<ExportedOutputValue>,
+        test_cases: Vec<ExportedTestCase>,
+        maps: &mut IdMaps,
+    ) -> Result<(), DomainError> {
         // 2. Input Params
-        for ip in dto.input_parameters {
-            let p_model = InputParameter {
-                id: 0,
-                problem_id: new_problem_id,
-                name: ip.name,
-                start: ip.start,
-                end: ip.end,
-                input_values: vec![],
-            };
+        for ip in input_parameters {
+            let p_model = input_parameter_model(new_problem_id, &ip);
             let new_ip_id = self
                 .input_param_repo
                 .create_raw(new_problem_id, &p_model)?;
 
-            for iv in ip.values {
-                let v_model = InputValue {
-                    id: 0,
-                    input_parameter_id: new_ip_id,
-                    value: iv.value,
-                    a: iv.a,
-                    b: iv.b,
-                    c: iv.c,
-                    d: iv.d,
-                    is_triangle: iv.is_triangle,
-                };
-                let new_iv_id = self.input_value_repo.create_raw(&v_model)?;
-                map_input_values.insert(iv.temp_id, new_iv_id);
+            maps.input_params.insert(ip.temp_id, new_ip_id);
+
+            for iv in &ip.values {
+                let new_iv_id = self
+                    .input_value_repo
+                    .create_raw(&input_value_model(new_ip_id, iv))?;
+                maps.input_values.insert(iv.temp_id, new_iv_id);
             }
         }
 
         // 3. Output Params
-        for op in dto.output_parameters {
-             let p_model = OutputParameter {
-                id: 0,
-                problem_id: new_problem_id,
-                name: op.name,
-                start: op.start,
-                end: op.end,
-                fuzzy_output_values: vec![],
-            };
+        for op in output_parameters {
+            let p_model = output_parameter_model(new_problem_id, &op);
             let new_op_id = self
                 .output_param_repo
                 .create_raw(new_problem_id, &p_model)?;
-            
-            map_output_params.insert(op.temp_id, new_op_id);
-
-            for fov in op.values {
-                let v_model = FuzzyOutputValue {
-                    id: 0,
-                    output_parameter_id: new_op_id,
-                    value: fov.value,
-                    a: fov.a,
-                    b: fov.b,
-                    c: fov.c,
-                    d: fov.d,
-                    is_triangle: fov.is_triangle,
-                };
-                let new_fov_id = self.fuzzy_output_value_repo.create_raw(&v_model)?;
-                map_fuzzy_values.insert(fov.temp_id, new_fov_id);
+
+            maps.output_params.insert(op.temp_id, new_op_id);
+
+            for fov in &op.values {
+                let new_fov_id = self
+                    .fuzzy_output_value_repo
+                    .create_raw(&fuzzy_output_value_model(new_op_id, fov))?;
+                maps.fuzzy_values.insert(fov.temp_id, new_fov_id);
             }
         }
 
         // 4. Output Values (Rules)
-        for ov in dto.output_values {
-            // Reconstruct IDs
-            let new_op_id = *map_output_params.get(&ov.output_parameter_temp_id)
-                .ok_or(DomainError::Validation("Output Param ID mismatch in export".to_string()))?;
-
-            let new_fov_id = if let Some(fid) = ov.fuzzy_output_value_temp_id {
-                 Some(*map_fuzzy_values.get(&fid)
-                 .ok_or(DomainError::Validation("Fuzzy Value ID mismatch in export".to_string()))?)
-            } else { None };
-
-            let new_input_ids: Vec<String> = ov.input_value_temp_ids.iter().map(|old_id| {
-                 map_input_values.get(old_id)
-                    .map(|id| id.to_string())
-                    .ok_or(DomainError::Validation("Input Value ID mismatch in export".to_string()))
-            }).collect::<Result<Vec<String>, DomainError>>()?;
-
-            // Sort IDs numerically before stringifying to match frontend hash generation
-            let mut sorted_new_ids: Vec<i64> = new_input_ids
-                .iter()
-                .filter_map(|s| s.parse::<i64>().ok())
-                .collect();
-            sorted_new_ids.sort();
+        for ov in &output_values {
+            self.output_value_repo.create(&rule_model(ov, maps)?)?;
+        }
 
-            let mut input_value_ids_string = String::new();
-            for id in sorted_new_ids {
-                input_value_ids_string.push_str(&format!("|{}|", id));
-            }
-            
-            let model = OutputValue {
-                id: 0,
-                output_parameter_id: new_op_id,
-                fuzzy_output_value_id: new_fov_id,
-                input_value_ids: input_value_ids_string,
-            };
+        // 5. Test cases
+        self.import_test_cases(new_problem_id, test_cases, maps)
+    }
 
-            self.output_value_repo.create(&model)?;
-        }
+    fn import_test_cases(
+        &self,
+        problem_id: i64,
+        test_cases: Vec<ExportedTestCase>,
+        maps: &IdMaps,
+    ) -> Result<(), DomainError> {
+        for tc in test_cases {
+            let inputs = tc
+                .inputs
+                .iter()
+                .map(|i| {
+                    let input_value_id = match i.input_value_temp_id {
+                        Some(vid) => Some(*maps.input_values.get(&vid).ok_or_else(|| {
+                            dangling(EntityKind::TestCase, "input_value_temp_id", vid)
+                        })?),
+                        None => None,
+                    };
+                    maps.input_params
+                        .get(&i.input_parameter_temp_id)
+                        .map(|id| TestCaseInput {
+                            input_parameter_id: *id,
+                            crisp_value: i.crisp_value,
+                            input_value_id,
+                        })
+                        .ok_or_else(|| {
+                            dangling(
+                                EntityKind::TestCase,
+                                "input_parameter_temp_id",
+                                i.input_parameter_temp_id,
+                            )
+                        })
+                })
+                .collect::<Result<Vec<TestCaseInput>, DomainError>>()?;
 
-        // 5. Recursion for children
-        for child in dto.children {
-            self.import_internal(
-                Some(new_problem_id), 
-                child, 
-                map_input_values, 
-                map_output_params, 
-                map_fuzzy_values
-            )?;
+            let expectations = tc
+                .expectations
+                .iter()
+                .map(|e| {
+                    let output_parameter_id = *maps.output_params
+                        .get(&e.output_parameter_temp_id)
+                        .ok_or_else(|| {
+                            dangling(
+                                EntityKind::TestCase,
+                                "output_parameter_temp_id",
+                                e.output_parameter_temp_id,
+                            )
+                        })?;
+                    let expected_fuzzy_output_value_id = match e.expected_fuzzy_output_value_temp_id {
+                        Some(fid) => Some(*maps.fuzzy_values.get(&fid).ok_or_else(|| {
+                            let field = "expected_fuzzy_output_value_temp_id";
+                            dangling(EntityKind::TestCase, field, fid)
+                        })?),
+                        None => None,
+                    };
+                    Ok(TestCaseExpectation {
+                        output_parameter_id,
+                        expected_value: e.expected_value,
+                        tolerance: e.tolerance,
+                        expected_fuzzy_output_value_id,
+                    })
+                })
+                .collect::<Result<Vec<TestCaseExpectation>, DomainError>>()?;
+
+            self.test_case_repo.create(&TestCase {
+                id: 0,
+                problem_id,
+                name: tc.name,
+                inputs,
+                expectations,
+            })?;
         }
 
         Ok(())
     }
 }
+
+fn input_parameter_model(problem_id: i64, ip: &ExportedInputParameter) -> InputParameter {
+    InputParameter {
+        id: 0,
+        problem_id,
+        name: ip.name.clone(),
+        start: ip.start,
+        end: ip.end,
+        // Categories are never reshaped as a partition
+        partition_mode: match ip.kind {
+            ParameterKind::Continuous => ip.partition_mode,
+            ParameterKind::Categorical => PartitionMode::FreeForm,
+        },
+        kind: ip.kind,
+        metadata: ip.metadata.clone(),
+        input_values: vec![],
+    }
+}
+
+fn input_value_model(input_parameter_id: i64, iv: &ExportedInputValue) -> InputValue {
+    InputValue {
+        id: 0,
+        input_parameter_id,
+        value: iv.value.clone(),
+        a: iv.a,
+        b: iv.b,
+        c: iv.c,
+        d: iv.d,
+        is_triangle: iv.is_triangle,
+        lower: iv.lower,
+    }
+}
+
+fn output_parameter_model(problem_id: i64, op: &ExportedOutputParameter) -> OutputParameter {
+    OutputParameter {
+        id: 0,
+        problem_id,
+        name: op.name.clone(),
+        start: op.start,
+        end: op.end,
+        partition_mode: op.partition_mode,
+        metadata: ParameterMetadata {
+            default_value: None,
+            ..op.metadata.clone()
+        },
+        fuzzy_output_values: vec![],
+    }
+}
+
+fn fuzzy_output_value_model(output_parameter_id: i64, fov: &ExportedFuzzyOutputValue) -> FuzzyOutputValue {
+    FuzzyOutputValue {
+        id: 0,
+        output_parameter_id,
+        value: fov.value.clone(),
+        a: fov.a,
+        b: fov.b,
+        c: fov.c,
+        d: fov.d,
+        is_triangle: fov.is_triangle,
+        lower: fov.lower,
+    }
+}
+
+/// Rule of `ov` with its temp ids replaced by the ids in `maps`
+fn rule_model(ov: &ExportedOutputValue, maps: &IdMaps) -> Result<OutputValue, DomainError> {
+    // Reconstruct IDs
+    let new_op_id = *maps.output_params.get(&ov.output_parameter_temp_id).ok_or_else(|| {
+        dangling(EntityKind::OutputValue, "output_parameter_temp_id", ov.output_parameter_temp_id)
+    })?;
+
+    let new_fov_id = if let Some(fid) = ov.fuzzy_output_value_temp_id {
+         Some(*maps.fuzzy_values.get(&fid)
+         .ok_or_else(|| dangling(EntityKind::OutputValue, "fuzzy_output_value_temp_id", fid))?)
+    } else { None };
+
+    // Sort IDs numerically before stringifying to match frontend hash generation
+    let mut sorted_new_ids: Vec<i64> = ov.input_value_temp_ids.iter().map(|old_id| {
+         maps.input_values.get(old_id)
+            .copied()
+            .ok_or_else(|| dangling(EntityKind::OutputValue, "input_value_temp_ids", *old_id))
+    }).collect::<Result<Vec<i64>, DomainError>>()?;
+    sorted_new_ids.sort();
+
+    let mut input_value_ids_string = String::new();
+    for id in sorted_new_ids {
+        input_value_ids_string.push_str(&format!("|{}|", id));
+    }
+
+    let antecedent_hedges = ov
+        .antecedent_hedges
+        .iter()
+        .map(|h| {
+            let id = maps.input_values.get(&h.input_value_temp_id).ok_or_else(|| {
+                let field = "antecedent_hedges.input_value_temp_id";
+                dangling(EntityKind::OutputValue, field, h.input_value_temp_id)
+            })?;
+            let hedge = Hedge::from_name(&h.hedge).ok_or_else(|| {
+                DomainError::invalid_field(
+                    EntityKind::OutputValue,
+                    None,
+                    FieldError::new(
+                        "antecedent_hedges.hedge",
+                        format!("Unknown hedge '{}'", h.hedge),
+                    ),
+                )
+            })?;
+            Ok((*id, hedge))
+        })
+        .collect::<Result<Vec<_>, DomainError>>()?;
+    let consequent_hedge = match &ov.consequent_hedge {
+        Some(name) => Some(
+            Hedge::from_name(name)
+                .ok_or_else(|| {
+                    DomainError::invalid_field(
+                        EntityKind::OutputValue,
+                        None,
+                        FieldError::new("consequent_hedge", format!("Unknown hedge '{}'", name)),
+                    )
+                })?
+                .as_str()
+                .to_string(),
+        ),
+        None => None,
+    };
+
+    Ok(OutputValue {
+        id: 0,
+        output_parameter_id: new_op_id,
+        fuzzy_output_value_id: new_fov_id,
+        input_value_ids: input_value_ids_string,
+        antecedent_hedges: format_antecedent_hedges(&antecedent_hedges),
+        consequent_hedge,
+    })
+}
+
+/// Error for a temp id in `field` that refers to nothing in the imported problem
+fn dangling(entity: EntityKind, field: &str, temp_id: i64) -> DomainError {
+    DomainError::invalid_field(
+        entity,
+        None,
+        FieldError::new(field, format!("{} does not exist in this problem", temp_id)),
+    )
+}
+
+/// Term ids of a rule in the stored "|id1||id2|" form, sorted
+fn antecedent_ids(input_value_ids: &str) -> Vec<i64> {
+    let mut ids: Vec<i64> = input_value_ids
+        .split('|')
+        .filter_map(|id| id.parse::<i64>().ok())
+        .collect();
+    ids.sort();
+    ids
+}
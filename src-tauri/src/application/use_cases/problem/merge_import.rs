@@ -0,0 +1,100 @@
+use crate::{
+    application::{
+        exported_problem::{ExportedProblem, ExportedTestCase},
+        use_cases::{
+            problem::{
+                export_problem::{to_exported, ExportProblemUseCase},
+                import_problem::ImportProblemUseCase,
+            },
+            problem_snapshot::{create::CreateProblemSnapshotUseCase, diff::to_problem},
+        },
+    },
+    domain::{
+        error::DomainError,
+        repository::UnitOfWork,
+        services::{
+            problem_diff::{diff_problems, ProblemDiff},
+            problem_merge::{merge_problems, MergeConflict, ProblemMerge},
+        },
+    },
+};
+
+/// What a merge-import would change, and what it could not apply
+#[derive(Debug, Clone)]
+pub struct MergePreview {
+    pub diff: ProblemDiff,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// Use case for merging an exported problem into an existing one instead of
+/// importing it as a new problem. Parameters and terms are matched by name;
+/// see `merge_problems`. Children, image and test cases of the file are ignored.
+pub struct MergeImportUseCase<'a> {
+    export_problem: &'a ExportProblemUseCase<'a>,
+    import_problem: &'a ImportProblemUseCase<'a>,
+    create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+    unit_of_work: &'a dyn UnitOfWork,
+}
+
+impl<'a> MergeImportUseCase<'a> {
+    pub fn new(
+        export_problem: &'a ExportProblemUseCase<'a>,
+        import_problem: &'a ImportProblemUseCase<'a>,
+        create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
+        Self {
+            export_problem,
+            import_problem,
+            create_snapshot,
+            unit_of_work,
+        }
+    }
+
+    /// Computes the changes merging `dto` into problem `problem_id` would make, without applying them
+    pub fn preview(&self, problem_id: i64, dto: ExportedProblem) -> Result<MergePreview, DomainError> {
+        let (_, merge, diff) = self.merge(problem_id, dto)?;
+
+        Ok(MergePreview {
+            diff,
+            conflicts: merge.conflicts,
+        })
+    }
+
+    /// Merges `dto` into problem `problem_id`.
+    ///
+    /// The current state is snapshotted automatically first. Matched parameters,
+    /// terms and rules keep their ids, and parameters their links to other
+    /// problems; added ones get new ids. Test cases are kept.
+    pub fn execute(&self, problem_id: i64, dto: ExportedProblem) -> Result<MergePreview, DomainError> {
+        let (test_cases, merge, diff) = self.merge(problem_id, dto)?;
+        let merged = to_exported(&merge.problem, test_cases);
+
+        self.unit_of_work.run(|| {
+            self.create_snapshot
+                .execute_automatic(problem_id, "Before merge import")?;
+            self.import_problem.execute_into(problem_id, merged)
+        })?;
+
+        Ok(MergePreview {
+            diff,
+            conflicts: merge.conflicts,
+        })
+    }
+
+    fn merge(
+        &self,
+        problem_id: i64,
+        dto: ExportedProblem,
+    ) -> Result<(Vec<ExportedTestCase>, ProblemMerge, ProblemDiff), DomainError> {
+        let mut current = self.export_problem.execute_without_children(problem_id)?;
+        // Test cases refer to the current ids, which the merged definition keeps
+        let test_cases = std::mem::take(&mut current.test_cases);
+        let current = to_problem(current);
+
+        let merge = merge_problems(&current, &to_problem(dto));
+        let diff = diff_problems(&current, &merge.problem);
+
+        Ok((test_cases, merge, diff))
+    }
+}
@@ -1,8 +1,12 @@
+pub mod clone;
 pub mod create;
 pub mod get_all_by_prev_problem_id;
 pub mod get_full_by_id;
+pub mod move_problem;
 pub mod remove_by_id;
 pub mod update_by_id;
 pub mod export_problem;
 pub mod import_problem;
+pub mod merge_import;
+pub mod validate_import;
 
@@ -0,0 +1,56 @@
+use crate::domain::{
+    error::{DomainError, EntityKind, FieldError},
+    repository::ProblemRepository,
+};
+
+pub struct MoveProblemUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+}
+
+impl<'a> MoveProblemUseCase<'a> {
+    pub fn new(problem_repository: &'a dyn ProblemRepository) -> Self {
+        Self { problem_repository }
+    }
+
+    /// Moves problem `id` with its subtree under `parent_id` (the root when `None`),
+    /// at `position` among the siblings there or last. Moving within the same
+    /// parent only reorders the siblings.
+    pub fn execute(
+        &self,
+        id: i64,
+        parent_id: Option<i64>,
+        position: Option<usize>,
+    ) -> Result<(), DomainError> {
+        // Fails with NotFound for an unknown problem
+        self.problem_repository.is_final(id)?;
+
+        if let Some(parent_id) = parent_id {
+            if parent_id == id || self.problem_repository.get_ancestor_ids(parent_id)?.contains(&id) {
+                return Err(DomainError::invalid_field(
+                    EntityKind::Problem,
+                    Some(id),
+                    FieldError::new(
+                        "prev_problem_id",
+                        "A problem can not be moved into itself or one of its descendants",
+                    ),
+                ));
+            }
+
+            if self.problem_repository.is_final(parent_id)? {
+                return Err(DomainError::invalid_field(
+                    EntityKind::Problem,
+                    Some(id),
+                    FieldError::new(
+                        "prev_problem_id",
+                        format!(
+                            "Problem {} is final and can not contain other problems",
+                            parent_id
+                        ),
+                    ),
+                ));
+            }
+        }
+
+        self.problem_repository.move_by_id(id, parent_id, position)
+    }
+}
@@ -0,0 +1,721 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::domain::{
+    entities::{parameter_kind::ParameterKind, partition_mode::PartitionMode},
+    services::{
+        fuzzification::category_index,
+        fuzzy_partition::{check_partition, PARTITION_TOLERANCE},
+        hedge::Hedge,
+        interval_type2::validate_lower_membership,
+    },
+};
+use crate::application::exported_problem::*;
+
+/// Image formats the problem cards can display
+pub const SUPPORTED_IMAGE_FORMATS: [&str; 3] = ["png", "jpg", "jpeg"];
+
+/// Temp id, name and `(a, b, c, d)` of a term
+type TermRow<'a> = (i64, &'a str, (f32, f32, f32, f32));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportIssueSeverity {
+    /// The file can not be imported as is
+    Error,
+    /// The file can be imported, but the result will not behave as expected
+    Warning,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportIssue {
+    pub severity: ImportIssueSeverity,
+    /// JSON path of the offending value, e.g. `$.children[0].output_values[3]`
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportValidationReport {
+    pub issues: Vec<ImportIssue>,
+}
+
+impl ImportValidationReport {
+    pub fn has_errors(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|i| i.severity == ImportIssueSeverity::Error)
+    }
+
+    fn error(&mut self, path: String, message: String) {
+        self.issues.push(ImportIssue {
+            severity: ImportIssueSeverity::Error,
+            path,
+            message,
+        });
+    }
+
+    fn warning(&mut self, path: String, message: String) {
+        self.issues.push(ImportIssue {
+            severity: ImportIssueSeverity::Warning,
+            path,
+            message,
+        });
+    }
+}
+
+/// Temp ids seen so far in the whole tree; the importer maps them tree-wide
+#[derive(Default)]
+struct SeenIds {
+    input_params: HashMap<i64, String>,
+    input_values: HashMap<i64, String>,
+    output_params: HashMap<i64, String>,
+    fuzzy_values: HashMap<i64, String>,
+}
+
+/// Use case for checking an exported problem tree before importing it.
+///
+/// Nothing is read from or written to the database.
+#[derive(Default)]
+pub struct ValidateImportUseCase;
+
+impl ValidateImportUseCase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, dto: &ExportedProblem) -> ImportValidationReport {
+        let mut report = ImportValidationReport::default();
+        let mut seen = SeenIds::default();
+
+        self.validate_problem(dto, "$", &mut seen, &mut report);
+
+        report
+    }
+
+    fn validate_problem(
+        &self,
+        dto: &ExportedProblem,
+        path: &str,
+        seen: &mut SeenIds,
+        report: &mut ImportValidationReport,
+    ) {
+        if dto.name.trim().is_empty() {
+            report.error(format!("{}.name", path), "Name cannot be empty".to_string());
+        }
+
+        if let Some(image) = &dto.image {
+            if !SUPPORTED_IMAGE_FORMATS.contains(&image.format.to_lowercase().as_str()) {
+                report.error(
+                    format!("{}.image.format", path),
+                    format!(
+                        "Unsupported image format '{}', expected one of {}",
+                        image.format,
+                        SUPPORTED_IMAGE_FORMATS.join(", ")
+                    ),
+                );
+            }
+            if image.data.is_empty() {
+                report.warning(format!("{}.image.data", path), "Image is empty".to_string());
+            }
+        }
+
+        if dto.is_final && !dto.children.is_empty() {
+            report.warning(
+                format!("{}.children", path),
+                "A final problem should not contain other problems".to_string(),
+            );
+        }
+        if !dto.is_final
+            && (!dto.input_parameters.is_empty() || !dto.output_parameters.is_empty())
+        {
+            report.warning(
+                path.to_string(),
+                "A group of problems should not define parameters".to_string(),
+            );
+        }
+
+        // Parameters and terms, and which of them belong to this problem
+        let mut input_values_of_param = HashMap::<i64, HashSet<i64>>::new();
+        let mut param_of_input_value = HashMap::<i64, i64>::new();
+        for (i, ip) in dto.input_parameters.iter().enumerate() {
+            let ip_path = format!("{}.input_parameters[{}]", path, i);
+            check_unique(&mut seen.input_params, ip.temp_id, &ip_path, "input parameter", report);
+            check_parameter(&ip.name, ip.start, ip.end, &ip_path, report);
+            let categorical = ip.kind == ParameterKind::Categorical;
+            if categorical {
+                // The default value is a category index rather than a point of the range
+                if let Some(value) = ip.metadata.default_value {
+                    if category_index(value, ip.values.len()).is_none() {
+                        report.error(
+                            format!("{}.default_value", ip_path),
+                            format!("Default value {} is not a category index", value),
+                        );
+                    }
+                }
+            } else if let Err(error) = ip.metadata.check(ip.start, ip.end) {
+                report.error(format!("{}.{}", ip_path, error.field), error.message);
+            }
+
+            let terms: Vec<TermRow> = ip
+                .values
+                .iter()
+                .map(|v| (v.temp_id, v.value.as_str(), (v.a, v.b, v.c, v.d)))
+                .collect();
+            // Categories are imported free-form whatever the file says
+            let mode = if categorical { PartitionMode::FreeForm } else { ip.partition_mode };
+            check_terms(&terms, ip.start, ip.end, mode, &ip_path, &mut seen.input_values, report);
+            for (j, v) in ip.values.iter().enumerate() {
+                if let Some(lower) = &v.lower {
+                    if let Err(message) = validate_lower_membership((v.a, v.b, v.c, v.d), v.is_triangle, lower) {
+                        report.error(
+                            format!("{}.values[{}].lower", ip_path, j),
+                            format!("Term '{}': {}", v.value, message),
+                        );
+                    }
+                }
+            }
+
+            input_values_of_param.insert(ip.temp_id, ip.values.iter().map(|v| v.temp_id).collect());
+            for v in &ip.values {
+                param_of_input_value.insert(v.temp_id, ip.temp_id);
+            }
+        }
+
+        let mut fuzzy_values_of_param = HashMap::<i64, HashSet<i64>>::new();
+        for (i, op) in dto.output_parameters.iter().enumerate() {
+            let op_path = format!("{}.output_parameters[{}]", path, i);
+            check_unique(&mut seen.output_params, op.temp_id, &op_path, "output parameter", report);
+            check_parameter(&op.name, op.start, op.end, &op_path, report);
+            if let Err(error) = op.metadata.check(op.start, op.end) {
+                report.error(format!("{}.{}", op_path, error.field), error.message);
+            }
+            if op.metadata.default_value.is_some() {
+                report.warning(
+                    format!("{}.default_value", op_path),
+                    "Output parameters have no default value; it will be ignored".to_string(),
+                );
+            }
+
+            let terms: Vec<TermRow> = op
+                .values
+                .iter()
+                .map(|v| (v.temp_id, v.value.as_str(), (v.a, v.b, v.c, v.d)))
+                .collect();
+            check_terms(&terms, op.start, op.end, op.partition_mode, &op_path, &mut seen.fuzzy_values, report);
+            for (j, v) in op.values.iter().enumerate() {
+                if let Some(lower) = &v.lower {
+                    if let Err(message) = validate_lower_membership((v.a, v.b, v.c, v.d), v.is_triangle, lower) {
+                        report.error(
+                            format!("{}.values[{}].lower", op_path, j),
+                            format!("Term '{}': {}", v.value, message),
+                        );
+                    }
+                }
+            }
+
+            fuzzy_values_of_param.insert(op.temp_id, op.values.iter().map(|v| v.temp_id).collect());
+        }
+
+        // Rules
+        let mut combinations = HashMap::<i64, HashSet<BTreeSet<i64>>>::new();
+        for (i, ov) in dto.output_values.iter().enumerate() {
+            let ov_path = format!("{}.output_values[{}]", path, i);
+
+            let Some(fuzzy_values) = fuzzy_values_of_param.get(&ov.output_parameter_temp_id) else {
+                report.error(
+                    format!("{}.output_parameter_temp_id", ov_path),
+                    format!(
+                        "Output parameter {} does not exist in this problem",
+                        ov.output_parameter_temp_id
+                    ),
+                );
+                continue;
+            };
+
+            if let Some(fid) = ov.fuzzy_output_value_temp_id {
+                if !fuzzy_values.contains(&fid) {
+                    report.error(
+                        format!("{}.fuzzy_output_value_temp_id", ov_path),
+                        format!(
+                            "Term {} is not a term of output parameter {}",
+                            fid, ov.output_parameter_temp_id
+                        ),
+                    );
+                }
+            }
+
+            let mut covered = HashMap::<i64, usize>::new();
+            let mut is_well_formed = true;
+            for (j, iv_id) in ov.input_value_temp_ids.iter().enumerate() {
+                match param_of_input_value.get(iv_id) {
+                    Some(param_id) => {
+                        if let Some(first) = covered.insert(*param_id, j) {
+                            report.error(
+                                format!("{}.input_value_temp_ids[{}]", ov_path, j),
+                                format!(
+                                    "Input parameter {} already has a term in this rule at index {}",
+                                    param_id, first
+                                ),
+                            );
+                            is_well_formed = false;
+                        }
+                    }
+                    None => {
+                        report.error(
+                            format!("{}.input_value_temp_ids[{}]", ov_path, j),
+                            format!("Input term {} does not exist in this problem", iv_id),
+                        );
+                        is_well_formed = false;
+                    }
+                }
+            }
+            for (j, h) in ov.antecedent_hedges.iter().enumerate() {
+                if !ov.input_value_temp_ids.contains(&h.input_value_temp_id) {
+                    report.error(
+                        format!("{}.antecedent_hedges[{}].input_value_temp_id", ov_path, j),
+                        format!("Input term {} is not an antecedent of this rule", h.input_value_temp_id),
+                    );
+                }
+                if Hedge::from_name(&h.hedge).is_none() {
+                    report.error(
+                        format!("{}.antecedent_hedges[{}].hedge", ov_path, j),
+                        format!("Unknown hedge '{}'", h.hedge),
+                    );
+                }
+            }
+            if let Some(name) = &ov.consequent_hedge {
+                if Hedge::from_name(name).is_none() {
+                    report.error(
+                        format!("{}.consequent_hedge", ov_path),
+                        format!("Unknown hedge '{}'", name),
+                    );
+                }
+            }
+            for (k, ip) in dto.input_parameters.iter().enumerate() {
+                if !ip.values.is_empty() && !covered.contains_key(&ip.temp_id) {
+                    report.error(
+                        format!("{}.input_value_temp_ids", ov_path),
+                        format!(
+                            "Rule has no term of input parameter '{}' ({}.input_parameters[{}])",
+                            ip.name, path, k
+                        ),
+                    );
+                    is_well_formed = false;
+                }
+            }
+
+            if is_well_formed {
+                let combination: BTreeSet<i64> = ov.input_value_temp_ids.iter().copied().collect();
+                if !combinations
+                    .entry(ov.output_parameter_temp_id)
+                    .or_default()
+                    .insert(combination)
+                {
+                    report.warning(
+                        ov_path,
+                        "Another rule of the same output parameter has the same antecedents"
+                            .to_string(),
+                    );
+                }
+            }
+        }
+
+        // The rule table normally holds every combination of input terms
+        if dto.is_final && !dto.input_parameters.is_empty() {
+            let expected: usize = dto.input_parameters.iter().map(|ip| ip.values.len()).product();
+            for (i, op) in dto.output_parameters.iter().enumerate() {
+                let found = combinations.get(&op.temp_id).map_or(0, |c| c.len());
+                if found < expected {
+                    report.warning(
+                        format!("{}.output_parameters[{}]", path, i),
+                        format!(
+                            "Only {} of {} input term combinations have a rule for '{}'",
+                            found, expected, op.name
+                        ),
+                    );
+                }
+            }
+        }
+
+        // Test cases
+        for (i, tc) in dto.test_cases.iter().enumerate() {
+            let tc_path = format!("{}.test_cases[{}]", path, i);
+            for (j, input) in tc.inputs.iter().enumerate() {
+                match input_values_of_param.get(&input.input_parameter_temp_id) {
+                    Some(input_values) => {
+                        if let Some(vid) = input.input_value_temp_id {
+                            if !input_values.contains(&vid) {
+                                report.error(
+                                    format!("{}.inputs[{}].input_value_temp_id", tc_path, j),
+                                    format!(
+                                        "Term {} is not a term of input parameter {}",
+                                        vid, input.input_parameter_temp_id
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                    None => report.error(
+                        format!("{}.inputs[{}].input_parameter_temp_id", tc_path, j),
+                        format!(
+                            "Input parameter {} does not exist in this problem",
+                            input.input_parameter_temp_id
+                        ),
+                    ),
+                }
+            }
+            for (j, expectation) in tc.expectations.iter().enumerate() {
+                let e_path = format!("{}.expectations[{}]", tc_path, j);
+                match fuzzy_values_of_param.get(&expectation.output_parameter_temp_id) {
+                    Some(fuzzy_values) => {
+                        if let Some(fid) = expectation.expected_fuzzy_output_value_temp_id {
+                            if !fuzzy_values.contains(&fid) {
+                                report.error(
+                                    format!("{}.expected_fuzzy_output_value_temp_id", e_path),
+                                    format!(
+                                        "Term {} is not a term of output parameter {}",
+                                        fid, expectation.output_parameter_temp_id
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                    None => report.error(
+                        format!("{}.output_parameter_temp_id", e_path),
+                        format!(
+                            "Output parameter {} does not exist in this problem",
+                            expectation.output_parameter_temp_id
+                        ),
+                    ),
+                }
+            }
+        }
+
+        for (i, child) in dto.children.iter().enumerate() {
+            self.validate_problem(child, &format!("{}.children[{}]", path, i), seen, report);
+        }
+    }
+}
+
+fn check_unique(
+    seen: &mut HashMap<i64, String>,
+    temp_id: i64,
+    path: &str,
+    kind: &str,
+    report: &mut ImportValidationReport,
+) {
+    if let Some(first) = seen.get(&temp_id) {
+        report.error(
+            format!("{}.temp_id", path),
+            format!("Duplicate {} temp id {}, first used at {}", kind, temp_id, first),
+        );
+    } else {
+        seen.insert(temp_id, path.to_string());
+    }
+}
+
+fn check_parameter(name: &str, start: f32, end: f32, path: &str, report: &mut ImportValidationReport) {
+    if name.trim().is_empty() {
+        report.warning(format!("{}.name", path), "Parameter name is empty".to_string());
+    }
+    if start.is_nan() || end.is_nan() || start >= end {
+        report.error(
+            path.to_string(),
+            format!("Start ({}) must be less than end ({})", start, end),
+        );
+    }
+}
+
+/// Checks temp ids, ranges and the Ruspini partition of the terms of one parameter;
+/// free-form terms only need ordered breakpoints
+fn check_terms(
+    terms: &[TermRow],
+    start: f32,
+    end: f32,
+    mode: PartitionMode,
+    path: &str,
+    seen: &mut HashMap<i64, String>,
+    report: &mut ImportValidationReport,
+) {
+    if terms.is_empty() {
+        report.warning(format!("{}.values", path), "Parameter has no terms".to_string());
+        return;
+    }
+
+    for (i, (temp_id, name, (a, b, c, d))) in terms.iter().enumerate() {
+        let term_path = format!("{}.values[{}]", path, i);
+        check_unique(seen, *temp_id, &term_path, "term", report);
+        if name.trim().is_empty() {
+            report.warning(format!("{}.value", term_path), "Term name is empty".to_string());
+        }
+        if !(a <= b && b <= c && c <= d) {
+            report.error(
+                term_path.clone(),
+                format!(
+                    "Term '{}' ({}, {}, {}, {}) must satisfy a <= b <= c <= d",
+                    name, a, b, c, d
+                ),
+            );
+        }
+        if [a, b, c, d].iter().any(|x| **x < start || **x > end) {
+            report.warning(
+                term_path,
+                format!(
+                    "Term '{}' ({}, {}, {}, {}) lies outside of the parameter range [{}, {}]",
+                    name, a, b, c, d, start, end
+                ),
+            );
+        }
+    }
+
+    if mode == PartitionMode::FreeForm {
+        return;
+    }
+    let shapes: Vec<_> = terms.iter().map(|(_, _, shape)| *shape).collect();
+    if let Err(message) = check_partition(&shapes, start, end, PARTITION_TOLERANCE) {
+        report.warning(
+            format!("{}.values", path),
+            format!("Terms do not form a fuzzy partition: {}", message),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input_value(temp_id: i64, value: &str, shape: (f32, f32, f32, f32)) -> ExportedInputValue {
+        ExportedInputValue {
+            temp_id,
+            value: value.to_string(),
+            a: shape.0,
+            b: shape.1,
+            c: shape.2,
+            d: shape.3,
+            is_triangle: false,
+            lower: None,
+        }
+    }
+
+    fn fuzzy_value(temp_id: i64, value: &str, shape: (f32, f32, f32, f32)) -> ExportedFuzzyOutputValue {
+        ExportedFuzzyOutputValue {
+            temp_id,
+            value: value.to_string(),
+            a: shape.0,
+            b: shape.1,
+            c: shape.2,
+            d: shape.3,
+            is_triangle: false,
+            lower: None,
+        }
+    }
+
+    fn rule(output_parameter: i64, fuzzy_value: i64, input_values: Vec<i64>) -> ExportedOutputValue {
+        ExportedOutputValue {
+            output_parameter_temp_id: output_parameter,
+            fuzzy_output_value_temp_id: Some(fuzzy_value),
+            input_value_temp_ids: input_values,
+            antecedent_hedges: vec![],
+            consequent_hedge: None,
+        }
+    }
+
+    /// One input and one output parameter, two terms each partitioning [0, 10], and a full rule table
+    fn problem() -> ExportedProblem {
+        ExportedProblem {
+            name: "Problem".to_string(),
+            description: String::new(),
+            is_final: true,
+            image: None,
+            input_parameters: vec![ExportedInputParameter {
+                temp_id: 1,
+                name: "x".to_string(),
+                start: 0.0,
+                end: 10.0,
+                partition_mode: PartitionMode::Ruspini,
+                kind: ParameterKind::default(),
+                metadata: Default::default(),
+                values: vec![
+                    input_value(11, "low", (0.0, 0.0, 4.0, 6.0)),
+                    input_value(12, "high", (4.0, 6.0, 10.0, 10.0)),
+                ],
+            }],
+            output_parameters: vec![ExportedOutputParameter {
+                temp_id: 2,
+                name: "y".to_string(),
+                start: 0.0,
+                end: 10.0,
+                partition_mode: PartitionMode::Ruspini,
+                metadata: Default::default(),
+                values: vec![
+                    fuzzy_value(21, "small", (0.0, 0.0, 4.0, 6.0)),
+                    fuzzy_value(22, "large", (4.0, 6.0, 10.0, 10.0)),
+                ],
+            }],
+            output_values: vec![rule(2, 21, vec![11]), rule(2, 22, vec![12])],
+            test_cases: vec![],
+            children: vec![],
+        }
+    }
+
+    fn paths(report: &ImportValidationReport, severity: ImportIssueSeverity) -> Vec<&str> {
+        report
+            .issues
+            .iter()
+            .filter(|i| i.severity == severity)
+            .map(|i| i.path.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_problem_has_no_issues() {
+        let report = ValidateImportUseCase::new().execute(&problem());
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_duplicate_temp_ids_are_errors_across_the_tree() {
+        // Temp ids are mapped tree-wide, so sibling problems can not reuse them either
+        let mut first = problem();
+        first.input_parameters[0].values[1].temp_id = 11;
+        let dto = ExportedProblem {
+            name: "Group".to_string(),
+            is_final: false,
+            input_parameters: vec![],
+            output_parameters: vec![],
+            output_values: vec![],
+            children: vec![first, problem()],
+            ..problem()
+        };
+
+        let report = ValidateImportUseCase::new().execute(&dto);
+        let errors = paths(&report, ImportIssueSeverity::Error);
+
+        assert!(errors.contains(&"$.children[0].input_parameters[0].values[1].temp_id"));
+        assert!(errors.contains(&"$.children[1].input_parameters[0].temp_id"));
+        assert!(errors.contains(&"$.children[1].output_parameters[0].temp_id"));
+        let duplicate = report
+            .issues
+            .iter()
+            .find(|i| i.path == "$.children[1].input_parameters[0].temp_id")
+            .unwrap();
+        assert_eq!(
+            duplicate.message,
+            "Duplicate input parameter temp id 1, first used at $.children[0].input_parameters[0]"
+        );
+    }
+
+    #[test]
+    fn test_dangling_rule_references_are_errors() {
+        let mut dto = problem();
+        dto.output_values[0].input_value_temp_ids = vec![99];
+        dto.output_values[1].fuzzy_output_value_temp_id = Some(98);
+        dto.output_values.push(rule(97, 21, vec![11]));
+
+        let report = ValidateImportUseCase::new().execute(&dto);
+        let errors = paths(&report, ImportIssueSeverity::Error);
+
+        assert_eq!(
+            errors,
+            vec![
+                "$.output_values[0].input_value_temp_ids[0]",
+                "$.output_values[0].input_value_temp_ids",
+                "$.output_values[1].fuzzy_output_value_temp_id",
+                "$.output_values[2].output_parameter_temp_id",
+            ]
+        );
+        assert_eq!(report.issues[0].message, "Input term 99 does not exist in this problem");
+    }
+
+    #[test]
+    fn test_dangling_hedge_and_test_case_references_are_errors() {
+        let mut dto = problem();
+        dto.output_values[0].antecedent_hedges.push(ExportedAntecedentHedge {
+            input_value_temp_id: 12,
+            hedge: "very".to_string(),
+        });
+        dto.test_cases.push(ExportedTestCase {
+            name: "case".to_string(),
+            inputs: vec![
+                ExportedTestCaseInput {
+                    input_parameter_temp_id: 5,
+                    crisp_value: 1.0,
+                    input_value_temp_id: None,
+                },
+                ExportedTestCaseInput {
+                    input_parameter_temp_id: 1,
+                    crisp_value: 0.0,
+                    input_value_temp_id: Some(21),
+                },
+            ],
+            expectations: vec![ExportedTestCaseExpectation {
+                output_parameter_temp_id: 2,
+                expected_value: None,
+                tolerance: 0.0,
+                expected_fuzzy_output_value_temp_id: Some(11),
+            }],
+        });
+
+        let report = ValidateImportUseCase::new().execute(&dto);
+
+        assert_eq!(
+            paths(&report, ImportIssueSeverity::Error),
+            vec![
+                "$.output_values[0].antecedent_hedges[0].input_value_temp_id",
+                "$.test_cases[0].inputs[0].input_parameter_temp_id",
+                "$.test_cases[0].inputs[1].input_value_temp_id",
+                "$.test_cases[0].expectations[0].expected_fuzzy_output_value_temp_id",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unordered_term_is_an_error() {
+        let mut dto = problem();
+        dto.input_parameters[0].values[0] = input_value(11, "low", (0.0, 5.0, 4.0, 6.0));
+
+        let report = ValidateImportUseCase::new().execute(&dto);
+
+        assert!(report.has_errors());
+        assert!(paths(&report, ImportIssueSeverity::Error).contains(&"$.input_parameters[0].values[0]"));
+    }
+
+    #[test]
+    fn test_broken_partition_is_only_a_warning() {
+        let mut dto = problem();
+        dto.output_parameters[0].values[1] = fuzzy_value(22, "large", (7.0, 8.0, 10.0, 10.0));
+
+        let report = ValidateImportUseCase::new().execute(&dto);
+
+        assert!(!report.has_errors());
+        assert_eq!(
+            paths(&report, ImportIssueSeverity::Warning),
+            vec!["$.output_parameters[0].values"]
+        );
+    }
+
+    #[test]
+    fn test_free_form_terms_need_no_partition() {
+        let mut dto = problem();
+        dto.output_parameters[0].partition_mode = PartitionMode::FreeForm;
+        dto.output_parameters[0].values[1] = fuzzy_value(22, "large", (7.0, 8.0, 10.0, 10.0));
+
+        let report = ValidateImportUseCase::new().execute(&dto);
+
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_out_of_range_term_is_a_warning() {
+        let mut dto = problem();
+        dto.input_parameters[0].partition_mode = PartitionMode::FreeForm;
+        dto.input_parameters[0].values[1] = input_value(12, "high", (4.0, 6.0, 10.0, 12.0));
+
+        let report = ValidateImportUseCase::new().execute(&dto);
+
+        assert!(!report.has_errors());
+        assert_eq!(
+            paths(&report, ImportIssueSeverity::Warning),
+            vec!["$.input_parameters[0].values[1]"]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    application::use_cases::problem::export_problem::ExportProblemUseCase,
+    domain::{
+        entities::problem_snapshot::ProblemSnapshot,
+        error::{DomainError, EntityKind, FieldError},
+        repository::ProblemSnapshotRepository,
+    },
+};
+
+/// Number of automatic snapshots kept per problem; manual snapshots are never pruned
+pub const AUTOMATIC_SNAPSHOT_LIMIT: usize = 20;
+
+pub struct CreateProblemSnapshotUseCase<'a> {
+    export_problem: &'a ExportProblemUseCase<'a>,
+    problem_snapshot_repository: &'a dyn ProblemSnapshotRepository,
+}
+
+impl<'a> CreateProblemSnapshotUseCase<'a> {
+    pub fn new(
+        export_problem: &'a ExportProblemUseCase<'a>,
+        problem_snapshot_repository: &'a dyn ProblemSnapshotRepository,
+    ) -> Self {
+        Self {
+            export_problem,
+            problem_snapshot_repository,
+        }
+    }
+
+    /// Saves a named snapshot requested by the user
+    pub fn execute(&self, problem_id: i64, name: &str) -> Result<i64, DomainError> {
+        if name.trim().is_empty() {
+            return Err(DomainError::invalid_field(
+                EntityKind::ProblemSnapshot,
+                None,
+                FieldError::new("name", "Snapshot name must not be empty"),
+            ));
+        }
+        self.create(problem_id, name, false)
+    }
+
+    /// Saves a snapshot before a destructive operation described by `reason`
+    /// and drops the oldest automatic snapshots above `AUTOMATIC_SNAPSHOT_LIMIT`
+    pub fn execute_automatic(&self, problem_id: i64, reason: &str) -> Result<i64, DomainError> {
+        let id = self.create(problem_id, reason, true)?;
+        self.problem_snapshot_repository
+            .prune_automatic(problem_id, AUTOMATIC_SNAPSHOT_LIMIT)?;
+        Ok(id)
+    }
+
+    fn create(&self, problem_id: i64, name: &str, is_automatic: bool) -> Result<i64, DomainError> {
+        // Sub-problems have their own snapshots; the image is not versioned
+        let mut definition = self.export_problem.execute_without_children(problem_id)?;
+        definition.image = None;
+
+        let definition =
+            serde_json::to_string(&definition).map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        self.problem_snapshot_repository.create(&ProblemSnapshot {
+            id: 0,
+            problem_id,
+            name: name.to_string(),
+            is_automatic,
+            definition,
+            created_at: String::new(),
+        })
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{
+    application::{
+        exported_problem::ExportedProblem, use_cases::problem::export_problem::ExportProblemUseCase,
+    },
+    domain::{
+        entities::{
+            fuzzy_output_value::FuzzyOutputValue, input_parameter::InputParameter,
+            input_value::InputValue, output_parameter::OutputParameter, output_value::OutputValue,
+            parameter_metadata::ParameterMetadata, problem::Problem,
+        },
+        error::DomainError,
+        repository::ProblemSnapshotRepository,
+        services::{
+            hedge::{format_antecedent_hedges, Hedge},
+            problem_diff::{diff_problems, ProblemDiff},
+        },
+    },
+};
+
+pub struct DiffProblemSnapshotsUseCase<'a> {
+    problem_snapshot_repository: &'a dyn ProblemSnapshotRepository,
+    export_problem: &'a ExportProblemUseCase<'a>,
+}
+
+impl<'a> DiffProblemSnapshotsUseCase<'a> {
+    pub fn new(
+        problem_snapshot_repository: &'a dyn ProblemSnapshotRepository,
+        export_problem: &'a ExportProblemUseCase<'a>,
+    ) -> Self {
+        Self {
+            problem_snapshot_repository,
+            export_problem,
+        }
+    }
+
+    /// Compares snapshot `old_id` with snapshot `new_id`, or with the current
+    /// definition of the same problem when `new_id` is `None`
+    pub fn execute(&self, old_id: i64, new_id: Option<i64>) -> Result<ProblemDiff, DomainError> {
+        let old_snapshot = self.problem_snapshot_repository.get_by_id(old_id)?;
+        let old = self.parse(&old_snapshot.definition)?;
+
+        let new = match new_id {
+            Some(new_id) => {
+                let new_snapshot = self.problem_snapshot_repository.get_by_id(new_id)?;
+                self.parse(&new_snapshot.definition)?
+            }
+            None => self
+                .export_problem
+                .execute_without_children(old_snapshot.problem_id)?,
+        };
+
+        Ok(diff_problems(&to_problem(old), &to_problem(new)))
+    }
+
+    fn parse(&self, definition: &str) -> Result<ExportedProblem, DomainError> {
+        serde_json::from_str(definition).map_err(|e| DomainError::Data(e.to_string()))
+    }
+}
+
+/// Rebuilds a problem entity from its exported form, using temp ids as ids
+pub(crate) fn to_problem(dto: ExportedProblem) -> Problem {
+    Problem {
+        id: 0,
+        prev_problem_id: None,
+        is_final: dto.is_final,
+        name: dto.name,
+        description: dto.description,
+        image_id: None,
+        created_at: String::new(),
+        updated_at: None,
+        input_parameters: dto
+            .input_parameters
+            .into_iter()
+            .map(|ip| InputParameter {
+                id: ip.temp_id,
+                problem_id: 0,
+                name: ip.name,
+                start: ip.start,
+                end: ip.end,
+                partition_mode: ip.partition_mode,
+                kind: ip.kind,
+                metadata: ip.metadata,
+                input_values: ip
+                    .values
+                    .into_iter()
+                    .map(|iv| InputValue {
+                        id: iv.temp_id,
+                        input_parameter_id: ip.temp_id,
+                        value: iv.value,
+                        a: iv.a,
+                        b: iv.b,
+                        c: iv.c,
+                        d: iv.d,
+                        is_triangle: iv.is_triangle,
+                        lower: iv.lower,
+                    })
+                    .collect(),
+            })
+            .collect(),
+        output_parameters: dto
+            .output_parameters
+            .into_iter()
+            .map(|op| OutputParameter {
+                id: op.temp_id,
+                problem_id: 0,
+                name: op.name,
+                start: op.start,
+                end: op.end,
+                partition_mode: op.partition_mode,
+                metadata: ParameterMetadata {
+                    default_value: None,
+                    ..op.metadata
+                },
+                fuzzy_output_values: op
+                    .values
+                    .into_iter()
+                    .map(|fov| FuzzyOutputValue {
+                        id: fov.temp_id,
+                        output_parameter_id: op.temp_id,
+                        value: fov.value,
+                        a: fov.a,
+                        b: fov.b,
+                        c: fov.c,
+                        d: fov.d,
+                        is_triangle: fov.is_triangle,
+                        lower: fov.lower,
+                    })
+                    .collect(),
+            })
+            .collect(),
+        output_values: dto
+            .output_values
+            .into_iter()
+            .enumerate()
+            .map(|(i, ov)| OutputValue {
+                id: i as i64,
+                output_parameter_id: ov.output_parameter_temp_id,
+                fuzzy_output_value_id: ov.fuzzy_output_value_temp_id,
+                input_value_ids: ov
+                    .input_value_temp_ids
+                    .iter()
+                    .map(|id| format!("|{}|", id))
+                    .collect(),
+                antecedent_hedges: format_antecedent_hedges(
+                    &ov.antecedent_hedges
+                        .iter()
+                        .filter_map(|h| Some((h.input_value_temp_id, Hedge::from_name(&h.hedge)?)))
+                        .collect::<Vec<_>>(),
+                ),
+                consequent_hedge: ov.consequent_hedge,
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,22 @@
+use crate::domain::{
+    entities::problem_snapshot::ProblemSnapshot, error::DomainError,
+    repository::ProblemSnapshotRepository,
+};
+
+pub struct GetProblemSnapshotsByProblemIdUseCase<'a> {
+    problem_snapshot_repository: &'a dyn ProblemSnapshotRepository,
+}
+
+impl<'a> GetProblemSnapshotsByProblemIdUseCase<'a> {
+    pub fn new(problem_snapshot_repository: &'a dyn ProblemSnapshotRepository) -> Self {
+        Self {
+            problem_snapshot_repository,
+        }
+    }
+
+    /// Returns the snapshots of a problem, newest first
+    pub fn execute(&self, problem_id: i64) -> Result<Vec<ProblemSnapshot>, DomainError> {
+        self.problem_snapshot_repository
+            .get_by_problem_id(problem_id)
+    }
+}
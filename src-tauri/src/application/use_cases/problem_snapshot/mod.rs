@@ -0,0 +1,5 @@
+pub mod create;
+pub mod diff;
+pub mod get_by_problem_id;
+pub mod remove_by_id;
+pub mod restore;
@@ -0,0 +1,17 @@
+use crate::domain::{error::DomainError, repository::ProblemSnapshotRepository};
+
+pub struct RemoveProblemSnapshotByIdUseCase<'a> {
+    problem_snapshot_repository: &'a dyn ProblemSnapshotRepository,
+}
+
+impl<'a> RemoveProblemSnapshotByIdUseCase<'a> {
+    pub fn new(problem_snapshot_repository: &'a dyn ProblemSnapshotRepository) -> Self {
+        Self {
+            problem_snapshot_repository,
+        }
+    }
+
+    pub fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.problem_snapshot_repository.remove_by_id(id)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    application::{
+        exported_problem::ExportedProblem,
+        use_cases::{
+            problem::import_problem::ImportProblemUseCase,
+            problem_snapshot::create::CreateProblemSnapshotUseCase,
+        },
+    },
+    domain::{
+        error::DomainError,
+        repository::{ProblemSnapshotRepository, UnitOfWork},
+    },
+};
+
+pub struct RestoreProblemSnapshotUseCase<'a> {
+    problem_snapshot_repository: &'a dyn ProblemSnapshotRepository,
+    create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+    import_problem: &'a ImportProblemUseCase<'a>,
+    unit_of_work: &'a dyn UnitOfWork,
+}
+
+impl<'a> RestoreProblemSnapshotUseCase<'a> {
+    pub fn new(
+        problem_snapshot_repository: &'a dyn ProblemSnapshotRepository,
+        create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+        import_problem: &'a ImportProblemUseCase<'a>,
+        unit_of_work: &'a dyn UnitOfWork,
+    ) -> Self {
+        Self {
+            problem_snapshot_repository,
+            create_snapshot,
+            import_problem,
+            unit_of_work,
+        }
+    }
+
+    /// Replaces the current definition of the snapshot's problem with the saved one.
+    ///
+    /// The current state is snapshotted automatically first, so a restore can itself be undone.
+    /// Parameters, terms and rules the problem still has keep their ids and links;
+    /// removed ones are created again under new ids.
+    pub fn execute(&self, id: i64) -> Result<(), DomainError> {
+        let snapshot = self.problem_snapshot_repository.get_by_id(id)?;
+        let definition: ExportedProblem = serde_json::from_str(&snapshot.definition)
+            .map_err(|e| DomainError::Data(e.to_string()))?;
+
+        self.unit_of_work.run(|| {
+            self.create_snapshot.execute_automatic(
+                snapshot.problem_id,
+                &format!("Before restoring '{}'", snapshot.name),
+            )?;
+
+            self.import_problem
+                .execute_into(snapshot.problem_id, definition)
+        })
+    }
+}
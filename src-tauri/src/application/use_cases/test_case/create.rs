@@ -0,0 +1,219 @@
+use crate::domain::{
+    entities::{problem::Problem, test_case::TestCase},
+    error::{DomainError, EntityKind, FieldError},
+    repository::{ProblemRepository, TestCaseRepository},
+};
+
+pub struct CreateTestCaseUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+    test_case_repository: &'a dyn TestCaseRepository,
+}
+
+impl<'a> CreateTestCaseUseCase<'a> {
+    pub fn new(
+        problem_repository: &'a dyn ProblemRepository,
+        test_case_repository: &'a dyn TestCaseRepository,
+    ) -> Self {
+        Self {
+            problem_repository,
+            test_case_repository,
+        }
+    }
+
+    pub fn execute(&self, test_case: &TestCase) -> Result<i64, DomainError> {
+        let problem = self.problem_repository.get_full_by_id(test_case.problem_id)?;
+        validate_test_case(None, test_case, &problem)?;
+        self.test_case_repository.create(test_case)
+    }
+}
+
+/// Checks every field of a test case of `problem`, including that the parameters
+/// and terms it refers to belong to it; `id` is `None` for a test case being created
+pub(super) fn validate_test_case(
+    id: Option<i64>,
+    test_case: &TestCase,
+    problem: &Problem,
+) -> Result<(), DomainError> {
+    let mut fields = Vec::new();
+    if test_case.name.trim().is_empty() {
+        fields.push(FieldError::new("name", "Name cannot be empty"));
+    }
+    for (i, input) in test_case.inputs.iter().enumerate() {
+        match problem
+            .input_parameters
+            .iter()
+            .find(|ip| ip.id == input.input_parameter_id)
+        {
+            None => fields.push(FieldError::new(
+                &format!("inputs[{}].input_parameter_id", i),
+                format!(
+                    "Input parameter {} does not belong to problem {}",
+                    input.input_parameter_id, problem.id
+                ),
+            )),
+            Some(ip) => {
+                if let Some(term_id) = input.input_value_id {
+                    if !ip.input_values.iter().any(|t| t.id == term_id) {
+                        fields.push(FieldError::new(
+                            &format!("inputs[{}].input_value_id", i),
+                            format!("Term {} is not a term of input parameter '{}'", term_id, ip.name),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    for (i, expectation) in test_case.expectations.iter().enumerate() {
+        match problem
+            .output_parameters
+            .iter()
+            .find(|op| op.id == expectation.output_parameter_id)
+        {
+            None => fields.push(FieldError::new(
+                &format!("expectations[{}].output_parameter_id", i),
+                format!(
+                    "Output parameter {} does not belong to problem {}",
+                    expectation.output_parameter_id, problem.id
+                ),
+            )),
+            Some(op) => {
+                if let Some(term_id) = expectation.expected_fuzzy_output_value_id {
+                    if !op.fuzzy_output_values.iter().any(|t| t.id == term_id) {
+                        fields.push(FieldError::new(
+                            &format!("expectations[{}].expected_fuzzy_output_value_id", i),
+                            format!("Term {} is not a term of output parameter '{}'", term_id, op.name),
+                        ));
+                    }
+                }
+            }
+        }
+        if expectation.expected_value.is_none()
+            && expectation.expected_fuzzy_output_value_id.is_none()
+        {
+            fields.push(FieldError::new(
+                &format!("expectations[{}]", i),
+                format!(
+                    "Expectation for output parameter {} has neither a value nor a term",
+                    expectation.output_parameter_id
+                ),
+            ));
+        }
+        if expectation.tolerance.is_nan() || expectation.tolerance < 0.0 {
+            fields.push(FieldError::new(
+                &format!("expectations[{}].tolerance", i),
+                format!(
+                    "Tolerance for output parameter {} must be >= 0",
+                    expectation.output_parameter_id
+                ),
+            ));
+        }
+    }
+    if fields.is_empty() {
+        Ok(())
+    } else {
+        Err(DomainError::InvalidFields {
+            entity: EntityKind::TestCase,
+            id,
+            fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{
+        fuzzy_output_value::FuzzyOutputValue, input_parameter::InputParameter,
+        output_parameter::OutputParameter, parameter_kind::ParameterKind,
+        parameter_metadata::ParameterMetadata, partition_mode::PartitionMode,
+        test_case::{TestCaseExpectation, TestCaseInput},
+    };
+
+    fn problem() -> Problem {
+        Problem {
+            id: 1,
+            prev_problem_id: None,
+            is_final: true,
+            name: "Heating".to_string(),
+            description: String::new(),
+            image_id: None,
+            created_at: String::new(),
+            updated_at: None,
+            input_parameters: vec![InputParameter {
+                id: 10,
+                problem_id: 1,
+                name: "temperature".to_string(),
+                start: 0.0,
+                end: 10.0,
+                partition_mode: PartitionMode::Ruspini,
+                kind: ParameterKind::Continuous,
+                metadata: ParameterMetadata::default(),
+                input_values: vec![],
+            }],
+            output_parameters: vec![OutputParameter {
+                id: 20,
+                problem_id: 1,
+                name: "power".to_string(),
+                start: 0.0,
+                end: 1.0,
+                partition_mode: PartitionMode::Ruspini,
+                metadata: ParameterMetadata::default(),
+                fuzzy_output_values: vec![FuzzyOutputValue {
+                    id: 200,
+                    output_parameter_id: 20,
+                    value: "low".to_string(),
+                    a: 0.0,
+                    b: 0.0,
+                    c: 0.5,
+                    d: 1.0,
+                    is_triangle: false,
+                    lower: None,
+                }],
+            }],
+            output_values: vec![],
+        }
+    }
+
+    fn test_case(input_parameter_id: i64, output_parameter_id: i64, term_id: Option<i64>) -> TestCase {
+        TestCase {
+            id: 0,
+            problem_id: 1,
+            name: "case".to_string(),
+            inputs: vec![TestCaseInput {
+                input_parameter_id,
+                crisp_value: 1.0,
+                input_value_id: None,
+            }],
+            expectations: vec![TestCaseExpectation {
+                output_parameter_id,
+                expected_value: Some(0.5),
+                tolerance: 0.1,
+                expected_fuzzy_output_value_id: term_id,
+            }],
+        }
+    }
+
+    fn invalid_fields(result: Result<(), DomainError>) -> Vec<String> {
+        match result {
+            Err(DomainError::InvalidFields { fields, .. }) => fields.into_iter().map(|f| f.field).collect(),
+            other => panic!("expected invalid fields, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_references_of_the_problem_are_accepted() {
+        assert!(validate_test_case(None, &test_case(10, 20, Some(200)), &problem()).is_ok());
+    }
+
+    #[test]
+    fn test_references_to_other_problems_are_rejected() {
+        assert_eq!(
+            invalid_fields(validate_test_case(None, &test_case(11, 21, None), &problem())),
+            vec!["inputs[0].input_parameter_id", "expectations[0].output_parameter_id"]
+        );
+        assert_eq!(
+            invalid_fields(validate_test_case(Some(3), &test_case(10, 20, Some(201)), &problem())),
+            vec!["expectations[0].expected_fuzzy_output_value_id"]
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use crate::domain::{
+    entities::test_case::TestCase, error::DomainError, repository::TestCaseRepository,
+};
+
+pub struct GetTestCasesByProblemIdUseCase<'a> {
+    test_case_repository: &'a dyn TestCaseRepository,
+}
+
+impl<'a> GetTestCasesByProblemIdUseCase<'a> {
+    pub fn new(test_case_repository: &'a dyn TestCaseRepository) -> Self {
+        Self {
+            test_case_repository,
+        }
+    }
+
+    pub fn execute(&self, problem_id: i64) -> Result<Vec<TestCase>, DomainError> {
+        self.test_case_repository.get_by_problem_id(problem_id)
+    }
+}
@@ -0,0 +1,6 @@
+pub mod create;
+pub mod get_by_problem_id;
+pub mod prune;
+pub mod remove_by_id;
+pub mod run;
+pub mod update_by_id;
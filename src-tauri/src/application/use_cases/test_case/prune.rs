@@ -0,0 +1,147 @@
+use crate::domain::{
+    entities::test_case::TestCase, error::DomainError, repository::TestCaseRepository,
+};
+
+/// A parameter or term test cases may refer to, about to be removed from its problem
+#[derive(Debug, Clone, Copy)]
+pub enum RemovedReference {
+    InputParameter(i64),
+    OutputParameter(i64),
+    FuzzyOutputValue(i64),
+}
+
+/// Use case removing the references to a removed parameter or term from the
+/// test cases of its problem, so the cases keep running on what is left
+pub struct PruneTestCasesUseCase<'a> {
+    test_case_repository: &'a dyn TestCaseRepository,
+}
+
+impl<'a> PruneTestCasesUseCase<'a> {
+    pub fn new(test_case_repository: &'a dyn TestCaseRepository) -> Self {
+        Self {
+            test_case_repository,
+        }
+    }
+
+    pub fn execute(&self, problem_id: i64, removed: RemovedReference) -> Result<(), DomainError> {
+        for mut test_case in self.test_case_repository.get_by_problem_id(problem_id)? {
+            if prune(&mut test_case, removed) {
+                self.test_case_repository
+                    .update_by_id(test_case.id, &test_case)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Drops inputs and expectations of the removed parameter. An expected term that is
+/// removed is cleared, and the expectation dropped if it expected nothing else.
+/// Returns whether the test case changed.
+fn prune(test_case: &mut TestCase, removed: RemovedReference) -> bool {
+    let (inputs, expectations) = (test_case.inputs.len(), test_case.expectations.len());
+    let mut cleared = false;
+
+    match removed {
+        RemovedReference::InputParameter(id) => {
+            test_case.inputs.retain(|input| input.input_parameter_id != id);
+        }
+        RemovedReference::OutputParameter(id) => {
+            test_case
+                .expectations
+                .retain(|expectation| expectation.output_parameter_id != id);
+        }
+        RemovedReference::FuzzyOutputValue(id) => {
+            for expectation in test_case.expectations.iter_mut() {
+                if expectation.expected_fuzzy_output_value_id == Some(id) {
+                    expectation.expected_fuzzy_output_value_id = None;
+                    cleared = true;
+                }
+            }
+            test_case.expectations.retain(|expectation| {
+                expectation.expected_value.is_some()
+                    || expectation.expected_fuzzy_output_value_id.is_some()
+            });
+        }
+    }
+
+    cleared || inputs != test_case.inputs.len() || expectations != test_case.expectations.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::test_case::{TestCaseExpectation, TestCaseInput};
+
+    fn test_case() -> TestCase {
+        TestCase {
+            id: 1,
+            problem_id: 1,
+            name: "case".to_string(),
+            inputs: vec![
+                TestCaseInput {
+                    input_parameter_id: 10,
+                    crisp_value: 1.0,
+                    input_value_id: None,
+                },
+                TestCaseInput {
+                    input_parameter_id: 11,
+                    crisp_value: 2.0,
+                    input_value_id: None,
+                },
+            ],
+            expectations: vec![
+                TestCaseExpectation {
+                    output_parameter_id: 20,
+                    expected_value: Some(0.5),
+                    tolerance: 0.1,
+                    expected_fuzzy_output_value_id: Some(200),
+                },
+                TestCaseExpectation {
+                    output_parameter_id: 21,
+                    expected_value: None,
+                    tolerance: 0.0,
+                    expected_fuzzy_output_value_id: Some(210),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_removed_input_parameter_drops_its_input() {
+        let mut case = test_case();
+        assert!(prune(&mut case, RemovedReference::InputParameter(10)));
+        assert_eq!(case.inputs.len(), 1);
+        assert_eq!(case.inputs[0].input_parameter_id, 11);
+    }
+
+    #[test]
+    fn test_removed_output_parameter_drops_its_expectation() {
+        let mut case = test_case();
+        assert!(prune(&mut case, RemovedReference::OutputParameter(20)));
+        assert_eq!(case.expectations.len(), 1);
+        assert_eq!(case.expectations[0].output_parameter_id, 21);
+    }
+
+    #[test]
+    fn test_removed_term_is_cleared_and_empty_expectations_dropped() {
+        let mut case = test_case();
+        assert!(prune(&mut case, RemovedReference::FuzzyOutputValue(200)));
+        assert_eq!(case.expectations.len(), 2);
+        assert_eq!(case.expectations[0].expected_fuzzy_output_value_id, None);
+        assert_eq!(case.expectations[0].expected_value, Some(0.5));
+
+        assert!(prune(&mut case, RemovedReference::FuzzyOutputValue(210)));
+        assert_eq!(case.expectations.len(), 1);
+        assert_eq!(case.expectations[0].output_parameter_id, 20);
+    }
+
+    #[test]
+    fn test_unrelated_removal_leaves_case_unchanged() {
+        let mut case = test_case();
+        assert!(!prune(&mut case, RemovedReference::InputParameter(99)));
+        assert!(!prune(&mut case, RemovedReference::OutputParameter(99)));
+        assert!(!prune(&mut case, RemovedReference::FuzzyOutputValue(99)));
+        assert_eq!(case.inputs.len(), 2);
+        assert_eq!(case.expectations.len(), 2);
+    }
+}
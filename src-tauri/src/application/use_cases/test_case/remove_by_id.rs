@@ -0,0 +1,17 @@
+use crate::domain::{error::DomainError, repository::TestCaseRepository};
+
+pub struct RemoveTestCaseByIdUseCase<'a> {
+    test_case_repository: &'a dyn TestCaseRepository,
+}
+
+impl<'a> RemoveTestCaseByIdUseCase<'a> {
+    pub fn new(test_case_repository: &'a dyn TestCaseRepository) -> Self {
+        Self {
+            test_case_repository,
+        }
+    }
+
+    pub fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.test_case_repository.remove_by_id(id)
+    }
+}
@@ -0,0 +1,362 @@
+use crate::{
+    application::use_cases::fuzzy_inference::{
+        evaluate, FuzzyEvaluationInput, FuzzyEvaluationResult,
+    },
+    domain::{
+        entities::{fuzzy_output_value::FuzzyOutputValue, problem::Problem, test_case::TestCase},
+        error::DomainError,
+        repository::{ProblemRepository, TestCaseRepository},
+        services::{
+            defuzzification::DefuzzificationMethod, membership_function::calculate_membership,
+        },
+    },
+};
+
+/// Outcome of a single expectation of a test case
+#[derive(Debug, Clone)]
+pub struct TestCaseOutputResult {
+    pub output_parameter_id: i64,
+    pub output_parameter_name: String,
+    pub expected_value: Option<f32>,
+    pub tolerance: f32,
+    pub actual_value: Option<f32>,
+    /// `actual_value - expected_value`, when both are known
+    pub difference: Option<f32>,
+    pub expected_term: Option<String>,
+    /// Term with the highest membership degree at `actual_value`
+    pub actual_term: Option<String>,
+    pub passed: bool,
+}
+
+/// Outcome of a single test case
+#[derive(Debug, Clone)]
+pub struct TestCaseResult {
+    pub test_case_id: i64,
+    pub name: String,
+    pub passed: bool,
+    /// Set when the case could not be evaluated at all (e.g. a missing input)
+    pub error: Option<String>,
+    pub outputs: Vec<TestCaseOutputResult>,
+}
+
+/// Summary of a test run over all cases of a problem
+#[derive(Debug, Clone)]
+pub struct TestRunReport {
+    pub problem_id: i64,
+    pub passed_count: usize,
+    pub failed_count: usize,
+    pub results: Vec<TestCaseResult>,
+}
+
+/// Use case for running all stored test cases of a problem through the inference engine
+pub struct RunTestCasesUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+    test_case_repository: &'a dyn TestCaseRepository,
+}
+
+impl<'a> RunTestCasesUseCase<'a> {
+    pub fn new(
+        problem_repository: &'a dyn ProblemRepository,
+        test_case_repository: &'a dyn TestCaseRepository,
+    ) -> Self {
+        Self {
+            problem_repository,
+            test_case_repository,
+        }
+    }
+
+    /// Evaluates every test case of the problem, loaded once, with [`evaluate`]
+    /// and compares the crisp outputs against the expectations.
+    ///
+    /// A case passes when every expectation passes: the crisp value lies within
+    /// `expected_value ± tolerance` and, if a term is expected, that term has the
+    /// highest membership degree at the crisp value.
+    pub fn execute(
+        &self,
+        problem_id: i64,
+        method: DefuzzificationMethod,
+        resolution: usize,
+    ) -> Result<TestRunReport, DomainError> {
+        let problem = self.problem_repository.get_full_by_id(problem_id)?;
+        let test_cases = self.test_case_repository.get_by_problem_id(problem_id)?;
+
+        let results: Vec<TestCaseResult> = test_cases
+            .iter()
+            .map(|test_case| {
+                let inputs: Vec<FuzzyEvaluationInput> = test_case
+                    .inputs
+                    .iter()
+                    .map(|i| FuzzyEvaluationInput {
+                        input_parameter_id: i.input_parameter_id,
+                        crisp_value: i.crisp_value,
+                        uncertainty: None,
+                        input_value_id: i.input_value_id,
+                        category_degrees: None,
+                    })
+                    .collect();
+
+                match evaluate(&problem, &inputs, method, resolution) {
+                    Ok(evaluation) => compare(&problem, test_case, &evaluation),
+                    Err(e) => TestCaseResult {
+                        test_case_id: test_case.id,
+                        name: test_case.name.clone(),
+                        passed: false,
+                        error: Some(e.to_string()),
+                        outputs: vec![],
+                    },
+                }
+            })
+            .collect();
+
+        let passed_count = results.iter().filter(|r| r.passed).count();
+
+        Ok(TestRunReport {
+            problem_id,
+            passed_count,
+            failed_count: results.len() - passed_count,
+            results,
+        })
+    }
+}
+
+/// Checks the evaluation of a test case against each of its expectations
+fn compare(
+    problem: &Problem,
+    test_case: &TestCase,
+    evaluation: &FuzzyEvaluationResult,
+) -> TestCaseResult {
+    let outputs: Vec<TestCaseOutputResult> = test_case
+        .expectations
+        .iter()
+        .map(|expectation| {
+            let output_param = problem
+                .output_parameters
+                .iter()
+                .find(|op| op.id == expectation.output_parameter_id);
+            let actual_value = evaluation
+                .outputs
+                .iter()
+                .find(|o| o.output_parameter_id == expectation.output_parameter_id)
+                .map(|o| o.crisp_value);
+
+            let terms: &[FuzzyOutputValue] = output_param
+                .map(|op| op.fuzzy_output_values.as_slice())
+                .unwrap_or(&[]);
+            let actual_term = actual_value.and_then(|v| dominant_term(v, terms));
+            let expected_term = expectation
+                .expected_fuzzy_output_value_id
+                .and_then(|id| terms.iter().find(|t| t.id == id));
+
+            let difference = match (actual_value, expectation.expected_value) {
+                (Some(actual), Some(expected)) => Some(actual - expected),
+                _ => None,
+            };
+
+            let value_passed = match (actual_value, expectation.expected_value) {
+                (Some(_), Some(_)) => difference
+                    .map(|d| d.abs() <= expectation.tolerance)
+                    .unwrap_or(false),
+                (Some(_), None) => true,
+                (None, _) => false,
+            };
+            let term_passed = match expectation.expected_fuzzy_output_value_id {
+                Some(expected_id) => actual_term.map(|t| t.id) == Some(expected_id),
+                None => true,
+            };
+
+            TestCaseOutputResult {
+                output_parameter_id: expectation.output_parameter_id,
+                output_parameter_name: output_param
+                    .map(|op| op.name.clone())
+                    .unwrap_or_default(),
+                expected_value: expectation.expected_value,
+                tolerance: expectation.tolerance,
+                actual_value,
+                difference,
+                expected_term: expected_term.map(|t| t.value.clone()),
+                actual_term: actual_term.map(|t| t.value.clone()),
+                passed: value_passed && term_passed,
+            }
+        })
+        .collect();
+
+    TestCaseResult {
+        test_case_id: test_case.id,
+        name: test_case.name.clone(),
+        passed: outputs.iter().all(|o| o.passed),
+        error: None,
+        outputs,
+    }
+}
+
+/// Returns the term with the highest membership degree at `x`, if any term covers it.
+/// On a tie, e.g. where two terms of a Ruspini partition cross, the first term wins.
+fn dominant_term(x: f32, terms: &[FuzzyOutputValue]) -> Option<&FuzzyOutputValue> {
+    terms
+        .iter()
+        .map(|t| (t, calculate_membership(x, t.a, t.b, t.c, t.d, t.is_triangle)))
+        .filter(|(_, mu)| *mu > 0.0)
+        .fold(None, |best: Option<(&FuzzyOutputValue, f32)>, (t, mu)| match best {
+            Some((_, best_mu)) if best_mu >= mu => best,
+            _ => Some((t, mu)),
+        })
+        .map(|(t, _)| t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::fuzzy_inference::FuzzyEvaluationOutputResult;
+    use crate::domain::entities::{
+        output_parameter::OutputParameter, parameter_metadata::ParameterMetadata,
+        partition_mode::PartitionMode, test_case::TestCaseExpectation,
+    };
+
+    fn term(id: i64, value: &str, [a, b, c, d]: [f32; 4]) -> FuzzyOutputValue {
+        FuzzyOutputValue {
+            id,
+            output_parameter_id: 2,
+            value: value.to_string(),
+            a,
+            b,
+            c,
+            d,
+            is_triangle: false,
+            lower: None,
+        }
+    }
+
+    /// Output "power" on [0, 1] with terms low (21) and high (22) crossing at 0.5
+    fn problem() -> Problem {
+        Problem {
+            id: 1,
+            prev_problem_id: None,
+            is_final: true,
+            name: "Heating".to_string(),
+            description: String::new(),
+            image_id: None,
+            created_at: String::new(),
+            updated_at: None,
+            input_parameters: vec![],
+            output_parameters: vec![OutputParameter {
+                id: 2,
+                problem_id: 1,
+                name: "power".to_string(),
+                start: 0.0,
+                end: 1.0,
+                partition_mode: PartitionMode::Ruspini,
+                metadata: ParameterMetadata::default(),
+                fuzzy_output_values: vec![
+                    term(21, "low", [0.0, 0.0, 0.25, 0.75]),
+                    term(22, "high", [0.25, 0.75, 1.0, 1.0]),
+                ],
+            }],
+            output_values: vec![],
+        }
+    }
+
+    fn evaluation(crisp_value: f32) -> FuzzyEvaluationResult {
+        FuzzyEvaluationResult {
+            problem_id: 1,
+            problem_name: "Heating".to_string(),
+            outputs: vec![FuzzyEvaluationOutputResult {
+                output_parameter_id: 2,
+                output_parameter_name: "power".to_string(),
+                unit: String::new(),
+                crisp_value,
+                display_value: crisp_value.to_string(),
+                fuzzified_inputs: vec![],
+                fired_rules_count: 1,
+                linguistic_terms: vec![],
+                linguistic_approximation: None,
+            }],
+            definition_hash: String::new(),
+        }
+    }
+
+    fn test_case(expected_value: Option<f32>, tolerance: f32, expected_term: Option<i64>) -> TestCase {
+        TestCase {
+            id: 7,
+            problem_id: 1,
+            name: "case".to_string(),
+            inputs: vec![],
+            expectations: vec![TestCaseExpectation {
+                output_parameter_id: 2,
+                expected_value,
+                tolerance,
+                expected_fuzzy_output_value_id: expected_term,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_value_within_tolerance_passes() {
+        let result = compare(&problem(), &test_case(Some(0.5), 0.25, None), &evaluation(0.75));
+        assert!(result.passed);
+        assert_eq!(result.outputs[0].difference, Some(0.25));
+        assert_eq!(result.outputs[0].actual_term.as_deref(), Some("high"));
+    }
+
+    #[test]
+    fn test_value_outside_tolerance_fails() {
+        let result = compare(&problem(), &test_case(Some(0.5), 0.1, None), &evaluation(0.75));
+        assert!(!result.passed);
+        assert_eq!(result.outputs[0].difference, Some(0.25));
+
+        let result = compare(&problem(), &test_case(Some(0.5), 0.0, None), &evaluation(0.5));
+        assert!(result.passed);
+    }
+
+    #[test]
+    fn test_expected_term_must_dominate() {
+        let result = compare(&problem(), &test_case(None, 0.0, Some(22)), &evaluation(0.9));
+        assert!(result.passed);
+        assert_eq!(result.outputs[0].expected_term.as_deref(), Some("high"));
+
+        let result = compare(&problem(), &test_case(None, 0.0, Some(22)), &evaluation(0.1));
+        assert!(!result.passed);
+        assert_eq!(result.outputs[0].actual_term.as_deref(), Some("low"));
+        assert_eq!(result.outputs[0].difference, None);
+    }
+
+    #[test]
+    fn test_value_and_term_must_both_pass() {
+        let result = compare(&problem(), &test_case(Some(0.9), 0.05, Some(21)), &evaluation(0.9));
+        assert!(!result.passed);
+    }
+
+    #[test]
+    fn test_missing_output_fails() {
+        let mut evaluation = evaluation(0.5);
+        evaluation.outputs.clear();
+        let result = compare(&problem(), &test_case(None, 0.0, Some(21)), &evaluation);
+        assert!(!result.passed);
+        assert_eq!(result.outputs[0].actual_value, None);
+        assert_eq!(result.outputs[0].actual_term, None);
+    }
+
+    #[test]
+    fn test_dominant_term_picks_highest_degree() {
+        let problem = problem();
+        let terms = &problem.output_parameters[0].fuzzy_output_values;
+        assert_eq!(dominant_term(0.3, terms).map(|t| t.id), Some(21));
+        assert_eq!(dominant_term(0.7, terms).map(|t| t.id), Some(22));
+    }
+
+    #[test]
+    fn test_dominant_term_tie_goes_to_first_term() {
+        let problem = problem();
+        let terms = &problem.output_parameters[0].fuzzy_output_values;
+        assert_eq!(dominant_term(0.5, terms).map(|t| t.id), Some(21));
+
+        let reversed: Vec<FuzzyOutputValue> = terms.iter().rev().cloned().collect();
+        assert_eq!(dominant_term(0.5, &reversed).map(|t| t.id), Some(22));
+    }
+
+    #[test]
+    fn test_dominant_term_outside_every_term() {
+        let terms = vec![term(21, "low", [0.0, 0.0, 0.2, 0.4])];
+        assert!(dominant_term(0.8, &terms).is_none());
+        assert!(dominant_term(0.5, &[]).is_none());
+    }
+}
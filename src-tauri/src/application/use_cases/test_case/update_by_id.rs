@@ -0,0 +1,32 @@
+use crate::domain::{
+    entities::test_case::TestCase,
+    error::DomainError,
+    repository::{ProblemRepository, TestCaseRepository},
+};
+
+use super::create::validate_test_case;
+
+pub struct UpdateTestCaseByIdUseCase<'a> {
+    problem_repository: &'a dyn ProblemRepository,
+    test_case_repository: &'a dyn TestCaseRepository,
+}
+
+impl<'a> UpdateTestCaseByIdUseCase<'a> {
+    pub fn new(
+        problem_repository: &'a dyn ProblemRepository,
+        test_case_repository: &'a dyn TestCaseRepository,
+    ) -> Self {
+        Self {
+            problem_repository,
+            test_case_repository,
+        }
+    }
+
+    /// Replaces name, inputs and expectations; the case stays with its problem
+    pub fn execute(&self, id: i64, test_case: &TestCase) -> Result<(), DomainError> {
+        let problem_id = self.test_case_repository.get_by_id(id)?.problem_id;
+        let problem = self.problem_repository.get_full_by_id(problem_id)?;
+        validate_test_case(Some(id), test_case, &problem)?;
+        self.test_case_repository.update_by_id(id, test_case)
+    }
+}
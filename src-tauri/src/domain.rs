@@ -1,4 +1,5 @@
+pub use fuzzy_engine::services;
+
 pub mod entities;
 pub mod error;
 pub mod repository;
-pub mod services;
@@ -1,7 +1,10 @@
-pub mod fuzzy_output_value;
+pub use fuzzy_engine::entities::{
+    fuzzy_output_value, input_parameter, input_value, lower_membership, output_parameter,
+    output_value, parameter_kind, parameter_link, parameter_metadata, partition_mode, problem,
+};
+
+pub mod evaluation_record;
 pub mod image;
-pub mod input_parameter;
-pub mod input_value;
-pub mod output_parameter;
-pub mod output_value;
-pub mod problem;
+pub mod journal_entry;
+pub mod problem_snapshot;
+pub mod test_case;
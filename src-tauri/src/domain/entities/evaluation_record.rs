@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::services::fuzzification::{CategoryDegree, InputUncertainty};
+
+/// A persisted run of the inference engine, kept for auditing and replay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvaluationRecord {
+    pub id: i64,
+    pub problem_id: i64,
+    pub problem_name: String,
+    pub inputs: Vec<EvaluationRecordInput>,
+    pub method: String,
+    pub resolution: usize,
+    pub outputs: Vec<EvaluationRecordOutput>,
+    pub definition_hash: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvaluationRecordInput {
+    pub input_parameter_id: i64,
+    pub crisp_value: f32,
+    #[serde(default)]
+    pub uncertainty: Option<InputUncertainty>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_value_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category_degrees: Option<Vec<CategoryDegree>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvaluationRecordOutput {
+    pub output_parameter_id: i64,
+    pub output_parameter_name: String,
+    pub crisp_value: f32,
+}
+
+/// Filter for listing evaluation history; `None` fields are not applied.
+///
+/// `from` and `to` are RFC 3339 timestamps compared against `created_at`.
+#[derive(Debug, Clone, Default)]
+pub struct EvaluationHistoryFilter {
+    pub problem_id: Option<i64>,
+    pub method: Option<String>,
+    pub definition_hash: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
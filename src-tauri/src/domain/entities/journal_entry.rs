@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// One recorded mutation of a problem definition.
+///
+/// `before` and `after` hold the full definition (a serialized `Problem`) around
+/// the mutation, so the inverse also covers everything a repository changed as
+/// a side effect: neighbouring terms reshaped to keep the partition, rules
+/// added or removed together with a term, and so on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub id: i64,
+    pub problem_id: i64,
+    pub description: String,
+    pub before: String,
+    pub after: String,
+    pub is_undone: bool,
+    pub created_at: String,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Saved state of a problem definition.
+///
+/// `definition` holds the problem serialized in the export format, so a snapshot
+/// can be restored with the same code path as an import.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProblemSnapshot {
+    pub id: i64,
+    pub problem_id: i64,
+    pub name: String,
+    /// Taken by the application before a destructive operation rather than by the user
+    pub is_automatic: bool,
+    pub definition: String,
+    pub created_at: String,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCase {
+    pub id: i64,
+    pub problem_id: i64,
+    pub name: String,
+    pub inputs: Vec<TestCaseInput>,
+    pub expectations: Vec<TestCaseExpectation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCaseInput {
+    pub input_parameter_id: i64,
+    pub crisp_value: f32,
+    /// Selected category of a categorical parameter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_value_id: Option<i64>,
+}
+
+/// Expected result for one output parameter.
+///
+/// A case may pin the crisp value (`expected_value ± tolerance`), the linguistic
+/// term with the highest membership at the crisp value (`expected_fuzzy_output_value_id`),
+/// or both.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCaseExpectation {
+    pub output_parameter_id: i64,
+    pub expected_value: Option<f32>,
+    pub tolerance: f32,
+    pub expected_fuzzy_output_value_id: Option<i64>,
+}
@@ -1,31 +1,137 @@
 use std::error::Error as StdError;
 use std::fmt;
 
+use fuzzy_engine::error as engine;
+use serde::Serialize;
+
+pub use fuzzy_engine::error::FieldError;
+
+/// Kind of entity an error is about: one of the engine, or one only the
+/// application stores
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKind {
+    Problem,
+    InputParameter,
+    InputValue,
+    OutputParameter,
+    FuzzyOutputValue,
+    OutputValue,
+    Image,
+    TestCase,
+    EvaluationRecord,
+    ProblemSnapshot,
+    JournalEntry,
+    ParameterLink,
+}
+
+impl EntityKind {
+    /// Name of the kind as used in messages, e.g. "Input parameter"
+    pub fn label(&self) -> &'static str {
+        match self {
+            EntityKind::Problem => "Problem",
+            EntityKind::InputParameter => "Input parameter",
+            EntityKind::InputValue => "Input value",
+            EntityKind::OutputParameter => "Output parameter",
+            EntityKind::FuzzyOutputValue => "Fuzzy output value",
+            EntityKind::OutputValue => "Output value",
+            EntityKind::Image => "Image",
+            EntityKind::TestCase => "Test case",
+            EntityKind::EvaluationRecord => "Evaluation record",
+            EntityKind::ProblemSnapshot => "Problem snapshot",
+            EntityKind::JournalEntry => "Journal entry",
+            EntityKind::ParameterLink => "Parameter link",
+        }
+    }
+}
+
+impl From<engine::EntityKind> for EntityKind {
+    fn from(kind: engine::EntityKind) -> Self {
+        match kind {
+            engine::EntityKind::Problem => EntityKind::Problem,
+            engine::EntityKind::InputParameter => EntityKind::InputParameter,
+            engine::EntityKind::InputValue => EntityKind::InputValue,
+            engine::EntityKind::OutputParameter => EntityKind::OutputParameter,
+            engine::EntityKind::FuzzyOutputValue => EntityKind::FuzzyOutputValue,
+            engine::EntityKind::OutputValue => EntityKind::OutputValue,
+            engine::EntityKind::ParameterLink => EntityKind::ParameterLink,
+        }
+    }
+}
+
+/// Error of the application, which also covers the entities the engine does not know
 #[derive(Debug)]
 pub enum DomainError {
-    NotFound(String),
+    NotFound {
+        entity: EntityKind,
+        id: i64,
+    },
     Validation(String),
-    ValidationError(String),
+    /// Values of particular fields of an entity were rejected; `id` is `None` for
+    /// entities that are being created
+    InvalidFields {
+        entity: EntityKind,
+        id: Option<i64>,
+        fields: Vec<FieldError>,
+    },
     Data(String),
     Internal(String),
+    /// The operation was stopped on request before it finished
+    Cancelled(String),
+}
+
+impl DomainError {
+    pub fn not_found(entity: EntityKind, id: i64) -> Self {
+        DomainError::NotFound { entity, id }
+    }
+
+    /// A single rejected field
+    pub fn invalid_field(entity: EntityKind, id: Option<i64>, error: FieldError) -> Self {
+        DomainError::InvalidFields {
+            entity,
+            id,
+            fields: vec![error],
+        }
+    }
+}
+
+impl From<engine::DomainError> for DomainError {
+    fn from(error: engine::DomainError) -> Self {
+        match error {
+            engine::DomainError::NotFound { entity, id } => DomainError::NotFound {
+                entity: entity.into(),
+                id,
+            },
+            engine::DomainError::Validation(msg) => DomainError::Validation(msg),
+            engine::DomainError::InvalidFields { entity, id, fields } => DomainError::InvalidFields {
+                entity: entity.into(),
+                id,
+                fields,
+            },
+            engine::DomainError::Data(msg) => DomainError::Data(msg),
+            engine::DomainError::Internal(msg) => DomainError::Internal(msg),
+            engine::DomainError::Cancelled(msg) => DomainError::Cancelled(msg),
+        }
+    }
 }
 
 impl fmt::Display for DomainError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            DomainError::NotFound(msg) => write!(f, "Not found: {}", msg),
+            DomainError::NotFound { entity, id } => write!(f, "Not found: {} {}", entity.label(), id),
             DomainError::Validation(msg) => write!(f, "Validation error: {}", msg),
-            DomainError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            DomainError::InvalidFields { fields, .. } => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|e| format!("{}: {}", e.field, e.message))
+                    .collect();
+                write!(f, "Validation error: {}", fields.join("; "))
+            }
             DomainError::Data(msg) => write!(f, "Data error: {}", msg),
             DomainError::Internal(msg) => write!(f, "Internal error: {}", msg),
+            DomainError::Cancelled(msg) => write!(f, "Cancelled: {}", msg),
         }
     }
 }
 
-impl StdError for DomainError {
-    fn source(&self) -> Option<&(dyn StdError + 'static)> {
-        match self {
-            _ => None,
-        }
-    }
-}
+impl StdError for DomainError {}
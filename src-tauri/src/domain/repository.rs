@@ -1,8 +1,10 @@
 use crate::domain::{
     entities::{
+        evaluation_record::{EvaluationHistoryFilter, EvaluationRecord},
         fuzzy_output_value::FuzzyOutputValue, image::*, input_parameter::InputParameter,
-        input_value::InputValue, output_parameter::OutputParameter, output_value::OutputValue,
-        problem::*,
+        input_value::InputValue, journal_entry::JournalEntry, lower_membership::LowerMembership,
+        output_parameter::OutputParameter, output_value::OutputValue, parameter_kind::ParameterKind, parameter_link::ParameterLink, parameter_metadata::ParameterMetadata, partition_mode::PartitionMode, problem::*,
+        problem_snapshot::ProblemSnapshot, test_case::TestCase,
     },
     error::DomainError,
 };
@@ -10,10 +12,28 @@ use crate::domain::{
 pub trait ProblemRepository: Send + Sync {
     fn get_all_by_prev_problem_id(&self, id: Option<i64>) -> Result<Vec<Problem>, DomainError>;
     fn get_full_by_id(&self, id: i64) -> Result<Problem, DomainError>;
-    fn create(&self, model: &Problem) -> Result<(i64, Option<i64>), DomainError>;
+    /// Creates the problem with `image`, if given. Returns the ids of both
+    fn create(&self, model: &Problem, image: Option<&Image>) -> Result<(i64, Option<i64>), DomainError>;
     fn remove_by_id(&self, id: i64) -> Result<(), DomainError>;
-    fn update_by_id(&self, id: i64, model: &Problem) -> Result<Option<i64>, DomainError>;
+    /// Updates the problem. A given `image` replaces the current one; otherwise
+    /// `model.image_id` is kept, and the image removed when it is `None`
+    fn update_by_id(&self, id: i64, model: &Problem, image: Option<&Image>) -> Result<Option<i64>, DomainError>;
     fn is_final(&self, id: i64) -> Result<bool, DomainError>;
+    /// Removes parameters, terms, rules and test cases of the problem, keeping the problem itself
+    fn clear_definition(&self, id: i64) -> Result<(), DomainError>;
+    /// Overwrites name, description, parameters, terms and rules of `model.id` with
+    /// the ones in `model`, keeping their ids. Test cases and the image are not touched.
+    fn replace_definition(&self, model: &Problem) -> Result<(), DomainError>;
+    /// Returns the ids of all groups above the problem, nearest first
+    fn get_ancestor_ids(&self, id: i64) -> Result<Vec<i64>, DomainError>;
+    /// Re-parents the problem, with its subtree, under `prev_problem_id` and puts it at
+    /// `position` among its new siblings, or after the last one when `position` is `None`
+    fn move_by_id(
+        &self,
+        id: i64,
+        prev_problem_id: Option<i64>,
+        position: Option<usize>,
+    ) -> Result<(), DomainError>;
 }
 
 pub trait ImageRepository: Send + Sync {
@@ -27,16 +47,34 @@ pub trait InputParameterRepository: Send + Sync {
     fn create_raw(&self, problem_id: i64, model: &InputParameter) -> Result<i64, DomainError>;
     fn remove_by_id(&self, id: i64) -> Result<(), DomainError>;
     fn update_by_id(&self, id: i64, model: &InputParameter) -> Result<(), DomainError>;
-    fn switch(&self, id_1: i64, id_2: i64) -> Result<(), DomainError>;
+    /// Sets whether term edits keep a Ruspini partition of the range
+    fn update_partition_mode(&self, id: i64, mode: PartitionMode) -> Result<(), DomainError>;
+    /// Sets whether the parameter is numeric or categorical
+    fn update_kind(&self, id: i64, kind: ParameterKind) -> Result<(), DomainError>;
+    /// Replaces the unit, description and display settings
+    fn update_metadata(&self, id: i64, metadata: &ParameterMetadata) -> Result<(), DomainError>;
+    /// Puts the item at index `position` among its siblings, or after the last one
+    /// when `position` is past the end
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError>;
 }
 
 pub trait InputValueRepository: Send + Sync {
     fn get_by_input_parameter_id(&self, input_parameter_id: i64) -> Result<Vec<InputValue>, DomainError>;
-    fn create(&self, model: &InputValue) -> Result<i64, DomainError>;
+    fn get_by_id(&self, id: i64) -> Result<InputValue, DomainError>;
+    /// Creates the term. In a free-form parameter it takes `shape`, or covers the whole
+    /// range without one; a Ruspini partition makes room by splitting its rightmost term
+    fn create(&self, model: &InputValue, shape: Option<[f32; 4]>) -> Result<i64, DomainError>;
     fn create_raw(&self, model: &InputValue) -> Result<i64, DomainError>;
     fn remove_by_id(&self, id: i64) -> Result<(), DomainError>;
     fn update_by_id(&self, id: i64, model: &InputValue) -> Result<(), DomainError>;
-    fn switch(&self, id_1: i64, id_2: i64) -> Result<(), DomainError>;
+    /// Writes the breakpoints and lower membership function of a term as given,
+    /// without adjusting its neighbours
+    fn update_shape(&self, id: i64, model: &InputValue) -> Result<(), DomainError>;
+    /// Sets or clears the lower membership function of an interval type-2 term
+    fn update_lower(&self, id: i64, lower: Option<&LowerMembership>) -> Result<(), DomainError>;
+    /// Puts the item at index `position` among its siblings, or after the last one
+    /// when `position` is past the end
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError>;
 }
 
 pub trait OutputParameterRepository: Send + Sync {
@@ -45,21 +83,120 @@ pub trait OutputParameterRepository: Send + Sync {
     fn create_raw(&self, problem_id: i64, model: &OutputParameter) -> Result<i64, DomainError>;
     fn remove_by_id(&self, id: i64) -> Result<(), DomainError>;
     fn update_by_id(&self, id: i64, model: &OutputParameter) -> Result<(), DomainError>;
-    fn switch(&self, id_1: i64, id_2: i64) -> Result<(), DomainError>;
+    /// Sets whether term edits keep a Ruspini partition of the range
+    fn update_partition_mode(&self, id: i64, mode: PartitionMode) -> Result<(), DomainError>;
+    /// Replaces the unit, description and display settings
+    fn update_metadata(&self, id: i64, metadata: &ParameterMetadata) -> Result<(), DomainError>;
+    /// Puts the item at index `position` among its siblings, or after the last one
+    /// when `position` is past the end
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError>;
 }
 
 pub trait FuzzyOutputValueRepository: Send + Sync {
     fn get_by_output_parameter_id(&self, output_parameter_id: i64) -> Result<Vec<FuzzyOutputValue>, DomainError>;
-    fn create(&self, model: &FuzzyOutputValue) -> Result<i64, DomainError>;
+    fn get_by_id(&self, id: i64) -> Result<FuzzyOutputValue, DomainError>;
+    /// Creates the term. In a free-form parameter it takes `shape`, or covers the whole
+    /// range without one; a Ruspini partition makes room by splitting its rightmost term
+    fn create(&self, model: &FuzzyOutputValue, shape: Option<[f32; 4]>) -> Result<i64, DomainError>;
     fn create_raw(&self, model: &FuzzyOutputValue) -> Result<i64, DomainError>;
     fn remove_by_id(&self, id: i64) -> Result<(), DomainError>;
     fn update_by_id(&self, id: i64, model: &FuzzyOutputValue) -> Result<(), DomainError>;
-    fn switch(&self, id_1: i64, id_2: i64) -> Result<(), DomainError>;
+    /// Writes the breakpoints and lower membership function of a term as given,
+    /// without adjusting its neighbours
+    fn update_shape(&self, id: i64, model: &FuzzyOutputValue) -> Result<(), DomainError>;
+    /// Sets or clears the lower membership function of an interval type-2 term
+    fn update_lower(&self, id: i64, lower: Option<&LowerMembership>) -> Result<(), DomainError>;
+    /// Puts the item at index `position` among its siblings, or after the last one
+    /// when `position` is past the end
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError>;
 }
 
 pub trait OutputValueRepository: Send + Sync {
     fn create(&self, model: &OutputValue) -> Result<i64, DomainError>;
     fn update_by_id(&self, id: i64, model: &OutputValue) -> Result<(), DomainError>;
     fn update_fuzzy_output_value(&self, id: i64, fuzzy_output_value_id: Option<i64>) -> Result<(), DomainError>;
+    /// Sets the hedges of a rule; `antecedent_hedges` is in the stored "|id:hedge|" form
+    fn update_hedges(
+        &self,
+        id: i64,
+        antecedent_hedges: &str,
+        consequent_hedge: Option<&str>,
+    ) -> Result<(), DomainError>;
     fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<OutputValue>, DomainError>;
+    fn get_by_id(&self, id: i64) -> Result<OutputValue, DomainError>;
+}
+
+pub trait TestCaseRepository: Send + Sync {
+    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<TestCase>, DomainError>;
+    fn get_by_id(&self, id: i64) -> Result<TestCase, DomainError>;
+    fn create(&self, model: &TestCase) -> Result<i64, DomainError>;
+    fn update_by_id(&self, id: i64, model: &TestCase) -> Result<(), DomainError>;
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError>;
+}
+
+pub trait ParameterLinkRepository: Send + Sync {
+    /// Returns every link whose parameters both still exist
+    fn get_all(&self) -> Result<Vec<ParameterLink>, DomainError>;
+    fn get_by_id(&self, id: i64) -> Result<ParameterLink, DomainError>;
+    fn create(&self, model: &ParameterLink) -> Result<i64, DomainError>;
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError>;
+}
+
+pub trait EvaluationHistoryRepository: Send + Sync {
+    fn get_filtered(
+        &self,
+        filter: &EvaluationHistoryFilter,
+    ) -> Result<Vec<EvaluationRecord>, DomainError>;
+    fn get_by_id(&self, id: i64) -> Result<EvaluationRecord, DomainError>;
+    fn create(&self, model: &EvaluationRecord) -> Result<i64, DomainError>;
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError>;
+    fn remove_by_problem_id(&self, problem_id: i64) -> Result<usize, DomainError>;
+}
+
+pub trait ProblemSnapshotRepository: Send + Sync {
+    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<ProblemSnapshot>, DomainError>;
+    fn get_by_id(&self, id: i64) -> Result<ProblemSnapshot, DomainError>;
+    fn create(&self, model: &ProblemSnapshot) -> Result<i64, DomainError>;
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError>;
+    /// Keeps only the `keep` newest automatic snapshots of the problem
+    fn prune_automatic(&self, problem_id: i64, keep: usize) -> Result<(), DomainError>;
+}
+
+pub trait JournalRepository: Send + Sync {
+    /// Returns the entries of a problem, newest first
+    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<JournalEntry>, DomainError>;
+    /// Newest entry that has not been undone
+    fn get_last_done(&self, problem_id: i64) -> Result<Option<JournalEntry>, DomainError>;
+    /// Oldest entry that has been undone, i.e. the next one to redo
+    fn get_first_undone(&self, problem_id: i64) -> Result<Option<JournalEntry>, DomainError>;
+    fn create(&self, model: &JournalEntry) -> Result<i64, DomainError>;
+    fn set_undone(&self, id: i64, is_undone: bool) -> Result<(), DomainError>;
+    fn remove_undone_by_problem_id(&self, problem_id: i64) -> Result<(), DomainError>;
+    /// Keeps only the `keep` newest entries of the problem
+    fn prune(&self, problem_id: i64, keep: usize) -> Result<(), DomainError>;
+}
+
+/// Groups the writes of several repositories so that they are committed or rolled back together.
+/// Units of work may be nested; only the outermost commit makes the writes durable.
+pub trait UnitOfWork: Send + Sync {
+    fn begin(&self) -> Result<(), DomainError>;
+    fn commit(&self) -> Result<(), DomainError>;
+    fn rollback(&self) -> Result<(), DomainError>;
+}
+
+impl dyn UnitOfWork + '_ {
+    /// Runs `work` inside a unit of work, committing on `Ok` and rolling back on `Err`
+    pub fn run<T>(&self, work: impl FnOnce() -> Result<T, DomainError>) -> Result<T, DomainError> {
+        self.begin()?;
+        match work() {
+            Ok(value) => {
+                self.commit()?;
+                Ok(value)
+            }
+            Err(e) => {
+                self.rollback()?;
+                Err(e)
+            }
+        }
+    }
 }
@@ -1,5 +0,0 @@
-pub mod membership_function;
-pub mod fuzzification;
-pub mod inference;
-pub mod defuzzification;
-pub mod fuzzy_partition;
@@ -1,3 +1,4 @@
+pub mod fuzzy_file;
 pub mod repository;
 pub mod state;
 pub mod tauri;
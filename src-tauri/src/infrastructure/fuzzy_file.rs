@@ -0,0 +1,204 @@
+use std::io::{Read, Write};
+
+use chrono::Utc;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::Deserialize;
+
+use crate::{
+    application::exported_problem::{ExportedProblem, FuzzyFile, FUZZY_FORMAT_VERSION},
+    domain::error::DomainError,
+};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Container a `.fuzzy` file is written in. Reading detects it from the first bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FuzzyFileCompression {
+    /// Plain, human readable JSON
+    #[default]
+    None,
+    Gzip,
+    Zstd,
+}
+
+/// Wraps `problem` into the current envelope and serializes it
+pub fn encode(
+    problem: ExportedProblem,
+    compression: FuzzyFileCompression,
+) -> Result<Vec<u8>, DomainError> {
+    let file = FuzzyFile {
+        format_version: FUZZY_FORMAT_VERSION,
+        generator: format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+        exported_at: Utc::now().to_rfc3339(),
+        problem,
+    };
+
+    let json = serde_json::to_vec_pretty(&file).map_err(|e| DomainError::Internal(e.to_string()))?;
+
+    match compression {
+        FuzzyFileCompression::None => Ok(json),
+        FuzzyFileCompression::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder
+                .write_all(&json)
+                .map_err(|e| DomainError::Internal(e.to_string()))?;
+            encoder
+                .finish()
+                .map_err(|e| DomainError::Internal(e.to_string()))
+        }
+        FuzzyFileCompression::Zstd => {
+            zstd::encode_all(json.as_slice(), 0).map_err(|e| DomainError::Internal(e.to_string()))
+        }
+    }
+}
+
+/// Reads a `.fuzzy` file of any version, compressed or not.
+///
+/// Unversioned files (a bare problem) are returned as version 1 with an empty generator.
+pub fn decode(bytes: &[u8]) -> Result<FuzzyFile, DomainError> {
+    let json = if bytes.starts_with(&GZIP_MAGIC) {
+        let mut json = Vec::new();
+        GzDecoder::new(bytes)
+            .read_to_end(&mut json)
+            .map_err(|e| DomainError::Data(format!("Corrupted gzip container: {}", e)))?;
+        json
+    } else if bytes.starts_with(&ZSTD_MAGIC) {
+        zstd::decode_all(bytes)
+            .map_err(|e| DomainError::Data(format!("Corrupted zstd container: {}", e)))?
+    } else {
+        bytes.to_vec()
+    };
+
+    let value: serde_json::Value =
+        serde_json::from_slice(&json).map_err(|e| DomainError::Data(e.to_string()))?;
+
+    match value.get("format_version").map(|v| v.as_u64().ok_or(v)) {
+        Some(Ok(version)) if version > FUZZY_FORMAT_VERSION as u64 => Err(DomainError::Validation(format!(
+            "File format version {} is newer than the supported version {}",
+            version, FUZZY_FORMAT_VERSION
+        ))),
+        Some(Ok(_)) => serde_json::from_value(value).map_err(|e| DomainError::Data(e.to_string())),
+        Some(Err(version)) => Err(DomainError::Data(format!("Invalid file format version {}", version))),
+        None => {
+            let problem: ExportedProblem =
+                serde_json::from_value(value).map_err(|e| DomainError::Data(e.to_string()))?;
+            Ok(FuzzyFile {
+                format_version: 1,
+                generator: String::new(),
+                exported_at: String::new(),
+                problem,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An unversioned file: the bare problem, image bytes as an array of numbers
+    const UNVERSIONED: &str = r#"{
+        "name": "Old",
+        "description": "",
+        "is_final": true,
+        "image": { "data": [1, 2, 255], "format": "png" },
+        "input_parameters": [],
+        "output_parameters": [],
+        "output_values": [],
+        "children": []
+    }"#;
+
+    fn problem() -> ExportedProblem {
+        let mut problem: ExportedProblem = serde_json::from_str(UNVERSIONED).unwrap();
+        problem.name = "Problem".to_string();
+        problem
+    }
+
+    #[test]
+    fn test_unversioned_file_is_read_as_version_1() {
+        let file = decode(UNVERSIONED.as_bytes()).unwrap();
+
+        assert_eq!(file.format_version, 1);
+        assert_eq!(file.generator, "");
+        assert_eq!(file.problem.name, "Old");
+        assert_eq!(file.problem.image.unwrap().data, vec![1, 2, 255]);
+    }
+
+    #[test]
+    fn test_image_is_written_and_read_as_base64() {
+        let bytes = encode(problem(), FuzzyFileCompression::None).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(value["problem"]["image"]["data"], "AQL/");
+
+        let file = decode(&bytes).unwrap();
+        assert_eq!(file.problem.image.unwrap().data, vec![1, 2, 255]);
+    }
+
+    #[test]
+    fn test_round_trip_keeps_the_problem() {
+        let bytes = encode(problem(), FuzzyFileCompression::None).unwrap();
+        let file = decode(&bytes).unwrap();
+
+        assert_eq!(file.format_version, FUZZY_FORMAT_VERSION);
+        assert!(file.generator.starts_with(env!("CARGO_PKG_NAME")));
+        assert_eq!(
+            serde_json::to_value(&file.problem).unwrap(),
+            serde_json::to_value(problem()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_compressed_files_are_detected_by_magic_bytes() {
+        for (compression, magic) in [
+            (FuzzyFileCompression::Gzip, &GZIP_MAGIC[..]),
+            (FuzzyFileCompression::Zstd, &ZSTD_MAGIC[..]),
+        ] {
+            let bytes = encode(problem(), compression).unwrap();
+            assert!(bytes.starts_with(magic), "{:?}", compression);
+
+            let file = decode(&bytes).unwrap();
+            assert_eq!(file.problem.name, "Problem");
+        }
+    }
+
+    #[test]
+    fn test_corrupted_container_is_a_data_error() {
+        let mut bytes = encode(problem(), FuzzyFileCompression::Gzip).unwrap();
+        bytes.truncate(12);
+
+        assert!(matches!(decode(&bytes), Err(DomainError::Data(_))));
+    }
+
+    #[test]
+    fn test_newer_version_is_rejected() {
+        let bytes = encode(problem(), FuzzyFileCompression::None).unwrap();
+        let mut value: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        value["format_version"] = (FUZZY_FORMAT_VERSION + 1).into();
+
+        let result = decode(&serde_json::to_vec(&value).unwrap());
+
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+
+    #[test]
+    fn test_older_version_is_read() {
+        let file = format!(
+            r#"{{ "format_version": 2, "generator": "", "exported_at": "", "problem": {} }}"#,
+            UNVERSIONED
+        );
+
+        let file = decode(file.as_bytes()).unwrap();
+
+        assert_eq!(file.format_version, 2);
+        assert_eq!(file.problem.name, "Old");
+    }
+
+    #[test]
+    fn test_invalid_version_is_a_data_error() {
+        let result = decode(br#"{ "format_version": "3" }"#);
+
+        assert!(matches!(result, Err(DomainError::Data(_))));
+    }
+}
@@ -1 +1,4 @@
+#[cfg(test)]
+mod conformance;
+pub mod memory;
 pub mod sqlite;
@@ -0,0 +1,1347 @@
+use crate::{
+    application::{
+        exported_problem::ExportedProblem,
+        use_cases::{
+            fuzzy_inference::evaluate::EvaluateFuzzySystemUseCase,
+            input_parameter::{
+                change_range::ChangeInputParameterRangeUseCase,
+                update_by_id::UpdateInputParameterByIdUseCase,
+            },
+            journal::{record::RecordMutationUseCase, undo::UndoUseCase},
+            test_case::run::RunTestCasesUseCase,
+            problem::{
+                clone::CloneProblemUseCase, export_problem::ExportProblemUseCase, import_problem::ImportProblemUseCase,
+                merge_import::MergeImportUseCase,
+            },
+            problem_snapshot::{create::CreateProblemSnapshotUseCase, restore::RestoreProblemSnapshotUseCase},
+        },
+    },
+    domain::{
+        entities::{
+            evaluation_record::{EvaluationHistoryFilter, EvaluationRecord},
+            fuzzy_output_value::FuzzyOutputValue,
+            image::Image,
+            input_parameter::InputParameter,
+            input_value::InputValue,
+            journal_entry::JournalEntry,
+            output_parameter::OutputParameter,
+            parameter_kind::ParameterKind,
+            parameter_link::ParameterLink,
+            parameter_metadata::ParameterMetadata,
+            partition_mode::PartitionMode,
+            problem::Problem,
+            problem_snapshot::ProblemSnapshot,
+            test_case::{TestCase, TestCaseExpectation, TestCaseInput},
+        },
+        error::{DomainError, EntityKind},
+        services::defuzzification::DefuzzificationMethod,
+    },
+    infrastructure::state::AppState,
+};
+use fuzzy_engine::evaluation::FuzzyEvaluationInput;
+
+// Every case runs against the SQLite and the in-memory repositories, so both keep
+// the same side effects: reshaped neighbours, regenerated rules, cascading deletes.
+
+macro_rules! conformance_cases {
+    ($($case:ident),* $(,)?) => {
+        mod sqlite {
+            use std::path::Path;
+
+            use crate::infrastructure::state::AppState;
+
+            pub(super) fn state() -> AppState {
+                AppState::sqlite(rusqlite::Connection::open_in_memory().unwrap(), Path::new(":memory:"), 0)
+            }
+
+            $(
+                #[test]
+                fn $case() {
+                    super::$case(&state());
+                }
+            )*
+        }
+
+        mod in_memory {
+            use crate::infrastructure::state::AppState;
+
+            pub(super) fn state() -> AppState {
+                AppState::in_memory()
+            }
+
+            $(
+                #[test]
+                fn $case() {
+                    super::$case(&state());
+                }
+            )*
+        }
+    };
+}
+
+conformance_cases!(
+    ruspini_terms_split_the_rightmost_term,
+    ruspini_neighbours_follow_an_edited_term,
+    ruspini_neighbours_close_the_gap_of_a_removed_term,
+    free_form_terms_are_stored_as_given,
+    rules_follow_the_input_terms,
+    new_output_parameter_gets_a_rule_per_combination,
+    removed_input_parameter_keeps_the_rules_of_its_first_term,
+    removed_output_term_is_cleared_from_rules,
+    moves_renumber_siblings,
+    removed_problem_takes_its_subtree_and_records_along,
+    clone_remaps_ids_to_the_copies,
+    clone_into_own_subtree_is_rejected,
+    update_rejects_a_range_the_terms_do_not_fit,
+    replace_definition_rejects_taken_ids,
+    missing_rows_are_not_found,
+    unit_of_work_rolls_back_every_repository,
+    input_parameter_is_fed_by_one_link,
+    undone_parameter_takes_its_link_along,
+    restored_snapshot_keeps_ids_and_links,
+    merged_file_keeps_ids_and_links,
+    replaced_definition_keeps_only_links_of_its_parameters,
+    history_is_filtered_newest_first,
+    journal_and_snapshots_are_pruned_oldest_first,
+    imported_problem_can_be_evaluated,
+    test_case_keeps_its_category_when_categories_move,
+);
+
+fn create_problem(state: &AppState, prev_problem_id: Option<i64>, name: &str) -> i64 {
+    let (id, _) = state
+        .problem_repository
+        .create(
+            &Problem {
+                id: 0,
+                prev_problem_id,
+                is_final: true,
+                name: name.to_string(),
+                description: String::new(),
+                image_id: None,
+                created_at: String::new(),
+                updated_at: None,
+                input_parameters: vec![],
+                output_parameters: vec![],
+                output_values: vec![],
+            },
+            None,
+        )
+        .unwrap();
+    id
+}
+
+fn create_input_parameter(state: &AppState, problem_id: i64, name: &str) -> i64 {
+    state
+        .input_parameter_repository
+        .create(
+            problem_id,
+            &InputParameter {
+                id: 0,
+                problem_id,
+                name: name.to_string(),
+                start: 0.0,
+                end: 10.0,
+                partition_mode: PartitionMode::Ruspini,
+                kind: ParameterKind::Continuous,
+                metadata: ParameterMetadata::default(),
+                input_values: vec![],
+            },
+        )
+        .unwrap()
+}
+
+fn input_term(input_parameter_id: i64, value: &str, [a, b, c, d]: [f32; 4]) -> InputValue {
+    InputValue {
+        id: 0,
+        input_parameter_id,
+        value: value.to_string(),
+        a,
+        b,
+        c,
+        d,
+        is_triangle: false,
+        lower: None,
+    }
+}
+
+fn create_input_value(state: &AppState, input_parameter_id: i64, value: &str) -> i64 {
+    state
+        .input_value_repository
+        .create(&input_term(input_parameter_id, value, [0.0; 4]), None)
+        .unwrap()
+}
+
+fn create_output_parameter(state: &AppState, problem_id: i64, name: &str) -> i64 {
+    state
+        .output_parameter_repository
+        .create(
+            problem_id,
+            &OutputParameter {
+                id: 0,
+                problem_id,
+                name: name.to_string(),
+                start: 0.0,
+                end: 1.0,
+                partition_mode: PartitionMode::Ruspini,
+                metadata: ParameterMetadata::default(),
+                fuzzy_output_values: vec![],
+            },
+        )
+        .unwrap()
+}
+
+fn create_fuzzy_output_value(state: &AppState, output_parameter_id: i64, value: &str) -> i64 {
+    state
+        .fuzzy_output_value_repository
+        .create(
+            &FuzzyOutputValue {
+                id: 0,
+                output_parameter_id,
+                value: value.to_string(),
+                a: 0.0,
+                b: 0.0,
+                c: 0.0,
+                d: 0.0,
+                is_triangle: false,
+                lower: None,
+            },
+            None,
+        )
+        .unwrap()
+}
+
+fn input_shape(state: &AppState, id: i64) -> [f32; 4] {
+    let term = state.input_value_repository.get_by_id(id).unwrap();
+    [term.a, term.b, term.c, term.d]
+}
+
+fn output_shape(state: &AppState, id: i64) -> [f32; 4] {
+    let term = state.fuzzy_output_value_repository.get_by_id(id).unwrap();
+    [term.a, term.b, term.c, term.d]
+}
+
+fn assert_shape(actual: [f32; 4], expected: [f32; 4]) {
+    assert!(
+        actual.iter().zip(expected).all(|(actual, expected)| (actual - expected).abs() < 1e-4),
+        "{:?} != {:?}",
+        actual,
+        expected
+    );
+}
+
+/// Antecedents of the rules of the problem, sorted
+fn rules(state: &AppState, problem_id: i64) -> Vec<String> {
+    let mut rules: Vec<String> = state
+        .output_value_repository
+        .get_by_problem_id(problem_id)
+        .unwrap()
+        .into_iter()
+        .map(|rule| rule.input_value_ids)
+        .collect();
+    rules.sort();
+    rules
+}
+
+fn antecedents(ids: &[i64]) -> String {
+    ids.iter().map(|id| format!("|{}|", id)).collect()
+}
+
+fn assert_not_found<T: std::fmt::Debug>(result: Result<T, DomainError>, kind: EntityKind, missing_id: i64) {
+    match result {
+        Err(DomainError::NotFound { entity, id }) => {
+            assert_eq!(entity, kind);
+            assert_eq!(id, missing_id);
+        }
+        other => panic!("expected {:?} {} not to be found, got {:?}", kind, missing_id, other),
+    }
+}
+
+fn assert_invalid_field<T: std::fmt::Debug>(
+    result: Result<T, DomainError>,
+    kind: EntityKind,
+    name: &str,
+) {
+    match result {
+        Err(DomainError::InvalidFields { entity, fields, .. }) => {
+            assert_eq!(entity, kind);
+            assert_eq!(fields[0].field, name);
+        }
+        other => panic!("expected field {} of {:?} to be rejected, got {:?}", name, kind, other),
+    }
+}
+
+fn ruspini_terms_split_the_rightmost_term(state: &AppState) {
+    let problem_id = create_problem(state, None, "Heating");
+    let x = create_input_parameter(state, problem_id, "temperature");
+
+    let low = create_input_value(state, x, "low");
+    assert_shape(input_shape(state, low), [0.0, 0.0, 10.0, 10.0]);
+
+    let mid = create_input_value(state, x, "mid");
+    assert_shape(input_shape(state, low), [0.0, 0.0, 2.5, 7.5]);
+    assert_shape(input_shape(state, mid), [2.5, 7.5, 10.0, 10.0]);
+
+    let high = create_input_value(state, x, "high");
+    assert_shape(input_shape(state, low), [0.0, 0.0, 2.5, 7.5]);
+    assert_shape(input_shape(state, mid), [2.5, 7.5, 8.125, 9.375]);
+    assert_shape(input_shape(state, high), [8.125, 9.375, 10.0, 10.0]);
+
+    let values: Vec<i64> = state
+        .input_value_repository
+        .get_by_input_parameter_id(x)
+        .unwrap()
+        .iter()
+        .map(|term| term.id)
+        .collect();
+    assert_eq!(values, vec![low, mid, high]);
+}
+
+fn ruspini_neighbours_follow_an_edited_term(state: &AppState) {
+    let problem_id = create_problem(state, None, "Heating");
+    let x = create_input_parameter(state, problem_id, "temperature");
+    let low = create_input_value(state, x, "low");
+    let mid = create_input_value(state, x, "mid");
+    let high = create_input_value(state, x, "high");
+
+    state
+        .input_value_repository
+        .update_by_id(mid, &input_term(x, "medium", [2.0, 6.0, 7.0, 9.0]))
+        .unwrap();
+
+    assert_shape(input_shape(state, low), [0.0, 0.0, 2.0, 6.0]);
+    assert_shape(input_shape(state, mid), [2.0, 6.0, 7.0, 9.0]);
+    assert_shape(input_shape(state, high), [7.0, 9.0, 10.0, 10.0]);
+    assert_eq!(state.input_value_repository.get_by_id(mid).unwrap().value, "medium");
+
+    let result = state
+        .input_value_repository
+        .update_by_id(mid, &input_term(x, "medium", [6.0, 2.0, 7.0, 9.0]));
+    assert_invalid_field(result, EntityKind::InputValue, "a");
+}
+
+fn ruspini_neighbours_close_the_gap_of_a_removed_term(state: &AppState) {
+    let problem_id = create_problem(state, None, "Heating");
+    let x = create_input_parameter(state, problem_id, "temperature");
+    let low = create_input_value(state, x, "low");
+    let mid = create_input_value(state, x, "mid");
+    let high = create_input_value(state, x, "high");
+
+    state.input_value_repository.remove_by_id(mid).unwrap();
+    assert_shape(input_shape(state, low), [0.0, 0.0, 4.21875, 7.65625]);
+    assert_shape(input_shape(state, high), [4.21875, 7.65625, 10.0, 10.0]);
+
+    state.input_value_repository.remove_by_id(high).unwrap();
+    assert_shape(input_shape(state, low), [0.0, 0.0, 10.0, 10.0]);
+}
+
+fn free_form_terms_are_stored_as_given(state: &AppState) {
+    let problem_id = create_problem(state, None, "Heating");
+    let x = create_input_parameter(state, problem_id, "temperature");
+    state
+        .input_parameter_repository
+        .update_partition_mode(x, PartitionMode::FreeForm)
+        .unwrap();
+
+    let create = |value, shape| {
+        state
+            .input_value_repository
+            .create(&input_term(x, value, [0.0; 4]), shape)
+    };
+    let whole = create("whole", None).unwrap();
+    let given = create("given", Some([1.0, 2.0, 3.0, 4.0])).unwrap();
+    let singleton = create("singleton", Some([5.0; 4])).unwrap();
+    assert_shape(input_shape(state, whole), [0.0, 0.0, 10.0, 10.0]);
+    assert_shape(input_shape(state, given), [1.0, 2.0, 3.0, 4.0]);
+    assert_shape(input_shape(state, singleton), [5.0; 4]);
+
+    let result = create("reversed", Some([4.0, 3.0, 2.0, 1.0]));
+    assert_invalid_field(result, EntityKind::InputValue, "shape");
+
+    state
+        .input_value_repository
+        .update_by_id(given, &input_term(x, "given", [5.0, 6.0, 7.0, 8.0]))
+        .unwrap();
+    state.input_value_repository.remove_by_id(given).unwrap();
+    assert_shape(input_shape(state, whole), [0.0, 0.0, 10.0, 10.0]);
+}
+
+fn rules_follow_the_input_terms(state: &AppState) {
+    let problem_id = create_problem(state, None, "Heating");
+    let y = create_output_parameter(state, problem_id, "power");
+    let x = create_input_parameter(state, problem_id, "temperature");
+    let z = create_input_parameter(state, problem_id, "humidity");
+    assert!(rules(state, problem_id).is_empty());
+
+    let x1 = create_input_value(state, x, "cold");
+    assert_eq!(rules(state, problem_id), vec![antecedents(&[x1])]);
+
+    let x2 = create_input_value(state, x, "hot");
+    assert_eq!(rules(state, problem_id), vec![antecedents(&[x1]), antecedents(&[x2])]);
+
+    let z1 = create_input_value(state, z, "dry");
+    assert_eq!(
+        rules(state, problem_id),
+        vec![antecedents(&[x1, z1]), antecedents(&[x2, z1])]
+    );
+
+    let z2 = create_input_value(state, z, "wet");
+    assert_eq!(
+        rules(state, problem_id),
+        vec![
+            antecedents(&[x1, z1]),
+            antecedents(&[x1, z2]),
+            antecedents(&[x2, z1]),
+            antecedents(&[x2, z2]),
+        ]
+    );
+    let output_parameters: Vec<i64> = state
+        .output_value_repository
+        .get_by_problem_id(problem_id)
+        .unwrap()
+        .iter()
+        .map(|rule| rule.output_parameter_id)
+        .collect();
+    assert_eq!(output_parameters, vec![y; 4]);
+
+    state.input_value_repository.remove_by_id(x2).unwrap();
+    assert_eq!(
+        rules(state, problem_id),
+        vec![antecedents(&[x1, z1]), antecedents(&[x1, z2])]
+    );
+
+    state.input_value_repository.remove_by_id(x1).unwrap();
+    assert_eq!(rules(state, problem_id), vec![antecedents(&[z1]), antecedents(&[z2])]);
+}
+
+fn new_output_parameter_gets_a_rule_per_combination(state: &AppState) {
+    let problem_id = create_problem(state, None, "Heating");
+    let x = create_input_parameter(state, problem_id, "temperature");
+    let x1 = create_input_value(state, x, "cold");
+    let x2 = create_input_value(state, x, "hot");
+    let z = create_input_parameter(state, problem_id, "humidity");
+    let z1 = create_input_value(state, z, "dry");
+    assert!(rules(state, problem_id).is_empty());
+
+    create_output_parameter(state, problem_id, "power");
+    assert_eq!(
+        rules(state, problem_id),
+        vec![antecedents(&[x1, z1]), antecedents(&[x2, z1])]
+    );
+}
+
+fn removed_input_parameter_keeps_the_rules_of_its_first_term(state: &AppState) {
+    let problem_id = create_problem(state, None, "Heating");
+    let x = create_input_parameter(state, problem_id, "temperature");
+    let x1 = create_input_value(state, x, "cold");
+    let x2 = create_input_value(state, x, "hot");
+    let z = create_input_parameter(state, problem_id, "humidity");
+    create_input_value(state, z, "dry");
+    create_input_value(state, z, "wet");
+    create_output_parameter(state, problem_id, "power");
+    assert_eq!(rules(state, problem_id).len(), 4);
+
+    state.input_parameter_repository.remove_by_id(z).unwrap();
+    assert_eq!(rules(state, problem_id), vec![antecedents(&[x1]), antecedents(&[x2])]);
+    assert_not_found(state.input_parameter_repository.get_by_id(z), EntityKind::InputParameter, z);
+
+    state.input_parameter_repository.remove_by_id(x).unwrap();
+    assert!(rules(state, problem_id).is_empty());
+}
+
+fn removed_output_term_is_cleared_from_rules(state: &AppState) {
+    let problem_id = create_problem(state, None, "Heating");
+    let x = create_input_parameter(state, problem_id, "temperature");
+    create_input_value(state, x, "cold");
+    let y = create_output_parameter(state, problem_id, "power");
+    let low = create_fuzzy_output_value(state, y, "low");
+    let high = create_fuzzy_output_value(state, y, "high");
+    assert_shape(output_shape(state, low), [0.0, 0.0, 0.25, 0.75]);
+    assert_shape(output_shape(state, high), [0.25, 0.75, 1.0, 1.0]);
+
+    let rule = state.output_value_repository.get_by_problem_id(problem_id).unwrap()[0].id;
+    state
+        .output_value_repository
+        .update_fuzzy_output_value(rule, Some(high))
+        .unwrap();
+
+    state.fuzzy_output_value_repository.remove_by_id(high).unwrap();
+    assert_eq!(state.output_value_repository.get_by_id(rule).unwrap().fuzzy_output_value_id, None);
+    assert_shape(output_shape(state, low), [0.0, 0.0, 1.0, 1.0]);
+}
+
+fn moves_renumber_siblings(state: &AppState) {
+    let first = create_problem(state, None, "First");
+    let second = create_problem(state, None, "Second");
+    state.problem_repository.move_by_id(second, None, Some(0)).unwrap();
+    let roots: Vec<i64> = state
+        .problem_repository
+        .get_all_by_prev_problem_id(None)
+        .unwrap()
+        .iter()
+        .map(|problem| problem.id)
+        .collect();
+    assert_eq!(roots, vec![second, first]);
+
+    state.problem_repository.move_by_id(first, Some(second), None).unwrap();
+    assert_eq!(state.problem_repository.get_ancestor_ids(first).unwrap(), vec![second]);
+
+    let x = create_input_parameter(state, first, "temperature");
+    let z = create_input_parameter(state, first, "humidity");
+    let w = create_input_parameter(state, first, "wind");
+    state.input_parameter_repository.move_by_id(w, 0).unwrap();
+
+    // A Ruspini partition keeps its shapes in place while the terms change order
+    let low = create_input_value(state, x, "low");
+    let mid = create_input_value(state, x, "mid");
+    let high = create_input_value(state, x, "high");
+    state.input_value_repository.move_by_id(high, 0).unwrap();
+
+    let problem = state.problem_repository.get_full_by_id(first).unwrap();
+    let parameters: Vec<i64> = problem.input_parameters.iter().map(|p| p.id).collect();
+    assert_eq!(parameters, vec![w, x, z]);
+    let terms: Vec<i64> = problem.input_parameters[1].input_values.iter().map(|t| t.id).collect();
+    assert_eq!(terms, vec![high, low, mid]);
+    assert_shape(input_shape(state, high), [0.0, 0.0, 2.5, 7.5]);
+    assert_shape(input_shape(state, low), [2.5, 7.5, 8.125, 9.375]);
+    assert_shape(input_shape(state, mid), [8.125, 9.375, 10.0, 10.0]);
+}
+
+fn removed_problem_takes_its_subtree_and_records_along(state: &AppState) {
+    let (parent, image_id) = state
+        .problem_repository
+        .create(
+            &Problem {
+                id: 0,
+                prev_problem_id: None,
+                is_final: false,
+                name: "Plant".to_string(),
+                description: String::new(),
+                image_id: None,
+                created_at: String::new(),
+                updated_at: None,
+                input_parameters: vec![],
+                output_parameters: vec![],
+                output_values: vec![],
+            },
+            Some(&Image {
+                id: 0,
+                image_data: vec![1, 2, 3],
+                image_format: "png".to_string(),
+            }),
+        )
+        .unwrap();
+    let image_id = image_id.unwrap();
+    let child = create_problem(state, Some(parent), "Boiler");
+    let x = create_input_parameter(state, parent, "demand");
+    let y = create_output_parameter(state, child, "heat");
+
+    state
+        .parameter_link_repository
+        .create(&ParameterLink {
+            id: 0,
+            output_parameter_id: y,
+            input_parameter_id: x,
+            source_problem_id: 0,
+            target_problem_id: 0,
+        })
+        .unwrap();
+    state
+        .test_case_repository
+        .create(&TestCase {
+            id: 0,
+            problem_id: parent,
+            name: "idle".to_string(),
+            inputs: vec![TestCaseInput {
+                input_parameter_id: x,
+                crisp_value: 0.0,
+                input_value_id: None,
+            }],
+            expectations: vec![],
+        })
+        .unwrap();
+    state
+        .problem_snapshot_repository
+        .create(&ProblemSnapshot {
+            id: 0,
+            problem_id: parent,
+            name: "before".to_string(),
+            is_automatic: false,
+            definition: "{}".to_string(),
+            created_at: String::new(),
+        })
+        .unwrap();
+    state.journal_repository.create(&journal_entry(parent, "rename")).unwrap();
+    assert_eq!(state.parameter_link_repository.get_all().unwrap().len(), 1);
+
+    state.problem_repository.remove_by_id(parent).unwrap();
+
+    assert_not_found(state.problem_repository.get_full_by_id(parent), EntityKind::Problem, parent);
+    assert_not_found(state.problem_repository.get_full_by_id(child), EntityKind::Problem, child);
+    assert!(state.problem_repository.get_all_by_prev_problem_id(None).unwrap().is_empty());
+    assert!(state.test_case_repository.get_by_problem_id(parent).unwrap().is_empty());
+    assert!(state.problem_snapshot_repository.get_by_problem_id(parent).unwrap().is_empty());
+    assert!(state.journal_repository.get_by_problem_id(parent).unwrap().is_empty());
+    assert!(state.parameter_link_repository.get_all().unwrap().is_empty());
+    assert!(state.image_repository.get_by_id(image_id).is_err());
+    assert_not_found(state.problem_repository.remove_by_id(parent), EntityKind::Problem, parent);
+}
+
+fn clone_remaps_ids_to_the_copies(state: &AppState) {
+    let original = create_problem(state, None, "Heating");
+    let x = create_input_parameter(state, original, "temperature");
+    let x1 = create_input_value(state, x, "cold");
+    create_input_value(state, x, "hot");
+    let y = create_output_parameter(state, original, "power");
+    let y1 = create_fuzzy_output_value(state, y, "low");
+    create_fuzzy_output_value(state, y, "high");
+    let rule = state
+        .output_value_repository
+        .get_by_problem_id(original)
+        .unwrap()
+        .into_iter()
+        .find(|rule| rule.input_value_ids == antecedents(&[x1]))
+        .unwrap();
+    state
+        .output_value_repository
+        .update_fuzzy_output_value(rule.id, Some(y1))
+        .unwrap();
+    state
+        .output_value_repository
+        .update_hedges(rule.id, &format!("|{}:very|", x1), Some("somewhat"))
+        .unwrap();
+    state
+        .test_case_repository
+        .create(&TestCase {
+            id: 0,
+            problem_id: original,
+            name: "cold".to_string(),
+            inputs: vec![TestCaseInput {
+                input_parameter_id: x,
+                crisp_value: 1.0,
+                input_value_id: None,
+            }],
+            expectations: vec![TestCaseExpectation {
+                output_parameter_id: y,
+                expected_value: None,
+                tolerance: 0.0,
+                expected_fuzzy_output_value_id: Some(y1),
+            }],
+        })
+        .unwrap();
+    create_problem(state, Some(original), "Boiler");
+
+    let copy_id = clone_problem(state, original, None, Some("Copy"), true).unwrap();
+    let copy = state.problem_repository.get_full_by_id(copy_id).unwrap();
+    assert_eq!(copy.name, "Copy");
+    assert_eq!(copy.input_parameters.len(), 1);
+    assert_eq!(copy.output_parameters.len(), 1);
+
+    let copy_x = &copy.input_parameters[0];
+    let copy_x1 = copy_x.input_values[0].id;
+    let copy_y = &copy.output_parameters[0];
+    let copy_y1 = copy_y.fuzzy_output_values[0].id;
+    assert_ne!(copy_x.id, x);
+    assert_ne!(copy_x1, x1);
+    assert_eq!(copy_x.input_values.len(), 2);
+    assert_eq!(copy_y.fuzzy_output_values.len(), 2);
+
+    assert_eq!(copy.output_values.len(), 2);
+    let copy_rule = copy
+        .output_values
+        .iter()
+        .find(|rule| rule.input_value_ids == antecedents(&[copy_x1]))
+        .unwrap();
+    assert_eq!(copy_rule.output_parameter_id, copy_y.id);
+    assert_eq!(copy_rule.fuzzy_output_value_id, Some(copy_y1));
+    assert_eq!(copy_rule.antecedent_hedges, format!("|{}:very|", copy_x1));
+    assert_eq!(copy_rule.consequent_hedge.as_deref(), Some("somewhat"));
+
+    let test_cases = state.test_case_repository.get_by_problem_id(copy_id).unwrap();
+    assert_eq!(test_cases.len(), 1);
+    assert_eq!(test_cases[0].inputs[0].input_parameter_id, copy_x.id);
+    assert_eq!(test_cases[0].expectations[0].output_parameter_id, copy_y.id);
+    assert_eq!(test_cases[0].expectations[0].expected_fuzzy_output_value_id, Some(copy_y1));
+
+    let children = state.problem_repository.get_all_by_prev_problem_id(Some(copy_id)).unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].name, "Boiler");
+    assert_eq!(state.problem_repository.get_full_by_id(original).unwrap().output_values.len(), 2);
+}
+
+fn update_rejects_a_range_the_terms_do_not_fit(state: &AppState) {
+    let problem_id = create_problem(state, None, "Heating");
+    let x = create_input_parameter(state, problem_id, "temperature");
+    create_input_value(state, x, "cold");
+    let hot = create_input_value(state, x, "hot");
+    let before = input_shape(state, hot);
+
+    let change_range = ChangeInputParameterRangeUseCase::new(
+        state.input_parameter_repository.as_ref(),
+        state.input_value_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    );
+    let update = UpdateInputParameterByIdUseCase::new(
+        state.input_parameter_repository.as_ref(),
+        &change_range,
+        state.unit_of_work.as_ref(),
+    );
+    let model = |name: &str, end| InputParameter {
+        name: name.to_string(),
+        end,
+        ..state.input_parameter_repository.get_by_id(x).unwrap()
+    };
+
+    assert_invalid_field(update.execute(x, &model("temp", 5.0)), EntityKind::InputParameter, "end");
+    let unchanged = state.input_parameter_repository.get_by_id(x).unwrap();
+    assert_eq!((unchanged.name.as_str(), unchanged.end), ("temperature", 10.0));
+
+    update.execute(x, &model("temp", 10.0)).unwrap();
+    let renamed = state.input_parameter_repository.get_by_id(x).unwrap();
+    assert_eq!((renamed.name.as_str(), renamed.end), ("temp", 10.0));
+    assert_shape(input_shape(state, hot), before);
+}
+
+fn clone_into_own_subtree_is_rejected(state: &AppState) {
+    let original = create_problem(state, None, "Heating");
+    let group = create_problem(state, Some(original), "Boilers");
+    create_problem(state, Some(group), "Boiler");
+
+    for parent_id in [original, group] {
+        let result = clone_problem(state, original, Some(parent_id), None, true);
+        assert_invalid_field(result, EntityKind::Problem, "prev_problem_id");
+    }
+
+    assert_eq!(state.problem_repository.get_all_by_prev_problem_id(None).unwrap().len(), 1);
+    assert_eq!(state.problem_repository.get_all_by_prev_problem_id(Some(original)).unwrap().len(), 1);
+    assert_eq!(state.problem_repository.get_all_by_prev_problem_id(Some(group)).unwrap().len(), 1);
+}
+
+fn replace_definition_rejects_taken_ids(state: &AppState) {
+    let problem_id = create_problem(state, None, "Heating");
+    let x = create_input_parameter(state, problem_id, "temperature");
+    create_input_value(state, x, "cold");
+    let mut definition = state.problem_repository.get_full_by_id(problem_id).unwrap();
+    definition.name = "Restored".to_string();
+
+    state.problem_repository.clear_definition(problem_id).unwrap();
+    state.problem_repository.replace_definition(&definition).unwrap();
+    let restored = state.problem_repository.get_full_by_id(problem_id).unwrap();
+    assert_eq!(restored.name, "Restored");
+    assert_eq!(restored.input_parameters[0].id, x);
+    assert_eq!(restored.input_parameters[0].input_values.len(), 1);
+
+    state.problem_repository.clear_definition(problem_id).unwrap();
+    let other = create_problem(state, None, "Cooling");
+    assert_eq!(create_input_parameter(state, other, "temperature"), x);
+
+    definition.name = "Restored again".to_string();
+    let result = state.problem_repository.replace_definition(&definition);
+    assert!(matches!(result, Err(DomainError::Validation(_))), "{:?}", result);
+    let unchanged = state.problem_repository.get_full_by_id(problem_id).unwrap();
+    assert_eq!(unchanged.name, "Restored");
+    assert!(unchanged.input_parameters.is_empty());
+}
+
+fn missing_rows_are_not_found(state: &AppState) {
+    let id = 999;
+    assert_not_found(state.problem_repository.get_full_by_id(id), EntityKind::Problem, id);
+    assert_not_found(state.problem_repository.is_final(id), EntityKind::Problem, id);
+    assert_not_found(state.problem_repository.move_by_id(id, None, None), EntityKind::Problem, id);
+    assert_not_found(clone_problem(state, id, None, None, true), EntityKind::Problem, id);
+    assert_not_found(state.input_parameter_repository.get_by_id(id), EntityKind::InputParameter, id);
+    assert_not_found(state.input_value_repository.get_by_id(id), EntityKind::InputValue, id);
+    assert_not_found(state.output_parameter_repository.get_by_id(id), EntityKind::OutputParameter, id);
+    assert_not_found(
+        state.fuzzy_output_value_repository.get_by_id(id),
+        EntityKind::FuzzyOutputValue,
+        id,
+    );
+    assert_not_found(state.output_value_repository.get_by_id(id), EntityKind::OutputValue, id);
+    assert_not_found(state.test_case_repository.get_by_id(id), EntityKind::TestCase, id);
+    assert_not_found(state.parameter_link_repository.get_by_id(id), EntityKind::ParameterLink, id);
+    assert_not_found(
+        state.evaluation_history_repository.get_by_id(id),
+        EntityKind::EvaluationRecord,
+        id,
+    );
+    assert_not_found(
+        state.problem_snapshot_repository.get_by_id(id),
+        EntityKind::ProblemSnapshot,
+        id,
+    );
+    assert_not_found(state.journal_repository.set_undone(id, true), EntityKind::JournalEntry, id);
+}
+
+fn unit_of_work_rolls_back_every_repository(state: &AppState) {
+    let kept = create_problem(state, None, "Kept");
+
+    let result: Result<(), DomainError> = state.unit_of_work.run(|| {
+        let problem_id = create_problem(state, None, "Discarded");
+        create_input_parameter(state, problem_id, "temperature");
+        state.problem_repository.remove_by_id(kept)?;
+        Err(DomainError::Validation("stop".to_string()))
+    });
+    assert!(result.is_err());
+
+    let roots = state.problem_repository.get_all_by_prev_problem_id(None).unwrap();
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0].id, kept);
+
+    // Only the outermost commit counts
+    state
+        .unit_of_work
+        .run(|| {
+            state.unit_of_work.run(|| {
+                create_problem(state, None, "Nested");
+                Ok(())
+            })
+        })
+        .unwrap();
+    assert_eq!(state.problem_repository.get_all_by_prev_problem_id(None).unwrap().len(), 2);
+}
+
+fn input_parameter_is_fed_by_one_link(state: &AppState) {
+    let source = create_problem(state, None, "Source");
+    let target = create_problem(state, None, "Target");
+    let y = create_output_parameter(state, source, "heat");
+    let z = create_output_parameter(state, source, "steam");
+    let x = create_input_parameter(state, target, "demand");
+    let link = |output_parameter_id| ParameterLink {
+        id: 0,
+        output_parameter_id,
+        input_parameter_id: x,
+        source_problem_id: 0,
+        target_problem_id: 0,
+    };
+
+    let id = state.parameter_link_repository.create(&link(y)).unwrap();
+    let result = state.parameter_link_repository.create(&link(z));
+    assert_invalid_field(result, EntityKind::ParameterLink, "input_parameter_id");
+
+    let stored = state.parameter_link_repository.get_by_id(id).unwrap();
+    assert_eq!(stored.output_parameter_id, y);
+    assert_eq!(stored.source_problem_id, source);
+    assert_eq!(stored.target_problem_id, target);
+
+    // Links of removed parameters go with them
+    state.output_parameter_repository.remove_by_id(y).unwrap();
+    assert!(state.parameter_link_repository.get_all().unwrap().is_empty());
+    state.parameter_link_repository.create(&link(z)).unwrap();
+}
+
+fn undone_parameter_takes_its_link_along(state: &AppState) {
+    let source = create_problem(state, None, "Source");
+    let target = create_problem(state, None, "Target");
+    let y = create_output_parameter(state, source, "heat");
+    let z = create_output_parameter(state, source, "steam");
+    let w = create_input_parameter(state, target, "supply");
+    let link = |output_parameter_id, input_parameter_id| ParameterLink {
+        id: 0,
+        output_parameter_id,
+        input_parameter_id,
+        source_problem_id: 0,
+        target_problem_id: 0,
+    };
+    let kept = state.parameter_link_repository.create(&link(z, w)).unwrap();
+
+    let record = RecordMutationUseCase::new(
+        state.problem_repository.as_ref(),
+        state.journal_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    );
+    let x = record
+        .execute(target, "Create input parameter", || {
+            Ok(create_input_parameter(state, target, "demand"))
+        })
+        .unwrap();
+    state.parameter_link_repository.create(&link(y, x)).unwrap();
+
+    let undo = UndoUseCase::new(
+        state.problem_repository.as_ref(),
+        state.journal_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    );
+    undo.execute(target).unwrap().unwrap();
+
+    assert_not_found(state.input_parameter_repository.get_by_id(x), EntityKind::InputParameter, x);
+    let links = state.parameter_link_repository.get_all().unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].id, kept);
+    // The input parameter is free to be linked again once recreated
+    let x = create_input_parameter(state, target, "demand");
+    state.parameter_link_repository.create(&link(y, x)).unwrap();
+}
+
+fn replaced_definition_keeps_only_links_of_its_parameters(state: &AppState) {
+    let source = create_problem(state, None, "Source");
+    let target = create_problem(state, None, "Target");
+    let y = create_output_parameter(state, source, "heat");
+    let z = create_output_parameter(state, source, "steam");
+    let w = create_input_parameter(state, target, "supply");
+    let link = |output_parameter_id, input_parameter_id| ParameterLink {
+        id: 0,
+        output_parameter_id,
+        input_parameter_id,
+        source_problem_id: 0,
+        target_problem_id: 0,
+    };
+    let kept = state.parameter_link_repository.create(&link(z, w)).unwrap();
+    let definition = state.problem_repository.get_full_by_id(target).unwrap();
+
+    let x = create_input_parameter(state, target, "demand");
+    state.parameter_link_repository.create(&link(y, x)).unwrap();
+    state.problem_repository.replace_definition(&definition).unwrap();
+
+    let links = state.parameter_link_repository.get_all().unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].id, kept);
+    assert_eq!(links[0].input_parameter_id, w);
+}
+
+fn history_is_filtered_newest_first(state: &AppState) {
+    let record = |problem_id, method: &str| EvaluationRecord {
+        id: 0,
+        problem_id,
+        problem_name: "Heating".to_string(),
+        inputs: vec![],
+        method: method.to_string(),
+        resolution: 100,
+        outputs: vec![],
+        definition_hash: "hash".to_string(),
+        created_at: String::new(),
+    };
+    let first = state.evaluation_history_repository.create(&record(1, "centroid")).unwrap();
+    let second = state.evaluation_history_repository.create(&record(1, "bisector")).unwrap();
+    let third = state.evaluation_history_repository.create(&record(1, "centroid")).unwrap();
+    state.evaluation_history_repository.create(&record(2, "centroid")).unwrap();
+
+    let ids = |filter: EvaluationHistoryFilter| -> Vec<i64> {
+        state
+            .evaluation_history_repository
+            .get_filtered(&filter)
+            .unwrap()
+            .iter()
+            .map(|record| record.id)
+            .collect()
+    };
+    assert_eq!(
+        ids(EvaluationHistoryFilter { problem_id: Some(1), ..Default::default() }),
+        vec![third, second, first]
+    );
+    assert_eq!(
+        ids(EvaluationHistoryFilter {
+            problem_id: Some(1),
+            method: Some("centroid".to_string()),
+            ..Default::default()
+        }),
+        vec![third, first]
+    );
+    assert_eq!(
+        ids(EvaluationHistoryFilter {
+            problem_id: Some(1),
+            limit: Some(1),
+            offset: Some(1),
+            ..Default::default()
+        }),
+        vec![second]
+    );
+
+    assert_eq!(state.evaluation_history_repository.remove_by_problem_id(1).unwrap(), 3);
+    assert_eq!(ids(EvaluationHistoryFilter::default()).len(), 1);
+}
+
+fn journal_entry(problem_id: i64, description: &str) -> JournalEntry {
+    JournalEntry {
+        id: 0,
+        problem_id,
+        description: description.to_string(),
+        before: "{}".to_string(),
+        after: "{}".to_string(),
+        is_undone: false,
+        created_at: String::new(),
+    }
+}
+
+fn journal_and_snapshots_are_pruned_oldest_first(state: &AppState) {
+    let problem_id = create_problem(state, None, "Heating");
+    let entries: Vec<i64> = ["first", "second", "third"]
+        .iter()
+        .map(|description| state.journal_repository.create(&journal_entry(problem_id, description)).unwrap())
+        .collect();
+    state.journal_repository.set_undone(entries[2], true).unwrap();
+    state.journal_repository.set_undone(entries[1], true).unwrap();
+    assert_eq!(state.journal_repository.get_last_done(problem_id).unwrap().unwrap().id, entries[0]);
+    assert_eq!(state.journal_repository.get_first_undone(problem_id).unwrap().unwrap().id, entries[1]);
+
+    state.journal_repository.prune(problem_id, 2).unwrap();
+    let kept: Vec<i64> = state
+        .journal_repository
+        .get_by_problem_id(problem_id)
+        .unwrap()
+        .iter()
+        .map(|entry| entry.id)
+        .collect();
+    assert_eq!(kept, vec![entries[2], entries[1]]);
+    state.journal_repository.remove_undone_by_problem_id(problem_id).unwrap();
+    assert!(state.journal_repository.get_by_problem_id(problem_id).unwrap().is_empty());
+
+    let snapshot = |name: &str, is_automatic| ProblemSnapshot {
+        id: 0,
+        problem_id,
+        name: name.to_string(),
+        is_automatic,
+        definition: "{}".to_string(),
+        created_at: String::new(),
+    };
+    let manual = state.problem_snapshot_repository.create(&snapshot("manual", false)).unwrap();
+    state.problem_snapshot_repository.create(&snapshot("auto 1", true)).unwrap();
+    let newest = state.problem_snapshot_repository.create(&snapshot("auto 2", true)).unwrap();
+
+    state.problem_snapshot_repository.prune_automatic(problem_id, 1).unwrap();
+    let kept: Vec<i64> = state
+        .problem_snapshot_repository
+        .get_by_problem_id(problem_id)
+        .unwrap()
+        .iter()
+        .map(|snapshot| snapshot.id)
+        .collect();
+    assert_eq!(kept, vec![newest, manual]);
+}
+
+fn imported_problem_can_be_evaluated(state: &AppState) {
+    let term = |temp_id, value: &str, [a, b, c, d]: [f32; 4]| {
+        serde_json::json!({
+            "temp_id": temp_id, "value": value, "a": a, "b": b, "c": c, "d": d, "is_triangle": false
+        })
+    };
+    let dto: ExportedProblem = serde_json::from_value(serde_json::json!({
+        "name": "Heating",
+        "description": "",
+        "is_final": true,
+        "image": null,
+        "input_parameters": [{
+            "temp_id": 1, "name": "temperature", "start": 0.0, "end": 10.0,
+            "values": [term(11, "cold", [0.0, 0.0, 2.0, 8.0]), term(12, "hot", [2.0, 8.0, 10.0, 10.0])],
+        }],
+        "output_parameters": [{
+            "temp_id": 2, "name": "power", "start": 0.0, "end": 1.0,
+            "values": [term(21, "low", [0.0, 0.0, 0.2, 0.8]), term(22, "high", [0.2, 0.8, 1.0, 1.0])],
+        }],
+        "output_values": [
+            { "output_parameter_temp_id": 2, "fuzzy_output_value_temp_id": 22, "input_value_temp_ids": [11] },
+            { "output_parameter_temp_id": 2, "fuzzy_output_value_temp_id": 21, "input_value_temp_ids": [12] },
+        ],
+        "children": [],
+    }))
+    .unwrap();
+
+    import_problem(state).execute(None, dto).unwrap();
+
+    let problem_id = state.problem_repository.get_all_by_prev_problem_id(None).unwrap()[0].id;
+    let input_parameter_id = state.problem_repository.get_full_by_id(problem_id).unwrap().input_parameters[0].id;
+    let power = |crisp_value| {
+        let result = EvaluateFuzzySystemUseCase::new(state.problem_repository.as_ref())
+            .execute(
+                problem_id,
+                vec![FuzzyEvaluationInput {
+                    input_parameter_id,
+                    crisp_value,
+                    uncertainty: None,
+                    input_value_id: None,
+                    category_degrees: None,
+                }],
+                DefuzzificationMethod::Centroid,
+                101,
+            )
+            .unwrap();
+        assert_eq!(result.problem_name, "Heating");
+        result.outputs[0].crisp_value
+    };
+    assert!(power(1.0) > 0.6);
+    assert!(power(9.0) < 0.4);
+}
+
+fn import_problem(state: &AppState) -> ImportProblemUseCase<'_> {
+    ImportProblemUseCase::new(
+        state.problem_repository.as_ref(),
+        state.input_parameter_repository.as_ref(),
+        state.input_value_repository.as_ref(),
+        state.output_parameter_repository.as_ref(),
+        state.fuzzy_output_value_repository.as_ref(),
+        state.output_value_repository.as_ref(),
+        state.test_case_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    )
+}
+
+fn export_problem(state: &AppState) -> ExportProblemUseCase<'_> {
+    ExportProblemUseCase::new(
+        state.problem_repository.as_ref(),
+        state.image_repository.as_ref(),
+        state.test_case_repository.as_ref(),
+    )
+}
+
+fn clone_problem(
+    state: &AppState,
+    id: i64,
+    parent_id: Option<i64>,
+    name: Option<&str>,
+    with_children: bool,
+) -> Result<i64, DomainError> {
+    let export = export_problem(state);
+    let import = import_problem(state);
+    CloneProblemUseCase::new(state.problem_repository.as_ref(), &export, &import, state.unit_of_work.as_ref())
+        .execute(id, parent_id, name, with_children)
+}
+
+/// Ids of the terms of the problem's first input parameter and of the rule of each,
+/// for a problem with one input parameter
+fn term_and_rule_ids(state: &AppState, problem_id: i64) -> (Vec<i64>, Vec<i64>) {
+    let problem = state.problem_repository.get_full_by_id(problem_id).unwrap();
+    let terms: Vec<i64> = problem.input_parameters[0].input_values.iter().map(|v| v.id).collect();
+    let rules = terms
+        .iter()
+        .map(|id| {
+            let antecedents = antecedents(&[*id]);
+            problem.output_values.iter().find(|r| r.input_value_ids == antecedents).unwrap().id
+        })
+        .collect();
+    (terms, rules)
+}
+
+fn restored_snapshot_keeps_ids_and_links(state: &AppState) {
+    let source = create_problem(state, None, "Source");
+    let target = create_problem(state, None, "Target");
+    let y = create_output_parameter(state, source, "heat");
+    create_output_parameter(state, target, "power");
+    let x = create_input_parameter(state, target, "demand");
+    let x1 = create_input_value(state, x, "low");
+    create_input_value(state, x, "high");
+    let link = state
+        .parameter_link_repository
+        .create(&ParameterLink {
+            id: 0,
+            output_parameter_id: y,
+            input_parameter_id: x,
+            source_problem_id: 0,
+            target_problem_id: 0,
+        })
+        .unwrap();
+
+    let export = export_problem(state);
+    let create_snapshot = CreateProblemSnapshotUseCase::new(&export, state.problem_snapshot_repository.as_ref());
+    let snapshot = create_snapshot.execute(target, "Saved").unwrap();
+    let (terms, rules) = term_and_rule_ids(state, target);
+
+    state.input_value_repository.remove_by_id(x1).unwrap();
+    create_input_value(state, x, "extreme");
+    create_input_parameter(state, target, "season");
+
+    let import = import_problem(state);
+    RestoreProblemSnapshotUseCase::new(
+        state.problem_snapshot_repository.as_ref(),
+        &create_snapshot,
+        &import,
+        state.unit_of_work.as_ref(),
+    )
+    .execute(snapshot)
+    .unwrap();
+
+    let restored = state.problem_repository.get_full_by_id(target).unwrap();
+    assert_eq!(restored.input_parameters.len(), 1);
+    assert_eq!(restored.input_parameters[0].id, x);
+    // The removed term comes back under a new id, the other one keeps its own
+    let (restored_terms, restored_rules) = term_and_rule_ids(state, target);
+    assert_eq!(restored_terms.len(), 2);
+    assert_ne!(restored_terms[0], terms[0]);
+    assert_ne!(restored_rules[0], rules[0]);
+    assert_eq!(restored_terms[1], terms[1]);
+    assert_eq!(restored_rules[1], rules[1]);
+
+    let links = state.parameter_link_repository.get_all().unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].id, link);
+    assert_eq!(links[0].input_parameter_id, x);
+}
+
+fn merged_file_keeps_ids_and_links(state: &AppState) {
+    let source = create_problem(state, None, "Source");
+    let target = create_problem(state, None, "Target");
+    let y = create_output_parameter(state, source, "heat");
+    create_output_parameter(state, target, "power");
+    let x = create_input_parameter(state, target, "demand");
+    create_input_value(state, x, "low");
+    create_input_value(state, x, "high");
+    let link = state
+        .parameter_link_repository
+        .create(&ParameterLink {
+            id: 0,
+            output_parameter_id: y,
+            input_parameter_id: x,
+            source_problem_id: 0,
+            target_problem_id: 0,
+        })
+        .unwrap();
+    let (terms, rules) = term_and_rule_ids(state, target);
+
+    let term = |temp_id, value: &str, [a, b, c, d]: [f32; 4]| {
+        serde_json::json!({
+            "temp_id": temp_id, "value": value, "a": a, "b": b, "c": c, "d": d, "is_triangle": false
+        })
+    };
+    let dto: ExportedProblem = serde_json::from_value(serde_json::json!({
+        "name": "Target",
+        "description": "",
+        "is_final": true,
+        "image": null,
+        "input_parameters": [{
+            "temp_id": 1, "name": "demand", "start": 0.0, "end": 10.0, "partition_mode": "free_form",
+            "values": [term(11, "high", [4.0, 6.0, 10.0, 10.0]), term(12, "extreme", [9.0, 10.0, 10.0, 10.0])],
+        }],
+        "output_parameters": [],
+        "output_values": [],
+        "children": [],
+    }))
+    .unwrap();
+
+    let export = export_problem(state);
+    let create_snapshot = CreateProblemSnapshotUseCase::new(&export, state.problem_snapshot_repository.as_ref());
+    let import = import_problem(state);
+    MergeImportUseCase::new(&export, &import, &create_snapshot, state.unit_of_work.as_ref())
+        .execute(target, dto)
+        .unwrap();
+
+    let merged = state.problem_repository.get_full_by_id(target).unwrap();
+    assert_eq!(merged.input_parameters.len(), 1);
+    assert_eq!(merged.input_parameters[0].id, x);
+    let merged_terms: Vec<(i64, &str)> = merged.input_parameters[0]
+        .input_values
+        .iter()
+        .map(|v| (v.id, v.value.as_str()))
+        .collect();
+    assert_eq!(merged_terms.len(), 3);
+    assert!(merged_terms.contains(&(terms[0], "low")));
+    assert!(merged_terms.contains(&(terms[1], "high")));
+    for rule in rules {
+        assert!(merged.output_values.iter().any(|r| r.id == rule));
+    }
+
+    let links = state.parameter_link_repository.get_all().unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].id, link);
+    assert_eq!(links[0].input_parameter_id, x);
+}
+
+/// Edits touching every side effect the repositories have on a definition
+fn edit_problem(state: &AppState) -> i64 {
+    let problem_id = create_problem(state, None, "Heating");
+    let y = create_output_parameter(state, problem_id, "power");
+    let x = create_input_parameter(state, problem_id, "temperature");
+    let z = create_input_parameter(state, problem_id, "humidity");
+    let x1 = create_input_value(state, x, "cold");
+    let x2 = create_input_value(state, x, "mild");
+    let x3 = create_input_value(state, x, "hot");
+    let z1 = create_input_value(state, z, "dry");
+    create_input_value(state, z, "wet");
+    let y1 = create_fuzzy_output_value(state, y, "low");
+    let y2 = create_fuzzy_output_value(state, y, "medium");
+    create_fuzzy_output_value(state, y, "high");
+
+    state
+        .input_value_repository
+        .update_by_id(x2, &input_term(x, "mild", [1.0, 4.0, 6.0, 9.0]))
+        .unwrap();
+    state.input_value_repository.move_by_id(x3, 0).unwrap();
+    state.input_value_repository.remove_by_id(z1).unwrap();
+    for (index, rule) in state.output_value_repository.get_by_problem_id(problem_id).unwrap().iter().enumerate() {
+        let consequent = if index % 2 == 0 { y1 } else { y2 };
+        state
+            .output_value_repository
+            .update_fuzzy_output_value(rule.id, Some(consequent))
+            .unwrap();
+    }
+    state.fuzzy_output_value_repository.remove_by_id(y2).unwrap();
+    state.input_value_repository.remove_by_id(x1).unwrap();
+    create_output_parameter(state, problem_id, "steam");
+    state
+        .input_parameter_repository
+        .update_partition_mode(x, PartitionMode::FreeForm)
+        .unwrap();
+    state
+        .input_value_repository
+        .create(&input_term(x, "scorching", [0.0; 4]), Some([8.0, 9.0, 10.0, 10.0]))
+        .unwrap();
+    state.input_parameter_repository.remove_by_id(z).unwrap();
+    problem_id
+}
+
+/// The stored definition without timestamps
+fn definition(state: &AppState, problem_id: i64) -> serde_json::Value {
+    let mut problem = state.problem_repository.get_full_by_id(problem_id).unwrap();
+    problem.created_at = String::new();
+    problem.updated_at = None;
+    serde_json::to_value(problem).unwrap()
+}
+
+#[test]
+fn backends_end_up_with_the_same_definition() {
+    let [sqlite, in_memory] = [sqlite::state(), in_memory::state()].map(|state| {
+        let problem_id = edit_problem(&state);
+        definition(&state, problem_id)
+    });
+    assert_eq!(sqlite, in_memory);
+}
+
+fn test_case_keeps_its_category_when_categories_move(state: &AppState) {
+    let problem_id = create_problem(state, None, "Paint");
+    let x = create_input_parameter(state, problem_id, "colour");
+    state.input_parameter_repository.update_kind(x, ParameterKind::Categorical).unwrap();
+    let red = create_input_value(state, x, "red");
+    let blue = create_input_value(state, x, "blue");
+    let y = create_output_parameter(state, problem_id, "price");
+    let low = create_fuzzy_output_value(state, y, "low");
+    let high = create_fuzzy_output_value(state, y, "high");
+    for rule in state.output_value_repository.get_by_problem_id(problem_id).unwrap() {
+        let term = if rule.input_value_ids == antecedents(&[red]) { low } else { high };
+        state
+            .output_value_repository
+            .update_fuzzy_output_value(rule.id, Some(term))
+            .unwrap();
+    }
+    state
+        .test_case_repository
+        .create(&TestCase {
+            id: 0,
+            problem_id,
+            name: "blue".to_string(),
+            inputs: vec![TestCaseInput {
+                input_parameter_id: x,
+                crisp_value: 0.0,
+                input_value_id: Some(blue),
+            }],
+            expectations: vec![TestCaseExpectation {
+                output_parameter_id: y,
+                expected_value: None,
+                tolerance: 0.0,
+                expected_fuzzy_output_value_id: Some(high),
+            }],
+        })
+        .unwrap();
+    let run = |problem_id| {
+        RunTestCasesUseCase::new(
+            state.problem_repository.as_ref(),
+            state.test_case_repository.as_ref(),
+        )
+        .execute(problem_id, DefuzzificationMethod::Centroid, 101)
+        .unwrap()
+    };
+    assert_eq!(run(problem_id).passed_count, 1);
+
+    state.input_value_repository.move_by_id(blue, 0).unwrap();
+    assert_eq!(run(problem_id).passed_count, 1);
+
+    // A copy selects the copied category
+    let copy_id = clone_problem(state, problem_id, None, Some("Copy"), false).unwrap();
+    assert_eq!(run(copy_id).passed_count, 1);
+}
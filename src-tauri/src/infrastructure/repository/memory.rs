@@ -0,0 +1,16 @@
+pub mod evaluation_history_repository;
+pub mod fuzzy_output_value_repository;
+pub mod image_repository;
+pub mod input_parameter_repository;
+pub mod input_value_repository;
+pub mod journal_repository;
+pub mod output_parameter_repository;
+pub mod output_value_repository;
+pub mod parameter_link_repository;
+pub mod position;
+pub mod problem_repository;
+pub mod problem_snapshot_repository;
+pub mod store;
+pub mod terms;
+pub mod test_case_repository;
+pub mod unit_of_work;
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::{
+    domain::{
+        entities::evaluation_record::{EvaluationHistoryFilter, EvaluationRecord},
+        error::{DomainError, EntityKind},
+        repository::EvaluationHistoryRepository,
+    },
+    infrastructure::repository::memory::store::MemoryStore,
+};
+
+pub struct InMemoryEvaluationHistoryRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryEvaluationHistoryRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+/// Whether the record passes every condition the filter sets
+fn matches(filter: &EvaluationHistoryFilter, record: &EvaluationRecord) -> bool {
+    filter.problem_id.is_none_or(|problem_id| record.problem_id == problem_id)
+        && filter.method.as_ref().is_none_or(|method| &record.method == method)
+        && filter
+            .definition_hash
+            .as_ref()
+            .is_none_or(|definition_hash| &record.definition_hash == definition_hash)
+        && filter.from.as_ref().is_none_or(|from| &record.created_at >= from)
+        && filter.to.as_ref().is_none_or(|to| &record.created_at <= to)
+}
+
+impl EvaluationHistoryRepository for InMemoryEvaluationHistoryRepository {
+    fn get_filtered(
+        &self,
+        filter: &EvaluationHistoryFilter,
+    ) -> Result<Vec<EvaluationRecord>, DomainError> {
+        self.store.read(|tables| {
+            let mut records: Vec<EvaluationRecord> = tables
+                .evaluation_history
+                .rows()
+                .filter(|record| matches(filter, record))
+                .cloned()
+                .collect();
+            records.sort_by(|left, right| {
+                right
+                    .created_at
+                    .cmp(&left.created_at)
+                    .then(right.id.cmp(&left.id))
+            });
+
+            // A negative limit means "no limit", as in SQLite
+            let offset = filter.offset.unwrap_or(0).max(0) as usize;
+            let limit = filter
+                .limit
+                .filter(|limit| *limit >= 0)
+                .map_or(usize::MAX, |limit| limit as usize);
+            Ok(records.into_iter().skip(offset).take(limit).collect())
+        })
+    }
+
+    fn get_by_id(&self, id: i64) -> Result<EvaluationRecord, DomainError> {
+        self.store.read(|tables| {
+            tables
+                .evaluation_history
+                .get(id)
+                .cloned()
+                .ok_or_else(|| DomainError::not_found(EntityKind::EvaluationRecord, id))
+        })
+    }
+
+    fn create(&self, model: &EvaluationRecord) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            Ok(tables.evaluation_history.insert(|id| EvaluationRecord {
+                id,
+                created_at: Utc::now().to_rfc3339(),
+                ..model.clone()
+            }))
+        })
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            tables
+                .evaluation_history
+                .remove(id)
+                .map(|_| ())
+                .ok_or_else(|| DomainError::not_found(EntityKind::EvaluationRecord, id))
+        })
+    }
+
+    fn remove_by_problem_id(&self, problem_id: i64) -> Result<usize, DomainError> {
+        self.store.write(|tables| {
+            Ok(tables
+                .evaluation_history
+                .retain(|record| record.problem_id != problem_id))
+        })
+    }
+}
@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::{
+            fuzzy_output_value::FuzzyOutputValue, lower_membership::LowerMembership,
+            partition_mode::PartitionMode,
+        },
+        error::{DomainError, EntityKind},
+        repository::FuzzyOutputValueRepository,
+    },
+    infrastructure::repository::memory::{
+        position,
+        store::{no_rows, MemoryStore, Positioned, Tables},
+        terms::{self, Term},
+    },
+};
+
+pub struct InMemoryFuzzyOutputValueRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryFuzzyOutputValueRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+impl FuzzyOutputValueRepository for InMemoryFuzzyOutputValueRepository {
+    fn get_by_output_parameter_id(&self, output_parameter_id: i64) -> Result<Vec<FuzzyOutputValue>, DomainError> {
+        self.store.read(|tables| {
+            Ok(position::siblings(&tables.fuzzy_output_value, output_parameter_id)
+                .into_iter()
+                .map(|term| term.row.clone())
+                .collect())
+        })
+    }
+
+    fn get_by_id(&self, id: i64) -> Result<FuzzyOutputValue, DomainError> {
+        self.store.read(|tables| {
+            tables
+                .fuzzy_output_value
+                .get(id)
+                .map(|term| term.row.clone())
+                .ok_or_else(|| DomainError::not_found(EntityKind::FuzzyOutputValue, id))
+        })
+    }
+
+    fn create(&self, model: &FuzzyOutputValue, shape: Option<[f32; 4]>) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            let output_parameter_id = model.output_parameter_id;
+            let parameter = &tables
+                .output_parameter
+                .get(output_parameter_id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::OutputParameter, output_parameter_id))?
+                .row;
+            let range = (parameter.start, parameter.end);
+            let free_form = parameter.partition_mode == PartitionMode::FreeForm;
+
+            let [a, b, c, d] = terms::new_term_breakpoints(
+                &mut tables.fuzzy_output_value,
+                EntityKind::FuzzyOutputValue,
+                output_parameter_id,
+                range,
+                free_form,
+                shape,
+            )?;
+            let position = position::next(&tables.fuzzy_output_value, output_parameter_id);
+            Ok(tables.fuzzy_output_value.insert(|id| Positioned {
+                position,
+                row: FuzzyOutputValue {
+                    id,
+                    output_parameter_id,
+                    value: model.value.clone(),
+                    a,
+                    b,
+                    c,
+                    d,
+                    is_triangle: false,
+                    lower: None,
+                },
+            }))
+        })
+    }
+
+    fn create_raw(&self, model: &FuzzyOutputValue) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            let position = position::next(&tables.fuzzy_output_value, model.output_parameter_id);
+            Ok(tables.fuzzy_output_value.insert(|id| Positioned {
+                position,
+                row: FuzzyOutputValue {
+                    id,
+                    ..model.clone()
+                },
+            }))
+        })
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let term = &tables
+                .fuzzy_output_value
+                .get(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::FuzzyOutputValue, id))?
+                .row;
+            let output_parameter_id = term.output_parameter_id;
+            let breakpoints = term.breakpoints();
+            let fuzzy_output_values_number = tables
+                .fuzzy_output_value
+                .rows()
+                .filter(|term| term.row.output_parameter_id == output_parameter_id)
+                .count();
+
+            // Free-form terms are stored as given, without reshaping their neighbours
+            if fuzzy_output_values_number > 1 && !is_free_form(tables, output_parameter_id)? {
+                terms::close_gap(&mut tables.fuzzy_output_value, output_parameter_id, breakpoints);
+            }
+
+            tables.fuzzy_output_value.remove(id);
+
+            // Rules concluding the term are kept without a consequent
+            for output_value in tables.output_value.rows_mut() {
+                if output_value.fuzzy_output_value_id == Some(id) {
+                    output_value.fuzzy_output_value_id = None;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    fn update_by_id(&self, id: i64, model: &FuzzyOutputValue) -> Result<(), DomainError> {
+        let breakpoints = model.breakpoints();
+        terms::validate_breakpoints(EntityKind::FuzzyOutputValue, id, breakpoints)?;
+
+        self.store.write(|tables| {
+            let term = &mut tables
+                .fuzzy_output_value
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::FuzzyOutputValue, id))?
+                .row;
+            term.value = model.value.clone();
+            term.set_left(model.a, model.b);
+            term.set_right(model.c, model.d);
+            let output_parameter_id = term.output_parameter_id;
+
+            // Free-form terms are stored as given, without reshaping their neighbours
+            if !is_free_form(tables, output_parameter_id)? {
+                terms::fit_neighbours(&mut tables.fuzzy_output_value, output_parameter_id, id, breakpoints);
+            }
+            Ok(())
+        })
+    }
+
+    fn update_shape(&self, id: i64, model: &FuzzyOutputValue) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let term = &mut tables
+                .fuzzy_output_value
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::FuzzyOutputValue, id))?
+                .row;
+            term.set_left(model.a, model.b);
+            term.set_right(model.c, model.d);
+            term.lower = model.lower;
+            Ok(())
+        })
+    }
+
+    fn update_lower(&self, id: i64, lower: Option<&LowerMembership>) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            tables
+                .fuzzy_output_value
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::FuzzyOutputValue, id))?
+                .row
+                .lower = lower.copied();
+            Ok(())
+        })
+    }
+
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let ids = position::move_to(&mut tables.fuzzy_output_value, EntityKind::FuzzyOutputValue, id, position)?;
+            let output_parameter_id = tables
+                .fuzzy_output_value
+                .get(id)
+                .ok_or_else(no_rows)?
+                .row
+                .output_parameter_id;
+            if !is_free_form(tables, output_parameter_id)? {
+                terms::keep_shapes_in_place(&mut tables.fuzzy_output_value, output_parameter_id, &ids);
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Whether the terms of the parameter are stored as given instead of keeping a
+/// Ruspini partition
+fn is_free_form(tables: &Tables, output_parameter_id: i64) -> Result<bool, DomainError> {
+    tables
+        .output_parameter
+        .get(output_parameter_id)
+        .map(|parameter| parameter.row.partition_mode == PartitionMode::FreeForm)
+        .ok_or_else(no_rows)
+}
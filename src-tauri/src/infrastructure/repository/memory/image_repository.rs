@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::image::Image,
+        error::{DomainError, EntityKind},
+        repository::ImageRepository,
+    },
+    infrastructure::repository::memory::store::MemoryStore,
+};
+
+pub struct InMemoryImageRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryImageRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+impl ImageRepository for InMemoryImageRepository {
+    fn get_by_id(&self, id: i64) -> Result<Image, DomainError> {
+        self.store.read(|tables| {
+            tables
+                .image
+                .get(id)
+                .cloned()
+                .ok_or_else(|| DomainError::not_found(EntityKind::Image, id))
+        })
+    }
+
+    fn update_by_id(&self, id: i64, model: &Image) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            if let Some(image) = tables.image.get_mut(id) {
+                image.image_data = model.image_data.clone();
+                image.image_format = model.image_format.clone();
+            }
+            Ok(())
+        })
+    }
+}
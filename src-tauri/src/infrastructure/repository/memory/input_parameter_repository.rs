@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::{
+            input_parameter::InputParameter, parameter_kind::ParameterKind,
+            parameter_metadata::ParameterMetadata, partition_mode::PartitionMode,
+        },
+        error::{DomainError, EntityKind},
+        repository::InputParameterRepository,
+    },
+    infrastructure::repository::memory::{
+        position,
+        store::{MemoryStore, Positioned},
+    },
+};
+
+pub struct InMemoryInputParameterRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryInputParameterRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+
+    fn update(&self, id: i64, update: impl FnOnce(&mut InputParameter)) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let parameter = tables
+                .input_parameter
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::InputParameter, id))?;
+            update(&mut parameter.row);
+            Ok(())
+        })
+    }
+}
+
+impl InputParameterRepository for InMemoryInputParameterRepository {
+    fn get_by_id(&self, id: i64) -> Result<InputParameter, DomainError> {
+        self.store.read(|tables| {
+            tables
+                .input_parameter
+                .get(id)
+                .map(|parameter| parameter.row.clone())
+                .ok_or_else(|| DomainError::not_found(EntityKind::InputParameter, id))
+        })
+    }
+
+    fn create(&self, problem_id: i64, model: &InputParameter) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            let position = position::next(&tables.input_parameter, problem_id);
+            Ok(tables.input_parameter.insert(|id| Positioned {
+                position,
+                row: InputParameter {
+                    id,
+                    problem_id,
+                    name: model.name.clone(),
+                    start: model.start,
+                    end: model.end,
+                    partition_mode: PartitionMode::default(),
+                    kind: ParameterKind::default(),
+                    metadata: ParameterMetadata::default(),
+                    input_values: vec![],
+                },
+            }))
+        })
+    }
+
+    fn create_raw(&self, problem_id: i64, model: &InputParameter) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            let position = position::next(&tables.input_parameter, problem_id);
+            Ok(tables.input_parameter.insert(|id| Positioned {
+                position,
+                row: InputParameter {
+                    id,
+                    problem_id,
+                    input_values: vec![],
+                    ..model.clone()
+                },
+            }))
+        })
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let problem_id = tables
+                .input_parameter
+                .get(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::InputParameter, id))?
+                .row
+                .problem_id;
+            let output_value_ids: Vec<i64> = tables
+                .output_values_of_problem(problem_id)
+                .map(|ov| ov.id)
+                .collect();
+
+            if tables.input_parameter_ids(problem_id).len() == 1 {
+                for output_value_id in output_value_ids {
+                    tables.output_value.remove(output_value_id);
+                }
+            } else if let Some(saving_input_value_id) = tables
+                .input_value
+                .rows()
+                .find(|term| term.row.input_parameter_id == id)
+                .map(|term| term.row.id)
+            {
+                // Rules with the parameter's first term are kept without it, the
+                // ones with its other terms would be duplicates and are dropped
+                let saving = format!("|{}|", saving_input_value_id);
+                for output_value_id in output_value_ids {
+                    let Some(output_value) = tables.output_value.get_mut(output_value_id) else {
+                        continue;
+                    };
+                    if output_value.input_value_ids.contains(&saving) {
+                        output_value.input_value_ids = output_value.input_value_ids.replace(&saving, "");
+                    } else {
+                        tables.output_value.remove(output_value_id);
+                    }
+                }
+            }
+
+            tables.parameter_link.retain(|link| link.input_parameter_id != id);
+            tables.input_parameter.remove(id);
+            Ok(())
+        })
+    }
+
+    fn update_by_id(&self, id: i64, model: &InputParameter) -> Result<(), DomainError> {
+        // Like an UPDATE matching no row, a missing parameter is not an error here
+        match self.update(id, |parameter| {
+            parameter.name = model.name.clone();
+            parameter.start = model.start;
+            parameter.end = model.end;
+        }) {
+            Err(DomainError::NotFound { .. }) => Ok(()),
+            result => result,
+        }
+    }
+
+    fn update_partition_mode(&self, id: i64, mode: PartitionMode) -> Result<(), DomainError> {
+        self.update(id, |parameter| parameter.partition_mode = mode)
+    }
+
+    fn update_kind(&self, id: i64, kind: ParameterKind) -> Result<(), DomainError> {
+        self.update(id, |parameter| parameter.kind = kind)
+    }
+
+    fn update_metadata(&self, id: i64, metadata: &ParameterMetadata) -> Result<(), DomainError> {
+        self.update(id, |parameter| parameter.metadata = metadata.clone())
+    }
+
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            position::move_to(&mut tables.input_parameter, EntityKind::InputParameter, id, position)?;
+            Ok(())
+        })
+    }
+}
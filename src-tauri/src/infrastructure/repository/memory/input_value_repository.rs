@@ -0,0 +1,272 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::{
+            input_value::InputValue, lower_membership::LowerMembership,
+            partition_mode::PartitionMode,
+        },
+        error::{DomainError, EntityKind},
+        repository::InputValueRepository,
+    },
+    infrastructure::repository::memory::{
+        position,
+        store::{
+            format_input_value_ids, new_rule, no_rows, parse_input_value_ids, MemoryStore,
+            Positioned, Tables,
+        },
+        terms::{self, Term},
+    },
+};
+
+pub struct InMemoryInputValueRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryInputValueRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+impl InputValueRepository for InMemoryInputValueRepository {
+    fn get_by_input_parameter_id(&self, input_parameter_id: i64) -> Result<Vec<InputValue>, DomainError> {
+        self.store.read(|tables| {
+            Ok(position::siblings(&tables.input_value, input_parameter_id)
+                .into_iter()
+                .map(|term| term.row.clone())
+                .collect())
+        })
+    }
+
+    fn get_by_id(&self, id: i64) -> Result<InputValue, DomainError> {
+        self.store.read(|tables| {
+            tables
+                .input_value
+                .get(id)
+                .map(|term| term.row.clone())
+                .ok_or_else(|| DomainError::not_found(EntityKind::InputValue, id))
+        })
+    }
+
+    fn create(&self, model: &InputValue, shape: Option<[f32; 4]>) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            let input_parameter_id = model.input_parameter_id;
+            let number_of_input_values = tables
+                .input_value
+                .rows()
+                .filter(|term| term.row.input_parameter_id == input_parameter_id)
+                .count();
+
+            let parameter = &tables
+                .input_parameter
+                .get(input_parameter_id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::InputParameter, input_parameter_id))?
+                .row;
+            let range = (parameter.start, parameter.end);
+            let free_form = parameter.partition_mode == PartitionMode::FreeForm;
+            let problem_id = parameter.problem_id;
+
+            let [a, b, c, d] = terms::new_term_breakpoints(
+                &mut tables.input_value,
+                EntityKind::InputValue,
+                input_parameter_id,
+                range,
+                free_form,
+                shape,
+            )?;
+            let position = position::next(&tables.input_value, input_parameter_id);
+            let new_input_value_id = tables.input_value.insert(|id| Positioned {
+                position,
+                row: InputValue {
+                    id,
+                    input_parameter_id,
+                    value: model.value.clone(),
+                    a,
+                    b,
+                    c,
+                    d,
+                    is_triangle: false,
+                    lower: None,
+                },
+            });
+
+            // Rules of the problem get the new term the same way as in the SQLite repository
+            let output_values: Vec<(i64, String, i64)> = tables
+                .output_values_of_problem(problem_id)
+                .map(|ov| (ov.id, ov.input_value_ids.clone(), ov.output_parameter_id))
+                .collect();
+            if output_values.is_empty() {
+                for output_parameter_id in tables.output_parameter_ids(problem_id) {
+                    tables.output_value.insert(|id| {
+                        new_rule(id, output_parameter_id, format!("|{}|", new_input_value_id))
+                    });
+                }
+            } else if number_of_input_values == 0 {
+                for (output_value_id, input_value_ids, _) in output_values {
+                    let mut ids = parse_input_value_ids(&input_value_ids);
+                    ids.push(new_input_value_id);
+                    if let Some(output_value) = tables.output_value.get_mut(output_value_id) {
+                        output_value.input_value_ids = format_input_value_ids(ids);
+                    }
+                }
+            } else {
+                let input_value_id_to_replace = tables
+                    .input_value
+                    .rows()
+                    .find(|term| {
+                        term.row.input_parameter_id == input_parameter_id
+                            && term.row.id != new_input_value_id
+                    })
+                    .map(|term| term.row.id)
+                    .ok_or_else(no_rows)?;
+                let replaced = format!("|{}|", input_value_id_to_replace);
+
+                for (_, input_value_ids, output_parameter_id) in output_values {
+                    if !input_value_ids.contains(&replaced) {
+                        continue;
+                    }
+                    let ids = parse_input_value_ids(
+                        &input_value_ids.replace(&replaced, &format!("|{}|", new_input_value_id)),
+                    );
+                    tables.output_value.insert(|id| {
+                        new_rule(id, output_parameter_id, format_input_value_ids(ids))
+                    });
+                }
+            }
+
+            Ok(new_input_value_id)
+        })
+    }
+
+    fn create_raw(&self, model: &InputValue) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            let position = position::next(&tables.input_value, model.input_parameter_id);
+            Ok(tables.input_value.insert(|id| Positioned {
+                position,
+                row: InputValue {
+                    id,
+                    ..model.clone()
+                },
+            }))
+        })
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let term = &tables
+                .input_value
+                .get(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::InputValue, id))?
+                .row;
+            let input_parameter_id = term.input_parameter_id;
+            let breakpoints = term.breakpoints();
+            let input_values_number = tables
+                .input_value
+                .rows()
+                .filter(|term| term.row.input_parameter_id == input_parameter_id)
+                .count();
+            let pattern = format!("|{}|", id);
+
+            if input_values_number > 1 {
+                // Free-form terms are stored as given, without reshaping their neighbours
+                if !is_free_form(tables, input_parameter_id)? {
+                    terms::close_gap(&mut tables.input_value, input_parameter_id, breakpoints);
+                }
+                tables
+                    .output_value
+                    .retain(|ov| !ov.input_value_ids.contains(&pattern));
+            } else {
+                // The parameter's last term only drops out of the rules
+                let problem_id = tables
+                    .input_parameter
+                    .get(input_parameter_id)
+                    .ok_or_else(no_rows)?
+                    .row
+                    .problem_id;
+                let output_value_ids: Vec<i64> = tables
+                    .output_values_of_problem(problem_id)
+                    .map(|ov| ov.id)
+                    .collect();
+                for output_value_id in output_value_ids {
+                    if let Some(output_value) = tables.output_value.get_mut(output_value_id) {
+                        output_value.input_value_ids = output_value.input_value_ids.replace(&pattern, "");
+                    }
+                }
+            }
+
+            tables.input_value.remove(id);
+            Ok(())
+        })
+    }
+
+    fn update_by_id(&self, id: i64, model: &InputValue) -> Result<(), DomainError> {
+        let breakpoints = model.breakpoints();
+        terms::validate_breakpoints(EntityKind::InputValue, id, breakpoints)?;
+
+        self.store.write(|tables| {
+            let term = &mut tables
+                .input_value
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::InputValue, id))?
+                .row;
+            term.value = model.value.clone();
+            term.set_left(model.a, model.b);
+            term.set_right(model.c, model.d);
+            let input_parameter_id = term.input_parameter_id;
+
+            // Free-form terms are stored as given, without reshaping their neighbours
+            if !is_free_form(tables, input_parameter_id)? {
+                terms::fit_neighbours(&mut tables.input_value, input_parameter_id, id, breakpoints);
+            }
+            Ok(())
+        })
+    }
+
+    fn update_shape(&self, id: i64, model: &InputValue) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let term = &mut tables
+                .input_value
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::InputValue, id))?
+                .row;
+            term.set_left(model.a, model.b);
+            term.set_right(model.c, model.d);
+            term.lower = model.lower;
+            Ok(())
+        })
+    }
+
+    fn update_lower(&self, id: i64, lower: Option<&LowerMembership>) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            tables
+                .input_value
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::InputValue, id))?
+                .row
+                .lower = lower.copied();
+            Ok(())
+        })
+    }
+
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let ids = position::move_to(&mut tables.input_value, EntityKind::InputValue, id, position)?;
+            let input_parameter_id = tables.input_value.get(id).ok_or_else(no_rows)?.row.input_parameter_id;
+            if !is_free_form(tables, input_parameter_id)? {
+                terms::keep_shapes_in_place(&mut tables.input_value, input_parameter_id, &ids);
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Whether the terms of the parameter are stored as given instead of keeping a
+/// Ruspini partition
+fn is_free_form(tables: &Tables, input_parameter_id: i64) -> Result<bool, DomainError> {
+    tables
+        .input_parameter
+        .get(input_parameter_id)
+        .map(|parameter| parameter.row.partition_mode == PartitionMode::FreeForm)
+        .ok_or_else(no_rows)
+}
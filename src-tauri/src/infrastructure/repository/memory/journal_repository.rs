@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::{
+    domain::{
+        entities::journal_entry::JournalEntry,
+        error::{DomainError, EntityKind},
+        repository::JournalRepository,
+    },
+    infrastructure::repository::memory::store::MemoryStore,
+};
+
+pub struct InMemoryJournalRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryJournalRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+impl JournalRepository for InMemoryJournalRepository {
+    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<JournalEntry>, DomainError> {
+        self.store.read(|tables| {
+            Ok(tables
+                .journal_entry
+                .rows()
+                .rev()
+                .filter(|entry| entry.problem_id == problem_id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    fn get_last_done(&self, problem_id: i64) -> Result<Option<JournalEntry>, DomainError> {
+        self.store.read(|tables| {
+            Ok(tables
+                .journal_entry
+                .rows()
+                .rev()
+                .find(|entry| entry.problem_id == problem_id && !entry.is_undone)
+                .cloned())
+        })
+    }
+
+    fn get_first_undone(&self, problem_id: i64) -> Result<Option<JournalEntry>, DomainError> {
+        self.store.read(|tables| {
+            Ok(tables
+                .journal_entry
+                .rows()
+                .find(|entry| entry.problem_id == problem_id && entry.is_undone)
+                .cloned())
+        })
+    }
+
+    fn create(&self, model: &JournalEntry) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            Ok(tables.journal_entry.insert(|id| JournalEntry {
+                id,
+                created_at: Utc::now().to_rfc3339(),
+                ..model.clone()
+            }))
+        })
+    }
+
+    fn set_undone(&self, id: i64, is_undone: bool) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            tables
+                .journal_entry
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::JournalEntry, id))?
+                .is_undone = is_undone;
+            Ok(())
+        })
+    }
+
+    fn remove_undone_by_problem_id(&self, problem_id: i64) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            tables
+                .journal_entry
+                .retain(|entry| !(entry.problem_id == problem_id && entry.is_undone));
+            Ok(())
+        })
+    }
+
+    fn prune(&self, problem_id: i64, keep: usize) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let pruned: Vec<i64> = tables
+                .journal_entry
+                .rows()
+                .rev()
+                .filter(|entry| entry.problem_id == problem_id)
+                .skip(keep)
+                .map(|entry| entry.id)
+                .collect();
+            for id in pruned {
+                tables.journal_entry.remove(id);
+            }
+            Ok(())
+        })
+    }
+}
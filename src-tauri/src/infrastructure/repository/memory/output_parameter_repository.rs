@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::{
+            output_parameter::OutputParameter, parameter_metadata::ParameterMetadata,
+            partition_mode::PartitionMode,
+        },
+        error::{DomainError, EntityKind},
+        repository::OutputParameterRepository,
+    },
+    infrastructure::repository::memory::{
+        position,
+        store::{new_rule, MemoryStore, Positioned},
+    },
+};
+
+pub struct InMemoryOutputParameterRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryOutputParameterRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+
+    fn update(&self, id: i64, update: impl FnOnce(&mut OutputParameter)) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let parameter = tables
+                .output_parameter
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::OutputParameter, id))?;
+            update(&mut parameter.row);
+            Ok(())
+        })
+    }
+}
+
+impl OutputParameterRepository for InMemoryOutputParameterRepository {
+    fn get_by_id(&self, id: i64) -> Result<OutputParameter, DomainError> {
+        self.store.read(|tables| {
+            tables
+                .output_parameter
+                .get(id)
+                .map(|parameter| parameter.row.clone())
+                .ok_or_else(|| DomainError::not_found(EntityKind::OutputParameter, id))
+        })
+    }
+
+    fn create(&self, problem_id: i64, model: &OutputParameter) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            let position = position::next(&tables.output_parameter, problem_id);
+            let new_output_parameter_id = tables.output_parameter.insert(|id| Positioned {
+                position,
+                row: OutputParameter {
+                    id,
+                    problem_id,
+                    name: model.name.clone(),
+                    start: 0.0,
+                    end: 1.0,
+                    partition_mode: PartitionMode::default(),
+                    metadata: ParameterMetadata::default(),
+                    fuzzy_output_values: vec![],
+                },
+            });
+
+            // One rule for every combination of input terms, parameters and terms in id order
+            let input_value_ids: Vec<Vec<i64>> = tables
+                .input_parameter_ids(problem_id)
+                .into_iter()
+                .map(|input_parameter_id| {
+                    tables
+                        .input_value
+                        .rows()
+                        .filter(|term| term.row.input_parameter_id == input_parameter_id)
+                        .map(|term| term.row.id)
+                        .collect()
+                })
+                .collect();
+            if !input_value_ids.is_empty() {
+                let combinations = input_value_ids.iter().fold(vec![String::new()], |combinations, ids| {
+                    combinations
+                        .iter()
+                        .flat_map(|prefix| ids.iter().map(move |id| format!("{}|{}|", prefix, id)))
+                        .collect()
+                });
+                for combination in combinations {
+                    tables
+                        .output_value
+                        .insert(|id| new_rule(id, new_output_parameter_id, combination));
+                }
+            }
+
+            Ok(new_output_parameter_id)
+        })
+    }
+
+    fn create_raw(&self, problem_id: i64, model: &OutputParameter) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            let position = position::next(&tables.output_parameter, problem_id);
+            Ok(tables.output_parameter.insert(|id| Positioned {
+                position,
+                row: OutputParameter {
+                    id,
+                    problem_id,
+                    fuzzy_output_values: vec![],
+                    ..model.clone()
+                },
+            }))
+        })
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            tables.parameter_link.retain(|link| link.output_parameter_id != id);
+            tables.output_parameter.remove(id);
+            Ok(())
+        })
+    }
+
+    fn update_by_id(&self, id: i64, model: &OutputParameter) -> Result<(), DomainError> {
+        // Like an UPDATE matching no row, a missing parameter is not an error here
+        match self.update(id, |parameter| {
+            parameter.name = model.name.clone();
+            parameter.start = model.start;
+            parameter.end = model.end;
+        }) {
+            Err(DomainError::NotFound { .. }) => Ok(()),
+            result => result,
+        }
+    }
+
+    fn update_partition_mode(&self, id: i64, mode: PartitionMode) -> Result<(), DomainError> {
+        self.update(id, |parameter| parameter.partition_mode = mode)
+    }
+
+    fn update_metadata(&self, id: i64, metadata: &ParameterMetadata) -> Result<(), DomainError> {
+        self.update(id, |parameter| parameter.metadata = metadata.clone())
+    }
+
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            position::move_to(&mut tables.output_parameter, EntityKind::OutputParameter, id, position)?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::output_value::OutputValue,
+        error::{DomainError, EntityKind},
+        repository::OutputValueRepository,
+    },
+    infrastructure::repository::memory::store::MemoryStore,
+};
+
+pub struct InMemoryOutputValueRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryOutputValueRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+impl OutputValueRepository for InMemoryOutputValueRepository {
+    fn create(&self, model: &OutputValue) -> Result<i64, DomainError> {
+        self.store
+            .write(|tables| Ok(tables.output_value.insert(|id| OutputValue { id, ..model.clone() })))
+    }
+
+    fn update_by_id(&self, id: i64, model: &OutputValue) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            if let Some(output_value) = tables.output_value.get_mut(id) {
+                *output_value = OutputValue { id, ..model.clone() };
+            }
+            Ok(())
+        })
+    }
+
+    fn update_fuzzy_output_value(&self, id: i64, fuzzy_output_value_id: Option<i64>) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            if let Some(output_value) = tables.output_value.get_mut(id) {
+                output_value.fuzzy_output_value_id = fuzzy_output_value_id;
+            }
+            Ok(())
+        })
+    }
+
+    fn update_hedges(
+        &self,
+        id: i64,
+        antecedent_hedges: &str,
+        consequent_hedge: Option<&str>,
+    ) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let output_value = tables
+                .output_value
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::OutputValue, id))?;
+            output_value.antecedent_hedges = antecedent_hedges.to_string();
+            output_value.consequent_hedge = consequent_hedge.map(str::to_string);
+            Ok(())
+        })
+    }
+
+    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<OutputValue>, DomainError> {
+        self.store
+            .read(|tables| Ok(tables.output_values_of_problem(problem_id).cloned().collect()))
+    }
+
+    fn get_by_id(&self, id: i64) -> Result<OutputValue, DomainError> {
+        self.store.read(|tables| {
+            tables
+                .output_value
+                .get(id)
+                .cloned()
+                .ok_or_else(|| DomainError::not_found(EntityKind::OutputValue, id))
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::parameter_link::ParameterLink,
+        error::{DomainError, EntityKind, FieldError},
+        repository::ParameterLinkRepository,
+    },
+    infrastructure::repository::memory::store::{MemoryStore, Tables},
+};
+
+pub struct InMemoryParameterLinkRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryParameterLinkRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+/// The link with the problems of both parameters, `None` if one of them was removed
+fn with_problems(tables: &Tables, link: &ParameterLink) -> Option<ParameterLink> {
+    let output_parameter = tables.output_parameter.get(link.output_parameter_id)?;
+    let input_parameter = tables.input_parameter.get(link.input_parameter_id)?;
+    Some(ParameterLink {
+        source_problem_id: output_parameter.row.problem_id,
+        target_problem_id: input_parameter.row.problem_id,
+        ..link.clone()
+    })
+}
+
+impl ParameterLinkRepository for InMemoryParameterLinkRepository {
+    fn get_all(&self) -> Result<Vec<ParameterLink>, DomainError> {
+        self.store.read(|tables| {
+            Ok(tables
+                .parameter_link
+                .rows()
+                .filter_map(|link| with_problems(tables, link))
+                .collect())
+        })
+    }
+
+    fn get_by_id(&self, id: i64) -> Result<ParameterLink, DomainError> {
+        self.store.read(|tables| {
+            tables
+                .parameter_link
+                .get(id)
+                .and_then(|link| with_problems(tables, link))
+                .ok_or_else(|| DomainError::not_found(EntityKind::ParameterLink, id))
+        })
+    }
+
+    fn create(&self, model: &ParameterLink) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            // An input parameter takes its value from at most one output parameter
+            if tables
+                .parameter_link
+                .rows()
+                .any(|link| link.input_parameter_id == model.input_parameter_id)
+            {
+                return Err(DomainError::invalid_field(
+                    EntityKind::ParameterLink,
+                    None,
+                    FieldError::new(
+                        "input_parameter_id",
+                        format!("Input parameter {} is already linked", model.input_parameter_id),
+                    ),
+                ));
+            }
+            Ok(tables.parameter_link.insert(|id| ParameterLink {
+                id,
+                output_parameter_id: model.output_parameter_id,
+                input_parameter_id: model.input_parameter_id,
+                source_problem_id: 0,
+                target_problem_id: 0,
+            }))
+        })
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            tables.parameter_link.remove(id);
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    domain::{
+        entities::{
+            fuzzy_output_value::FuzzyOutputValue, input_parameter::InputParameter,
+            input_value::InputValue, output_parameter::OutputParameter,
+        },
+        error::{DomainError, EntityKind},
+    },
+    infrastructure::repository::memory::store::{Positioned, Table},
+};
+
+// Same ordering rules as the SQLite `position` helpers: siblings are read back
+// by position, then id, so gaps left by deletions do not matter.
+
+/// A row ordered among the other children of its parent
+pub trait Child {
+    fn id(&self) -> i64;
+    fn parent_id(&self) -> i64;
+}
+
+impl Child for InputParameter {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn parent_id(&self) -> i64 {
+        self.problem_id
+    }
+}
+
+impl Child for OutputParameter {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn parent_id(&self) -> i64 {
+        self.problem_id
+    }
+}
+
+impl Child for InputValue {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn parent_id(&self) -> i64 {
+        self.input_parameter_id
+    }
+}
+
+impl Child for FuzzyOutputValue {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn parent_id(&self) -> i64 {
+        self.output_parameter_id
+    }
+}
+
+/// Children of `parent_id`, ordered by position, then id
+pub fn siblings<T: Child>(table: &Table<Positioned<T>>, parent_id: i64) -> Vec<&Positioned<T>> {
+    let mut siblings: Vec<_> = table
+        .rows()
+        .filter(|sibling| sibling.row.parent_id() == parent_id)
+        .collect();
+    siblings.sort_by_key(|sibling| (sibling.position, sibling.row.id()));
+    siblings
+}
+
+/// Position after the last child of `parent_id`
+pub fn next<T: Child>(table: &Table<Positioned<T>>, parent_id: i64) -> i64 {
+    table
+        .rows()
+        .filter(|sibling| sibling.row.parent_id() == parent_id)
+        .map(|sibling| sibling.position + 1)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Moves row `id` to index `position` among its siblings (after the last one when
+/// `position` is past the end) and renumbers them from 0. Returns the sibling ids
+/// in their new order.
+pub fn move_to<T: Child>(
+    table: &mut Table<Positioned<T>>,
+    entity: EntityKind,
+    id: i64,
+    position: usize,
+) -> Result<Vec<i64>, DomainError> {
+    let parent_id = table
+        .get(id)
+        .ok_or_else(|| DomainError::not_found(entity, id))?
+        .row
+        .parent_id();
+
+    let mut ids: Vec<i64> = siblings(table, parent_id)
+        .into_iter()
+        .map(|sibling| sibling.row.id())
+        .filter(|sibling_id| *sibling_id != id)
+        .collect();
+    ids.insert(position.min(ids.len()), id);
+    for (position, sibling_id) in ids.iter().enumerate() {
+        if let Some(sibling) = table.get_mut(*sibling_id) {
+            sibling.position = position as i64;
+        }
+    }
+
+    Ok(ids)
+}
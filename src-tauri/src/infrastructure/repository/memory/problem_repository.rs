@@ -0,0 +1,362 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::entities::fuzzy_output_value::FuzzyOutputValue;
+use crate::domain::entities::image::Image;
+use crate::domain::entities::input_parameter::InputParameter;
+use crate::domain::entities::input_value::InputValue;
+use crate::domain::entities::output_parameter::OutputParameter;
+use crate::domain::entities::problem::Problem;
+use crate::domain::error::{DomainError, EntityKind};
+use crate::domain::repository::ProblemRepository;
+use crate::infrastructure::repository::memory::{
+    position,
+    store::{MemoryStore, Positioned, Tables},
+};
+
+pub struct InMemoryProblemRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryProblemRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+/// Ids of the problems directly under `prev_problem_id`, ordered by position, then id
+fn child_ids(tables: &Tables, prev_problem_id: Option<i64>, except: Option<i64>) -> Vec<i64> {
+    let mut children: Vec<(i64, i64)> = tables
+        .problem
+        .rows()
+        .filter(|p| p.row.prev_problem_id == prev_problem_id && Some(p.row.id) != except)
+        .map(|p| (p.position, p.row.id))
+        .collect();
+    children.sort();
+    children.into_iter().map(|(_, id)| id).collect()
+}
+
+/// Inserts a problem after its last sibling
+fn insert_problem(tables: &mut Tables, problem: Problem) -> i64 {
+    let position = tables
+        .problem
+        .rows()
+        .filter(|p| p.row.prev_problem_id == problem.prev_problem_id)
+        .map(|p| p.position + 1)
+        .max()
+        .unwrap_or(0);
+    tables.problem.insert(|id| Positioned {
+        position,
+        row: Problem { id, ..problem },
+    })
+}
+
+/// Removes the problem with its subtree, test cases, snapshots, journal, links
+/// and image
+fn deep_delete(tables: &mut Tables, id: i64) -> Result<(), DomainError> {
+    for child_id in child_ids(tables, Some(id), None) {
+        deep_delete(tables, child_id)?;
+    }
+
+    let image_id = tables
+        .problem
+        .get(id)
+        .ok_or_else(|| DomainError::not_found(EntityKind::Problem, id))?
+        .row
+        .image_id;
+    tables.test_case.retain(|test_case| test_case.problem_id != id);
+    tables.problem_snapshot.retain(|snapshot| snapshot.problem_id != id);
+    tables.journal_entry.retain(|entry| entry.problem_id != id);
+    unlink_parameters(tables, id);
+    tables.problem.remove(id);
+
+    if let Some(image_id) = image_id {
+        tables.image.remove(image_id);
+    }
+
+    Ok(())
+}
+
+/// Removes the links from or to parameters of the problem
+fn unlink_parameters(tables: &mut Tables, problem_id: i64) {
+    let output_parameter_ids = tables.output_parameter_ids(problem_id);
+    let input_parameter_ids = tables.input_parameter_ids(problem_id);
+    tables.parameter_link.retain(|link| {
+        !output_parameter_ids.contains(&link.output_parameter_id)
+            && !input_parameter_ids.contains(&link.input_parameter_id)
+    });
+}
+
+/// Removes the parameters of the problem with their terms and rules
+fn remove_parameters(tables: &mut Tables, problem_id: i64) {
+    let output_parameter_ids = tables.output_parameter_ids(problem_id);
+    tables
+        .output_value
+        .retain(|ov| !output_parameter_ids.contains(&ov.output_parameter_id));
+    tables
+        .fuzzy_output_value
+        .retain(|term| !output_parameter_ids.contains(&term.row.output_parameter_id));
+    tables.output_parameter.retain(|p| p.row.problem_id != problem_id);
+
+    let input_parameter_ids = tables.input_parameter_ids(problem_id);
+    tables
+        .input_value
+        .retain(|term| !input_parameter_ids.contains(&term.row.input_parameter_id));
+    tables.input_parameter.retain(|p| p.row.problem_id != problem_id);
+}
+
+impl ProblemRepository for InMemoryProblemRepository {
+    fn get_all_by_prev_problem_id(&self, id: Option<i64>) -> Result<Vec<Problem>, DomainError> {
+        self.store.read(|tables| {
+            Ok(child_ids(tables, id, None)
+                .into_iter()
+                .filter_map(|child_id| tables.problem.get(child_id))
+                .map(|p| p.row.clone())
+                .collect())
+        })
+    }
+
+    fn get_full_by_id(&self, id: i64) -> Result<Problem, DomainError> {
+        self.store.read(|tables| {
+            let mut problem = tables
+                .problem
+                .get(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::Problem, id))?
+                .row
+                .clone();
+
+            problem.input_parameters = position::siblings(&tables.input_parameter, id)
+                .into_iter()
+                .map(|p| InputParameter {
+                    input_values: position::siblings(&tables.input_value, p.row.id)
+                        .into_iter()
+                        .map(|term| term.row.clone())
+                        .collect(),
+                    ..p.row.clone()
+                })
+                .collect();
+            problem.output_parameters = position::siblings(&tables.output_parameter, id)
+                .into_iter()
+                .map(|p| OutputParameter {
+                    fuzzy_output_values: position::siblings(&tables.fuzzy_output_value, p.row.id)
+                        .into_iter()
+                        .map(|term| term.row.clone())
+                        .collect(),
+                    ..p.row.clone()
+                })
+                .collect();
+            problem.output_values = tables.output_values_of_problem(id).cloned().collect();
+
+            Ok(problem)
+        })
+    }
+
+    fn create(&self, model: &Problem, image: Option<&Image>) -> Result<(i64, Option<i64>), DomainError> {
+        self.store.write(|tables| {
+            let image_id = image.map(|image| {
+                tables.image.insert(|id| Image {
+                    id,
+                    ..image.clone()
+                })
+            });
+
+            let new_id = insert_problem(
+                tables,
+                Problem {
+                    id: 0,
+                    prev_problem_id: model.prev_problem_id,
+                    is_final: model.is_final,
+                    name: model.name.clone(),
+                    description: model.description.clone(),
+                    image_id,
+                    created_at: Utc::now().to_rfc3339(),
+                    updated_at: None,
+                    input_parameters: vec![],
+                    output_parameters: vec![],
+                    output_values: vec![],
+                },
+            );
+
+            Ok((new_id, image_id))
+        })
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        self.store.write(|tables| deep_delete(tables, id))
+    }
+
+    fn update_by_id(&self, id: i64, model: &Problem, image: Option<&Image>) -> Result<Option<i64>, DomainError> {
+        self.store.write(|tables| {
+            let old_image_id = tables.problem.get(id).and_then(|p| p.row.image_id);
+
+            let new_image_id = match image {
+                Some(image) => Some(tables.image.insert(|id| Image {
+                    id,
+                    ..image.clone()
+                })),
+                // Keep existing image_id from the model (which might be None if deleted, or some ID if unchanged)
+                None => model.image_id,
+            };
+
+            if let Some(problem) = tables.problem.get_mut(id) {
+                problem.row.is_final = model.is_final;
+                problem.row.name = model.name.clone();
+                problem.row.description = model.description.clone();
+                problem.row.image_id = new_image_id;
+                problem.row.updated_at = Some(Utc::now().to_rfc3339());
+            }
+
+            // A replaced or removed image is deleted
+            if old_image_id != new_image_id {
+                if let Some(old_id) = old_image_id {
+                    tables.image.remove(old_id);
+                }
+            }
+
+            Ok(new_image_id)
+        })
+    }
+
+    fn is_final(&self, id: i64) -> Result<bool, DomainError> {
+        self.store.read(|tables| {
+            tables
+                .problem
+                .get(id)
+                .map(|p| p.row.is_final)
+                .ok_or_else(|| DomainError::not_found(EntityKind::Problem, id))
+        })
+    }
+
+    fn clear_definition(&self, id: i64) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            unlink_parameters(tables, id);
+            remove_parameters(tables, id);
+            tables.test_case.retain(|test_case| test_case.problem_id != id);
+            Ok(())
+        })
+    }
+
+    fn replace_definition(&self, model: &Problem) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let problem = tables
+                .problem
+                .get_mut(model.id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::Problem, model.id))?;
+            problem.row.name = model.name.clone();
+            problem.row.description = model.description.clone();
+
+            remove_parameters(tables, model.id);
+
+            // Ids removed above may have been reused by another problem in the meantime
+            let taken = |e: String| {
+                DomainError::Validation(format!(
+                    "Problem {} can not be restored because its ids are taken: {}",
+                    model.id, e
+                ))
+            };
+
+            // Positions follow the order of the definition
+            for (ip_position, ip) in model.input_parameters.iter().enumerate() {
+                let row = InputParameter {
+                    problem_id: model.id,
+                    input_values: vec![],
+                    ..ip.clone()
+                };
+                tables
+                    .input_parameter
+                    .insert_with_id("input_parameter", ip.id, Positioned { position: ip_position as i64, row })
+                    .map_err(taken)?;
+                for (iv_position, iv) in ip.input_values.iter().enumerate() {
+                    let row = InputValue {
+                        input_parameter_id: ip.id,
+                        ..iv.clone()
+                    };
+                    tables
+                        .input_value
+                        .insert_with_id("input_value", iv.id, Positioned { position: iv_position as i64, row })
+                        .map_err(taken)?;
+                }
+            }
+
+            for (op_position, op) in model.output_parameters.iter().enumerate() {
+                let row = OutputParameter {
+                    problem_id: model.id,
+                    fuzzy_output_values: vec![],
+                    ..op.clone()
+                };
+                tables
+                    .output_parameter
+                    .insert_with_id("output_parameter", op.id, Positioned { position: op_position as i64, row })
+                    .map_err(taken)?;
+                for (fov_position, fov) in op.fuzzy_output_values.iter().enumerate() {
+                    let row = FuzzyOutputValue {
+                        output_parameter_id: op.id,
+                        ..fov.clone()
+                    };
+                    tables
+                        .fuzzy_output_value
+                        .insert_with_id("fuzzy_output_value", fov.id, Positioned { position: fov_position as i64, row })
+                        .map_err(taken)?;
+                }
+            }
+
+            for ov in &model.output_values {
+                tables
+                    .output_value
+                    .insert_with_id("output_value", ov.id, ov.clone())
+                    .map_err(taken)?;
+            }
+
+            // Links of parameters the definition no longer has go with them
+            let (input_parameter, output_parameter) = (&tables.input_parameter, &tables.output_parameter);
+            tables.parameter_link.retain(|link| {
+                input_parameter.get(link.input_parameter_id).is_some()
+                    && output_parameter.get(link.output_parameter_id).is_some()
+            });
+
+            Ok(())
+        })
+    }
+
+    fn get_ancestor_ids(&self, id: i64) -> Result<Vec<i64>, DomainError> {
+        self.store.read(|tables| {
+            let parent_of = |id: i64| tables.problem.get(id).and_then(|p| p.row.prev_problem_id);
+
+            let mut ids = Vec::new();
+            let mut next = parent_of(id);
+            // Stops on an already broken (cyclic) hierarchy
+            while let Some(ancestor_id) = next.filter(|ancestor_id| !ids.contains(ancestor_id)) {
+                ids.push(ancestor_id);
+                next = parent_of(ancestor_id);
+            }
+            Ok(ids)
+        })
+    }
+
+    fn move_by_id(
+        &self,
+        id: i64,
+        prev_problem_id: Option<i64>,
+        position: Option<usize>,
+    ) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let mut sibling_ids = child_ids(tables, prev_problem_id, Some(id));
+            let index = position.unwrap_or(sibling_ids.len()).min(sibling_ids.len());
+            sibling_ids.insert(index, id);
+
+            tables
+                .problem
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::Problem, id))?
+                .row
+                .prev_problem_id = prev_problem_id;
+            for (position, sibling_id) in sibling_ids.iter().enumerate() {
+                if let Some(sibling) = tables.problem.get_mut(*sibling_id) {
+                    sibling.position = position as i64;
+                }
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::{
+    domain::{
+        entities::problem_snapshot::ProblemSnapshot,
+        error::{DomainError, EntityKind},
+        repository::ProblemSnapshotRepository,
+    },
+    infrastructure::repository::memory::store::MemoryStore,
+};
+
+pub struct InMemoryProblemSnapshotRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryProblemSnapshotRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+impl ProblemSnapshotRepository for InMemoryProblemSnapshotRepository {
+    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<ProblemSnapshot>, DomainError> {
+        self.store.read(|tables| {
+            Ok(tables
+                .problem_snapshot
+                .rows()
+                .rev()
+                .filter(|snapshot| snapshot.problem_id == problem_id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    fn get_by_id(&self, id: i64) -> Result<ProblemSnapshot, DomainError> {
+        self.store.read(|tables| {
+            tables
+                .problem_snapshot
+                .get(id)
+                .cloned()
+                .ok_or_else(|| DomainError::not_found(EntityKind::ProblemSnapshot, id))
+        })
+    }
+
+    fn create(&self, model: &ProblemSnapshot) -> Result<i64, DomainError> {
+        self.store.write(|tables| {
+            Ok(tables.problem_snapshot.insert(|id| ProblemSnapshot {
+                id,
+                created_at: Utc::now().to_rfc3339(),
+                ..model.clone()
+            }))
+        })
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            tables
+                .problem_snapshot
+                .remove(id)
+                .map(|_| ())
+                .ok_or_else(|| DomainError::not_found(EntityKind::ProblemSnapshot, id))
+        })
+    }
+
+    fn prune_automatic(&self, problem_id: i64, keep: usize) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let pruned: Vec<i64> = tables
+                .problem_snapshot
+                .rows()
+                .rev()
+                .filter(|snapshot| snapshot.problem_id == problem_id && snapshot.is_automatic)
+                .skip(keep)
+                .map(|snapshot| snapshot.id)
+                .collect();
+            for id in pruned {
+                tables.problem_snapshot.remove(id);
+            }
+            Ok(())
+        })
+    }
+}
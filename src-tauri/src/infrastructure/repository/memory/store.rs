@@ -0,0 +1,274 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Condvar, Mutex, MutexGuard},
+    thread::{self, ThreadId},
+};
+
+use crate::domain::{
+    entities::{
+        evaluation_record::EvaluationRecord, fuzzy_output_value::FuzzyOutputValue, image::Image,
+        input_parameter::InputParameter, input_value::InputValue, journal_entry::JournalEntry,
+        output_parameter::OutputParameter, output_value::OutputValue,
+        parameter_link::ParameterLink, problem::Problem, problem_snapshot::ProblemSnapshot,
+        test_case::TestCase,
+    },
+    error::DomainError,
+};
+
+/// Rows of one table by id.
+///
+/// New rows get the id after the largest one in use, as SQLite assigns rowids, so
+/// both backends hand out the same ids for the same sequence of writes.
+#[derive(Clone)]
+pub struct Table<T> {
+    rows: BTreeMap<i64, T>,
+}
+
+impl<T> Default for Table<T> {
+    fn default() -> Self {
+        Self { rows: BTreeMap::new() }
+    }
+}
+
+impl<T> Table<T> {
+    /// Inserts the row built from its new id and returns the id
+    pub fn insert(&mut self, row: impl FnOnce(i64) -> T) -> i64 {
+        let id = self.rows.keys().next_back().map_or(1, |last| last + 1);
+        self.rows.insert(id, row(id));
+        id
+    }
+
+    /// Inserts a row with a given id; fails with the message SQLite gives when
+    /// the id is taken
+    pub fn insert_with_id(&mut self, table: &str, id: i64, row: T) -> Result<(), String> {
+        if self.rows.contains_key(&id) {
+            return Err(format!("UNIQUE constraint failed: {}.id", table));
+        }
+        self.rows.insert(id, row);
+        Ok(())
+    }
+
+    pub fn get(&self, id: i64) -> Option<&T> {
+        self.rows.get(&id)
+    }
+
+    pub fn get_mut(&mut self, id: i64) -> Option<&mut T> {
+        self.rows.get_mut(&id)
+    }
+
+    pub fn remove(&mut self, id: i64) -> Option<T> {
+        self.rows.remove(&id)
+    }
+
+    /// Keeps only the rows `keep` is true for; returns how many were removed
+    pub fn retain(&mut self, mut keep: impl FnMut(&T) -> bool) -> usize {
+        let before = self.rows.len();
+        self.rows.retain(|_, row| keep(row));
+        before - self.rows.len()
+    }
+
+    /// Rows in id order
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.rows.values()
+    }
+
+    /// Rows in id order
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.rows.values_mut()
+    }
+}
+
+/// A row with its `position` among its siblings; read back ordered by position, then id
+#[derive(Clone)]
+pub struct Positioned<T> {
+    pub position: i64,
+    pub row: T,
+}
+
+/// Everything the SQLite schema holds, one table per entity.
+///
+/// Child collections of the stored entities (the parameters of a problem, the terms
+/// of a parameter, ...) are left empty; they live in their own tables. As in the
+/// SQLite database, deleting a row only removes what the repositories remove
+/// explicitly.
+#[derive(Clone, Default)]
+pub struct Tables {
+    pub image: Table<Image>,
+    pub problem: Table<Positioned<Problem>>,
+    pub input_parameter: Table<Positioned<InputParameter>>,
+    pub input_value: Table<Positioned<InputValue>>,
+    pub output_parameter: Table<Positioned<OutputParameter>>,
+    pub fuzzy_output_value: Table<Positioned<FuzzyOutputValue>>,
+    pub output_value: Table<OutputValue>,
+    pub test_case: Table<TestCase>,
+    pub evaluation_history: Table<EvaluationRecord>,
+    pub problem_snapshot: Table<ProblemSnapshot>,
+    pub journal_entry: Table<JournalEntry>,
+    pub parameter_link: Table<ParameterLink>,
+}
+
+impl Tables {
+    /// Ids of the input parameters of the problem, in id order
+    pub fn input_parameter_ids(&self, problem_id: i64) -> Vec<i64> {
+        self.input_parameter
+            .rows()
+            .filter(|p| p.row.problem_id == problem_id)
+            .map(|p| p.row.id)
+            .collect()
+    }
+
+    /// Ids of the output parameters of the problem, in id order
+    pub fn output_parameter_ids(&self, problem_id: i64) -> Vec<i64> {
+        self.output_parameter
+            .rows()
+            .filter(|p| p.row.problem_id == problem_id)
+            .map(|p| p.row.id)
+            .collect()
+    }
+
+    /// Rules of the problem, i.e. of its existing output parameters, in id order
+    pub fn output_values_of_problem(&self, problem_id: i64) -> impl Iterator<Item = &OutputValue> {
+        self.output_value.rows().filter(move |ov| {
+            self.output_parameter
+                .get(ov.output_parameter_id)
+                .is_some_and(|op| op.row.problem_id == problem_id)
+        })
+    }
+}
+
+/// The error SQLite reports when a lookup the repositories treat as infallible
+/// finds nothing
+pub fn no_rows() -> DomainError {
+    DomainError::Internal("Query returned no rows".to_string())
+}
+
+/// Term ids of a rule in the stored "|id1||id2|" form
+pub fn parse_input_value_ids(input_value_ids: &str) -> Vec<i64> {
+    input_value_ids
+        .split('|')
+        .filter_map(|id| id.parse::<i64>().ok())
+        .collect()
+}
+
+/// Formats term ids of a rule in the stored "|id1||id2|" form, sorted numerically
+pub fn format_input_value_ids(mut ids: Vec<i64>) -> String {
+    ids.sort();
+    ids.iter().map(|id| format!("|{}|", id)).collect()
+}
+
+/// A rule without consequent term and hedges, as generated for new terms and parameters
+pub fn new_rule(id: i64, output_parameter_id: i64, input_value_ids: String) -> OutputValue {
+    OutputValue {
+        id,
+        output_parameter_id,
+        fuzzy_output_value_id: None,
+        input_value_ids,
+        antecedent_hedges: String::new(),
+        consequent_hedge: None,
+    }
+}
+
+/// In-memory counterpart of the SQLite database shared by all repositories.
+///
+/// Every write runs on the tables as one transaction: if it fails, the tables are
+/// restored to what they were before it. Units of work nest like the SQLite
+/// savepoints; while one is open, writes of other threads wait until it ends and
+/// their reads see the tables as they were when it began.
+pub struct MemoryStore {
+    state: Mutex<State>,
+    unit_of_work_ended: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    tables: Tables,
+    /// Tables as they were when each open unit of work began, outermost first
+    savepoints: Vec<Tables>,
+    owner: Option<ThreadId>,
+}
+
+impl State {
+    fn is_other_thread(&self) -> bool {
+        self.owner.is_some_and(|owner| owner != thread::current().id())
+    }
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(State::default()),
+            unit_of_work_ended: Condvar::new(),
+        }
+    }
+
+    /// Runs a query on the tables
+    pub fn read<T>(&self, query: impl FnOnce(&Tables) -> Result<T, DomainError>) -> Result<T, DomainError> {
+        let state = self.lock()?;
+        match state.savepoints.first() {
+            Some(committed) if state.is_other_thread() => query(committed),
+            _ => query(&state.tables),
+        }
+    }
+
+    /// Runs a write on the tables, undoing all of it if it fails
+    pub fn write<T>(&self, write: impl FnOnce(&mut Tables) -> Result<T, DomainError>) -> Result<T, DomainError> {
+        let mut state = self.wait_for_other_threads()?;
+        let before = state.tables.clone();
+        let result = write(&mut state.tables);
+        if result.is_err() {
+            state.tables = before;
+        }
+        result
+    }
+
+    /// Opens a (nested) unit of work of this thread
+    pub fn begin_unit_of_work(&self) -> Result<(), DomainError> {
+        let mut state = self.wait_for_other_threads()?;
+        state.owner = Some(thread::current().id());
+        let savepoint = state.tables.clone();
+        state.savepoints.push(savepoint);
+        Ok(())
+    }
+
+    /// Keeps or undoes the writes of the innermost unit of work of this thread;
+    /// other threads may write again once the outermost one has ended
+    pub fn finish_unit_of_work(&self, commit: bool) -> Result<(), DomainError> {
+        let mut state = self.lock()?;
+        if state.owner != Some(thread::current().id()) {
+            return Err(DomainError::Internal("No unit of work is open".to_string()));
+        }
+        let savepoint = state
+            .savepoints
+            .pop()
+            .ok_or_else(|| DomainError::Internal("No unit of work is open".to_string()))?;
+        if !commit {
+            state.tables = savepoint;
+        }
+        if state.savepoints.is_empty() {
+            state.owner = None;
+            self.unit_of_work_ended.notify_all();
+        }
+        Ok(())
+    }
+
+    fn wait_for_other_threads(&self) -> Result<MutexGuard<'_, State>, DomainError> {
+        let mut state = self.lock()?;
+        while state.is_other_thread() {
+            state = self
+                .unit_of_work_ended
+                .wait(state)
+                .map_err(|e| DomainError::Internal(e.to_string()))?;
+        }
+        Ok(state)
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, State>, DomainError> {
+        self.state.lock().map_err(|e| DomainError::Internal(e.to_string()))
+    }
+}
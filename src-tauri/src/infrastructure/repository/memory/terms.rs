@@ -0,0 +1,237 @@
+use crate::{
+    domain::{
+        entities::{
+            fuzzy_output_value::FuzzyOutputValue, input_value::InputValue,
+            lower_membership::LowerMembership,
+        },
+        error::{DomainError, EntityKind, FieldError},
+    },
+    infrastructure::repository::memory::{
+        position::{self, Child},
+        store::{Positioned, Table},
+    },
+};
+
+// Input terms and output terms follow the same rules when they are created, edited,
+// removed or moved: free-form terms are stored as given, the terms of a Ruspini
+// partition reshape their neighbours so the partition keeps covering the range.
+
+/// Breakpoints, triangle flag and lower membership function of a term
+type Shape = ([f32; 4], bool, Option<LowerMembership>);
+
+/// A linguistic term of an input or output parameter
+pub trait Term: Child {
+    fn breakpoints(&self) -> [f32; 4];
+    fn set_left(&mut self, a: f32, b: f32);
+    fn set_right(&mut self, c: f32, d: f32);
+    fn shape(&self) -> Shape;
+    fn set_shape(&mut self, shape: Shape);
+}
+
+macro_rules! impl_term {
+    ($entity:ty) => {
+        impl Term for $entity {
+            fn breakpoints(&self) -> [f32; 4] {
+                [self.a, self.b, self.c, self.d]
+            }
+
+            fn set_left(&mut self, a: f32, b: f32) {
+                self.a = a;
+                self.b = b;
+            }
+
+            fn set_right(&mut self, c: f32, d: f32) {
+                self.c = c;
+                self.d = d;
+            }
+
+            fn shape(&self) -> Shape {
+                (self.breakpoints(), self.is_triangle, self.lower)
+            }
+
+            fn set_shape(&mut self, ([a, b, c, d], is_triangle, lower): Shape) {
+                self.set_left(a, b);
+                self.set_right(c, d);
+                self.is_triangle = is_triangle;
+                self.lower = lower;
+            }
+        }
+    };
+}
+
+impl_term!(InputValue);
+impl_term!(FuzzyOutputValue);
+
+/// Breakpoints of a new term of the parameter `parent_id` ranging from `start` to
+/// `end`. In a Ruspini partition the rightmost term is split to make room for it.
+///
+/// `entity` is the kind of term named in the validation error.
+pub fn new_term_breakpoints<T: Term>(
+    table: &mut Table<Positioned<T>>,
+    entity: EntityKind,
+    parent_id: i64,
+    (start, end): (f32, f32),
+    free_form: bool,
+    shape: Option<[f32; 4]>,
+) -> Result<[f32; 4], DomainError> {
+    if free_form {
+        // Stored as given; a term created without a shape covers the whole range
+        return match shape {
+            None => Ok([start, start, end, end]),
+            Some([a, b, c, d]) if a <= b && b <= c && c <= d => Ok([a, b, c, d]),
+            Some([a, b, c, d]) => Err(DomainError::invalid_field(
+                entity,
+                None,
+                FieldError::new(
+                    "shape",
+                    format!(
+                        "Breakpoints ({}, {}, {}, {}) must satisfy a <= b <= c <= d",
+                        a, b, c, d
+                    ),
+                ),
+            )),
+        };
+    }
+
+    // Rightmost term, the first one by id among equals
+    let rightmost = table
+        .rows()
+        .filter(|term| term.row.parent_id() == parent_id)
+        .fold(None::<&Positioned<T>>, |rightmost, term| match rightmost {
+            Some(r) if r.row.breakpoints()[3] >= term.row.breakpoints()[3] => Some(r),
+            _ => Some(term),
+        })
+        .map(|term| (term.row.id(), term.row.breakpoints()));
+    let Some((prev_id, [prev_a, prev_b, _, prev_d])) = rightmost else {
+        // First term: covers entire range
+        return Ok([start, start, end, end]);
+    };
+
+    let epsilon = (end - start) * 0.001;
+    let (new_prev_c, new_prev_d, breakpoints) = if (prev_d - end).abs() < epsilon * 2.0 {
+        // Last term spans to end: the old term keeps the first half, the new one
+        // takes the second half up to the end
+        let range = prev_d - prev_b;
+        let mid = prev_b + range / 2.0;
+        let overlap = range / 4.0;
+        let (c, d) = (mid - overlap, mid + overlap);
+        (c, d, [c, d, end, end])
+    } else {
+        let mid = (prev_a + prev_d) / 2.0;
+        let quarter = (prev_d - prev_a) / 4.0;
+        let (c, d) = (mid - quarter, mid + quarter);
+        (c, d, [c, d, prev_d - quarter, prev_d])
+    };
+    if let Some(prev) = table.get_mut(prev_id) {
+        prev.row.set_right(new_prev_c, new_prev_d);
+    }
+
+    Ok(breakpoints)
+}
+
+/// Closest terms of the parameter left and right of `a`, leaving out `except`
+fn neighbours<T: Term>(
+    table: &Table<Positioned<T>>,
+    parent_id: i64,
+    except: Option<i64>,
+    a: f32,
+) -> (Option<i64>, Option<i64>) {
+    let mut prev: Option<(i64, f32)> = None;
+    let mut next: Option<(i64, f32)> = None;
+    for term in table.rows() {
+        if term.row.parent_id() != parent_id || Some(term.row.id()) == except {
+            continue;
+        }
+        let term_a = term.row.breakpoints()[0];
+        if term_a < a && prev.is_none_or(|(_, prev_a)| term_a > prev_a) {
+            prev = Some((term.row.id(), term_a));
+        }
+        if term_a > a && next.is_none_or(|(_, next_a)| term_a < next_a) {
+            next = Some((term.row.id(), term_a));
+        }
+    }
+    (prev.map(|(id, _)| id), next.map(|(id, _)| id))
+}
+
+/// Reshapes the neighbours of a Ruspini term about to be removed so they close the
+/// gap it leaves
+pub fn close_gap<T: Term>(table: &mut Table<Positioned<T>>, parent_id: i64, [a, b, c, d]: [f32; 4]) {
+    match neighbours(table, parent_id, None, a) {
+        (Some(prev), Some(next)) => {
+            let mid = a + (d - a) / 2.0;
+            let pivot = (d - a) / 4.0;
+            let (prev_c, prev_d) = (mid - pivot, mid + pivot);
+            if let Some(prev) = table.get_mut(prev) {
+                prev.row.set_right(prev_c, prev_d);
+            }
+            if let Some(next) = table.get_mut(next) {
+                next.row.set_left(prev_c, prev_d);
+            }
+        }
+        // Deleting the last one, extend prev to the end
+        (Some(prev), None) => {
+            if let Some(prev) = table.get_mut(prev) {
+                prev.row.set_right(c, d);
+            }
+        }
+        // Deleting the first one, extend next to the start
+        (None, Some(next)) => {
+            if let Some(next) = table.get_mut(next) {
+                next.row.set_left(a, b);
+            }
+        }
+        (None, None) => {}
+    }
+}
+
+/// Makes the neighbours of an edited Ruspini term overlap with its new breakpoints
+pub fn fit_neighbours<T: Term>(table: &mut Table<Positioned<T>>, parent_id: i64, id: i64, [a, b, c, d]: [f32; 4]) {
+    let (prev, next) = neighbours(table, parent_id, Some(id), a);
+    if let Some(prev) = prev.and_then(|prev| table.get_mut(prev)) {
+        prev.row.set_right(a, b);
+    }
+    if let Some(next) = next.and_then(|next| table.get_mut(next)) {
+        next.row.set_left(c, d);
+    }
+}
+
+/// Checks the breakpoints of the edited term `id`: a <= b <= c <= d
+pub fn validate_breakpoints(
+    entity: EntityKind,
+    id: i64,
+    [a, b, c, d]: [f32; 4],
+) -> Result<(), DomainError> {
+    for ((left_name, left), (right_name, right)) in [(("a", a), ("b", b)), (("b", b), ("c", c)), (("c", c), ("d", d))] {
+        if left > right {
+            return Err(DomainError::invalid_field(
+                entity,
+                Some(id),
+                FieldError::new(
+                    left_name,
+                    format!("{} ({}) must be <= {} ({})", left_name, left, right_name, right),
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Terms of a Ruspini partition are ordered along the range, so after a move the
+/// shapes stay where they are and each term takes the shape at its new position
+pub fn keep_shapes_in_place<T: Term>(table: &mut Table<Positioned<T>>, parent_id: i64, ids: &[i64]) {
+    let mut shapes: Vec<(i64, Shape)> = position::siblings(table, parent_id)
+        .into_iter()
+        .map(|term| (term.row.id(), term.row.shape()))
+        .collect();
+    shapes.sort_by(|(left_id, ([left_a, .., left_d], ..)), (right_id, ([right_a, .., right_d], ..))| {
+        left_a
+            .total_cmp(right_a)
+            .then(left_d.total_cmp(right_d))
+            .then(left_id.cmp(right_id))
+    });
+    for (term_id, (_, shape)) in ids.iter().zip(shapes) {
+        if let Some(term) = table.get_mut(*term_id) {
+            term.row.set_shape(shape);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::test_case::TestCase,
+        error::{DomainError, EntityKind},
+        repository::TestCaseRepository,
+    },
+    infrastructure::repository::memory::store::MemoryStore,
+};
+
+pub struct InMemoryTestCaseRepository {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryTestCaseRepository {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+impl TestCaseRepository for InMemoryTestCaseRepository {
+    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<TestCase>, DomainError> {
+        self.store.read(|tables| {
+            Ok(tables
+                .test_case
+                .rows()
+                .filter(|test_case| test_case.problem_id == problem_id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    fn get_by_id(&self, id: i64) -> Result<TestCase, DomainError> {
+        self.store.read(|tables| {
+            tables
+                .test_case
+                .get(id)
+                .cloned()
+                .ok_or_else(|| DomainError::not_found(EntityKind::TestCase, id))
+        })
+    }
+
+    fn create(&self, model: &TestCase) -> Result<i64, DomainError> {
+        self.store
+            .write(|tables| Ok(tables.test_case.insert(|id| TestCase { id, ..model.clone() })))
+    }
+
+    fn update_by_id(&self, id: i64, model: &TestCase) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            let test_case = tables
+                .test_case
+                .get_mut(id)
+                .ok_or_else(|| DomainError::not_found(EntityKind::TestCase, id))?;
+            test_case.name = model.name.clone();
+            test_case.inputs = model.inputs.clone();
+            test_case.expectations = model.expectations.clone();
+            Ok(())
+        })
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        self.store.write(|tables| {
+            tables.test_case.remove(id);
+            Ok(())
+        })
+    }
+}
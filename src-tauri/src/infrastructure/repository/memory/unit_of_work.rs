@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{error::DomainError, repository::UnitOfWork},
+    infrastructure::repository::memory::store::MemoryStore,
+};
+
+/// Unit of work on the store shared by all in-memory repositories, see
+/// [`MemoryStore`] for how it isolates other threads
+pub struct InMemoryUnitOfWork {
+    store: Arc<MemoryStore>,
+}
+
+impl InMemoryUnitOfWork {
+    pub fn new(store: Arc<MemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+impl UnitOfWork for InMemoryUnitOfWork {
+    fn begin(&self) -> Result<(), DomainError> {
+        self.store.begin_unit_of_work()
+    }
+
+    fn commit(&self) -> Result<(), DomainError> {
+        self.store.finish_unit_of_work(true)
+    }
+
+    fn rollback(&self) -> Result<(), DomainError> {
+        self.store.finish_unit_of_work(false)
+    }
+}
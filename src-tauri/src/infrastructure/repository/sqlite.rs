@@ -1,7 +1,18 @@
+pub mod connection_pool;
+pub mod evaluation_history_repository;
 pub mod fuzzy_output_value_repository;
 pub mod image_repository;
 pub mod input_parameter_repository;
 pub mod input_value_repository;
+pub mod journal_repository;
+pub mod lower_membership;
 pub mod output_parameter_repository;
 pub mod output_value_repository;
+pub mod parameter_link_repository;
+pub mod parameter_metadata;
+pub mod position;
 pub mod problem_repository;
+pub mod problem_snapshot_repository;
+pub mod query_error;
+pub mod test_case_repository;
+pub mod unit_of_work;
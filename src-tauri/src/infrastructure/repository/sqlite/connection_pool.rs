@@ -0,0 +1,174 @@
+use std::{
+    ops::{Deref, DerefMut},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Condvar, Mutex, MutexGuard,
+    },
+    thread::{self, ThreadId},
+    time::Duration,
+};
+
+use rusqlite::{Connection, OpenFlags};
+
+use crate::domain::error::DomainError;
+
+/// How long a connection waits for a lock held by another connection before failing
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Connections to one SQLite database in WAL mode: a single writer and a few
+/// read-only connections, so reads are not queued behind a long write.
+///
+/// While a thread has a unit of work open, its reads go to the writer so they see
+/// its uncommitted writes, and writes of other threads wait until it is committed
+/// or rolled back instead of ending up inside it.
+pub struct SqliteConnectionPool {
+    writer: Mutex<Connection>,
+    readers: Vec<Mutex<Connection>>,
+    next_reader: AtomicUsize,
+    unit_of_work: Mutex<UnitOfWorkOwner>,
+    unit_of_work_ended: Condvar,
+}
+
+#[derive(Default)]
+struct UnitOfWorkOwner {
+    thread: Option<ThreadId>,
+    depth: usize,
+}
+
+impl UnitOfWorkOwner {
+    fn is_other_thread(&self) -> bool {
+        self.thread.is_some_and(|owner| owner != thread::current().id())
+    }
+}
+
+/// A connection borrowed from the pool
+pub enum PooledConnection<'a> {
+    Reader(MutexGuard<'a, Connection>),
+    Writer(MutexGuard<'a, Connection>),
+}
+
+impl Deref for PooledConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        match self {
+            PooledConnection::Reader(conn) | PooledConnection::Writer(conn) => conn,
+        }
+    }
+}
+
+impl DerefMut for PooledConnection<'_> {
+    fn deref_mut(&mut self) -> &mut Connection {
+        match self {
+            PooledConnection::Reader(conn) | PooledConnection::Writer(conn) => conn,
+        }
+    }
+}
+
+impl SqliteConnectionPool {
+    /// Switches the database the writer is connected to into WAL mode and opens
+    /// `readers` read-only connections next to it
+    pub fn open(writer: Connection, path: &Path, readers: usize) -> Result<Self, DomainError> {
+        writer
+            .query_row("PRAGMA journal_mode = WAL", [], |row| row.get::<_, String>(0))
+            .map_err(internal)?;
+        writer.pragma_update(None, "synchronous", "NORMAL").map_err(internal)?;
+        writer.busy_timeout(BUSY_TIMEOUT).map_err(internal)?;
+
+        let readers = (0..readers)
+            .map(|_| {
+                let conn = Connection::open_with_flags(
+                    path,
+                    OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+                )
+                .map_err(internal)?;
+                conn.busy_timeout(BUSY_TIMEOUT).map_err(internal)?;
+                Ok(Mutex::new(conn))
+            })
+            .collect::<Result<Vec<_>, DomainError>>()?;
+
+        Ok(Self {
+            writer: Mutex::new(writer),
+            readers,
+            next_reader: AtomicUsize::new(0),
+            unit_of_work: Mutex::new(UnitOfWorkOwner::default()),
+            unit_of_work_ended: Condvar::new(),
+        })
+    }
+
+    /// A connection for queries: a free reader, or the writer inside this
+    /// thread's unit of work
+    pub fn read(&self) -> Result<PooledConnection<'_>, DomainError> {
+        let in_unit_of_work = {
+            let owner = self.unit_of_work.lock().map_err(internal)?;
+            owner.thread == Some(thread::current().id())
+        };
+        if in_unit_of_work || self.readers.is_empty() {
+            return self.writer();
+        }
+
+        if let Some(conn) = self.readers.iter().find_map(|reader| reader.try_lock().ok()) {
+            return Ok(PooledConnection::Reader(conn));
+        }
+        let index = self.next_reader.fetch_add(1, Ordering::Relaxed) % self.readers.len();
+        let conn = self.readers[index].lock().map_err(internal)?;
+        Ok(PooledConnection::Reader(conn))
+    }
+
+    /// The writer, once no other thread has a unit of work open
+    pub fn write(&self) -> Result<PooledConnection<'_>, DomainError> {
+        let mut owner = self.unit_of_work.lock().map_err(internal)?;
+        while owner.is_other_thread() {
+            owner = self.unit_of_work_ended.wait(owner).map_err(internal)?;
+        }
+        // Taken before releasing the owner so no unit of work can begin in between
+        let conn = self.writer();
+        drop(owner);
+        conn
+    }
+
+    /// Opens a (nested) unit of work of this thread as a savepoint on the writer
+    pub fn begin_unit_of_work(&self, begin_sql: &str) -> Result<(), DomainError> {
+        {
+            let mut owner = self.unit_of_work.lock().map_err(internal)?;
+            while owner.is_other_thread() {
+                owner = self.unit_of_work_ended.wait(owner).map_err(internal)?;
+            }
+            owner.thread = Some(thread::current().id());
+            owner.depth += 1;
+        }
+        let result = self.writer()?.execute_batch(begin_sql).map_err(internal);
+        if result.is_err() {
+            self.end_unit_of_work()?;
+        }
+        result
+    }
+
+    /// Commits or rolls back the innermost unit of work of this thread; other
+    /// threads may write again once the outermost one has ended
+    pub fn finish_unit_of_work(&self, finish_sql: &str) -> Result<(), DomainError> {
+        let result = self.writer()?.execute_batch(finish_sql).map_err(internal);
+        self.end_unit_of_work()?;
+        result
+    }
+
+    fn end_unit_of_work(&self) -> Result<(), DomainError> {
+        let mut owner = self.unit_of_work.lock().map_err(internal)?;
+        owner.depth = owner.depth.saturating_sub(1);
+        if owner.depth == 0 {
+            owner.thread = None;
+            self.unit_of_work_ended.notify_all();
+        }
+        Ok(())
+    }
+
+    fn writer(&self) -> Result<PooledConnection<'_>, DomainError> {
+        let conn = self.writer.lock().map_err(internal)?;
+        Ok(PooledConnection::Writer(conn))
+    }
+}
+
+fn internal(e: impl ToString) -> DomainError {
+    DomainError::Internal(e.to_string())
+}
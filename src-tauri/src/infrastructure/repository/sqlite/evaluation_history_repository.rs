@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use rusqlite::{params, params_from_iter, types::Value, Row};
+
+use crate::domain::{
+    entities::evaluation_record::{
+        EvaluationHistoryFilter, EvaluationRecord, EvaluationRecordInput, EvaluationRecordOutput,
+    },
+    error::{DomainError, EntityKind},
+    repository::EvaluationHistoryRepository,
+};
+
+const SELECT_COLUMNS: &str = "SELECT id, problem_id, problem_name, inputs, method, resolution, outputs, definition_hash, created_at FROM evaluation_history";
+
+type RawRecord = (i64, i64, String, String, String, i64, String, String, String);
+use crate::infrastructure::repository::sqlite::{
+    connection_pool::SqliteConnectionPool, query_error::not_found_or_internal,
+};
+
+pub struct SqliteEvaluationHistoryRepository {
+    conn: Arc<SqliteConnectionPool>,
+}
+
+impl SqliteEvaluationHistoryRepository {
+    pub fn new(conn: Arc<SqliteConnectionPool>) -> Self {
+        Self { conn }
+    }
+
+    fn read_row(row: &Row) -> rusqlite::Result<RawRecord> {
+        Ok((
+            row.get(0)?,
+            row.get(1)?,
+            row.get(2)?,
+            row.get(3)?,
+            row.get(4)?,
+            row.get(5)?,
+            row.get(6)?,
+            row.get(7)?,
+            row.get(8)?,
+        ))
+    }
+
+    // Inputs and outputs are stored as JSON arrays, as in `test_case`
+    fn decode(raw: RawRecord) -> Result<EvaluationRecord, DomainError> {
+        let (id, problem_id, problem_name, inputs, method, resolution, outputs, definition_hash, created_at) =
+            raw;
+        let inputs: Vec<EvaluationRecordInput> =
+            serde_json::from_str(&inputs).map_err(|e| DomainError::Data(e.to_string()))?;
+        let outputs: Vec<EvaluationRecordOutput> =
+            serde_json::from_str(&outputs).map_err(|e| DomainError::Data(e.to_string()))?;
+        Ok(EvaluationRecord {
+            id,
+            problem_id,
+            problem_name,
+            inputs,
+            method,
+            resolution: resolution as usize,
+            outputs,
+            definition_hash,
+            created_at,
+        })
+    }
+}
+
+impl EvaluationHistoryRepository for SqliteEvaluationHistoryRepository {
+    fn get_filtered(
+        &self,
+        filter: &EvaluationHistoryFilter,
+    ) -> Result<Vec<EvaluationRecord>, DomainError> {
+        let mut conditions: Vec<&str> = vec![];
+        let mut values: Vec<Value> = vec![];
+
+        if let Some(problem_id) = filter.problem_id {
+            conditions.push("problem_id = ?");
+            values.push(Value::Integer(problem_id));
+        }
+        if let Some(method) = &filter.method {
+            conditions.push("method = ?");
+            values.push(Value::Text(method.clone()));
+        }
+        if let Some(definition_hash) = &filter.definition_hash {
+            conditions.push("definition_hash = ?");
+            values.push(Value::Text(definition_hash.clone()));
+        }
+        if let Some(from) = &filter.from {
+            conditions.push("created_at >= ?");
+            values.push(Value::Text(from.clone()));
+        }
+        if let Some(to) = &filter.to {
+            conditions.push("created_at <= ?");
+            values.push(Value::Text(to.clone()));
+        }
+
+        let mut sql = SELECT_COLUMNS.to_string();
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+        sql.push_str(" ORDER BY created_at DESC, id DESC LIMIT ? OFFSET ?");
+        // A negative LIMIT means "no limit" in SQLite
+        values.push(Value::Integer(filter.limit.unwrap_or(-1)));
+        values.push(Value::Integer(filter.offset.unwrap_or(0)));
+
+        let conn = self.conn.read()?;
+
+        let mut stmt = conn
+            .prepare(&sql)
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let rows = stmt
+            .query_map(params_from_iter(values), Self::read_row)
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        rows.into_iter().map(Self::decode).collect()
+    }
+
+    fn get_by_id(&self, id: i64) -> Result<EvaluationRecord, DomainError> {
+        let conn = self.conn.read()?;
+
+        let raw = conn
+            .query_row(
+                &format!("{} WHERE id = ?", SELECT_COLUMNS),
+                params![id],
+                Self::read_row,
+            )
+            .map_err(not_found_or_internal(EntityKind::EvaluationRecord, id))?;
+
+        Self::decode(raw)
+    }
+
+    fn create(&self, model: &EvaluationRecord) -> Result<i64, DomainError> {
+        let inputs = serde_json::to_string(&model.inputs)
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let outputs = serde_json::to_string(&model.outputs)
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let conn = self.conn.write()?;
+
+        let mut stmt = conn
+            .prepare(
+                "INSERT INTO evaluation_history
+            (problem_id, problem_name, inputs, method, resolution, outputs, definition_hash, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let time = Utc::now().to_rfc3339();
+
+        stmt.execute(params![
+            &model.problem_id,
+            &model.problem_name,
+            &inputs,
+            &model.method,
+            &(model.resolution as i64),
+            &outputs,
+            &model.definition_hash,
+            &time,
+        ])
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let removed = conn
+            .execute("DELETE FROM evaluation_history WHERE id = ?", params![&id])
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        if removed == 0 {
+            return Err(DomainError::not_found(EntityKind::EvaluationRecord, id));
+        }
+        Ok(())
+    }
+
+    fn remove_by_problem_id(&self, problem_id: i64) -> Result<usize, DomainError> {
+        let conn = self.conn.write()?;
+        conn.execute(
+            "DELETE FROM evaluation_history WHERE problem_id = ?",
+            params![&problem_id],
+        )
+        .map_err(|e| DomainError::Internal(e.to_string()))
+    }
+}
@@ -1,31 +1,38 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
 use rusqlite::{params, Connection};
 
-use crate::domain::{
-    entities::fuzzy_output_value::FuzzyOutputValue, error::DomainError,
-    repository::FuzzyOutputValueRepository,
+use crate::{
+    domain::{
+        entities::{
+            fuzzy_output_value::FuzzyOutputValue, lower_membership::LowerMembership,
+            partition_mode::PartitionMode,
+        },
+        error::{DomainError, EntityKind, FieldError},
+        repository::FuzzyOutputValueRepository,
+    },
+    infrastructure::repository::sqlite::{
+        connection_pool::SqliteConnectionPool, lower_membership, position,
+        query_error::not_found_or_internal,
+    },
 };
 
 pub struct SqliteFuzzyOutputValueRepository {
-    conn: Arc<Mutex<Connection>>,
+    conn: Arc<SqliteConnectionPool>,
 }
 
 impl SqliteFuzzyOutputValueRepository {
-    pub fn create(conn: Arc<Mutex<Connection>>) -> Self {
+    pub fn create(conn: Arc<SqliteConnectionPool>) -> Self {
         Self { conn: conn }
     }
 }
 
 impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
     fn get_by_output_parameter_id(&self, output_parameter_id: i64) -> Result<Vec<FuzzyOutputValue>, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.read()?;
         
         let mut stmt = conn
-            .prepare("SELECT id, output_parameter_id, value, a, b, c, d, is_triangle FROM fuzzy_output_value WHERE output_parameter_id = ?")
+            .prepare("SELECT id, output_parameter_id, value, a, b, c, d, is_triangle, lower_membership FROM fuzzy_output_value WHERE output_parameter_id = ? ORDER BY position, id")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         
         let values = stmt
@@ -39,6 +46,7 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
                     c: row.get(5)?,
                     d: row.get(6)?,
                     is_triangle: row.get(7)?,
+                    lower: lower_membership::decode(8, row.get(8)?)?,
                 })
             })
             .map_err(|e| DomainError::Internal(e.to_string()))?
@@ -48,13 +56,33 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
         Ok(values)
     }
 
-    fn create(&self, model: &FuzzyOutputValue) -> Result<i64, DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
-        let transaction = conn
-            .transaction()
+    fn get_by_id(&self, id: i64) -> Result<FuzzyOutputValue, DomainError> {
+        let conn = self.conn.read()?;
+
+        conn.query_row(
+            "SELECT id, output_parameter_id, value, a, b, c, d, is_triangle, lower_membership FROM fuzzy_output_value WHERE id = ?",
+            params![id],
+            |row| {
+                Ok(FuzzyOutputValue {
+                    id: row.get(0)?,
+                    output_parameter_id: row.get(1)?,
+                    value: row.get(2)?,
+                    a: row.get(3)?,
+                    b: row.get(4)?,
+                    c: row.get(5)?,
+                    d: row.get(6)?,
+                    is_triangle: row.get(7)?,
+                    lower: lower_membership::decode(8, row.get(8)?)?,
+                })
+            },
+        )
+        .map_err(not_found_or_internal(EntityKind::FuzzyOutputValue, id))
+    }
+
+    fn create(&self, model: &FuzzyOutputValue, shape: Option<[f32; 4]>) -> Result<i64, DomainError> {
+        let mut conn = self.conn.write()?;
+        let mut transaction = conn
+            .savepoint()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         let result: Result<i64, DomainError> = {
@@ -66,20 +94,49 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
                 )
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
 
-            let (output_parameter_start, output_parameter_end): (f32, f32) = transaction
+            let (output_parameter_start, output_parameter_end, partition_mode): (f32, f32, String) = transaction
                 .query_row(
-                    "SELECT start, end FROM output_parameter WHERE id = ?",
+                    "SELECT start, end, partition_mode FROM output_parameter WHERE id = ?",
                     params![model.output_parameter_id],
-                    |row| Ok((row.get(0)?, row.get(1)?)),
+                    |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
                 )
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                .map_err(not_found_or_internal(EntityKind::OutputParameter, model.output_parameter_id))?;
 
             let a: f32;
             let b: f32;
             let c: f32;
             let d: f32;
 
-            if number_of_fuzzy_output_values > 0 {
+            if PartitionMode::from_name(&partition_mode) == Some(PartitionMode::FreeForm) {
+                // Stored as given; a term created without a shape covers the whole range
+                match shape {
+                    None => {
+                        a = output_parameter_start;
+                        b = output_parameter_start;
+                        c = output_parameter_end;
+                        d = output_parameter_end;
+                    }
+                    Some([sa, sb, sc, sd]) if sa <= sb && sb <= sc && sc <= sd => {
+                        a = sa;
+                        b = sb;
+                        c = sc;
+                        d = sd;
+                    }
+                    Some([sa, sb, sc, sd]) => {
+                        return Err(DomainError::invalid_field(
+                            EntityKind::FuzzyOutputValue,
+                            None,
+                            FieldError::new(
+                                "shape",
+                                format!(
+                                    "Breakpoints ({}, {}, {}, {}) must satisfy a <= b <= c <= d",
+                                    sa, sb, sc, sd
+                                ),
+                            ),
+                        ));
+                    }
+                }
+            } else if number_of_fuzzy_output_values > 0 {
                 // Get the last term (rightmost) to split it
                 let (prev_id, prev_a, prev_b, _prev_c, prev_d): (i64, f32, f32, f32, f32) = transaction
                     .query_row(
@@ -87,7 +144,7 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
                         params![model.output_parameter_id],
                         |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
                     )
-                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                    .map_err(not_found_or_internal(EntityKind::OutputParameter, model.output_parameter_id))?;
 
                 // Split the rightmost term into two overlapping terms following Ruspini partition rules
                 // For overlapping terms: prev.c = next.a, prev.d = next.b
@@ -150,8 +207,9 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
                 d = output_parameter_end;
             }
 
+            let position = position::next(&transaction, "fuzzy_output_value", "output_parameter_id", model.output_parameter_id)?;
             let mut stmt = transaction
-                .prepare("INSERT INTO fuzzy_output_value (output_parameter_id, value, a, b, c, d) VALUES (?, ?, ?, ?, ?, ?)")
+                .prepare("INSERT INTO fuzzy_output_value (output_parameter_id, value, a, b, c, d, position) VALUES (?, ?, ?, ?, ?, ?, ?)")
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
             stmt.execute(params![
                 &model.output_parameter_id,
@@ -160,6 +218,7 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
                 &b,
                 &c,
                 &d,
+                &position,
             ])
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
@@ -183,13 +242,11 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
     }
 
     fn create_raw(&self, model: &FuzzyOutputValue) -> Result<i64, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
         
+        let position = position::next(&conn, "fuzzy_output_value", "output_parameter_id", model.output_parameter_id)?;
         let mut stmt = conn
-            .prepare("INSERT INTO fuzzy_output_value (output_parameter_id, value, a, b, c, d, is_triangle) VALUES (?, ?, ?, ?, ?, ?, ?)")
+            .prepare("INSERT INTO fuzzy_output_value (output_parameter_id, value, a, b, c, d, is_triangle, lower_membership, position) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         
         stmt.execute(params![
@@ -199,7 +256,9 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
             &model.b,
             &model.c,
             &model.d,
-            &model.is_triangle
+            &model.is_triangle,
+            &lower_membership::encode(model.lower.as_ref())?,
+            &position
         ])
         .map_err(|e| DomainError::Internal(e.to_string()))?;
 
@@ -207,12 +266,9 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
     }
 
     fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
-        let transaction = conn
-            .transaction()
+        let mut conn = self.conn.write()?;
+        let mut transaction = conn
+            .savepoint()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         let result: Result<(), DomainError> = {
@@ -222,7 +278,7 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
                     params![&id],
                     |row| row.get(0),
                 )
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                .map_err(not_found_or_internal(EntityKind::FuzzyOutputValue, id))?;
 
             let fuzzy_output_values_number: i64 = transaction
                 .query_row(
@@ -233,101 +289,104 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
 
             if fuzzy_output_values_number > 1 {
-                let (a, _b, _c, d): (f32, f32, f32, f32) = transaction
-                    .query_row(
-                        "SELECT a, b, c, d FROM fuzzy_output_value WHERE id = ?",
-                        params![id],
-                        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
-                    )
-                    .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-                let prev_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
-                    .query_row(
-                        "SELECT id, a, b, c, d FROM fuzzy_output_value WHERE output_parameter_id = ? AND a < ? ORDER BY a DESC LIMIT 1",
-                        params![output_parameter_id, a],
-                        |row| {
-                            Ok((
-                                row.get(0)?,
-                                row.get(1)?,
-                                row.get(2)?,
-                                row.get(3)?,
-                                row.get(4)?,
-                            ))
-                        },
-                    );
-
-                let next_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
-                    .query_row(
-                        "SELECT id, a, b, c, d FROM fuzzy_output_value WHERE output_parameter_id = ? AND a > ? ORDER BY a ASC LIMIT 1",
-                        params![output_parameter_id, a],
-                        |row| {
-                            Ok((
-                                row.get(0)?,
-                                row.get(1)?,
-                                row.get(2)?,
-                                row.get(3)?,
-                                row.get(4)?,
-                            ))
-                        },
-                    );
-
-                match (prev_result, next_result) {
-                    (Ok(prev), Ok(next)) => {
-                        let mid = a + (d - a) / 2.0;
-                        let pivot = (d - a) / 4.0;
-
-                        let prev_c = mid - pivot;
-                        let prev_d = mid + pivot;
-
-                        transaction
-                            .execute(
-                                "UPDATE fuzzy_output_value SET c = ?, d = ? WHERE id = ?",
-                                params![prev_c, prev_d, prev.0],
-                            )
-                            .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-                        let next_a = prev_c;
-                        let next_b = prev_d;
-
-                        transaction
-                            .execute(
-                                "UPDATE fuzzy_output_value SET a = ?, b = ? WHERE id = ?",
-                                params![next_a, next_b, next.0],
-                            )
-                            .map_err(|e| DomainError::Internal(e.to_string()))?;
-                    }
-                    (Ok(prev), Err(rusqlite::Error::QueryReturnedNoRows)) => {
-                        // No next element - we're deleting the last one, extend prev to end
-                        transaction
-                            .execute(
-                                "UPDATE fuzzy_output_value SET c = ?, d = ? WHERE id = ?",
-                                params![_c, d, prev.0],
-                            )
-                            .map_err(|e| DomainError::Internal(e.to_string()))?;
-                    }
-                    (Ok(_prev), Err(e)) => {
-                        return Err(DomainError::Data(e.to_string()));
-                    }
-                    (Err(rusqlite::Error::QueryReturnedNoRows), Ok(next)) => {
-                        // No prev element - we're deleting the first one, extend next to start
-                        transaction
-                            .execute(
-                                "UPDATE fuzzy_output_value SET a = ?, b = ? WHERE id = ?",
-                                params![a, _b, next.0],
-                            )
-                            .map_err(|e| DomainError::Internal(e.to_string()))?;
-                    }
-                    (Err(e), Ok(_next)) => {
-                        return Err(DomainError::Data(e.to_string()));
-                    }
-                    (Err(rusqlite::Error::QueryReturnedNoRows), Err(rusqlite::Error::QueryReturnedNoRows)) => {
-                        // Single element being deleted - nothing to adjust
-                    }
-                    (Err(prev_e), Err(_next_e)) => {
-                        if !matches!(prev_e, rusqlite::Error::QueryReturnedNoRows) {
-                            return Err(DomainError::Data(prev_e.to_string()));
+                // Free-form terms are stored as given, without reshaping their neighbours
+                if !is_free_form(&transaction, output_parameter_id)? {
+                    let (a, _b, _c, d): (f32, f32, f32, f32) = transaction
+                        .query_row(
+                            "SELECT a, b, c, d FROM fuzzy_output_value WHERE id = ?",
+                            params![id],
+                            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+                        )
+                        .map_err(not_found_or_internal(EntityKind::FuzzyOutputValue, id))?;
+
+                    let prev_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
+                        .query_row(
+                            "SELECT id, a, b, c, d FROM fuzzy_output_value WHERE output_parameter_id = ? AND a < ? ORDER BY a DESC LIMIT 1",
+                            params![output_parameter_id, a],
+                            |row| {
+                                Ok((
+                                    row.get(0)?,
+                                    row.get(1)?,
+                                    row.get(2)?,
+                                    row.get(3)?,
+                                    row.get(4)?,
+                                ))
+                            },
+                        );
+
+                    let next_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
+                        .query_row(
+                            "SELECT id, a, b, c, d FROM fuzzy_output_value WHERE output_parameter_id = ? AND a > ? ORDER BY a ASC LIMIT 1",
+                            params![output_parameter_id, a],
+                            |row| {
+                                Ok((
+                                    row.get(0)?,
+                                    row.get(1)?,
+                                    row.get(2)?,
+                                    row.get(3)?,
+                                    row.get(4)?,
+                                ))
+                            },
+                        );
+
+                    match (prev_result, next_result) {
+                        (Ok(prev), Ok(next)) => {
+                            let mid = a + (d - a) / 2.0;
+                            let pivot = (d - a) / 4.0;
+
+                            let prev_c = mid - pivot;
+                            let prev_d = mid + pivot;
+
+                            transaction
+                                .execute(
+                                    "UPDATE fuzzy_output_value SET c = ?, d = ? WHERE id = ?",
+                                    params![prev_c, prev_d, prev.0],
+                                )
+                                .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+                            let next_a = prev_c;
+                            let next_b = prev_d;
+
+                            transaction
+                                .execute(
+                                    "UPDATE fuzzy_output_value SET a = ?, b = ? WHERE id = ?",
+                                    params![next_a, next_b, next.0],
+                                )
+                                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                        }
+                        (Ok(prev), Err(rusqlite::Error::QueryReturnedNoRows)) => {
+                            // No next element - we're deleting the last one, extend prev to end
+                            transaction
+                                .execute(
+                                    "UPDATE fuzzy_output_value SET c = ?, d = ? WHERE id = ?",
+                                    params![_c, d, prev.0],
+                                )
+                                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                        }
+                        (Ok(_prev), Err(e)) => {
+                            return Err(DomainError::Data(e.to_string()));
+                        }
+                        (Err(rusqlite::Error::QueryReturnedNoRows), Ok(next)) => {
+                            // No prev element - we're deleting the first one, extend next to start
+                            transaction
+                                .execute(
+                                    "UPDATE fuzzy_output_value SET a = ?, b = ? WHERE id = ?",
+                                    params![a, _b, next.0],
+                                )
+                                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                        }
+                        (Err(e), Ok(_next)) => {
+                            return Err(DomainError::Data(e.to_string()));
+                        }
+                        (Err(rusqlite::Error::QueryReturnedNoRows), Err(rusqlite::Error::QueryReturnedNoRows)) => {
+                            // Single element being deleted - nothing to adjust
+                        }
+                        (Err(prev_e), Err(_next_e)) => {
+                            if !matches!(prev_e, rusqlite::Error::QueryReturnedNoRows) {
+                                return Err(DomainError::Data(prev_e.to_string()));
+                            }
+                            return Err(DomainError::Data(_next_e.to_string()));
                         }
-                        return Err(DomainError::Data(_next_e.to_string()));
                     }
                 }
             }
@@ -368,30 +427,30 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
         // First term: a = b (allowed)
         // Last term: c = d (allowed)
         if model.a > model.b {
-            return Err(DomainError::Validation(format!(
-                "Invalid fuzzy_output_value: a ({}) must be <= b ({})",
-                model.a, model.b
-            )));
+            return Err(DomainError::invalid_field(
+                EntityKind::FuzzyOutputValue,
+                Some(id),
+                FieldError::new("a", format!("a ({}) must be <= b ({})", model.a, model.b)),
+            ));
         }
         if model.b > model.c {
-            return Err(DomainError::Validation(format!(
-                "Invalid fuzzy_output_value: b ({}) must be <= c ({})",
-                model.b, model.c
-            )));
+            return Err(DomainError::invalid_field(
+                EntityKind::FuzzyOutputValue,
+                Some(id),
+                FieldError::new("b", format!("b ({}) must be <= c ({})", model.b, model.c)),
+            ));
         }
         if model.c > model.d {
-            return Err(DomainError::Validation(format!(
-                "Invalid fuzzy_output_value: c ({}) must be <= d ({})",
-                model.c, model.d
-            )));
+            return Err(DomainError::invalid_field(
+                EntityKind::FuzzyOutputValue,
+                Some(id),
+                FieldError::new("c", format!("c ({}) must be <= d ({})", model.c, model.d)),
+            ));
         }
         
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
-        let transaction = conn
-            .transaction()
+        let mut conn = self.conn.write()?;
+        let mut transaction = conn
+            .savepoint()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         let result: Result<(), DomainError> = {
@@ -401,7 +460,7 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
                     params![id],
                     |row| row.get(0),
                 )
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                .map_err(not_found_or_internal(EntityKind::FuzzyOutputValue, id))?;
 
             transaction
                 .execute(
@@ -417,82 +476,85 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
                 )
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
 
-            let prev_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
-                .query_row(
-                    "SELECT id, a, b, c, d FROM fuzzy_output_value WHERE output_parameter_id = ? AND id != ? AND a < ? ORDER BY a DESC LIMIT 1",
-                    params![output_parameter_id, id, model.a],
-                    |row| {
-                        Ok((
-                            row.get(0)?,
-                            row.get(1)?,
-                            row.get(2)?,
-                            row.get(3)?,
-                            row.get(4)?,
-                        ))
-                    },
-                );
+            // Free-form terms are stored as given, without reshaping their neighbours
+            if !is_free_form(&transaction, output_parameter_id)? {
+                let prev_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
+                    .query_row(
+                        "SELECT id, a, b, c, d FROM fuzzy_output_value WHERE output_parameter_id = ? AND id != ? AND a < ? ORDER BY a DESC LIMIT 1",
+                        params![output_parameter_id, id, model.a],
+                        |row| {
+                            Ok((
+                                row.get(0)?,
+                                row.get(1)?,
+                                row.get(2)?,
+                                row.get(3)?,
+                                row.get(4)?,
+                            ))
+                        },
+                    );
 
-            let next_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
-                .query_row(
-                    "SELECT id, a, b, c, d FROM fuzzy_output_value WHERE output_parameter_id = ? AND id != ? AND a > ? ORDER BY a ASC LIMIT 1",
-                    params![output_parameter_id, id, model.a],
-                    |row| {
-                        Ok((
-                            row.get(0)?,
-                            row.get(1)?,
-                            row.get(2)?,
-                            row.get(3)?,
-                            row.get(4)?,
-                        ))
-                    },
-                );
+                let next_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
+                    .query_row(
+                        "SELECT id, a, b, c, d FROM fuzzy_output_value WHERE output_parameter_id = ? AND id != ? AND a > ? ORDER BY a ASC LIMIT 1",
+                        params![output_parameter_id, id, model.a],
+                        |row| {
+                            Ok((
+                                row.get(0)?,
+                                row.get(1)?,
+                                row.get(2)?,
+                                row.get(3)?,
+                                row.get(4)?,
+                            ))
+                        },
+                    );
 
-            match (prev_result, next_result) {
-                (Ok(prev), Ok(next)) => {
-                    transaction
-                        .execute(
-                            "UPDATE fuzzy_output_value SET c = ?, d = ? WHERE id = ?",
-                            params![model.a, model.b, prev.0],
-                        )
-                        .map_err(|e| DomainError::Internal(e.to_string()))?;
+                match (prev_result, next_result) {
+                    (Ok(prev), Ok(next)) => {
+                        transaction
+                            .execute(
+                                "UPDATE fuzzy_output_value SET c = ?, d = ? WHERE id = ?",
+                                params![model.a, model.b, prev.0],
+                            )
+                            .map_err(|e| DomainError::Internal(e.to_string()))?;
 
-                    transaction
-                        .execute(
-                            "UPDATE fuzzy_output_value SET a = ?, b = ? WHERE id = ?",
-                            params![model.c, model.d, next.0],
-                        )
-                        .map_err(|e| DomainError::Internal(e.to_string()))?;
-                }
-                (Ok(prev), Err(rusqlite::Error::QueryReturnedNoRows)) => {
-                    transaction
-                        .execute(
-                            "UPDATE fuzzy_output_value SET c = ?, d = ? WHERE id = ?",
-                            params![model.a, model.b, prev.0],
-                        )
-                        .map_err(|e| DomainError::Internal(e.to_string()))?;
-                }
-                (Ok(_prev), Err(e)) => {
-                    return Err(DomainError::Data(e.to_string()));
-                }
-                (Err(rusqlite::Error::QueryReturnedNoRows), Ok(next)) => {
-                    transaction
-                        .execute(
-                            "UPDATE fuzzy_output_value SET a = ?, b = ? WHERE id = ?",
-                            params![model.c, model.d, next.0],
-                        )
-                        .map_err(|e| DomainError::Internal(e.to_string()))?;
-                }
-                (Err(e), Ok(_next)) => {
-                    return Err(DomainError::Data(e.to_string()));
-                }
-                (Err(rusqlite::Error::QueryReturnedNoRows), Err(rusqlite::Error::QueryReturnedNoRows)) => {
-                    // Single element - nothing to adjust
-                }
-                (Err(prev_e), Err(_next_e)) => {
-                    if !matches!(prev_e, rusqlite::Error::QueryReturnedNoRows) {
-                        return Err(DomainError::Data(prev_e.to_string()));
+                        transaction
+                            .execute(
+                                "UPDATE fuzzy_output_value SET a = ?, b = ? WHERE id = ?",
+                                params![model.c, model.d, next.0],
+                            )
+                            .map_err(|e| DomainError::Internal(e.to_string()))?;
+                    }
+                    (Ok(prev), Err(rusqlite::Error::QueryReturnedNoRows)) => {
+                        transaction
+                            .execute(
+                                "UPDATE fuzzy_output_value SET c = ?, d = ? WHERE id = ?",
+                                params![model.a, model.b, prev.0],
+                            )
+                            .map_err(|e| DomainError::Internal(e.to_string()))?;
+                    }
+                    (Ok(_prev), Err(e)) => {
+                        return Err(DomainError::Data(e.to_string()));
+                    }
+                    (Err(rusqlite::Error::QueryReturnedNoRows), Ok(next)) => {
+                        transaction
+                            .execute(
+                                "UPDATE fuzzy_output_value SET a = ?, b = ? WHERE id = ?",
+                                params![model.c, model.d, next.0],
+                            )
+                            .map_err(|e| DomainError::Internal(e.to_string()))?;
+                    }
+                    (Err(e), Ok(_next)) => {
+                        return Err(DomainError::Data(e.to_string()));
+                    }
+                    (Err(rusqlite::Error::QueryReturnedNoRows), Err(rusqlite::Error::QueryReturnedNoRows)) => {
+                        // Single element - nothing to adjust
+                    }
+                    (Err(prev_e), Err(_next_e)) => {
+                        if !matches!(prev_e, rusqlite::Error::QueryReturnedNoRows) {
+                            return Err(DomainError::Data(prev_e.to_string()));
+                        }
+                        return Err(DomainError::Data(_next_e.to_string()));
                     }
-                    return Err(DomainError::Data(_next_e.to_string()));
                 }
             }
 
@@ -515,59 +577,106 @@ impl FuzzyOutputValueRepository for SqliteFuzzyOutputValueRepository {
         }
     }
 
-    fn switch(&self, id_1: i64, id_2: i64) -> Result<(), DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
+    fn update_shape(&self, id: i64, model: &FuzzyOutputValue) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let updated = conn
+            .execute(
+                "UPDATE fuzzy_output_value SET a = ?, b = ?, c = ?, d = ?, lower_membership = ? WHERE id = ?",
+                params![
+                    model.a,
+                    model.b,
+                    model.c,
+                    model.d,
+                    lower_membership::encode(model.lower.as_ref())?,
+                    id
+                ],
+            )
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::FuzzyOutputValue, id));
+        }
 
-        let tx = conn
-            .transaction()
+        Ok(())
+    }
+
+    fn update_lower(&self, id: i64, lower: Option<&LowerMembership>) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let updated = conn
+            .execute(
+                "UPDATE fuzzy_output_value SET lower_membership = ? WHERE id = ?",
+                params![lower_membership::encode(lower)?, id],
+            )
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::FuzzyOutputValue, id));
+        }
 
-        let value_1 = {
-            let mut stmt = tx
-                .prepare("SELECT a, b, c, d FROM fuzzy_output_value WHERE id = ?")
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
-            let result: (f32, f32, f32, f32) = stmt
-                .query_row(params![&id_1], |row| {
-                    Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
-                })
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+        Ok(())
+    }
 
-            result
-        };
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError> {
+        let mut conn = self.conn.write()?;
+        let tx = conn
+            .savepoint()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
 
-        let value_2 = {
-            let mut stmt = tx
-                .prepare("SELECT a, b, c, d FROM fuzzy_output_value WHERE id = ?")
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
-            let result: (f32, f32, f32, f32) = stmt
-                .query_row(params![&id_2], |row| {
-                    Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
-                })
+        let ids = position::move_to(&tx, EntityKind::FuzzyOutputValue, "fuzzy_output_value", "output_parameter_id", id, position)?;
+
+        let output_parameter_id: i64 = tx
+            .query_row(
+                "SELECT output_parameter_id FROM fuzzy_output_value WHERE id = ?",
+                params![id],
+                |row| row.get(0),
+            )
+            .map_err(not_found_or_internal(EntityKind::FuzzyOutputValue, id))?;
+        if !is_free_form(&tx, output_parameter_id)? {
+            // Terms of a Ruspini partition are ordered along the range, so the shapes
+            // stay where they are and each term takes the shape at its new position
+            let shapes = {
+                let mut stmt = tx
+                    .prepare("SELECT a, b, c, d, is_triangle, lower_membership FROM fuzzy_output_value WHERE output_parameter_id = ? ORDER BY a, d, id")
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                let shapes = stmt
+                    .query_map(params![output_parameter_id], |row| {
+                        Ok((
+                            row.get::<_, f32>(0)?,
+                            row.get::<_, f32>(1)?,
+                            row.get::<_, f32>(2)?,
+                            row.get::<_, f32>(3)?,
+                            row.get::<_, bool>(4)?,
+                            row.get::<_, Option<String>>(5)?,
+                        ))
+                    })
+                    .map_err(|e| DomainError::Internal(e.to_string()))?
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                shapes
+            };
+            for (term_id, (a, b, c, d, is_triangle, lower)) in ids.iter().zip(shapes) {
+                tx.execute(
+                    "UPDATE fuzzy_output_value SET a = ?, b = ?, c = ?, d = ?, is_triangle = ?, lower_membership = ? WHERE id = ?",
+                    params![a, b, c, d, is_triangle, lower, term_id],
+                )
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-            result
-        };
-
-        // Swap only trapezoid parameters (a, b, c, d), keep value names and IDs unchanged
-        tx.execute(
-            "UPDATE fuzzy_output_value SET a = ?, b = ?, c = ?, d = ? WHERE id = ?",
-            params![value_2.0, value_2.1, value_2.2, value_2.3, id_1],
-        )
-        .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-        tx.execute(
-            "UPDATE fuzzy_output_value SET a = ?, b = ?, c = ?, d = ? WHERE id = ?",
-            params![value_1.0, value_1.1, value_1.2, value_1.3, id_2],
-        )
-        .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-        // No need to update output_value table - IDs remain the same
+            }
+        }
 
         tx.commit()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         Ok(())
     }
 }
+
+/// Whether the terms of the parameter are stored as given instead of keeping a
+/// Ruspini partition
+fn is_free_form(conn: &Connection, output_parameter_id: i64) -> Result<bool, DomainError> {
+    let partition_mode: String = conn
+        .query_row(
+            "SELECT partition_mode FROM output_parameter WHERE id = ?",
+            params![output_parameter_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+    Ok(PartitionMode::from_name(&partition_mode) == Some(PartitionMode::FreeForm))
+}
@@ -1,17 +1,22 @@
-use std::{
-    sync::{Arc, Mutex},
-};
+use std::sync::Arc;
 
-use rusqlite::{params, Connection};
+use rusqlite::params;
 
-use crate::domain::{entities::image::Image, error::DomainError, repository::ImageRepository};
+use crate::domain::{
+    entities::image::Image,
+    error::{DomainError, EntityKind},
+    repository::ImageRepository,
+};
+use crate::infrastructure::repository::sqlite::{
+    connection_pool::SqliteConnectionPool, query_error::not_found_or_internal,
+};
 
 pub struct SqliteImageRepository {
-    conn: Arc<Mutex<Connection>>,
+    conn: Arc<SqliteConnectionPool>,
 }
 
 impl SqliteImageRepository {
-    pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
+    pub fn new(conn: Arc<SqliteConnectionPool>) -> Self {
         Self {
             conn: conn,
         }
@@ -20,10 +25,7 @@ impl SqliteImageRepository {
 
 impl ImageRepository for SqliteImageRepository {
     fn get_by_id(&self, id: i64) -> Result<Image, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.read()?;
         let mut stmt = conn
             .prepare("SELECT id, image_data, image_format FROM image WHERE id = ?")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -35,16 +37,13 @@ impl ImageRepository for SqliteImageRepository {
                     image_format: row.get(2)?,
                 })
             })
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+            .map_err(not_found_or_internal(EntityKind::Image, id))?;
 
         Ok(image)
     }
 
     fn update_by_id(&self, id: i64, model: &Image) -> Result<(), DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
 
         let mut stmt = conn
             .prepare("UPDATE image SET image_data = ?, image_format = ? WHERE id = ?")
@@ -1,32 +1,40 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
-use rusqlite::{params, Connection};
+use rusqlite::params;
 
 use crate::domain::{
-    entities::{input_parameter::InputParameter, input_value::InputValue},
-    error::DomainError,
+    entities::{
+        input_parameter::InputParameter, input_value::InputValue,
+        parameter_kind::ParameterKind, parameter_metadata::ParameterMetadata,
+        partition_mode::PartitionMode,
+    },
+    error::{DomainError, EntityKind},
     repository::InputParameterRepository,
 };
+use crate::infrastructure::repository::sqlite::{
+    connection_pool::SqliteConnectionPool, parameter_metadata, position,
+    query_error::not_found_or_internal,
+};
 
 pub struct SqliteInputParameterRepository {
-    conn: Arc<Mutex<Connection>>,
+    conn: Arc<SqliteConnectionPool>,
 }
 
 impl SqliteInputParameterRepository {
-    pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
+    pub fn new(conn: Arc<SqliteConnectionPool>) -> Self {
         Self { conn: conn }
     }
 }
 
 impl InputParameterRepository for SqliteInputParameterRepository {
     fn get_by_id(&self, id: i64) -> Result<InputParameter, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.read()?;
         let model: InputParameter = conn
             .query_row(
-                "SELECT id, problem_id, name, start, end FROM input_parameter WHERE id = ?",
+                &format!(
+                    "SELECT id, problem_id, name, start, end, partition_mode, kind, {} FROM input_parameter WHERE id = ?",
+                    parameter_metadata::COLUMNS
+                ),
                 params![id],
                 |row| {
                     Ok(InputParameter {
@@ -35,27 +43,29 @@ impl InputParameterRepository for SqliteInputParameterRepository {
                         name: row.get(2)?,
                         start: row.get(3)?,
                         end: row.get(4)?,
+                        partition_mode: PartitionMode::from_name(&row.get::<_, String>(5)?)
+                            .unwrap_or_default(),
+                        kind: ParameterKind::from_name(&row.get::<_, String>(6)?).unwrap_or_default(),
+                        metadata: parameter_metadata::read(row, 7)?,
                         input_values: Vec::<InputValue>::new(),
                     })
                 },
             )
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+            .map_err(not_found_or_internal(EntityKind::InputParameter, id))?;
 
         Ok(model)
     }
 
     fn create(&self, problem_id: i64, model: &InputParameter) -> Result<i64, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
+        let position = position::next(&conn, "input_parameter", "problem_id", problem_id)?;
         let mut stmt = conn
             .prepare(
-                "INSERT INTO input_parameter (problem_id, name, start, end) VALUES (?, ?, ?, ?)",
+                "INSERT INTO input_parameter (problem_id, name, start, end, position) VALUES (?, ?, ?, ?, ?)",
             )
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
-        stmt.execute(params![&problem_id, &model.name, &model.start, &model.end,])
+        stmt.execute(params![&problem_id, &model.name, &model.start, &model.end, &position])
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         let new_id = conn.last_insert_rowid();
@@ -64,28 +74,35 @@ impl InputParameterRepository for SqliteInputParameterRepository {
     }
 
     fn create_raw(&self, problem_id: i64, model: &InputParameter) -> Result<i64, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
 
+        let position = position::next(&conn, "input_parameter", "problem_id", problem_id)?;
         let mut stmt = conn
-            .prepare("INSERT INTO input_parameter (problem_id, name, start, end) VALUES (?, ?, ?, ?)")
+            .prepare("INSERT INTO input_parameter (problem_id, name, start, end, partition_mode, kind, position, unit, description, precision, default_value) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
-        stmt.execute(params![&problem_id, &model.name, &model.start, &model.end])
+        stmt.execute(params![
+            &problem_id,
+            &model.name,
+            &model.start,
+            &model.end,
+            model.partition_mode.as_str(),
+            model.kind.as_str(),
+            &position,
+            &model.metadata.unit,
+            &model.metadata.description,
+            &model.metadata.precision,
+            &model.metadata.default_value
+        ])
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         Ok(conn.last_insert_rowid())
     }
 
     fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let mut conn = self.conn.write()?;
 
-        let transaction = conn
-            .transaction()
+        let mut transaction = conn
+            .savepoint()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         let result: Result<(), DomainError> = {
@@ -95,7 +112,7 @@ impl InputParameterRepository for SqliteInputParameterRepository {
                     params![&id],
                     |row| row.get(0),
                 )
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                .map_err(not_found_or_internal(EntityKind::InputParameter, id))?;
 
             let input_parameter_number: i64 = transaction
                 .query_row(
@@ -175,6 +192,9 @@ impl InputParameterRepository for SqliteInputParameterRepository {
                 }
             }
 
+            transaction
+                .execute("DELETE FROM parameter_link WHERE input_parameter_id = ?", params![&id])
+                .map_err(|e| DomainError::Internal(e.to_string()))?;
             transaction
                 .execute("DELETE FROM input_parameter WHERE id = ?", params![&id])
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -198,10 +218,7 @@ impl InputParameterRepository for SqliteInputParameterRepository {
     }
 
     fn update_by_id(&self, id: i64, model: &InputParameter) -> Result<(), DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
 
         let mut stmt = conn
             .prepare("UPDATE input_parameter SET name = ?, start = ?, end = ? WHERE id = ?")
@@ -212,65 +229,64 @@ impl InputParameterRepository for SqliteInputParameterRepository {
         Ok(())
     }
 
-    fn switch(&self, id_1: i64, id_2: i64) -> Result<(), DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-        let tx = conn
-            .transaction()
+    fn update_partition_mode(&self, id: i64, mode: PartitionMode) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let updated = conn
+            .execute(
+                "UPDATE input_parameter SET partition_mode = ? WHERE id = ?",
+                params![mode.as_str(), id],
+            )
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::InputParameter, id));
+        }
 
-        let param_1 = {
-            let mut stmt = tx
-                .prepare("SELECT name, start, end FROM input_parameter WHERE id = ?")
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+        Ok(())
+    }
 
-            let result: (String, f64, f64) = stmt
-                .query_row(params![id_1], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+    fn update_kind(&self, id: i64, kind: ParameterKind) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let updated = conn
+            .execute(
+                "UPDATE input_parameter SET kind = ? WHERE id = ?",
+                params![kind.as_str(), id],
+            )
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::InputParameter, id));
+        }
 
-            result
-        };
+        Ok(())
+    }
 
-        let param_2 = {
-            let mut stmt = tx
-                .prepare("SELECT name, start, end FROM input_parameter WHERE id = ?")
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+    fn update_metadata(&self, id: i64, metadata: &ParameterMetadata) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let updated = conn
+            .execute(
+                "UPDATE input_parameter SET unit = ?, description = ?, precision = ?, default_value = ? WHERE id = ?",
+                params![
+                    &metadata.unit,
+                    &metadata.description,
+                    &metadata.precision,
+                    &metadata.default_value,
+                    id
+                ],
+            )
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::InputParameter, id));
+        }
 
-            let result: (String, f64, f64) = stmt
-                .query_row(params![id_2], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+        Ok(())
+    }
 
-            result
-        };
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError> {
+        let mut conn = self.conn.write()?;
+        let tx = conn
+            .savepoint()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
 
-        // Swap all parameter data (name, start, end)
-        tx.execute(
-            "UPDATE input_parameter SET name = ?, start = ?, end = ? WHERE id = ?",
-            params![param_2.0, param_2.1, param_2.2, id_1],
-        )
-        .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-        tx.execute(
-            "UPDATE input_parameter SET name = ?, start = ?, end = ? WHERE id = ?",
-            params![param_1.0, param_1.1, param_1.2, id_2],
-        )
-        .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-        // Swap input_value associations between parameters
-        tx.execute(
-            "UPDATE input_value SET input_parameter_id = 
-            CASE 
-                WHEN input_parameter_id = ? THEN ? 
-                WHEN input_parameter_id = ? THEN ? 
-                ELSE input_parameter_id 
-            END 
-        WHERE input_parameter_id IN (?, ?)",
-            params![id_1, id_2, id_2, id_1, id_1, id_2],
-        )
-        .map_err(|e| DomainError::Internal(e.to_string()))?;
+        position::move_to(&tx, EntityKind::InputParameter, "input_parameter", "problem_id", id, position)?;
 
         tx.commit()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -1,30 +1,38 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
 use rusqlite::{params, Connection};
 
-use crate::domain::{
-    entities::input_value::InputValue, error::DomainError, repository::InputValueRepository,
+use crate::{
+    domain::{
+        entities::{
+            input_value::InputValue, lower_membership::LowerMembership,
+            partition_mode::PartitionMode,
+        },
+        error::{DomainError, EntityKind, FieldError},
+        repository::InputValueRepository,
+    },
+    infrastructure::repository::sqlite::{
+        connection_pool::SqliteConnectionPool, lower_membership, position,
+        query_error::not_found_or_internal,
+    },
 };
 
 pub struct SqliteInputValueRepository {
-    conn: Arc<Mutex<Connection>>,
+    conn: Arc<SqliteConnectionPool>,
 }
 
 impl SqliteInputValueRepository {
-    pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
+    pub fn new(conn: Arc<SqliteConnectionPool>) -> Self {
         Self { conn: conn }
     }
 }
 
 impl InputValueRepository for SqliteInputValueRepository {
     fn get_by_input_parameter_id(&self, input_parameter_id: i64) -> Result<Vec<InputValue>, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.read()?;
         
         let mut stmt = conn
-            .prepare("SELECT id, input_parameter_id, value, a, b, c, d, is_triangle FROM input_value WHERE input_parameter_id = ?")
+            .prepare("SELECT id, input_parameter_id, value, a, b, c, d, is_triangle, lower_membership FROM input_value WHERE input_parameter_id = ? ORDER BY position, id")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         
         let values = stmt
@@ -38,6 +46,7 @@ impl InputValueRepository for SqliteInputValueRepository {
                     c: row.get(5)?,
                     d: row.get(6)?,
                     is_triangle: row.get(7)?,
+                    lower: lower_membership::decode(8, row.get(8)?)?,
                 })
             })
             .map_err(|e| DomainError::Internal(e.to_string()))?
@@ -47,13 +56,33 @@ impl InputValueRepository for SqliteInputValueRepository {
         Ok(values)
     }
 
-    fn create(&self, model: &InputValue) -> Result<i64, DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
-        let transaction = conn
-            .transaction()
+    fn get_by_id(&self, id: i64) -> Result<InputValue, DomainError> {
+        let conn = self.conn.read()?;
+
+        conn.query_row(
+            "SELECT id, input_parameter_id, value, a, b, c, d, is_triangle, lower_membership FROM input_value WHERE id = ?",
+            params![id],
+            |row| {
+                Ok(InputValue {
+                    id: row.get(0)?,
+                    input_parameter_id: row.get(1)?,
+                    value: row.get(2)?,
+                    a: row.get(3)?,
+                    b: row.get(4)?,
+                    c: row.get(5)?,
+                    d: row.get(6)?,
+                    is_triangle: row.get(7)?,
+                    lower: lower_membership::decode(8, row.get(8)?)?,
+                })
+            },
+        )
+        .map_err(not_found_or_internal(EntityKind::InputValue, id))
+    }
+
+    fn create(&self, model: &InputValue, shape: Option<[f32; 4]>) -> Result<i64, DomainError> {
+        let mut conn = self.conn.write()?;
+        let mut transaction = conn
+            .savepoint()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         let number_of_input_values: i64 = transaction
@@ -69,15 +98,44 @@ impl InputValueRepository for SqliteInputValueRepository {
         let c: f32;
         let d: f32;
 
-        let (input_parameter_start, input_parameter_end): (f32, f32) = transaction
+        let (input_parameter_start, input_parameter_end, partition_mode): (f32, f32, String) = transaction
             .query_row(
-                "SELECT start, end FROM input_parameter WHERE id = ?",
+                "SELECT start, end, partition_mode FROM input_parameter WHERE id = ?",
                 params![model.input_parameter_id],
-                |row| Ok((row.get(0)?, row.get(1)?)),
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
             )
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-        if number_of_input_values > 0 {
+            .map_err(not_found_or_internal(EntityKind::InputParameter, model.input_parameter_id))?;
+
+        if PartitionMode::from_name(&partition_mode) == Some(PartitionMode::FreeForm) {
+            // Stored as given; a term created without a shape covers the whole range
+            match shape {
+                None => {
+                    a = input_parameter_start;
+                    b = input_parameter_start;
+                    c = input_parameter_end;
+                    d = input_parameter_end;
+                }
+                Some([sa, sb, sc, sd]) if sa <= sb && sb <= sc && sc <= sd => {
+                    a = sa;
+                    b = sb;
+                    c = sc;
+                    d = sd;
+                }
+                Some([sa, sb, sc, sd]) => {
+                    return Err(DomainError::invalid_field(
+                        EntityKind::InputValue,
+                        None,
+                        FieldError::new(
+                            "shape",
+                            format!(
+                                "Breakpoints ({}, {}, {}, {}) must satisfy a <= b <= c <= d",
+                                sa, sb, sc, sd
+                            ),
+                        ),
+                    ));
+                }
+            }
+        } else if number_of_input_values > 0 {
             // Get the last term (rightmost) to split it
             let (prev_id, prev_a, prev_b, _prev_c, prev_d): (i64, f32, f32, f32, f32) = transaction
                 .query_row(
@@ -85,7 +143,7 @@ impl InputValueRepository for SqliteInputValueRepository {
                     params![model.input_parameter_id],
                     |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
                 )
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                .map_err(not_found_or_internal(EntityKind::InputParameter, model.input_parameter_id))?;
 
             // Split the rightmost term into two overlapping terms following Ruspini partition rules
             // For overlapping terms: prev.c = next.a, prev.d = next.b
@@ -149,15 +207,17 @@ impl InputValueRepository for SqliteInputValueRepository {
         }
 
         let result = {
+            let position = position::next(&transaction, "input_value", "input_parameter_id", model.input_parameter_id)?;
             let mut stmt = transaction
-                .prepare("INSERT INTO input_value (input_parameter_id, value, a, b, c, d) VALUES (?, ?, ?, ?, ?, ?)").map_err(|e| DomainError::Internal(e.to_string()))?;
+                .prepare("INSERT INTO input_value (input_parameter_id, value, a, b, c, d, position) VALUES (?, ?, ?, ?, ?, ?, ?)").map_err(|e| DomainError::Internal(e.to_string()))?;
             stmt.execute(rusqlite::params![
                 &model.input_parameter_id,
                 &model.value,
                 &a,
                 &b,
                 &c,
-                &d
+                &d,
+                &position
             ])
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
@@ -169,7 +229,7 @@ impl InputValueRepository for SqliteInputValueRepository {
                     params![&model.input_parameter_id],
                     |row| row.get(0),
                 )
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                .map_err(not_found_or_internal(EntityKind::InputParameter, model.input_parameter_id))?;
 
             let mut stmt = transaction.prepare("SELECT output_value.id, output_value.input_value_ids, output_value.output_parameter_id FROM output_value LEFT JOIN output_parameter ON output_value.output_parameter_id = output_parameter.id WHERE output_parameter.problem_id = ?") .map_err(|e| DomainError::Internal(e.to_string()))?;
             let mut output_values_rows = stmt
@@ -291,13 +351,11 @@ impl InputValueRepository for SqliteInputValueRepository {
     }
 
     fn create_raw(&self, model: &InputValue) -> Result<i64, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
         
+        let position = position::next(&conn, "input_value", "input_parameter_id", model.input_parameter_id)?;
         let mut stmt = conn
-            .prepare("INSERT INTO input_value (input_parameter_id, value, a, b, c, d, is_triangle) VALUES (?, ?, ?, ?, ?, ?, ?)")
+            .prepare("INSERT INTO input_value (input_parameter_id, value, a, b, c, d, is_triangle, lower_membership, position) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         
         stmt.execute(params![
@@ -307,7 +365,9 @@ impl InputValueRepository for SqliteInputValueRepository {
             &model.b,
             &model.c,
             &model.d,
-            &model.is_triangle
+            &model.is_triangle,
+            &lower_membership::encode(model.lower.as_ref())?,
+            &position
         ])
         .map_err(|e| DomainError::Internal(e.to_string()))?;
 
@@ -315,12 +375,9 @@ impl InputValueRepository for SqliteInputValueRepository {
     }
 
     fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
-        let transaction = conn
-            .transaction()
+        let mut conn = self.conn.write()?;
+        let mut transaction = conn
+            .savepoint()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         let result: Result<(), DomainError> = {
             let input_parameter_id: i64 = transaction
@@ -329,7 +386,7 @@ impl InputValueRepository for SqliteInputValueRepository {
                     params![&id],
                     |row| row.get(0),
                 )
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                .map_err(not_found_or_internal(EntityKind::InputValue, id))?;
 
             let input_values_number: i64 = transaction
                 .query_row(
@@ -340,32 +397,19 @@ impl InputValueRepository for SqliteInputValueRepository {
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
 
             if input_values_number > 1 {
-                let (a, b, c, d): (f32, f32, f32, f32) = transaction
-                    .query_row(
-                        "SELECT a, b, c, d FROM input_value WHERE id = ?",
-                        params![id],
-                        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
-                    )
-                    .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-                let prev_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
-                    .query_row(
-                    "SELECT id, a, b, c, d FROM input_value WHERE input_parameter_id = ? AND a < ? ORDER BY a DESC LIMIT 1",
-                    params![input_parameter_id, a],
-                    |row| {
-                        Ok((
-                            row.get(0)?,
-                            row.get(1)?,
-                            row.get(2)?,
-                            row.get(3)?,
-                            row.get(4)?,
-                        ))
-                    },
-                );
+                // Free-form terms are stored as given, without reshaping their neighbours
+                if !is_free_form(&transaction, input_parameter_id)? {
+                    let (a, b, c, d): (f32, f32, f32, f32) = transaction
+                        .query_row(
+                            "SELECT a, b, c, d FROM input_value WHERE id = ?",
+                            params![id],
+                            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+                        )
+                        .map_err(not_found_or_internal(EntityKind::InputValue, id))?;
 
-                let next_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
-                    .query_row(
-                        "SELECT id, a, b, c, d FROM input_value WHERE input_parameter_id = ? AND a > ? ORDER BY a ASC LIMIT 1",
+                    let prev_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
+                        .query_row(
+                        "SELECT id, a, b, c, d FROM input_value WHERE input_parameter_id = ? AND a < ? ORDER BY a DESC LIMIT 1",
                         params![input_parameter_id, a],
                         |row| {
                             Ok((
@@ -378,65 +422,81 @@ impl InputValueRepository for SqliteInputValueRepository {
                         },
                     );
 
-                match (prev_result, next_result) {
-                    (Ok(prev), Ok(next)) => {
-                        let mid = a + (d - a) / 2.0;
-                        let pivot = (d - a) / 4.0;
-
-                        let prev_c = mid - pivot;
-                        let prev_d = mid + pivot;
-
-                        transaction
-                            .execute(
-                                "UPDATE input_value SET c = ?, d = ? WHERE id = ?",
-                                params![prev_c, prev_d, prev.0],
-                            )
-                            .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-                        let next_a = prev_c;
-                        let next_b = prev_d;
+                    let next_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
+                        .query_row(
+                            "SELECT id, a, b, c, d FROM input_value WHERE input_parameter_id = ? AND a > ? ORDER BY a ASC LIMIT 1",
+                            params![input_parameter_id, a],
+                            |row| {
+                                Ok((
+                                    row.get(0)?,
+                                    row.get(1)?,
+                                    row.get(2)?,
+                                    row.get(3)?,
+                                    row.get(4)?,
+                                ))
+                            },
+                        );
 
-                        transaction
-                            .execute(
-                                "UPDATE input_value SET a = ?, b = ? WHERE id = ?",
-                                params![next_a, next_b, next.0],
-                            )
-                            .map_err(|e| DomainError::Internal(e.to_string()))?;
-                    }
-                    (Ok(prev), Err(rusqlite::Error::QueryReturnedNoRows)) => {
-                        // No next element - we're deleting the last one, extend prev to end
-                        transaction
-                            .execute(
-                                "UPDATE input_value SET c = ?, d = ? WHERE id = ?",
-                                params![c, d, prev.0],
-                            )
-                            .map_err(|e| DomainError::Internal(e.to_string()))?;
-                    }
-                    (Ok(_prev), Err(e)) => {
-                        return Err(DomainError::Data(e.to_string()));
-                    }
-                    (Err(rusqlite::Error::QueryReturnedNoRows), Ok(next)) => {
-                        // No prev element - we're deleting the first one, extend next to start
-                        transaction
-                            .execute(
-                                "UPDATE input_value SET a = ?, b = ? WHERE id = ?",
-                                params![a, b, next.0],
-                            )
-                            .map_err(|e| DomainError::Internal(e.to_string()))?;
-                    }
-                    (Err(e), Ok(_next)) => {
-                        return Err(DomainError::Data(e.to_string()));
-                    }
-                    (Err(rusqlite::Error::QueryReturnedNoRows), Err(rusqlite::Error::QueryReturnedNoRows)) => {
-                        // Single element being deleted - nothing to adjust
-                    }
-                    (Err(prev_e), Err(_next_e)) => {
-                        // Check if prev_e is QueryReturnedNoRows
-                        if !matches!(prev_e, rusqlite::Error::QueryReturnedNoRows) {
-                            return Err(DomainError::Data(prev_e.to_string()));
+                    match (prev_result, next_result) {
+                        (Ok(prev), Ok(next)) => {
+                            let mid = a + (d - a) / 2.0;
+                            let pivot = (d - a) / 4.0;
+
+                            let prev_c = mid - pivot;
+                            let prev_d = mid + pivot;
+
+                            transaction
+                                .execute(
+                                    "UPDATE input_value SET c = ?, d = ? WHERE id = ?",
+                                    params![prev_c, prev_d, prev.0],
+                                )
+                                .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+                            let next_a = prev_c;
+                            let next_b = prev_d;
+
+                            transaction
+                                .execute(
+                                    "UPDATE input_value SET a = ?, b = ? WHERE id = ?",
+                                    params![next_a, next_b, next.0],
+                                )
+                                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                        }
+                        (Ok(prev), Err(rusqlite::Error::QueryReturnedNoRows)) => {
+                            // No next element - we're deleting the last one, extend prev to end
+                            transaction
+                                .execute(
+                                    "UPDATE input_value SET c = ?, d = ? WHERE id = ?",
+                                    params![c, d, prev.0],
+                                )
+                                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                        }
+                        (Ok(_prev), Err(e)) => {
+                            return Err(DomainError::Data(e.to_string()));
+                        }
+                        (Err(rusqlite::Error::QueryReturnedNoRows), Ok(next)) => {
+                            // No prev element - we're deleting the first one, extend next to start
+                            transaction
+                                .execute(
+                                    "UPDATE input_value SET a = ?, b = ? WHERE id = ?",
+                                    params![a, b, next.0],
+                                )
+                                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                        }
+                        (Err(e), Ok(_next)) => {
+                            return Err(DomainError::Data(e.to_string()));
+                        }
+                        (Err(rusqlite::Error::QueryReturnedNoRows), Err(rusqlite::Error::QueryReturnedNoRows)) => {
+                            // Single element being deleted - nothing to adjust
+                        }
+                        (Err(prev_e), Err(_next_e)) => {
+                            // Check if prev_e is QueryReturnedNoRows
+                            if !matches!(prev_e, rusqlite::Error::QueryReturnedNoRows) {
+                                return Err(DomainError::Data(prev_e.to_string()));
+                            }
+                            // next_e is a real error
+                            return Err(DomainError::Data(_next_e.to_string()));
                         }
-                        // next_e is a real error
-                        return Err(DomainError::Data(_next_e.to_string()));
                     }
                 }
 
@@ -536,30 +596,30 @@ impl InputValueRepository for SqliteInputValueRepository {
         // First term: a = b (allowed)
         // Last term: c = d (allowed)
         if model.a > model.b {
-            return Err(DomainError::Validation(format!(
-                "Invalid input_value: a ({}) must be <= b ({})",
-                model.a, model.b
-            )));
+            return Err(DomainError::invalid_field(
+                EntityKind::InputValue,
+                Some(id),
+                FieldError::new("a", format!("a ({}) must be <= b ({})", model.a, model.b)),
+            ));
         }
         if model.b > model.c {
-            return Err(DomainError::Validation(format!(
-                "Invalid input_value: b ({}) must be <= c ({})",
-                model.b, model.c
-            )));
+            return Err(DomainError::invalid_field(
+                EntityKind::InputValue,
+                Some(id),
+                FieldError::new("b", format!("b ({}) must be <= c ({})", model.b, model.c)),
+            ));
         }
         if model.c > model.d {
-            return Err(DomainError::Validation(format!(
-                "Invalid input_value: c ({}) must be <= d ({})",
-                model.c, model.d
-            )));
+            return Err(DomainError::invalid_field(
+                EntityKind::InputValue,
+                Some(id),
+                FieldError::new("c", format!("c ({}) must be <= d ({})", model.c, model.d)),
+            ));
         }
         
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
-        let transaction = conn
-            .transaction()
+        let mut conn = self.conn.write()?;
+        let mut transaction = conn
+            .savepoint()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         let result: Result<(), DomainError> = {
             let mut stmt = transaction
@@ -583,84 +643,87 @@ impl InputValueRepository for SqliteInputValueRepository {
                     params![id],
                     |row| row.get(0),
                 )
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+                .map_err(not_found_or_internal(EntityKind::InputValue, id))?;
 
-            let prev_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
-                .query_row(
-                    "SELECT id, a, b, c, d FROM input_value WHERE input_parameter_id = ? AND id != ? AND a < ? ORDER BY a DESC LIMIT 1",
-                    params![input_parameter_id, id, model.a],
-                    |row| {
-                        Ok((
-                            row.get(0)?,
-                            row.get(1)?,
-                            row.get(2)?,
-                            row.get(3)?,
-                            row.get(4)?,
-                        ))
-                    },
-                );
+            // Free-form terms are stored as given, without reshaping their neighbours
+            if !is_free_form(&transaction, input_parameter_id)? {
+                let prev_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
+                    .query_row(
+                        "SELECT id, a, b, c, d FROM input_value WHERE input_parameter_id = ? AND id != ? AND a < ? ORDER BY a DESC LIMIT 1",
+                        params![input_parameter_id, id, model.a],
+                        |row| {
+                            Ok((
+                                row.get(0)?,
+                                row.get(1)?,
+                                row.get(2)?,
+                                row.get(3)?,
+                                row.get(4)?,
+                            ))
+                        },
+                    );
 
-            let next_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
-                .query_row(
-                    "SELECT id, a, b, c, d FROM input_value WHERE input_parameter_id = ? AND id != ? AND a > ? ORDER BY a ASC LIMIT 1",
-                    params![input_parameter_id, id, model.a],
-                    |row| {
-                        Ok((
-                            row.get(0)?,
-                            row.get(1)?,
-                            row.get(2)?,
-                            row.get(3)?,
-                            row.get(4)?,
-                        ))
-                    },
-                );
+                let next_result: Result<(i64, f32, f32, f32, f32), rusqlite::Error> = transaction
+                    .query_row(
+                        "SELECT id, a, b, c, d FROM input_value WHERE input_parameter_id = ? AND id != ? AND a > ? ORDER BY a ASC LIMIT 1",
+                        params![input_parameter_id, id, model.a],
+                        |row| {
+                            Ok((
+                                row.get(0)?,
+                                row.get(1)?,
+                                row.get(2)?,
+                                row.get(3)?,
+                                row.get(4)?,
+                            ))
+                        },
+                    );
 
-            match (prev_result, next_result) {
-                (Ok(prev), Ok(next)) => {
-                    transaction
-                        .execute(
-                            "UPDATE input_value SET c = ?, d = ? WHERE id = ?",
-                            params![model.a, model.b, prev.0],
-                        )
-                        .map_err(|e| DomainError::Internal(e.to_string()))?;
+                match (prev_result, next_result) {
+                    (Ok(prev), Ok(next)) => {
+                        transaction
+                            .execute(
+                                "UPDATE input_value SET c = ?, d = ? WHERE id = ?",
+                                params![model.a, model.b, prev.0],
+                            )
+                            .map_err(|e| DomainError::Internal(e.to_string()))?;
 
-                    transaction
-                        .execute(
-                            "UPDATE input_value SET a = ?, b = ? WHERE id = ?",
-                            params![model.c, model.d, next.0],
-                        )
-                        .map_err(|e| DomainError::Internal(e.to_string()))?;
-                }
-                (Ok(prev), Err(rusqlite::Error::QueryReturnedNoRows)) => {
-                    transaction
-                        .execute(
-                            "UPDATE input_value SET c = ?, d = ? WHERE id = ?",
-                            params![model.a, model.b, prev.0],
-                        )
-                        .map_err(|e| DomainError::Internal(e.to_string()))?;
-                }
-                (Ok(_prev), Err(e)) => {
-                    return Err(DomainError::Data(e.to_string()));
-                }
-                (Err(rusqlite::Error::QueryReturnedNoRows), Ok(next)) => {
-                    transaction
-                        .execute(
-                            "UPDATE input_value SET a = ?, b = ? WHERE id = ?",
-                            params![model.c, model.d, next.0],
-                        )
-                        .map_err(|e| DomainError::Internal(e.to_string()))?;
-                }
-                (Err(e), Ok(_next)) => {
-                    return Err(DomainError::Data(e.to_string()));
-                }
-                (Err(rusqlite::Error::QueryReturnedNoRows), Err(rusqlite::Error::QueryReturnedNoRows)) => {
-                    // Single element - nothing to adjust
-                }
-                (Err(prev_e), Err(_next_e)) => {
-                    if !matches!(prev_e, rusqlite::Error::QueryReturnedNoRows) {
-                        return Err(DomainError::Data(prev_e.to_string()));
+                        transaction
+                            .execute(
+                                "UPDATE input_value SET a = ?, b = ? WHERE id = ?",
+                                params![model.c, model.d, next.0],
+                            )
+                            .map_err(|e| DomainError::Internal(e.to_string()))?;
+                    }
+                    (Ok(prev), Err(rusqlite::Error::QueryReturnedNoRows)) => {
+                        transaction
+                            .execute(
+                                "UPDATE input_value SET c = ?, d = ? WHERE id = ?",
+                                params![model.a, model.b, prev.0],
+                            )
+                            .map_err(|e| DomainError::Internal(e.to_string()))?;
+                    }
+                    (Ok(_prev), Err(e)) => {
+                        return Err(DomainError::Data(e.to_string()));
+                    }
+                    (Err(rusqlite::Error::QueryReturnedNoRows), Ok(next)) => {
+                        transaction
+                            .execute(
+                                "UPDATE input_value SET a = ?, b = ? WHERE id = ?",
+                                params![model.c, model.d, next.0],
+                            )
+                            .map_err(|e| DomainError::Internal(e.to_string()))?;
+                    }
+                    (Err(e), Ok(_next)) => {
+                        return Err(DomainError::Data(e.to_string()));
+                    }
+                    (Err(rusqlite::Error::QueryReturnedNoRows), Err(rusqlite::Error::QueryReturnedNoRows)) => {
+                        // Single element - nothing to adjust
+                    }
+                    (Err(prev_e), Err(_next_e)) => {
+                        if !matches!(prev_e, rusqlite::Error::QueryReturnedNoRows) {
+                            return Err(DomainError::Data(prev_e.to_string()));
+                        }
+                        return Err(DomainError::Data(_next_e.to_string()));
                     }
-                    return Err(DomainError::Data(_next_e.to_string()));
                 }
             }
 
@@ -683,61 +746,106 @@ impl InputValueRepository for SqliteInputValueRepository {
         }
     }
 
-    fn switch(&self, id_1: i64, id_2: i64) -> Result<(), DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
-        let tx = conn
-            .transaction()
+    fn update_shape(&self, id: i64, model: &InputValue) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let updated = conn
+            .execute(
+                "UPDATE input_value SET a = ?, b = ?, c = ?, d = ?, lower_membership = ? WHERE id = ?",
+                params![
+                    model.a,
+                    model.b,
+                    model.c,
+                    model.d,
+                    lower_membership::encode(model.lower.as_ref())?,
+                    id
+                ],
+            )
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::InputValue, id));
+        }
 
-        let value_1 = {
-            let mut stmt = tx
-                .prepare("SELECT a, b, c, d FROM input_value WHERE id = ?")
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-            let result: (f32, f32, f32, f32) = stmt
-                .query_row(params![id_1], |row| {
-                    Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
-                })
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-            result
-        };
-
-        let value_2 = {
-            let mut stmt = tx
-                .prepare("SELECT a, b, c, d FROM input_value WHERE id = ?")
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+        Ok(())
+    }
 
-            let result: (f32, f32, f32, f32) = stmt
-                .query_row(params![id_2], |row| {
-                    Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
-                })
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
+    fn update_lower(&self, id: i64, lower: Option<&LowerMembership>) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let updated = conn
+            .execute(
+                "UPDATE input_value SET lower_membership = ? WHERE id = ?",
+                params![lower_membership::encode(lower)?, id],
+            )
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::InputValue, id));
+        }
 
-            result
-        };
+        Ok(())
+    }
 
-        // Swap only trapezoid parameters (a, b, c, d), keep value names and IDs unchanged
-        tx.execute(
-            "UPDATE input_value SET a = ?, b = ?, c = ?, d = ? WHERE id = ?",
-            params![value_2.0, value_2.1, value_2.2, value_2.3, id_1],
-        )
-        .map_err(|e| DomainError::Internal(e.to_string()))?;
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError> {
+        let mut conn = self.conn.write()?;
+        let tx = conn
+            .savepoint()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
 
-        tx.execute(
-            "UPDATE input_value SET a = ?, b = ?, c = ?, d = ? WHERE id = ?",
-            params![value_1.0, value_1.1, value_1.2, value_1.3, id_2],
-        )
-        .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let ids = position::move_to(&tx, EntityKind::InputValue, "input_value", "input_parameter_id", id, position)?;
 
-        // No need to update output_value table - IDs remain the same
+        let input_parameter_id: i64 = tx
+            .query_row(
+                "SELECT input_parameter_id FROM input_value WHERE id = ?",
+                params![id],
+                |row| row.get(0),
+            )
+            .map_err(not_found_or_internal(EntityKind::InputValue, id))?;
+        if !is_free_form(&tx, input_parameter_id)? {
+            // Terms of a Ruspini partition are ordered along the range, so the shapes
+            // stay where they are and each term takes the shape at its new position
+            let shapes = {
+                let mut stmt = tx
+                    .prepare("SELECT a, b, c, d, is_triangle, lower_membership FROM input_value WHERE input_parameter_id = ? ORDER BY a, d, id")
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                let shapes = stmt
+                    .query_map(params![input_parameter_id], |row| {
+                        Ok((
+                            row.get::<_, f32>(0)?,
+                            row.get::<_, f32>(1)?,
+                            row.get::<_, f32>(2)?,
+                            row.get::<_, f32>(3)?,
+                            row.get::<_, bool>(4)?,
+                            row.get::<_, Option<String>>(5)?,
+                        ))
+                    })
+                    .map_err(|e| DomainError::Internal(e.to_string()))?
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                shapes
+            };
+            for (term_id, (a, b, c, d, is_triangle, lower)) in ids.iter().zip(shapes) {
+                tx.execute(
+                    "UPDATE input_value SET a = ?, b = ?, c = ?, d = ?, is_triangle = ?, lower_membership = ? WHERE id = ?",
+                    params![a, b, c, d, is_triangle, lower, term_id],
+                )
+                .map_err(|e| DomainError::Internal(e.to_string()))?;
+            }
+        }
 
         tx.commit()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
-
         Ok(())
     }
 }
+
+/// Whether the terms of the parameter are stored as given instead of keeping a
+/// Ruspini partition
+fn is_free_form(conn: &Connection, input_parameter_id: i64) -> Result<bool, DomainError> {
+    let partition_mode: String = conn
+        .query_row(
+            "SELECT partition_mode FROM input_parameter WHERE id = ?",
+            params![input_parameter_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+    Ok(PartitionMode::from_name(&partition_mode) == Some(PartitionMode::FreeForm))
+}
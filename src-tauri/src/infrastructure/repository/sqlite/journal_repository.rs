@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use rusqlite::{params, Row};
+
+use crate::domain::{
+    entities::journal_entry::JournalEntry,
+    error::{DomainError, EntityKind},
+    repository::JournalRepository,
+};
+
+const SELECT_COLUMNS: &str =
+    "SELECT id, problem_id, description, before, after, is_undone, created_at FROM journal_entry";
+use crate::infrastructure::repository::sqlite::connection_pool::SqliteConnectionPool;
+
+pub struct SqliteJournalRepository {
+    conn: Arc<SqliteConnectionPool>,
+}
+
+impl SqliteJournalRepository {
+    pub fn new(conn: Arc<SqliteConnectionPool>) -> Self {
+        Self { conn }
+    }
+
+    fn read_row(row: &Row) -> rusqlite::Result<JournalEntry> {
+        Ok(JournalEntry {
+            id: row.get(0)?,
+            problem_id: row.get(1)?,
+            description: row.get(2)?,
+            before: row.get(3)?,
+            after: row.get(4)?,
+            is_undone: row.get(5)?,
+            created_at: row.get(6)?,
+        })
+    }
+
+    fn get_optional(&self, sql: &str, problem_id: i64) -> Result<Option<JournalEntry>, DomainError> {
+        let conn = self.conn.read()?;
+
+        match conn.query_row(sql, params![problem_id], Self::read_row) {
+            Ok(entry) => Ok(Some(entry)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(DomainError::Internal(e.to_string())),
+        }
+    }
+}
+
+impl JournalRepository for SqliteJournalRepository {
+    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<JournalEntry>, DomainError> {
+        let conn = self.conn.read()?;
+
+        let mut stmt = conn
+            .prepare(&format!("{} WHERE problem_id = ? ORDER BY id DESC", SELECT_COLUMNS))
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let entries = stmt
+            .query_map(params![problem_id], Self::read_row)
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(entries)
+    }
+
+    fn get_last_done(&self, problem_id: i64) -> Result<Option<JournalEntry>, DomainError> {
+        self.get_optional(
+            &format!(
+                "{} WHERE problem_id = ? AND is_undone = 0 ORDER BY id DESC LIMIT 1",
+                SELECT_COLUMNS
+            ),
+            problem_id,
+        )
+    }
+
+    fn get_first_undone(&self, problem_id: i64) -> Result<Option<JournalEntry>, DomainError> {
+        self.get_optional(
+            &format!(
+                "{} WHERE problem_id = ? AND is_undone = 1 ORDER BY id ASC LIMIT 1",
+                SELECT_COLUMNS
+            ),
+            problem_id,
+        )
+    }
+
+    fn create(&self, model: &JournalEntry) -> Result<i64, DomainError> {
+        let conn = self.conn.write()?;
+
+        let mut stmt = conn
+            .prepare("INSERT INTO journal_entry (problem_id, description, before, after, is_undone, created_at) VALUES (?, ?, ?, ?, ?, ?)")
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let time = Utc::now().to_rfc3339();
+
+        stmt.execute(params![
+            &model.problem_id,
+            &model.description,
+            &model.before,
+            &model.after,
+            &model.is_undone,
+            &time,
+        ])
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    fn set_undone(&self, id: i64, is_undone: bool) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let updated = conn
+            .execute(
+                "UPDATE journal_entry SET is_undone = ? WHERE id = ?",
+                params![&is_undone, &id],
+            )
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::JournalEntry, id));
+        }
+        Ok(())
+    }
+
+    fn remove_undone_by_problem_id(&self, problem_id: i64) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        conn.execute(
+            "DELETE FROM journal_entry WHERE problem_id = ? AND is_undone = 1",
+            params![&problem_id],
+        )
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+        Ok(())
+    }
+
+    fn prune(&self, problem_id: i64, keep: usize) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        conn.execute(
+            "DELETE FROM journal_entry WHERE problem_id = ? AND id NOT IN
+            (SELECT id FROM journal_entry WHERE problem_id = ? ORDER BY id DESC LIMIT ?)",
+            params![&problem_id, &problem_id, &(keep as i64)],
+        )
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use rusqlite::types::Type;
+
+use crate::domain::{entities::lower_membership::LowerMembership, error::DomainError};
+
+// The lower membership function of an interval type-2 term is kept as JSON in the
+// nullable `lower_membership` column of `input_value` and `fuzzy_output_value`;
+// NULL marks an ordinary type-1 term.
+
+pub fn encode(lower: Option<&LowerMembership>) -> Result<Option<String>, DomainError> {
+    lower
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(|e| DomainError::Internal(e.to_string()))
+}
+
+/// Decodes the column read at `index`, for use inside row mappers
+pub fn decode(index: usize, raw: Option<String>) -> rusqlite::Result<Option<LowerMembership>> {
+    raw.map(|json| serde_json::from_str(&json))
+        .transpose()
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(index, Type::Text, Box::new(e)))
+}
@@ -1,32 +1,39 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
-use rusqlite::{params, Connection};
+use rusqlite::params;
 
 use crate::domain::{
-    entities::{fuzzy_output_value::FuzzyOutputValue, output_parameter::OutputParameter},
-    error::DomainError,
+    entities::{
+        fuzzy_output_value::FuzzyOutputValue, output_parameter::OutputParameter,
+        parameter_metadata::ParameterMetadata, partition_mode::PartitionMode,
+    },
+    error::{DomainError, EntityKind},
     repository::OutputParameterRepository,
 };
+use crate::infrastructure::repository::sqlite::{
+    connection_pool::SqliteConnectionPool, parameter_metadata, position,
+    query_error::not_found_or_internal,
+};
 
 pub struct SqliteOutputParameterRepository {
-    conn: Arc<Mutex<Connection>>,
+    conn: Arc<SqliteConnectionPool>,
 }
 
 impl SqliteOutputParameterRepository {
-    pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
+    pub fn new(conn: Arc<SqliteConnectionPool>) -> Self {
         Self { conn: conn }
     }
 }
 
 impl OutputParameterRepository for SqliteOutputParameterRepository {
     fn get_by_id(&self, id: i64) -> Result<OutputParameter, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.read()?;
         let model: OutputParameter = conn
             .query_row(
-                "SELECT id, problem_id, name, start, end FROM output_parameter WHERE id = ?",
+                &format!(
+                    "SELECT id, problem_id, name, start, end, partition_mode, {} FROM output_parameter WHERE id = ?",
+                    parameter_metadata::COLUMNS
+                ),
                 params![id],
                 |row| {
                     Ok(OutputParameter {
@@ -35,28 +42,29 @@ impl OutputParameterRepository for SqliteOutputParameterRepository {
                         name: row.get(2)?,
                         start: row.get(3)?,
                         end: row.get(4)?,
+                        partition_mode: PartitionMode::from_name(&row.get::<_, String>(5)?)
+                            .unwrap_or_default(),
+                        metadata: parameter_metadata::read(row, 6)?,
                         fuzzy_output_values: Vec::<FuzzyOutputValue>::new(),
                     })
                 },
             )
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+            .map_err(not_found_or_internal(EntityKind::OutputParameter, id))?;
 
         Ok(model)
     }
 
     fn create(&self, problem_id: i64, model: &OutputParameter) -> Result<i64, DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
-        let transaction = conn
-            .transaction()
+        let mut conn = self.conn.write()?;
+        let mut transaction = conn
+            .savepoint()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         let result = {
+            let position = position::next(&transaction, "output_parameter", "problem_id", problem_id)?;
             let mut stmt = transaction
-                .prepare("INSERT INTO output_parameter (problem_id, name) VALUES (?, ?)")
+                .prepare("INSERT INTO output_parameter (problem_id, name, position) VALUES (?, ?, ?)")
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
-            stmt.execute(rusqlite::params![&problem_id, &model.name,])
+            stmt.execute(rusqlite::params![&problem_id, &model.name, &position])
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
 
             let new_output_parameter_id = transaction.last_insert_rowid();
@@ -167,24 +175,32 @@ impl OutputParameterRepository for SqliteOutputParameterRepository {
     }
 
     fn create_raw(&self, problem_id: i64, model: &OutputParameter) -> Result<i64, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
 
+        let position = position::next(&conn, "output_parameter", "problem_id", problem_id)?;
         let mut stmt = conn
-            .prepare("INSERT INTO output_parameter (problem_id, name, start, end) VALUES (?, ?, ?, ?)")
+            .prepare("INSERT INTO output_parameter (problem_id, name, start, end, partition_mode, position, unit, description, precision, default_value) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
-        stmt.execute(rusqlite::params![&problem_id, &model.name, &model.start, &model.end])
+        stmt.execute(rusqlite::params![
+            &problem_id,
+            &model.name,
+            &model.start,
+            &model.end,
+            model.partition_mode.as_str(),
+            &position,
+            &model.metadata.unit,
+            &model.metadata.description,
+            &model.metadata.precision,
+            &model.metadata.default_value
+        ])
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         Ok(conn.last_insert_rowid())
     }
 
     fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
-        let conn = self
-            .conn
-            .lock()
+        let conn = self.conn.write()?;
+        conn.execute("DELETE FROM parameter_link WHERE output_parameter_id = ?", params![id])
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         conn.execute("DELETE FROM output_parameter WHERE id = ?", &[&id])
             .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -192,10 +208,7 @@ impl OutputParameterRepository for SqliteOutputParameterRepository {
     }
 
     fn update_by_id(&self, id: i64, model: &OutputParameter) -> Result<(), DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
         let mut stmt = conn
             .prepare("UPDATE output_parameter SET name = ?, start = ?, end = ? WHERE id = ?")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -204,77 +217,49 @@ impl OutputParameterRepository for SqliteOutputParameterRepository {
         Ok(())
     }
 
-    fn switch(&self, id_1: i64, id_2: i64) -> Result<(), DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
-        let tx = conn
-            .transaction()
+    fn update_partition_mode(&self, id: i64, mode: PartitionMode) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let updated = conn
+            .execute(
+                "UPDATE output_parameter SET partition_mode = ? WHERE id = ?",
+                params![mode.as_str(), id],
+            )
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::OutputParameter, id));
+        }
 
-        let param_1 = {
-            let mut stmt = tx
-                .prepare("SELECT name, start, end FROM output_parameter WHERE id = ?")
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-            let result: (String, f64, f64) = stmt
-                .query_row(params![id_1], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-            result
-        };
-
-        let param_2 = {
-            let mut stmt = tx
-                .prepare("SELECT name, start, end FROM output_parameter WHERE id = ?")
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-            let result: (String, f64, f64) = stmt
-                .query_row(params![id_2], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
-                .map_err(|e| DomainError::Internal(e.to_string()))?;
-
-            result
-        };
+        Ok(())
+    }
 
-        // Swap all parameter data (name, start, end)
-        tx.execute(
-            "UPDATE output_parameter SET name = ?, start = ?, end = ? WHERE id = ?",
-            params![param_2.0, param_2.1, param_2.2, id_1],
-        )
-        .map_err(|e| DomainError::Internal(e.to_string()))?;
+    fn update_metadata(&self, id: i64, metadata: &ParameterMetadata) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let updated = conn
+            .execute(
+                "UPDATE output_parameter SET unit = ?, description = ?, precision = ?, default_value = ? WHERE id = ?",
+                params![
+                    &metadata.unit,
+                    &metadata.description,
+                    &metadata.precision,
+                    &metadata.default_value,
+                    id
+                ],
+            )
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::OutputParameter, id));
+        }
 
-        tx.execute(
-            "UPDATE output_parameter SET name = ?, start = ?, end = ? WHERE id = ?",
-            params![param_1.0, param_1.1, param_1.2, id_2],
-        )
-        .map_err(|e| DomainError::Internal(e.to_string()))?;
+        Ok(())
+    }
 
-        // Swap output_value associations between parameters
-        tx.execute(
-            "UPDATE output_value SET output_parameter_id = 
-            CASE 
-                WHEN output_parameter_id = ? THEN ? 
-                WHEN output_parameter_id = ? THEN ? 
-                ELSE output_parameter_id 
-            END 
-        WHERE output_parameter_id IN (?, ?)",
-            params![id_1, id_2, id_2, id_1, id_1, id_2],
-        )
-        .map_err(|e| DomainError::Internal(e.to_string()))?;
+    fn move_by_id(&self, id: i64, position: usize) -> Result<(), DomainError> {
+        let mut conn = self.conn.write()?;
+        let tx = conn
+            .savepoint()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
 
-        // Swap fuzzy_output_value associations between parameters
-        tx.execute(
-            "UPDATE fuzzy_output_value SET output_parameter_id = 
-            CASE 
-                WHEN output_parameter_id = ? THEN ? 
-                WHEN output_parameter_id = ? THEN ? 
-                ELSE output_parameter_id 
-            END 
-        WHERE output_parameter_id IN (?, ?)",
-            params![id_1, id_2, id_2, id_1, id_1, id_2],
-        )
-        .map_err(|e| DomainError::Internal(e.to_string()))?;
+        position::move_to(&tx, EntityKind::OutputParameter, "output_parameter", "problem_id", id, position)?;
 
         tx.commit()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
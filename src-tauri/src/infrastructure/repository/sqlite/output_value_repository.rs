@@ -1,34 +1,40 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
-use rusqlite::{params, Connection};
+use rusqlite::params;
 
 use crate::domain::{
-    entities::output_value::OutputValue, error::DomainError, repository::OutputValueRepository,
+    entities::output_value::OutputValue,
+    error::{DomainError, EntityKind},
+    repository::OutputValueRepository,
+};
+use crate::infrastructure::repository::sqlite::{
+    connection_pool::SqliteConnectionPool, query_error::not_found_or_internal,
 };
 
 pub struct SqliteOutputValueRepository {
-    conn: Arc<Mutex<Connection>>,
+    conn: Arc<SqliteConnectionPool>,
 }
 
 impl SqliteOutputValueRepository {
-    pub fn create(conn: Arc<Mutex<Connection>>) -> Self {
+    pub fn create(conn: Arc<SqliteConnectionPool>) -> Self {
         Self { conn: conn }
     }
 }
 
 impl OutputValueRepository for SqliteOutputValueRepository {
     fn create(&self, model: &OutputValue) -> Result<i64, DomainError> {
-        let conn = self.conn.lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
         
         let mut stmt = conn
-            .prepare("INSERT INTO output_value (output_parameter_id, fuzzy_output_value_id, input_value_ids) VALUES (?, ?, ?)")
+            .prepare("INSERT INTO output_value (output_parameter_id, fuzzy_output_value_id, input_value_ids, antecedent_hedges, consequent_hedge) VALUES (?, ?, ?, ?, ?)")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
             
         stmt.execute(params![
             &model.output_parameter_id,
             &model.fuzzy_output_value_id,
-            &model.input_value_ids
+            &model.input_value_ids,
+            &model.antecedent_hedges,
+            &model.consequent_hedge
         ])
         .map_err(|e| DomainError::Internal(e.to_string()))?;
 
@@ -36,15 +42,16 @@ impl OutputValueRepository for SqliteOutputValueRepository {
     }
 
     fn update_by_id(&self, id: i64, model: &OutputValue) -> Result<(), DomainError> {
-        let conn = self.conn.lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
         let mut stmt = conn
-            .prepare("UPDATE output_value SET output_parameter_id = ?, fuzzy_output_value_id = ?, input_value_ids = ? WHERE id = ?")
+            .prepare("UPDATE output_value SET output_parameter_id = ?, fuzzy_output_value_id = ?, input_value_ids = ?, antecedent_hedges = ?, consequent_hedge = ? WHERE id = ?")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         stmt.execute(params![
             &model.output_parameter_id,
             &model.fuzzy_output_value_id,
             &model.input_value_ids,
+            &model.antecedent_hedges,
+            &model.consequent_hedge,
             &id
         ])
         .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -53,8 +60,7 @@ impl OutputValueRepository for SqliteOutputValueRepository {
     }
 
     fn update_fuzzy_output_value(&self, id: i64, fuzzy_output_value_id: Option<i64>) -> Result<(), DomainError> {
-        let conn = self.conn.lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
         let mut stmt = conn
             .prepare("UPDATE output_value SET fuzzy_output_value_id = ? WHERE id = ?")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -64,12 +70,31 @@ impl OutputValueRepository for SqliteOutputValueRepository {
         Ok(())
     }
 
-    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<OutputValue>, DomainError> {
-        let conn = self.conn.lock()
+    fn update_hedges(
+        &self,
+        id: i64,
+        antecedent_hedges: &str,
+        consequent_hedge: Option<&str>,
+    ) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let updated = conn
+            .execute(
+                "UPDATE output_value SET antecedent_hedges = ?, consequent_hedge = ? WHERE id = ?",
+                params![antecedent_hedges, consequent_hedge, id],
+            )
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::OutputValue, id));
+        }
+
+        Ok(())
+    }
+
+    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<OutputValue>, DomainError> {
+        let conn = self.conn.read()?;
         let mut stmt = conn
             .prepare(
-                "SELECT ov.id, ov.output_parameter_id, ov.fuzzy_output_value_id, ov.input_value_ids 
+                "SELECT ov.id, ov.output_parameter_id, ov.fuzzy_output_value_id, ov.input_value_ids, ov.antecedent_hedges, ov.consequent_hedge
                  FROM output_value ov
                  JOIN output_parameter op ON ov.output_parameter_id = op.id
                  WHERE op.problem_id = ?"
@@ -83,6 +108,8 @@ impl OutputValueRepository for SqliteOutputValueRepository {
                     output_parameter_id: row.get(1)?,
                     fuzzy_output_value_id: row.get(2)?,
                     input_value_ids: row.get(3)?,
+                    antecedent_hedges: row.get(4)?,
+                    consequent_hedge: row.get(5)?,
                 })
             })
             .map_err(|e| DomainError::Internal(e.to_string()))?
@@ -91,4 +118,24 @@ impl OutputValueRepository for SqliteOutputValueRepository {
 
         Ok(output_values)
     }
+
+    fn get_by_id(&self, id: i64) -> Result<OutputValue, DomainError> {
+        let conn = self.conn.read()?;
+
+        conn.query_row(
+            "SELECT id, output_parameter_id, fuzzy_output_value_id, input_value_ids, antecedent_hedges, consequent_hedge FROM output_value WHERE id = ?",
+            params![id],
+            |row| {
+                Ok(OutputValue {
+                    id: row.get(0)?,
+                    output_parameter_id: row.get(1)?,
+                    fuzzy_output_value_id: row.get(2)?,
+                    input_value_ids: row.get(3)?,
+                    antecedent_hedges: row.get(4)?,
+                    consequent_hedge: row.get(5)?,
+                })
+            },
+        )
+        .map_err(not_found_or_internal(EntityKind::OutputValue, id))
+    }
 }
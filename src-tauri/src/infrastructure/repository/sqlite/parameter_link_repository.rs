@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use rusqlite::{params, ErrorCode, Row};
+
+use crate::domain::{
+    entities::parameter_link::ParameterLink, error::{DomainError, EntityKind, FieldError},
+    repository::ParameterLinkRepository,
+};
+
+// Joined with both parameters, so links whose parameter was removed are skipped
+const SELECT_COLUMNS: &str = "SELECT parameter_link.id, parameter_link.output_parameter_id,
+    parameter_link.input_parameter_id, output_parameter.problem_id, input_parameter.problem_id
+    FROM parameter_link
+    JOIN output_parameter ON output_parameter.id = parameter_link.output_parameter_id
+    JOIN input_parameter ON input_parameter.id = parameter_link.input_parameter_id";
+use crate::infrastructure::repository::sqlite::{
+    connection_pool::SqliteConnectionPool, query_error::not_found_or_internal,
+};
+
+pub struct SqliteParameterLinkRepository {
+    conn: Arc<SqliteConnectionPool>,
+}
+
+impl SqliteParameterLinkRepository {
+    pub fn new(conn: Arc<SqliteConnectionPool>) -> Self {
+        Self { conn }
+    }
+
+    fn read_row(row: &Row) -> rusqlite::Result<ParameterLink> {
+        Ok(ParameterLink {
+            id: row.get(0)?,
+            output_parameter_id: row.get(1)?,
+            input_parameter_id: row.get(2)?,
+            source_problem_id: row.get(3)?,
+            target_problem_id: row.get(4)?,
+        })
+    }
+}
+
+impl ParameterLinkRepository for SqliteParameterLinkRepository {
+    fn get_all(&self) -> Result<Vec<ParameterLink>, DomainError> {
+        let conn = self.conn.read()?;
+
+        let mut stmt = conn
+            .prepare(&format!("{} ORDER BY parameter_link.id", SELECT_COLUMNS))
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let links = stmt
+            .query_map(params![], Self::read_row)
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(links)
+    }
+
+    fn get_by_id(&self, id: i64) -> Result<ParameterLink, DomainError> {
+        let conn = self.conn.read()?;
+
+        conn.query_row(
+            &format!("{} WHERE parameter_link.id = ?", SELECT_COLUMNS),
+            params![id],
+            Self::read_row,
+        )
+        .map_err(not_found_or_internal(EntityKind::ParameterLink, id))
+    }
+
+    fn create(&self, model: &ParameterLink) -> Result<i64, DomainError> {
+        let conn = self.conn.write()?;
+
+        conn.execute(
+            "INSERT INTO parameter_link (output_parameter_id, input_parameter_id) VALUES (?, ?)",
+            params![model.output_parameter_id, model.input_parameter_id],
+        )
+        .map_err(|e| match e.sqlite_error_code() {
+            Some(ErrorCode::ConstraintViolation) => DomainError::invalid_field(
+                EntityKind::ParameterLink,
+                None,
+                FieldError::new(
+                    "input_parameter_id",
+                    format!("Input parameter {} is already linked", model.input_parameter_id),
+                ),
+            ),
+            _ => DomainError::Internal(e.to_string()),
+        })?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        conn.execute("DELETE FROM parameter_link WHERE id = ?", params![id])
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        Ok(())
+    }
+}
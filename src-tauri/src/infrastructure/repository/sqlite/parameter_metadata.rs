@@ -0,0 +1,20 @@
+use rusqlite::Row;
+
+use crate::domain::entities::parameter_metadata::ParameterMetadata;
+
+// The metadata of input and output parameters lives in the `unit`, `description`,
+// `precision` and `default_value` columns of their tables; output parameters never
+// have a default value.
+
+/// Metadata columns in the order `read` expects them
+pub const COLUMNS: &str = "unit, description, precision, default_value";
+
+/// Reads the metadata from the columns starting at `first`, for use inside row mappers
+pub fn read(row: &Row, first: usize) -> rusqlite::Result<ParameterMetadata> {
+    Ok(ParameterMetadata {
+        unit: row.get(first)?,
+        description: row.get(first + 1)?,
+        precision: row.get(first + 2)?,
+        default_value: row.get(first + 3)?,
+    })
+}
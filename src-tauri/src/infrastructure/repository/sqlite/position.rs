@@ -0,0 +1,63 @@
+use rusqlite::{params, Connection};
+
+use crate::{
+    domain::error::{DomainError, EntityKind},
+    infrastructure::repository::sqlite::query_error::not_found_or_internal,
+};
+
+// Parameters and terms carry an explicit `position` among their siblings (the
+// parameters of a problem, the terms of a parameter). Rows are read back
+// `ORDER BY position, id`, so gaps left by deletions do not matter.
+
+/// Position after the last sibling under `parent_column = parent_id`
+pub fn next(conn: &Connection, table: &str, parent_column: &str, parent_id: i64) -> Result<i64, DomainError> {
+    conn.query_row(
+        &format!("SELECT COALESCE(MAX(position) + 1, 0) FROM {} WHERE {} = ?", table, parent_column),
+        params![parent_id],
+        |row| row.get(0),
+    )
+    .map_err(|e| DomainError::Internal(e.to_string()))
+}
+
+/// Moves row `id` to index `position` among its siblings (after the last one when
+/// `position` is past the end) and renumbers them from 0. Returns the sibling ids
+/// in their new order.
+pub fn move_to(
+    conn: &Connection,
+    entity: EntityKind,
+    table: &str,
+    parent_column: &str,
+    id: i64,
+    position: usize,
+) -> Result<Vec<i64>, DomainError> {
+    let parent_id: i64 = conn
+        .query_row(
+            &format!("SELECT {} FROM {} WHERE id = ?", parent_column, table),
+            params![id],
+            |row| row.get(0),
+        )
+        .map_err(not_found_or_internal(entity, id))?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT id FROM {} WHERE {} = ? AND id != ? ORDER BY position, id",
+            table, parent_column
+        ))
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+    let mut ids = stmt
+        .query_map(params![parent_id, id], |row| row.get(0))
+        .map_err(|e| DomainError::Internal(e.to_string()))?
+        .collect::<Result<Vec<i64>, _>>()
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+    ids.insert(position.min(ids.len()), id);
+    for (position, sibling_id) in ids.iter().enumerate() {
+        conn.execute(
+            &format!("UPDATE {} SET position = ? WHERE id = ?", table),
+            params![position as i64, sibling_id],
+        )
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+    }
+
+    Ok(ids)
+}
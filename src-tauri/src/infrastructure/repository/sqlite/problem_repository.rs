@@ -1,23 +1,29 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
 use chrono::Utc;
 use rusqlite::{params, Connection, ErrorCode};
 
 use crate::domain::entities::fuzzy_output_value::FuzzyOutputValue;
+use crate::domain::entities::image::Image;
 use crate::domain::entities::input_parameter::InputParameter;
 use crate::domain::entities::input_value::InputValue;
 use crate::domain::entities::output_parameter::OutputParameter;
 use crate::domain::entities::output_value::OutputValue;
+use crate::domain::entities::{parameter_kind::ParameterKind, partition_mode::PartitionMode};
 use crate::domain::entities::problem::Problem;
-use crate::domain::error::DomainError;
+use crate::domain::error::{DomainError, EntityKind};
 use crate::domain::repository::ProblemRepository;
+use crate::infrastructure::repository::sqlite::{
+    connection_pool::SqliteConnectionPool, lower_membership, parameter_metadata,
+    query_error::not_found_or_internal,
+};
 
 pub struct SqliteProblemRepository {
-    conn: Arc<Mutex<Connection>>,
+    conn: Arc<SqliteConnectionPool>,
 }
 
 impl SqliteProblemRepository {
-    pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
+    pub fn new(conn: Arc<SqliteConnectionPool>) -> Self {
         Self { conn: conn }
     }
 
@@ -26,7 +32,7 @@ impl SqliteProblemRepository {
         input_parameter: &mut InputParameter,
         conn: &Connection,
     ) -> Result<(), DomainError> {
-        let mut stmt = conn.prepare("SELECT id, input_parameter_id, value, a, b, c, d, is_triangle FROM input_value WHERE input_parameter_id = ? ORDER BY a ASC").map_err(|e|  DomainError::Internal(e.to_string()))?;
+        let mut stmt = conn.prepare("SELECT id, input_parameter_id, value, a, b, c, d, is_triangle, lower_membership FROM input_value WHERE input_parameter_id = ? ORDER BY position, id").map_err(|e|  DomainError::Internal(e.to_string()))?;
         let result = stmt
             .query_map(params![input_parameter.id], |row| {
                 Ok(InputValue {
@@ -38,6 +44,7 @@ impl SqliteProblemRepository {
                     c: row.get(5)?,
                     d: row.get(6)?,
                     is_triangle: row.get(7)?,
+                    lower: lower_membership::decode(8, row.get(8)?)?,
                 })
             })
             .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -57,7 +64,7 @@ impl SqliteProblemRepository {
         output_parameter: &mut OutputParameter,
         conn: &Connection,
     ) -> Result<(), DomainError> {
-        let mut stmt = conn.prepare("SELECT id, output_parameter_id, value, a, b, c, d, is_triangle FROM fuzzy_output_value WHERE output_parameter_id = ? ORDER BY a ASC").map_err(|e|  DomainError::Internal(e.to_string()))?;
+        let mut stmt = conn.prepare("SELECT id, output_parameter_id, value, a, b, c, d, is_triangle, lower_membership FROM fuzzy_output_value WHERE output_parameter_id = ? ORDER BY position, id").map_err(|e|  DomainError::Internal(e.to_string()))?;
         let result = stmt
             .query_map(params![output_parameter.id], |row| {
                 Ok(FuzzyOutputValue {
@@ -69,6 +76,7 @@ impl SqliteProblemRepository {
                     c: row.get(5)?,
                     d: row.get(6)?,
                     is_triangle: row.get(7)?,
+                    lower: lower_membership::decode(8, row.get(8)?)?,
                 })
             })
             .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -85,7 +93,7 @@ impl SqliteProblemRepository {
 
     fn deep_delete(&self, id: i64, conn: &Connection) -> Result<(), DomainError> {
         let mut stmt = conn
-            .prepare("SELECT id FROM problem WHERE prev_problem_id = ?")
+            .prepare("SELECT id FROM problem WHERE prev_problem_id = ? ORDER BY position, id")
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         let ids_result = stmt
             .query_map(rusqlite::params![&id], |row| {
@@ -112,7 +120,19 @@ impl SqliteProblemRepository {
             .map_err(|e| DomainError::Internal(e.to_string()))?;
         let image_id: Option<i64> = stmt
             .query_row(params![id], |row| row.get(0))
+            .map_err(not_found_or_internal(EntityKind::Problem, id))?;
+        conn.execute("DELETE FROM test_case WHERE problem_id = ?", params![id])
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+        conn.execute("DELETE FROM problem_snapshot WHERE problem_id = ?", params![id])
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        conn.execute("DELETE FROM journal_entry WHERE problem_id = ?", params![id])
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        conn.execute(
+            "DELETE FROM parameter_link WHERE output_parameter_id IN (SELECT id FROM output_parameter WHERE problem_id = ?1)
+            OR input_parameter_id IN (SELECT id FROM input_parameter WHERE problem_id = ?1)",
+            params![id],
+        )
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
         conn.execute("DELETE FROM problem WHERE id = ?", &[&id])
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
@@ -127,15 +147,12 @@ impl SqliteProblemRepository {
 
 impl ProblemRepository for SqliteProblemRepository {
     fn get_all_by_prev_problem_id(&self, id: Option<i64>) -> Result<Vec<Problem>, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.read()?;
 
         let problems_result: Vec<_>;
 
         if let Some(id) = id {
-            let mut stmt = conn.prepare("SELECT id, prev_problem_id, is_final, name, description, created_at, updated_at, image_id FROM problem WHERE prev_problem_id = ?").map_err(|e|  DomainError::Internal(e.to_string()))?;
+            let mut stmt = conn.prepare("SELECT id, prev_problem_id, is_final, name, description, created_at, updated_at, image_id FROM problem WHERE prev_problem_id = ? ORDER BY position, id").map_err(|e|  DomainError::Internal(e.to_string()))?;
 
             let result = stmt
                 .query_map(params![id], |row| {
@@ -151,13 +168,12 @@ impl ProblemRepository for SqliteProblemRepository {
                         input_parameters: Vec::<InputParameter>::new(),
                         output_parameters: Vec::<OutputParameter>::new(),
                         output_values: Vec::<OutputValue>::new(),
-                        image: None,
                     })
                 })
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
             problems_result = result.collect();
         } else {
-            let mut stmt = conn.prepare("SELECT id, prev_problem_id, is_final, name, description, created_at, updated_at, image_id FROM problem WHERE prev_problem_id IS NULL").map_err(|e|  DomainError::Internal(e.to_string()))?;
+            let mut stmt = conn.prepare("SELECT id, prev_problem_id, is_final, name, description, created_at, updated_at, image_id FROM problem WHERE prev_problem_id IS NULL ORDER BY position, id").map_err(|e|  DomainError::Internal(e.to_string()))?;
 
             let result = stmt
                 .query_map(params![], |row| {
@@ -173,7 +189,6 @@ impl ProblemRepository for SqliteProblemRepository {
                         input_parameters: Vec::<InputParameter>::new(),
                         output_parameters: Vec::<OutputParameter>::new(),
                         output_values: Vec::<OutputValue>::new(),
-                        image: None,
                     })
                 })
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -192,10 +207,7 @@ impl ProblemRepository for SqliteProblemRepository {
     }
 
     fn get_full_by_id(&self, id: i64) -> Result<Problem, DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.read()?;
         let mut stmt = conn.prepare("SELECT id, prev_problem_id, is_final, name, description, created_at, updated_at, image_id FROM problem WHERE id = ?").map_err(|e|  DomainError::Internal(e.to_string()))?;
         let mut problem = stmt
             .query_row(params![id], |row| {
@@ -211,21 +223,15 @@ impl ProblemRepository for SqliteProblemRepository {
                     input_parameters: Vec::<InputParameter>::new(),
                     output_parameters: Vec::<OutputParameter>::new(),
                     output_values: Vec::<OutputValue>::new(),
-                    image: None,
                 })
             })
-            .map_err(|e| match e.sqlite_error_code() {
-                None => DomainError::Internal(e.to_string()),
-                Some(error_code) => match error_code {
-                    ErrorCode::NotFound => DomainError::NotFound(e.to_string()),
-                    _ => DomainError::Internal(e.to_string()),
-                },
-            })?;
+            .map_err(not_found_or_internal(EntityKind::Problem, id))?;
 
         let mut stmt = conn
-            .prepare(
-                "SELECT id, problem_id, name, start, end FROM input_parameter WHERE problem_id = ?",
-            )
+            .prepare(&format!(
+                "SELECT id, problem_id, name, start, end, partition_mode, kind, {} FROM input_parameter WHERE problem_id = ? ORDER BY position, id",
+                parameter_metadata::COLUMNS
+            ))
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         let result = stmt
@@ -236,6 +242,10 @@ impl ProblemRepository for SqliteProblemRepository {
                     name: row.get(2)?,
                     start: row.get(3)?,
                     end: row.get(4)?,
+                    partition_mode: PartitionMode::from_name(&row.get::<_, String>(5)?)
+                        .unwrap_or_default(),
+                    kind: ParameterKind::from_name(&row.get::<_, String>(6)?).unwrap_or_default(),
+                    metadata: parameter_metadata::read(row, 7)?,
                     input_values: Vec::<InputValue>::new(),
                 })
             })
@@ -254,9 +264,10 @@ impl ProblemRepository for SqliteProblemRepository {
         }
 
         let mut stmt = conn
-            .prepare(
-                "SELECT id, problem_id, name, start, end FROM output_parameter WHERE problem_id = ?",
-            )
+            .prepare(&format!(
+                "SELECT id, problem_id, name, start, end, partition_mode, {} FROM output_parameter WHERE problem_id = ? ORDER BY position, id",
+                parameter_metadata::COLUMNS
+            ))
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         let result = stmt
@@ -267,6 +278,9 @@ impl ProblemRepository for SqliteProblemRepository {
                     name: row.get(2)?,
                     start: row.get(3)?,
                     end: row.get(4)?,
+                    partition_mode: PartitionMode::from_name(&row.get::<_, String>(5)?)
+                        .unwrap_or_default(),
+                    metadata: parameter_metadata::read(row, 6)?,
                     fuzzy_output_values: Vec::<FuzzyOutputValue>::new(),
                 })
             })
@@ -289,7 +303,7 @@ impl ProblemRepository for SqliteProblemRepository {
 
         let mut stmt = conn
             .prepare(
-                "SELECT output_value.id, output_value.output_parameter_id, output_value.input_value_ids, output_value.fuzzy_output_value_id 
+                "SELECT output_value.id, output_value.output_parameter_id, output_value.input_value_ids, output_value.fuzzy_output_value_id, output_value.antecedent_hedges, output_value.consequent_hedge
                 FROM output_value 
                 LEFT JOIN output_parameter ON output_value.output_parameter_id = output_parameter.id 
                 WHERE output_parameter.problem_id = ?",
@@ -303,6 +317,8 @@ impl ProblemRepository for SqliteProblemRepository {
                     output_parameter_id: row.get(1)?,
                     input_value_ids: row.get(2)?,
                     fuzzy_output_value_id: row.get(3)?,
+                    antecedent_hedges: row.get(4)?,
+                    consequent_hedge: row.get(5)?,
                 })
             })
             .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -320,20 +336,17 @@ impl ProblemRepository for SqliteProblemRepository {
         Ok(problem)
     }
 
-    fn create(&self, model: &Problem) -> Result<(i64, Option<i64>), DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+    fn create(&self, model: &Problem, image: Option<&Image>) -> Result<(i64, Option<i64>), DomainError> {
+        let mut conn = self.conn.write()?;
 
-        let transaction = conn
-            .transaction()
+        let mut transaction = conn
+            .savepoint()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         let result: Result<(i64, Option<i64>), DomainError> = {
             let mut image_id = Option::<i64>::None;
 
-            if let Some(image) = &model.image {
+            if let Some(image) = image {
                 let mut stmt = transaction
                     .prepare("INSERT INTO image (image_data, image_format) VALUES (?, ?)")
                     .map_err(|e| DomainError::Internal(e.to_string()))?;
@@ -346,8 +359,8 @@ impl ProblemRepository for SqliteProblemRepository {
             let mut stmt = transaction
                 .prepare(
                     "INSERT INTO problem
-            (prev_problem_id, is_final, name, description, created_at, image_id)
-            VALUES (?, ?, ?, ?, ?, ?)",
+            (prev_problem_id, is_final, name, description, created_at, image_id, position)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, (SELECT COALESCE(MAX(position) + 1, 0) FROM problem WHERE prev_problem_id IS ?1))",
                 )
                 .map_err(|e| DomainError::Internal(e.to_string()))?;
 
@@ -390,24 +403,18 @@ impl ProblemRepository for SqliteProblemRepository {
     }
 
     fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let conn = self.conn.write()?;
         self.deep_delete(id, &conn)
     }
 
-    fn update_by_id(&self, id: i64, model: &Problem) -> Result<Option<i64>, DomainError> {
-        let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| DomainError::Internal(e.to_string()))?;
+    fn update_by_id(&self, id: i64, model: &Problem, image: Option<&Image>) -> Result<Option<i64>, DomainError> {
+        let mut conn = self.conn.write()?;
         let transaction = conn
-            .transaction()
+            .savepoint()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         // Handle image update logic
-        // 1. If model.image is SOME, it means we are setting a NEW image.
+        // 1. If an image is given, it means we are setting a NEW image.
         //    We need to insert it and use its ID.
         //    We should also probably delete the old image if it existed...
         //    But we don't know the old image ID easily unless we query for it, OR assume model.image_id
@@ -427,7 +434,7 @@ impl ProblemRepository for SqliteProblemRepository {
         // Drop statement to release borrow on transaction
         drop(stmt);
 
-        let new_image_id = if let Some(image) = &model.image {
+        let new_image_id = if let Some(image) = image {
             // Insert new image
             let mut stmt = transaction
                 .prepare("INSERT INTO image (image_data, image_format) VALUES (?, ?)")
@@ -454,13 +461,7 @@ impl ProblemRepository for SqliteProblemRepository {
                 &time,
                 &id,
             ])
-            .map_err(|e| match e.sqlite_error_code() {
-                None => DomainError::Internal(e.to_string()),
-                Some(error_code) => match error_code {
-                    ErrorCode::NotFound => DomainError::NotFound(e.to_string()),
-                    _ => DomainError::Internal(e.to_string()),
-                },
-            })?;
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
         }
 
         if old_image_id != new_image_id {
@@ -479,23 +480,275 @@ impl ProblemRepository for SqliteProblemRepository {
     }
 
     fn is_final(&self, id: i64) -> Result<bool, DomainError> {
-        let conn = self
-            .conn
-            .lock()
+        let conn = self.conn.read()?;
+        let mut stmt = conn
+            .prepare("SELECT is_final FROM problem WHERE id = ?")
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let is_final: bool = stmt
+            .query_row(params![id], |row| row.get(0))
+            .map_err(not_found_or_internal(EntityKind::Problem, id))?;
+        Ok(is_final)
+    }
+
+    fn clear_definition(&self, id: i64) -> Result<(), DomainError> {
+        let mut conn = self.conn.write()?;
+        let mut transaction = conn
+            .savepoint()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let result: Result<(), DomainError> = {
+            for sql in [
+                "DELETE FROM parameter_link WHERE output_parameter_id IN (SELECT id FROM output_parameter WHERE problem_id = ?)",
+                "DELETE FROM parameter_link WHERE input_parameter_id IN (SELECT id FROM input_parameter WHERE problem_id = ?)",
+                "DELETE FROM output_value WHERE output_parameter_id IN (SELECT id FROM output_parameter WHERE problem_id = ?)",
+                "DELETE FROM fuzzy_output_value WHERE output_parameter_id IN (SELECT id FROM output_parameter WHERE problem_id = ?)",
+                "DELETE FROM output_parameter WHERE problem_id = ?",
+                "DELETE FROM input_value WHERE input_parameter_id IN (SELECT id FROM input_parameter WHERE problem_id = ?)",
+                "DELETE FROM input_parameter WHERE problem_id = ?",
+                "DELETE FROM test_case WHERE problem_id = ?",
+            ] {
+                transaction
+                    .execute(sql, params![&id])
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+            }
+            Ok(())
+        };
+
+        match result {
+            Ok(()) => {
+                transaction
+                    .commit()
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                Ok(())
+            }
+            Err(e) => {
+                transaction
+                    .rollback()
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                Err(e)
+            }
+        }
+    }
+
+    fn replace_definition(&self, model: &Problem) -> Result<(), DomainError> {
+        let mut conn = self.conn.write()?;
+        let mut transaction = conn
+            .savepoint()
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let result: Result<(), DomainError> = {
+            let updated = transaction
+                .execute(
+                    "UPDATE problem SET name = ?, description = ? WHERE id = ?",
+                    params![&model.name, &model.description, &model.id],
+                )
+                .map_err(|e| DomainError::Internal(e.to_string()))?;
+            if updated == 0 {
+                return Err(DomainError::not_found(EntityKind::Problem, model.id));
+            }
+
+            // Deleting the parameters drops their links, from this or other problems
+            let links = {
+                let mut stmt = transaction
+                    .prepare(
+                        "SELECT id, output_parameter_id, input_parameter_id FROM parameter_link
+                         WHERE output_parameter_id IN (SELECT id FROM output_parameter WHERE problem_id = ?1)
+                            OR input_parameter_id IN (SELECT id FROM input_parameter WHERE problem_id = ?1)",
+                    )
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                let links = stmt
+                    .query_map(params![&model.id], |row| {
+                        Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?, row.get::<_, i64>(2)?))
+                    })
+                    .map_err(|e| DomainError::Internal(e.to_string()))?
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                links
+            };
+
+            for sql in [
+                "DELETE FROM parameter_link WHERE output_parameter_id IN (SELECT id FROM output_parameter WHERE problem_id = ?1) OR input_parameter_id IN (SELECT id FROM input_parameter WHERE problem_id = ?1)",
+                "DELETE FROM output_value WHERE output_parameter_id IN (SELECT id FROM output_parameter WHERE problem_id = ?)",
+                "DELETE FROM fuzzy_output_value WHERE output_parameter_id IN (SELECT id FROM output_parameter WHERE problem_id = ?)",
+                "DELETE FROM output_parameter WHERE problem_id = ?",
+                "DELETE FROM input_value WHERE input_parameter_id IN (SELECT id FROM input_parameter WHERE problem_id = ?)",
+                "DELETE FROM input_parameter WHERE problem_id = ?",
+            ] {
+                transaction
+                    .execute(sql, params![&model.id])
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+            }
+
+            // Ids deleted above may have been reused by another problem in the meantime
+            let map_insert_error = |e: rusqlite::Error| match e.sqlite_error_code() {
+                Some(ErrorCode::ConstraintViolation) => DomainError::Validation(format!(
+                    "Problem {} can not be restored because its ids are taken: {}",
+                    model.id, e
+                )),
+                _ => DomainError::Internal(e.to_string()),
+            };
+
+            // Positions follow the order of the definition
+            for (ip_position, ip) in model.input_parameters.iter().enumerate() {
+                transaction
+                    .execute(
+                        "INSERT INTO input_parameter (id, problem_id, name, start, end, partition_mode, kind, position, unit, description, precision, default_value) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                        params![&ip.id, &model.id, &ip.name, &ip.start, &ip.end, ip.partition_mode.as_str(), ip.kind.as_str(), &(ip_position as i64), &ip.metadata.unit, &ip.metadata.description, &ip.metadata.precision, &ip.metadata.default_value],
+                    )
+                    .map_err(map_insert_error)?;
+                for (iv_position, iv) in ip.input_values.iter().enumerate() {
+                    transaction
+                        .execute(
+                            "INSERT INTO input_value (id, input_parameter_id, value, a, b, c, d, is_triangle, lower_membership, position) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                            params![&iv.id, &ip.id, &iv.value, &iv.a, &iv.b, &iv.c, &iv.d, &iv.is_triangle, &lower_membership::encode(iv.lower.as_ref())?, &(iv_position as i64)],
+                        )
+                        .map_err(map_insert_error)?;
+                }
+            }
+
+            for (op_position, op) in model.output_parameters.iter().enumerate() {
+                transaction
+                    .execute(
+                        "INSERT INTO output_parameter (id, problem_id, name, start, end, partition_mode, position, unit, description, precision, default_value) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                        params![&op.id, &model.id, &op.name, &op.start, &op.end, op.partition_mode.as_str(), &(op_position as i64), &op.metadata.unit, &op.metadata.description, &op.metadata.precision, &op.metadata.default_value],
+                    )
+                    .map_err(map_insert_error)?;
+                for (fov_position, fov) in op.fuzzy_output_values.iter().enumerate() {
+                    transaction
+                        .execute(
+                            "INSERT INTO fuzzy_output_value (id, output_parameter_id, value, a, b, c, d, is_triangle, lower_membership, position) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                            params![&fov.id, &op.id, &fov.value, &fov.a, &fov.b, &fov.c, &fov.d, &fov.is_triangle, &lower_membership::encode(fov.lower.as_ref())?, &(fov_position as i64)],
+                        )
+                        .map_err(map_insert_error)?;
+                }
+            }
+
+            for ov in &model.output_values {
+                transaction
+                    .execute(
+                        "INSERT INTO output_value (id, output_parameter_id, fuzzy_output_value_id, input_value_ids, antecedent_hedges, consequent_hedge) VALUES (?, ?, ?, ?, ?, ?)",
+                        params![&ov.id, &ov.output_parameter_id, &ov.fuzzy_output_value_id, &ov.input_value_ids, &ov.antecedent_hedges, &ov.consequent_hedge],
+                    )
+                    .map_err(map_insert_error)?;
+            }
+
+            // Links of parameters the definition still has are put back; the others go
+            for (id, output_parameter_id, input_parameter_id) in links {
+                transaction
+                    .execute(
+                        "INSERT INTO parameter_link (id, output_parameter_id, input_parameter_id) SELECT ?1, ?2, ?3 WHERE EXISTS (SELECT 1 FROM output_parameter WHERE id = ?2) AND EXISTS (SELECT 1 FROM input_parameter WHERE id = ?3)",
+                        params![id, output_parameter_id, input_parameter_id],
+                    )
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+            }
+
+            Ok(())
+        };
+
+        match result {
+            Ok(()) => {
+                transaction
+                    .commit()
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                Ok(())
+            }
+            Err(e) => {
+                transaction
+                    .rollback()
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                Err(e)
+            }
+        }
+    }
+
+    fn get_ancestor_ids(&self, id: i64) -> Result<Vec<i64>, DomainError> {
+        let conn = self.conn.read()?;
+
+        // UNION instead of UNION ALL stops on an already broken (cyclic) hierarchy
         let mut stmt = conn
-            .prepare("SELECT EXISTS(SELECT is_final FROM problem WHERE id = ?)")
+            .prepare(
+                "WITH RECURSIVE ancestor(id, depth) AS (
+                    SELECT prev_problem_id, 0 FROM problem WHERE id = ?
+                    UNION
+                    SELECT problem.prev_problem_id, ancestor.depth + 1
+                    FROM problem JOIN ancestor ON problem.id = ancestor.id
+                )
+                SELECT id FROM ancestor WHERE id IS NOT NULL ORDER BY depth",
+            )
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let ids = stmt
+            .query_map(params![id], |row| row.get(0))
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+            .collect::<Result<Vec<i64>, _>>()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(ids)
+    }
+
+    fn move_by_id(
+        &self,
+        id: i64,
+        prev_problem_id: Option<i64>,
+        position: Option<usize>,
+    ) -> Result<(), DomainError> {
+        let mut conn = self.conn.write()?;
+        let mut transaction = conn
+            .savepoint()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let result = {
+            let mut stmt = transaction
+                .prepare(
+                    "SELECT id FROM problem WHERE prev_problem_id IS ? AND id != ? ORDER BY position, id",
+                )
+                .map_err(|e| DomainError::Internal(e.to_string()))?;
+            let mut sibling_ids = stmt
+                .query_map(params![prev_problem_id, id], |row| row.get(0))
+                .map_err(|e| DomainError::Internal(e.to_string()))?
+                .collect::<Result<Vec<i64>, _>>()
+                .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+            let index = position.unwrap_or(sibling_ids.len()).min(sibling_ids.len());
+            sibling_ids.insert(index, id);
 
-        let exists: bool = stmt.query_row(params![id], |row| row.get(0)).map_err(|e| {
-            match e.sqlite_error_code() {
-                None => DomainError::Internal(e.to_string()),
-                Some(error_code) => match error_code {
-                    ErrorCode::NotFound => DomainError::NotFound(e.to_string()),
-                    _ => DomainError::Internal(e.to_string()),
-                },
+            let moved = transaction
+                .execute(
+                    "UPDATE problem SET prev_problem_id = ? WHERE id = ?",
+                    params![prev_problem_id, id],
+                )
+                .map_err(|e| DomainError::Internal(e.to_string()))?;
+            if moved == 0 {
+                Err(DomainError::not_found(EntityKind::Problem, id))
+            } else {
+                sibling_ids
+                    .iter()
+                    .enumerate()
+                    .try_for_each(|(position, sibling_id)| {
+                        transaction
+                            .execute(
+                                "UPDATE problem SET position = ? WHERE id = ?",
+                                params![position as i64, sibling_id],
+                            )
+                            .map(|_| ())
+                            .map_err(|e| DomainError::Internal(e.to_string()))
+                    })
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                transaction
+                    .commit()
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                Ok(())
             }
-        })?;
-        Ok(exists)
+            Err(e) => {
+                transaction
+                    .rollback()
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                Err(e)
+            }
+        }
     }
 }
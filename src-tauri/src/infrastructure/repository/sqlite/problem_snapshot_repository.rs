@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use rusqlite::{params, Row};
+
+use crate::domain::{
+    entities::problem_snapshot::ProblemSnapshot, error::{DomainError, EntityKind},
+    repository::ProblemSnapshotRepository,
+};
+use crate::infrastructure::repository::sqlite::{
+    connection_pool::SqliteConnectionPool, query_error::not_found_or_internal,
+};
+
+pub struct SqliteProblemSnapshotRepository {
+    conn: Arc<SqliteConnectionPool>,
+}
+
+impl SqliteProblemSnapshotRepository {
+    pub fn new(conn: Arc<SqliteConnectionPool>) -> Self {
+        Self { conn }
+    }
+
+    fn read_row(row: &Row) -> rusqlite::Result<ProblemSnapshot> {
+        Ok(ProblemSnapshot {
+            id: row.get(0)?,
+            problem_id: row.get(1)?,
+            name: row.get(2)?,
+            is_automatic: row.get(3)?,
+            definition: row.get(4)?,
+            created_at: row.get(5)?,
+        })
+    }
+}
+
+impl ProblemSnapshotRepository for SqliteProblemSnapshotRepository {
+    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<ProblemSnapshot>, DomainError> {
+        let conn = self.conn.read()?;
+
+        let mut stmt = conn
+            .prepare("SELECT id, problem_id, name, is_automatic, definition, created_at FROM problem_snapshot WHERE problem_id = ? ORDER BY id DESC")
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let snapshots = stmt
+            .query_map(params![problem_id], Self::read_row)
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(snapshots)
+    }
+
+    fn get_by_id(&self, id: i64) -> Result<ProblemSnapshot, DomainError> {
+        let conn = self.conn.read()?;
+
+        conn.query_row(
+            "SELECT id, problem_id, name, is_automatic, definition, created_at FROM problem_snapshot WHERE id = ?",
+            params![id],
+            Self::read_row,
+        )
+        .map_err(not_found_or_internal(EntityKind::ProblemSnapshot, id))
+    }
+
+    fn create(&self, model: &ProblemSnapshot) -> Result<i64, DomainError> {
+        let conn = self.conn.write()?;
+
+        let mut stmt = conn
+            .prepare("INSERT INTO problem_snapshot (problem_id, name, is_automatic, definition, created_at) VALUES (?, ?, ?, ?, ?)")
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let time = Utc::now().to_rfc3339();
+
+        stmt.execute(params![
+            &model.problem_id,
+            &model.name,
+            &model.is_automatic,
+            &model.definition,
+            &time,
+        ])
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        let removed = conn
+            .execute("DELETE FROM problem_snapshot WHERE id = ?", params![&id])
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        if removed == 0 {
+            return Err(DomainError::not_found(EntityKind::ProblemSnapshot, id));
+        }
+        Ok(())
+    }
+
+    fn prune_automatic(&self, problem_id: i64, keep: usize) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        conn.execute(
+            "DELETE FROM problem_snapshot WHERE problem_id = ? AND is_automatic = 1 AND id NOT IN
+            (SELECT id FROM problem_snapshot WHERE problem_id = ? AND is_automatic = 1 ORDER BY id DESC LIMIT ?)",
+            params![&problem_id, &problem_id, &(keep as i64)],
+        )
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+use crate::domain::error::{DomainError, EntityKind};
+
+/// Maps the error of a query for the `entity` with `id`: no row means it does not
+/// exist, anything else is an internal failure
+pub fn not_found_or_internal(entity: EntityKind, id: i64) -> impl Fn(rusqlite::Error) -> DomainError {
+    move |e| match e {
+        rusqlite::Error::QueryReturnedNoRows => DomainError::not_found(entity, id),
+        _ => DomainError::Internal(e.to_string()),
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use rusqlite::params;
+
+use crate::domain::{
+    entities::test_case::{TestCase, TestCaseExpectation, TestCaseInput},
+    error::{DomainError, EntityKind},
+    repository::TestCaseRepository,
+};
+use crate::infrastructure::repository::sqlite::{
+    connection_pool::SqliteConnectionPool, query_error::not_found_or_internal,
+};
+
+pub struct SqliteTestCaseRepository {
+    conn: Arc<SqliteConnectionPool>,
+}
+
+impl SqliteTestCaseRepository {
+    pub fn new(conn: Arc<SqliteConnectionPool>) -> Self {
+        Self { conn }
+    }
+
+    // Inputs and expectations are stored as JSON arrays, the same way rules keep
+    // their antecedents in a single `input_value_ids` column.
+    fn encode(model: &TestCase) -> Result<(String, String), DomainError> {
+        let inputs = serde_json::to_string(&model.inputs)
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let expectations = serde_json::to_string(&model.expectations)
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        Ok((inputs, expectations))
+    }
+
+    fn decode(
+        id: i64,
+        problem_id: i64,
+        name: String,
+        inputs: String,
+        expectations: String,
+    ) -> Result<TestCase, DomainError> {
+        let inputs: Vec<TestCaseInput> =
+            serde_json::from_str(&inputs).map_err(|e| DomainError::Data(e.to_string()))?;
+        let expectations: Vec<TestCaseExpectation> =
+            serde_json::from_str(&expectations).map_err(|e| DomainError::Data(e.to_string()))?;
+        Ok(TestCase {
+            id,
+            problem_id,
+            name,
+            inputs,
+            expectations,
+        })
+    }
+}
+
+impl TestCaseRepository for SqliteTestCaseRepository {
+    fn get_by_problem_id(&self, problem_id: i64) -> Result<Vec<TestCase>, DomainError> {
+        let conn = self.conn.read()?;
+
+        let mut stmt = conn
+            .prepare("SELECT id, problem_id, name, inputs, expectations FROM test_case WHERE problem_id = ? ORDER BY id")
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let rows = stmt
+            .query_map(params![problem_id], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                ))
+            })
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|(id, problem_id, name, inputs, expectations)| {
+                Self::decode(id, problem_id, name, inputs, expectations)
+            })
+            .collect()
+    }
+
+    fn get_by_id(&self, id: i64) -> Result<TestCase, DomainError> {
+        let conn = self.conn.read()?;
+
+        let (id, problem_id, name, inputs, expectations): (i64, i64, String, String, String) = conn
+            .query_row(
+                "SELECT id, problem_id, name, inputs, expectations FROM test_case WHERE id = ?",
+                params![id],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
+            )
+            .map_err(not_found_or_internal(EntityKind::TestCase, id))?;
+
+        Self::decode(id, problem_id, name, inputs, expectations)
+    }
+
+    fn create(&self, model: &TestCase) -> Result<i64, DomainError> {
+        let (inputs, expectations) = Self::encode(model)?;
+
+        let conn = self.conn.write()?;
+
+        let mut stmt = conn
+            .prepare("INSERT INTO test_case (problem_id, name, inputs, expectations) VALUES (?, ?, ?, ?)")
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        stmt.execute(params![&model.problem_id, &model.name, &inputs, &expectations])
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    fn update_by_id(&self, id: i64, model: &TestCase) -> Result<(), DomainError> {
+        let (inputs, expectations) = Self::encode(model)?;
+
+        let conn = self.conn.write()?;
+
+        let updated = conn
+            .execute(
+                "UPDATE test_case SET name = ?, inputs = ?, expectations = ? WHERE id = ?",
+                params![&model.name, &inputs, &expectations, &id],
+            )
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        if updated == 0 {
+            return Err(DomainError::not_found(EntityKind::TestCase, id));
+        }
+        Ok(())
+    }
+
+    fn remove_by_id(&self, id: i64) -> Result<(), DomainError> {
+        let conn = self.conn.write()?;
+        conn.execute("DELETE FROM test_case WHERE id = ?", params![&id])
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        Ok(())
+    }
+}
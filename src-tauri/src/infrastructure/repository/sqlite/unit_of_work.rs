@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{error::DomainError, repository::UnitOfWork},
+    infrastructure::repository::sqlite::connection_pool::SqliteConnectionPool,
+};
+
+/// Unit of work on the writer connection shared by all SQLite repositories.
+///
+/// Repositories open their own transactions as savepoints, so while a unit of work
+/// is open their writes nest inside it and only become durable once it is committed.
+/// The pool keeps other threads from writing until then.
+pub struct SqliteUnitOfWork {
+    conn: Arc<SqliteConnectionPool>,
+}
+
+impl SqliteUnitOfWork {
+    pub fn new(conn: Arc<SqliteConnectionPool>) -> Self {
+        Self { conn }
+    }
+}
+
+impl UnitOfWork for SqliteUnitOfWork {
+    fn begin(&self) -> Result<(), DomainError> {
+        self.conn.begin_unit_of_work("SAVEPOINT unit_of_work")
+    }
+
+    fn commit(&self) -> Result<(), DomainError> {
+        self.conn.finish_unit_of_work("RELEASE unit_of_work")
+    }
+
+    fn rollback(&self) -> Result<(), DomainError> {
+        self.conn
+            .finish_unit_of_work("ROLLBACK TO unit_of_work; RELEASE unit_of_work")
+    }
+}
@@ -1,24 +1,57 @@
 use std::{
+    collections::HashMap,
     env,
+    path::Path,
     sync::{Arc, Mutex},
 };
 
 use crate::{
-    domain::repository::{
-        FuzzyOutputValueRepository, ImageRepository, InputParameterRepository,
-        InputValueRepository, OutputParameterRepository, OutputValueRepository, ProblemRepository,
+    domain::{
+        repository::{
+            EvaluationHistoryRepository, FuzzyOutputValueRepository, ImageRepository,
+            InputParameterRepository, InputValueRepository, JournalRepository,
+            OutputParameterRepository, OutputValueRepository, ParameterLinkRepository, ProblemRepository,
+            ProblemSnapshotRepository, TestCaseRepository, UnitOfWork,
+        },
+        services::parallel_evaluation::JobControl,
+    },
+    infrastructure::repository::memory::{
+        evaluation_history_repository::InMemoryEvaluationHistoryRepository,
+        fuzzy_output_value_repository::InMemoryFuzzyOutputValueRepository,
+        image_repository::InMemoryImageRepository,
+        input_parameter_repository::InMemoryInputParameterRepository,
+        input_value_repository::InMemoryInputValueRepository,
+        journal_repository::InMemoryJournalRepository,
+        output_parameter_repository::InMemoryOutputParameterRepository,
+        output_value_repository::InMemoryOutputValueRepository,
+        parameter_link_repository::InMemoryParameterLinkRepository,
+        problem_repository::InMemoryProblemRepository,
+        problem_snapshot_repository::InMemoryProblemSnapshotRepository,
+        store::MemoryStore,
+        test_case_repository::InMemoryTestCaseRepository,
+        unit_of_work::InMemoryUnitOfWork,
     },
     infrastructure::repository::sqlite::{
+        connection_pool::SqliteConnectionPool,
+        evaluation_history_repository::SqliteEvaluationHistoryRepository,
         fuzzy_output_value_repository::SqliteFuzzyOutputValueRepository,
         image_repository::SqliteImageRepository,
         input_parameter_repository::SqliteInputParameterRepository,
         input_value_repository::SqliteInputValueRepository,
+        journal_repository::SqliteJournalRepository,
         output_parameter_repository::SqliteOutputParameterRepository,
         output_value_repository::SqliteOutputValueRepository,
+        parameter_link_repository::SqliteParameterLinkRepository,
         problem_repository::SqliteProblemRepository,
+        problem_snapshot_repository::SqliteProblemSnapshotRepository,
+        test_case_repository::SqliteTestCaseRepository,
+        unit_of_work::SqliteUnitOfWork,
     },
 };
 
+/// Read-only connections next to the writer; enough for the UI's concurrent queries
+const READ_CONNECTIONS: usize = 4;
+
 pub struct AppState {
     pub problem_repository: Box<dyn ProblemRepository>,
     pub input_parameter_repository: Box<dyn InputParameterRepository>,
@@ -27,6 +60,14 @@ pub struct AppState {
     pub fuzzy_output_value_repository: Box<dyn FuzzyOutputValueRepository>,
     pub output_value_repository: Box<dyn OutputValueRepository>,
     pub image_repository: Box<dyn ImageRepository>,
+    pub test_case_repository: Box<dyn TestCaseRepository>,
+    pub evaluation_history_repository: Box<dyn EvaluationHistoryRepository>,
+    pub problem_snapshot_repository: Box<dyn ProblemSnapshotRepository>,
+    pub journal_repository: Box<dyn JournalRepository>,
+    pub parameter_link_repository: Box<dyn ParameterLinkRepository>,
+    pub unit_of_work: Box<dyn UnitOfWork>,
+    /// Running batch and sweep evaluations by job id, so they can be cancelled
+    pub evaluation_jobs: Mutex<HashMap<String, Arc<JobControl>>>,
 }
 
 impl AppState {
@@ -35,9 +76,15 @@ impl AppState {
         let exe_dir = current_exe_path
             .parent()
             .expect("Failed to get parent directory of executable");
-        let dir_path = exe_dir.join("main.db").to_str().unwrap().to_string();
-        let conn = rusqlite::Connection::open(dir_path).expect("Failed to open database");
+        let db_path = exe_dir.join("main.db");
+        let conn = rusqlite::Connection::open(&db_path).expect("Failed to open database");
+
+        Self::sqlite(conn, &db_path, READ_CONNECTIONS)
+    }
 
+    /// Repositories on the SQLite database behind `conn`, creating or migrating its
+    /// schema; `path` is where the `readers` read-only connections open it
+    pub fn sqlite(conn: rusqlite::Connection, path: &Path, readers: usize) -> Self {
         conn.execute_batch(
             "
             CREATE TABLE IF NOT EXISTS image (
@@ -54,7 +101,8 @@ impl AppState {
                 description TEXT NOT NULL,
                 image_id INTEGER REFERENCES image(id),
                 created_at VARCHAR(255) NOT NULL,
-                updated_at VARCHAR(255)
+                updated_at VARCHAR(255),
+                position INTEGER NOT NULL DEFAULT(0)
             );
 
             CREATE TABLE IF NOT EXISTS input_parameter (
@@ -62,7 +110,14 @@ impl AppState {
                 problem_id INTEGER NOT NULL REFERENCES problem(id) ON DELETE CASCADE,
                 name VARCHAR(255) NOT NULL,
                 start REAL NOT NULL DEFAULT(0),
-                end REAL NOT NULL DEFAULT(1)
+                end REAL NOT NULL DEFAULT(1),
+                partition_mode VARCHAR(255) NOT NULL DEFAULT('ruspini'),
+                kind VARCHAR(255) NOT NULL DEFAULT('continuous'),
+                unit VARCHAR(255) NOT NULL DEFAULT(''),
+                description TEXT NOT NULL DEFAULT(''),
+                precision INTEGER,
+                default_value REAL,
+                position INTEGER NOT NULL DEFAULT(0)
             );
 
             CREATE TABLE IF NOT EXISTS input_value (
@@ -73,7 +128,9 @@ impl AppState {
                 b REAL NOT NULL,
                 c REAL NOT NULL,
                 d REAL NOT NULL,
-                is_triangle BOOLEAN NOT NULL DEFAULT(false)
+                is_triangle BOOLEAN NOT NULL DEFAULT(false),
+                lower_membership TEXT,
+                position INTEGER NOT NULL DEFAULT(0)
             );
 
             CREATE TABLE IF NOT EXISTS output_parameter (
@@ -81,7 +138,13 @@ impl AppState {
                 problem_id INTEGER NOT NULL REFERENCES problem(id) ON DELETE CASCADE,
                 name VARCHAR(255) NOT NULL,
                 start REAL NOT NULL DEFAULT(0),
-                end REAL NOT NULL DEFAULT(1) 
+                end REAL NOT NULL DEFAULT(1),
+                partition_mode VARCHAR(255) NOT NULL DEFAULT('ruspini'),
+                unit VARCHAR(255) NOT NULL DEFAULT(''),
+                description TEXT NOT NULL DEFAULT(''),
+                precision INTEGER,
+                default_value REAL,
+                position INTEGER NOT NULL DEFAULT(0)
             );
 
             CREATE TABLE IF NOT EXISTS fuzzy_output_value (
@@ -92,20 +155,106 @@ impl AppState {
                 b REAL NOT NULL,
                 c REAL NOT NULL,
                 d REAL NOT NULL,
-                is_triangle BOOLEAN NOT NULL DEFAULT(false)
+                is_triangle BOOLEAN NOT NULL DEFAULT(false),
+                lower_membership TEXT,
+                position INTEGER NOT NULL DEFAULT(0)
             );
 
             CREATE TABLE IF NOT EXISTS output_value (
                 id INTEGER PRIMARY KEY,
                 output_parameter_id INTEGER NOT NULL REFERENCES output_parameter(id) ON DELETE CASCADE,
                 fuzzy_output_value_id INTEGER REFERENCES fuzzy_output_value(id) ON DELETE SET NULL,
-                input_value_ids TEXT NOT NULL
+                input_value_ids TEXT NOT NULL,
+                antecedent_hedges TEXT NOT NULL DEFAULT(''),
+                consequent_hedge VARCHAR(255)
+            );
+
+            CREATE TABLE IF NOT EXISTS test_case (
+                id INTEGER PRIMARY KEY,
+                problem_id INTEGER NOT NULL REFERENCES problem(id) ON DELETE CASCADE,
+                name VARCHAR(255) NOT NULL,
+                inputs TEXT NOT NULL,
+                expectations TEXT NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS evaluation_history (
+                id INTEGER PRIMARY KEY,
+                problem_id INTEGER NOT NULL,
+                problem_name VARCHAR(255) NOT NULL,
+                inputs TEXT NOT NULL,
+                method VARCHAR(255) NOT NULL,
+                resolution INTEGER NOT NULL,
+                outputs TEXT NOT NULL,
+                definition_hash VARCHAR(255) NOT NULL,
+                created_at VARCHAR(255) NOT NULL
+            );
+
+            CREATE INDEX IF NOT EXISTS evaluation_history_problem_id
+                ON evaluation_history(problem_id, created_at);
+
+            CREATE TABLE IF NOT EXISTS problem_snapshot (
+                id INTEGER PRIMARY KEY,
+                problem_id INTEGER NOT NULL REFERENCES problem(id) ON DELETE CASCADE,
+                name VARCHAR(255) NOT NULL,
+                is_automatic BOOLEAN NOT NULL DEFAULT(false),
+                definition TEXT NOT NULL,
+                created_at VARCHAR(255) NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS journal_entry (
+                id INTEGER PRIMARY KEY,
+                problem_id INTEGER NOT NULL REFERENCES problem(id) ON DELETE CASCADE,
+                description TEXT NOT NULL,
+                before TEXT NOT NULL,
+                after TEXT NOT NULL,
+                is_undone BOOLEAN NOT NULL DEFAULT(false),
+                created_at VARCHAR(255) NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS parameter_link (
+                id INTEGER PRIMARY KEY,
+                output_parameter_id INTEGER NOT NULL REFERENCES output_parameter(id) ON DELETE CASCADE,
+                input_parameter_id INTEGER NOT NULL UNIQUE REFERENCES input_parameter(id) ON DELETE CASCADE
             );
         ",
         )
         .unwrap();
 
-        let shared_conn = Arc::new(Mutex::new(conn));
+        // Columns added after the first release, for databases created before them
+        add_column_if_missing(&conn, "problem", "position", "INTEGER NOT NULL DEFAULT(0)");
+        add_column_if_missing(&conn, "output_value", "antecedent_hedges", "TEXT NOT NULL DEFAULT('')");
+        add_column_if_missing(&conn, "output_value", "consequent_hedge", "VARCHAR(255)");
+        add_column_if_missing(&conn, "input_value", "lower_membership", "TEXT");
+        add_column_if_missing(&conn, "fuzzy_output_value", "lower_membership", "TEXT");
+        add_column_if_missing(&conn, "input_parameter", "partition_mode", "VARCHAR(255) NOT NULL DEFAULT('ruspini')");
+        add_column_if_missing(&conn, "output_parameter", "partition_mode", "VARCHAR(255) NOT NULL DEFAULT('ruspini')");
+        add_column_if_missing(&conn, "input_parameter", "unit", "VARCHAR(255) NOT NULL DEFAULT('')");
+        add_column_if_missing(&conn, "input_parameter", "description", "TEXT NOT NULL DEFAULT('')");
+        add_column_if_missing(&conn, "input_parameter", "precision", "INTEGER");
+        add_column_if_missing(&conn, "input_parameter", "default_value", "REAL");
+        add_column_if_missing(&conn, "output_parameter", "unit", "VARCHAR(255) NOT NULL DEFAULT('')");
+        add_column_if_missing(&conn, "output_parameter", "description", "TEXT NOT NULL DEFAULT('')");
+        add_column_if_missing(&conn, "output_parameter", "precision", "INTEGER");
+        add_column_if_missing(&conn, "output_parameter", "default_value", "REAL");
+        add_column_if_missing(&conn, "input_parameter", "kind", "VARCHAR(255) NOT NULL DEFAULT('continuous')");
+        // Existing parameters keep their creation order and terms their order along the range
+        if add_column_if_missing(&conn, "input_parameter", "position", "INTEGER NOT NULL DEFAULT(0)") {
+            backfill_positions(&conn, "input_parameter", "problem_id", "o.id < t.id");
+        }
+        if add_column_if_missing(&conn, "output_parameter", "position", "INTEGER NOT NULL DEFAULT(0)") {
+            backfill_positions(&conn, "output_parameter", "problem_id", "o.id < t.id");
+        }
+        if add_column_if_missing(&conn, "input_value", "position", "INTEGER NOT NULL DEFAULT(0)") {
+            backfill_positions(&conn, "input_value", "input_parameter_id", "o.a < t.a OR (o.a = t.a AND o.id < t.id)");
+        }
+        if add_column_if_missing(&conn, "fuzzy_output_value", "position", "INTEGER NOT NULL DEFAULT(0)") {
+            backfill_positions(&conn, "fuzzy_output_value", "output_parameter_id", "o.a < t.a OR (o.a = t.a AND o.id < t.id)");
+        }
+
+        let shared_conn = Arc::new(
+            SqliteConnectionPool::open(conn, path, readers)
+                .expect("Failed to open database connections"),
+        );
 
         Self {
             problem_repository: Box::new(SqliteProblemRepository::new(Arc::clone(&shared_conn))),
@@ -115,6 +264,61 @@ impl AppState {
             fuzzy_output_value_repository: Box::new(SqliteFuzzyOutputValueRepository::create(Arc::clone(&shared_conn))),
             output_value_repository: Box::new(SqliteOutputValueRepository::create(Arc::clone(&shared_conn))),
             image_repository: Box::new(SqliteImageRepository::new(Arc::clone(&shared_conn))),
+            test_case_repository: Box::new(SqliteTestCaseRepository::new(Arc::clone(&shared_conn))),
+            evaluation_history_repository: Box::new(SqliteEvaluationHistoryRepository::new(Arc::clone(&shared_conn))),
+            problem_snapshot_repository: Box::new(SqliteProblemSnapshotRepository::new(Arc::clone(&shared_conn))),
+            journal_repository: Box::new(SqliteJournalRepository::new(Arc::clone(&shared_conn))),
+            parameter_link_repository: Box::new(SqliteParameterLinkRepository::new(Arc::clone(&shared_conn))),
+            unit_of_work: Box::new(SqliteUnitOfWork::new(Arc::clone(&shared_conn))),
+            evaluation_jobs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Repositories on an empty store kept in memory, which is lost when the state is dropped
+    pub fn in_memory() -> Self {
+        let store = Arc::new(MemoryStore::new());
+
+        Self {
+            problem_repository: Box::new(InMemoryProblemRepository::new(Arc::clone(&store))),
+            input_parameter_repository: Box::new(InMemoryInputParameterRepository::new(Arc::clone(&store))),
+            input_value_repository: Box::new(InMemoryInputValueRepository::new(Arc::clone(&store))),
+            output_parameter_repository: Box::new(InMemoryOutputParameterRepository::new(Arc::clone(&store))),
+            fuzzy_output_value_repository: Box::new(InMemoryFuzzyOutputValueRepository::new(Arc::clone(&store))),
+            output_value_repository: Box::new(InMemoryOutputValueRepository::new(Arc::clone(&store))),
+            image_repository: Box::new(InMemoryImageRepository::new(Arc::clone(&store))),
+            test_case_repository: Box::new(InMemoryTestCaseRepository::new(Arc::clone(&store))),
+            evaluation_history_repository: Box::new(InMemoryEvaluationHistoryRepository::new(Arc::clone(&store))),
+            problem_snapshot_repository: Box::new(InMemoryProblemSnapshotRepository::new(Arc::clone(&store))),
+            journal_repository: Box::new(InMemoryJournalRepository::new(Arc::clone(&store))),
+            parameter_link_repository: Box::new(InMemoryParameterLinkRepository::new(Arc::clone(&store))),
+            unit_of_work: Box::new(InMemoryUnitOfWork::new(Arc::clone(&store))),
+            evaluation_jobs: Mutex::new(HashMap::new()),
         }
     }
 }
+
+/// Adds the column unless the table already has it; returns whether it was added
+fn add_column_if_missing(conn: &rusqlite::Connection, table: &str, column: &str, definition: &str) -> bool {
+    let exists: bool = conn
+        .query_row(
+            &format!("SELECT EXISTS(SELECT 1 FROM pragma_table_info('{}') WHERE name = ?)", table),
+            [column],
+            |row| row.get(0),
+        )
+        .unwrap();
+
+    if !exists {
+        conn.execute_batch(&format!("ALTER TABLE {} ADD COLUMN {} {};", table, column, definition))
+            .unwrap();
+    }
+    !exists
+}
+
+/// Numbers the rows of `table` from 0 within each `parent_column`, in the order
+/// given by `precedes` (an SQL condition on rows `o` and `t` true when `o` comes first)
+fn backfill_positions(conn: &rusqlite::Connection, table: &str, parent_column: &str, precedes: &str) {
+    conn.execute_batch(&format!(
+        "UPDATE {table} AS t SET position = (SELECT COUNT(*) FROM {table} o WHERE o.{parent_column} = t.{parent_column} AND ({precedes}));"
+    ))
+    .unwrap();
+}
@@ -0,0 +1,67 @@
+use tauri::State;
+
+use crate::{
+    application::use_cases::evaluation_history::{
+        get_filtered::GetEvaluationHistoryUseCase, remove_by_id::RemoveEvaluationRecordByIdUseCase,
+        remove_by_problem_id::RemoveEvaluationHistoryByProblemIdUseCase,
+        replay::ReplayEvaluationUseCase,
+    },
+    infrastructure::{
+        state::AppState,
+        tauri::dtos::error_dtos::ErrorResponse,
+        tauri::dtos::evaluation_history_dtos::{
+            EvaluationHistoryFilterRequest, EvaluationRecordResponse, ReplayEvaluationResponse,
+        },
+    },
+};
+
+/// Lists recorded evaluations, newest first
+#[tauri::command]
+pub fn get_evaluation_history(
+    filter: Option<EvaluationHistoryFilterRequest>,
+    state: State<'_, AppState>,
+) -> Result<Vec<EvaluationRecordResponse>, ErrorResponse> {
+    let use_case = GetEvaluationHistoryUseCase::new(state.evaluation_history_repository.as_ref());
+
+    let records = use_case.execute(&filter.unwrap_or_default().to_filter())?;
+    Ok(records.iter().map(EvaluationRecordResponse::from).collect())
+}
+
+#[tauri::command(async)]
+pub fn remove_evaluation_record_by_id(
+    id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let use_case =
+        RemoveEvaluationRecordByIdUseCase::new(state.evaluation_history_repository.as_ref());
+
+    use_case.execute(id).map_err(ErrorResponse::from)
+}
+
+/// Removes the whole history of a problem and returns the number of removed records
+#[tauri::command(async)]
+pub fn remove_evaluation_history_by_problem_id(
+    problem_id: i64,
+    state: State<'_, AppState>,
+) -> Result<usize, ErrorResponse> {
+    let use_case = RemoveEvaluationHistoryByProblemIdUseCase::new(
+        state.evaluation_history_repository.as_ref(),
+    );
+
+    use_case.execute(problem_id).map_err(ErrorResponse::from)
+}
+
+/// Re-evaluates a recorded evaluation against the current problem definition
+#[tauri::command]
+pub fn replay_evaluation(
+    id: i64,
+    state: State<'_, AppState>,
+) -> Result<ReplayEvaluationResponse, ErrorResponse> {
+    let use_case = ReplayEvaluationUseCase::new(
+        state.problem_repository.as_ref(),
+        state.evaluation_history_repository.as_ref(),
+    );
+
+    let result = use_case.execute(id)?;
+    Ok(ReplayEvaluationResponse::from(result))
+}
@@ -1,11 +1,29 @@
-use tauri::State;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use tauri::{AppHandle, Emitter, State};
 
 use crate::{
-    application::use_cases::fuzzy_inference::EvaluateFuzzySystemUseCase,
+    application::use_cases::{
+        evaluation_history::record::RecordEvaluationUseCase,
+        fuzzy_inference::{
+            EvaluateBatchUseCase, EvaluateChainUseCase, EvaluateFuzzySystemUseCase,
+            EvaluateSweepUseCase, EvaluateType2SystemUseCase,
+        },
+    },
+    domain::services::parallel_evaluation::JobControl,
     infrastructure::{
         state::AppState,
-        tauri::dtos::fuzzy_inference_dtos::{
-            EvaluateFuzzySystemRequest, EvaluateFuzzySystemResponse,
+        tauri::dtos::{
+            error_dtos::ErrorResponse,
+            fuzzy_inference_dtos::{
+                EvaluateBatchRequest, EvaluateBatchResponse, EvaluateChainResponse,
+                EvaluateFuzzySystemRequest, EvaluateFuzzySystemResponse, EvaluateSweepRequest,
+                EvaluateSweepResponse, EvaluateType2SystemRequest, EvaluateType2SystemResponse,
+                EvaluationProgressEvent,
+            },
         },
     },
 };
@@ -24,21 +42,182 @@ use crate::{
 ///
 /// # Returns
 /// EvaluateFuzzySystemResponse containing crisp outputs and debug information
-#[tauri::command]
+///
+/// Every successful evaluation is appended to the evaluation history.
+#[tauri::command(async)]
 pub fn evaluate_fuzzy_system(
     request: EvaluateFuzzySystemRequest,
     state: State<'_, AppState>,
-) -> Result<EvaluateFuzzySystemResponse, String> {
+) -> Result<EvaluateFuzzySystemResponse, ErrorResponse> {
     let use_case = EvaluateFuzzySystemUseCase::new(state.problem_repository.as_ref());
+    let inputs = request.to_inputs();
+    let method = request.get_method();
+    let resolution = request.get_resolution();
+
+    let result = use_case.execute(request.problem_id, inputs.clone(), method, resolution)?;
+
+    RecordEvaluationUseCase::new(state.evaluation_history_repository.as_ref())
+        .execute(&inputs, method, resolution, &result)?;
+
+    Ok(EvaluateFuzzySystemResponse::from(result))
+}
+
+/// Evaluates a problem together with every problem feeding it through parameter
+/// links, in topological order, and returns the intermediate results as well.
+///
+/// `request.inputs` covers the unlinked inputs of all these problems.
+/// Every evaluated problem is appended to the evaluation history.
+#[tauri::command(async)]
+pub fn evaluate_chain(
+    request: EvaluateFuzzySystemRequest,
+    state: State<'_, AppState>,
+) -> Result<EvaluateChainResponse, ErrorResponse> {
+    let use_case = EvaluateChainUseCase::new(
+        state.problem_repository.as_ref(),
+        state.parameter_link_repository.as_ref(),
+    );
+    let method = request.get_method();
+    let resolution = request.get_resolution();
+
+    let result = use_case.execute(request.problem_id, request.to_inputs(), method, resolution)?;
+
+    let record = RecordEvaluationUseCase::new(state.evaluation_history_repository.as_ref());
+    for step in &result.steps {
+        record.execute(&step.inputs, method, resolution, &step.result)?;
+    }
+
+    Ok(EvaluateChainResponse::from(result))
+}
+
+/// Evaluates a problem as an interval type-2 fuzzy system and returns the
+/// Karnik–Mendel type-reduced interval of each output along with its midpoint.
+///
+/// Type-2 results are not recorded in the evaluation history, which only
+/// replays type-1 evaluations.
+#[tauri::command]
+pub fn evaluate_fuzzy_system_type2(
+    request: EvaluateType2SystemRequest,
+    state: State<'_, AppState>,
+) -> Result<EvaluateType2SystemResponse, ErrorResponse> {
+    let use_case = EvaluateType2SystemUseCase::new(state.problem_repository.as_ref());
+
+    let result = use_case.execute(
+        request.problem_id,
+        request.to_inputs(),
+        request.get_resolution(),
+    )?;
 
-    let result = use_case
-        .execute(
+    Ok(EvaluateType2SystemResponse::from(result))
+}
+
+/// Evaluates every row of a CSV table of inputs in parallel and returns the table
+/// with the crisp outputs appended.
+///
+/// Runs off the main thread and emits "evaluation-progress" events tagged with
+/// `request.job_id`, which `cancel_evaluation` accepts. Batch results are not
+/// recorded in the evaluation history.
+#[tauri::command(async)]
+pub fn evaluate_batch(
+    request: EvaluateBatchRequest,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<EvaluateBatchResponse, ErrorResponse> {
+    let use_case = EvaluateBatchUseCase::new(state.problem_repository.as_ref());
+    let (columns, rows) = request.parse_csv();
+
+    let result = run_job(&app, &state, &request.job_id, |control, on_progress| {
+        use_case.execute(
             request.problem_id,
-            request.to_inputs(),
+            columns,
+            rows,
             request.get_method(),
             request.get_resolution(),
+            control,
+            on_progress,
         )
-        .map_err(|e| e.to_string())?;
+    })?;
 
-    Ok(EvaluateFuzzySystemResponse::from(result))
+    Ok(EvaluateBatchResponse::new(result, request.delimiter()))
+}
+
+/// Computes a control curve over one input or a control surface over two, with
+/// the other inputs held at `request.fixed_inputs`, in parallel.
+///
+/// Runs off the main thread and emits "evaluation-progress" events tagged with
+/// `request.job_id`, which `cancel_evaluation` accepts.
+#[tauri::command(async)]
+pub fn evaluate_sweep(
+    request: EvaluateSweepRequest,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<EvaluateSweepResponse, ErrorResponse> {
+    let use_case = EvaluateSweepUseCase::new(state.problem_repository.as_ref());
+
+    let result = run_job(&app, &state, &request.job_id, |control, on_progress| {
+        use_case.execute(
+            request.problem_id,
+            request.x.into(),
+            request.y.map(|y| y.into()),
+            request.to_fixed_inputs(),
+            request.get_method(),
+            request.get_resolution(),
+            control,
+            on_progress,
+        )
+    })?;
+
+    Ok(EvaluateSweepResponse::from(result))
+}
+
+/// Asks a running batch or sweep to stop; it then fails with a "Cancelled" error.
+/// Unknown or finished jobs are ignored.
+#[tauri::command]
+pub fn cancel_evaluation(job_id: String, state: State<'_, AppState>) -> Result<(), ErrorResponse> {
+    let jobs = state
+        .evaluation_jobs
+        .lock()
+        .map_err(|e| ErrorResponse::internal(e.to_string()))?;
+    if let Some(control) = jobs.get(&job_id) {
+        control.cancel();
+    }
+    Ok(())
+}
+
+/// Runs `job` registered under `job_id`, emitting a progress event whenever
+/// another percent of it is done
+fn run_job<R>(
+    app: &AppHandle,
+    state: &AppState,
+    job_id: &str,
+    job: impl FnOnce(&JobControl, &(dyn Fn(usize, usize) + Sync)) -> R,
+) -> R {
+    let control = Arc::new(JobControl::new());
+    state
+        .evaluation_jobs
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(job_id.to_string(), Arc::clone(&control));
+
+    let reported_percent = AtomicUsize::new(0);
+    let on_progress = |completed: usize, total: usize| {
+        let percent = completed * 100 / total.max(1);
+        if reported_percent.fetch_max(percent, Ordering::Relaxed) < percent {
+            let _ = app.emit(
+                "evaluation-progress",
+                EvaluationProgressEvent {
+                    job_id: job_id.to_string(),
+                    completed,
+                    total,
+                },
+            );
+        }
+    };
+    let result = job(&control, &on_progress);
+
+    state
+        .evaluation_jobs
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(job_id);
+    result
 }
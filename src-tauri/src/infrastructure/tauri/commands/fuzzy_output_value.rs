@@ -1,52 +1,108 @@
 use tauri::State;
 
 use crate::{
-    application::use_cases::fuzzy_output_value::{
-        create::CreateFuzzyOutputValueUseCase,
-        remove_by_id::RemoveFuzzyOutputValueByIdUseCase,
-        update_by_id::UpdateFuzzyOutputValueByIdUseCase,
+    application::use_cases::{
+        fuzzy_output_value::{
+            create::CreateFuzzyOutputValueUseCase, remove_by_id::RemoveFuzzyOutputValueByIdUseCase,
+            update_by_id::UpdateFuzzyOutputValueByIdUseCase,
+            update_lower::UpdateFuzzyOutputValueLowerUseCase,
+        },
+        problem_snapshot::create::CreateProblemSnapshotUseCase,
     },
     infrastructure::{
         state::AppState,
+        tauri::commands::{
+            journal::{
+                journaled, problem_id_of_fuzzy_output_value, problem_id_of_output_parameter,
+            },
+            problem_snapshot::export_use_case,
+        },
+        tauri::dtos::error_dtos::ErrorResponse,
         tauri::dtos::fuzzy_output_value_dtos::{
-            CreateFuzzyOutputValueRequest, UpdateFuzzyOutputValueRequest,
+            CreateFuzzyOutputValueRequest, UpdateFuzzyOutputValueLowerRequest,
+            UpdateFuzzyOutputValueRequest,
         },
     },
 };
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn create_fuzzy_output_value(
     create_request: CreateFuzzyOutputValueRequest,
     state: State<'_, AppState>,
-) -> Result<i64, String> {
+) -> Result<i64, ErrorResponse> {
+    let problem_id = problem_id_of_output_parameter(&state, create_request.output_parameter_id)?;
     let use_case = CreateFuzzyOutputValueUseCase::new(state.fuzzy_output_value_repository.as_ref());
 
-    let id = use_case
-        .execute(&create_request.to_entity())
-        .map_err(|e| e.to_string())?;
-    Ok(id)
+    journaled(&state, problem_id, "Create output term", || {
+        use_case.execute(&create_request.to_entity(), create_request.shape)
+    })
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn update_fuzzy_output_value_by_id(
     id: i64,
     update_request: UpdateFuzzyOutputValueRequest,
     state: State<'_, AppState>,
-) -> Result<(), String> {
-    let use_case = UpdateFuzzyOutputValueByIdUseCase::new(state.fuzzy_output_value_repository.as_ref());
-    use_case
-        .execute(id, &update_request.to_entity())
-        .map_err(|e| e.to_string())
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_fuzzy_output_value(&state, id)?;
+    let use_case =
+        UpdateFuzzyOutputValueByIdUseCase::new(state.fuzzy_output_value_repository.as_ref());
+    journaled(&state, problem_id, "Update output term", || {
+        use_case.execute(id, &update_request.to_entity())
+    })
 }
 
-#[tauri::command]
-pub fn remove_fuzzy_output_value_by_id(id: i64, state: State<'_, AppState>) -> Result<(), String> {
-    let use_case = RemoveFuzzyOutputValueByIdUseCase::new(state.fuzzy_output_value_repository.as_ref());
-    use_case.execute(id).map_err(|e| e.to_string())
+#[tauri::command(async)]
+pub fn remove_fuzzy_output_value_by_id(
+    id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let term = state.fuzzy_output_value_repository.get_by_id(id)?;
+    let parameter = state
+        .output_parameter_repository
+        .get_by_id(term.output_parameter_id)?;
+    let export = export_use_case(&state);
+    let create_snapshot =
+        CreateProblemSnapshotUseCase::new(&export, state.problem_snapshot_repository.as_ref());
+    let use_case = RemoveFuzzyOutputValueByIdUseCase::new(
+        state.fuzzy_output_value_repository.as_ref(),
+        state.output_parameter_repository.as_ref(),
+        state.test_case_repository.as_ref(),
+        &create_snapshot,
+        state.unit_of_work.as_ref(),
+    );
+    journaled(
+        &state,
+        parameter.problem_id,
+        &format!("Delete term '{}' of '{}'", term.value, parameter.name),
+        || use_case.execute(id),
+    )
 }
 
-#[tauri::command]
-pub fn switch_fuzzy_output_values(id1: i64, id2: i64, state: State<'_, AppState>) -> Result<(), String> {
+#[tauri::command(async)]
+pub fn update_fuzzy_output_value_lower(
+    id: i64,
+    request: UpdateFuzzyOutputValueLowerRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_fuzzy_output_value(&state, id)?;
+    let use_case =
+        UpdateFuzzyOutputValueLowerUseCase::new(state.fuzzy_output_value_repository.as_ref());
+
+    journaled(&state, problem_id, "Change output term footprint", || {
+        use_case.execute(id, request.lower.as_ref())
+    })
+}
+
+#[tauri::command(async)]
+pub fn move_fuzzy_output_value(
+    id: i64,
+    position: usize,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_fuzzy_output_value(&state, id)?;
     let repository = state.fuzzy_output_value_repository.as_ref();
-    repository.switch(id1, id2).map_err(|e| e.to_string())
+    journaled(&state, problem_id, "Reorder output terms", || {
+        repository.move_by_id(id, position)
+    })
 }
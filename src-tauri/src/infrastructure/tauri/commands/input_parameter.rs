@@ -1,54 +1,171 @@
 use tauri::State;
 
 use crate::{
-    application::use_cases::input_parameter::{
-        create::CreateInputParameterUseCase, remove_by_id::RemoveInputParameterByIdUseCase, update_by_id::UpdateInputParameterByIdUseCase
+    application::use_cases::{
+        input_parameter::{
+            change_range::ChangeInputParameterRangeUseCase, create::CreateInputParameterUseCase,
+            remove_by_id::RemoveInputParameterByIdUseCase,
+            update_by_id::UpdateInputParameterByIdUseCase,
+            update_kind::UpdateInputParameterKindUseCase,
+            update_metadata::UpdateInputParameterMetadataUseCase,
+            update_partition_mode::UpdateInputParameterPartitionModeUseCase,
+        },
+        problem_snapshot::create::CreateProblemSnapshotUseCase,
     },
     infrastructure::{
         state::AppState,
+        tauri::commands::{
+            journal::{journaled, problem_id_of_input_parameter},
+            problem_snapshot::export_use_case,
+        },
+        tauri::dtos::error_dtos::ErrorResponse,
         tauri::dtos::input_parameter_dtos::{
-            CreateInputParameterRequest, UpdateInputParameterRequest,
+            ChangeInputParameterRangeRequest, CreateInputParameterRequest,
+            UpdateInputParameterKindRequest, UpdateInputParameterMetadataRequest,
+            UpdateInputParameterPartitionModeRequest, UpdateInputParameterRequest,
         },
     },
 };
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn create_input_parameter(
     create_request: CreateInputParameterRequest,
     state: State<'_, AppState>,
-) -> Result<i64, String> {
+) -> Result<i64, ErrorResponse> {
     let use_case = CreateInputParameterUseCase::new(state.input_parameter_repository.as_ref());
 
-    let id = use_case
-        .execute(&create_request.to_entity())
-        .map_err(|e| e.to_string())?;
-    Ok(id)
+    journaled(
+        &state,
+        create_request.problem_id,
+        "Create input parameter",
+        || use_case.execute(&create_request.to_entity()),
+    )
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn update_input_parameter_by_id(
     id: i64,
     update_request: UpdateInputParameterRequest,
     state: State<'_, AppState>,
-) -> Result<(), String> {
-    let use_case = UpdateInputParameterByIdUseCase::new(state.input_parameter_repository.as_ref());
-    use_case
-        .execute(id, &update_request.to_entity())
-        .map_err(|e| e.to_string())
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_input_parameter(&state, id)?;
+    let change_range = ChangeInputParameterRangeUseCase::new(
+        state.input_parameter_repository.as_ref(),
+        state.input_value_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    );
+    let use_case = UpdateInputParameterByIdUseCase::new(
+        state.input_parameter_repository.as_ref(),
+        &change_range,
+        state.unit_of_work.as_ref(),
+    );
+    journaled(&state, problem_id, "Update input parameter", || {
+        use_case.execute(id, &update_request.to_entity())
+    })
+}
+
+#[tauri::command(async)]
+pub fn change_input_parameter_range(
+    id: i64,
+    change_request: ChangeInputParameterRangeRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_input_parameter(&state, id)?;
+    let policy = change_request.to_policy(id)?;
+    let use_case = ChangeInputParameterRangeUseCase::new(
+        state.input_parameter_repository.as_ref(),
+        state.input_value_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    );
+    journaled(&state, problem_id, "Change input parameter range", || {
+        use_case.execute(id, change_request.start, change_request.end, policy)
+    })
+}
+
+#[tauri::command(async)]
+pub fn update_input_parameter_partition_mode(
+    id: i64,
+    update_request: UpdateInputParameterPartitionModeRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_input_parameter(&state, id)?;
+    let use_case = UpdateInputParameterPartitionModeUseCase::new(
+        state.input_parameter_repository.as_ref(),
+        state.input_value_repository.as_ref(),
+    );
+    journaled(
+        &state,
+        problem_id,
+        "Change input parameter partition mode",
+        || use_case.execute(id, update_request.partition_mode),
+    )
+}
+
+#[tauri::command(async)]
+pub fn update_input_parameter_kind(
+    id: i64,
+    update_request: UpdateInputParameterKindRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_input_parameter(&state, id)?;
+    let use_case = UpdateInputParameterKindUseCase::new(
+        state.input_parameter_repository.as_ref(),
+        state.parameter_link_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    );
+    journaled(&state, problem_id, "Change input parameter kind", || {
+        use_case.execute(id, update_request.kind)
+    })
+}
+
+#[tauri::command(async)]
+pub fn update_input_parameter_metadata(
+    id: i64,
+    update_request: UpdateInputParameterMetadataRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_input_parameter(&state, id)?;
+    let use_case = UpdateInputParameterMetadataUseCase::new(
+        state.input_parameter_repository.as_ref(),
+        state.input_value_repository.as_ref(),
+    );
+    journaled(&state, problem_id, "Change input parameter details", || {
+        use_case.execute(id, &update_request.to_metadata())
+    })
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn remove_input_parameter_by_id(
     id: i64,
-    state: State<'_, AppState>
-) -> Result<(), String> {
-    let use_case = RemoveInputParameterByIdUseCase::new(state.input_parameter_repository.as_ref());
-    use_case
-        .execute(id)
-        .map_err(|e| e.to_string())
-}
-
-#[tauri::command]
-pub fn switch_input_parameters(id1: i64, id2: i64, state: State<'_, AppState>) -> Result<(), String> {
-    state.input_parameter_repository.switch(id1, id2).map_err(|e| e.to_string())
-}
\ No newline at end of file
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let parameter = state.input_parameter_repository.get_by_id(id)?;
+    let export = export_use_case(&state);
+    let create_snapshot =
+        CreateProblemSnapshotUseCase::new(&export, state.problem_snapshot_repository.as_ref());
+    let use_case = RemoveInputParameterByIdUseCase::new(
+        state.input_parameter_repository.as_ref(),
+        state.test_case_repository.as_ref(),
+        &create_snapshot,
+        state.unit_of_work.as_ref(),
+    );
+    journaled(
+        &state,
+        parameter.problem_id,
+        &format!("Delete input parameter '{}'", parameter.name),
+        || use_case.execute(id),
+    )
+}
+
+#[tauri::command(async)]
+pub fn move_input_parameter(
+    id: i64,
+    position: usize,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_input_parameter(&state, id)?;
+    let repository = state.input_parameter_repository.as_ref();
+    journaled(&state, problem_id, "Reorder input parameters", || {
+        repository.move_by_id(id, position)
+    })
+}
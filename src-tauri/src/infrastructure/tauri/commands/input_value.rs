@@ -1,51 +1,100 @@
 use tauri::State;
 
 use crate::{
-    application::use_cases::input_value::{
-        create::CreateInputValueUseCase,
-        remove_by_id::RemoveInputValueByIdUseCase,
-        update_by_id::UpdateInputValueByIdUseCase,
+    application::use_cases::{
+        input_value::{
+            create::CreateInputValueUseCase, remove_by_id::RemoveInputValueByIdUseCase,
+            update_by_id::UpdateInputValueByIdUseCase, update_lower::UpdateInputValueLowerUseCase,
+        },
+        problem_snapshot::create::CreateProblemSnapshotUseCase,
     },
     infrastructure::{
         state::AppState,
-        tauri::dtos::input_value_dtos::{CreateInputValueRequest, UpdateInputValueRequest},
+        tauri::commands::{
+            journal::{journaled, problem_id_of_input_parameter, problem_id_of_input_value},
+            problem_snapshot::export_use_case,
+        },
+        tauri::dtos::error_dtos::ErrorResponse,
+        tauri::dtos::input_value_dtos::{
+            CreateInputValueRequest, UpdateInputValueLowerRequest, UpdateInputValueRequest,
+        },
     },
 };
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn create_input_value(
     create_request: CreateInputValueRequest,
     state: State<'_, AppState>,
-) -> Result<i64, String> {
+) -> Result<i64, ErrorResponse> {
+    let problem_id = problem_id_of_input_parameter(&state, create_request.input_parameter_id)?;
     let use_case = CreateInputValueUseCase::new(state.input_value_repository.as_ref());
 
-    use_case
-        .execute(&create_request.to_entity())
-        .map_err(|e| e.to_string())
+    journaled(&state, problem_id, "Create input term", || {
+        use_case.execute(&create_request.to_entity(), create_request.shape)
+    })
 }
 
-#[tauri::command]
-pub fn remove_input_value_by_id(id: i64, state: State<'_, AppState>) -> Result<(), String> {
-    let use_case = RemoveInputValueByIdUseCase::new(state.input_value_repository.as_ref());
+#[tauri::command(async)]
+pub fn remove_input_value_by_id(id: i64, state: State<'_, AppState>) -> Result<(), ErrorResponse> {
+    let term = state.input_value_repository.get_by_id(id)?;
+    let parameter = state
+        .input_parameter_repository
+        .get_by_id(term.input_parameter_id)?;
+    let export = export_use_case(&state);
+    let create_snapshot =
+        CreateProblemSnapshotUseCase::new(&export, state.problem_snapshot_repository.as_ref());
+    let use_case = RemoveInputValueByIdUseCase::new(
+        state.input_value_repository.as_ref(),
+        state.input_parameter_repository.as_ref(),
+        &create_snapshot,
+        state.unit_of_work.as_ref(),
+    );
 
-    use_case.execute(id).map_err(|e| e.to_string())
+    journaled(
+        &state,
+        parameter.problem_id,
+        &format!("Delete term '{}' of '{}'", term.value, parameter.name),
+        || use_case.execute(id),
+    )
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn update_input_value_by_id(
     id: i64,
     update_request: UpdateInputValueRequest,
     state: State<'_, AppState>,
-) -> Result<(), String> {
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_input_value(&state, id)?;
     let use_case = UpdateInputValueByIdUseCase::new(state.input_value_repository.as_ref());
 
-    use_case
-        .execute(id, &update_request.to_entity())
-        .map_err(|e| e.to_string())
+    journaled(&state, problem_id, "Update input term", || {
+        use_case.execute(id, &update_request.to_entity())
+    })
 }
 
-#[tauri::command]
-pub fn switch_input_values(id1: i64, id2: i64, state: State<'_, AppState>) -> Result<(), String> {
+#[tauri::command(async)]
+pub fn update_input_value_lower(
+    id: i64,
+    request: UpdateInputValueLowerRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_input_value(&state, id)?;
+    let use_case = UpdateInputValueLowerUseCase::new(state.input_value_repository.as_ref());
+
+    journaled(&state, problem_id, "Change input term footprint", || {
+        use_case.execute(id, request.lower.as_ref())
+    })
+}
+
+#[tauri::command(async)]
+pub fn move_input_value(
+    id: i64,
+    position: usize,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_input_value(&state, id)?;
     let repository = state.input_value_repository.as_ref();
-    repository.switch(id1, id2).map_err(|e| e.to_string())
+    journaled(&state, problem_id, "Reorder input terms", || {
+        repository.move_by_id(id, position)
+    })
 }
@@ -0,0 +1,117 @@
+use tauri::State;
+
+use crate::{
+    application::use_cases::journal::{
+        get_by_problem_id::GetJournalByProblemIdUseCase, record::RecordMutationUseCase,
+        redo::RedoUseCase, undo::UndoUseCase,
+    },
+    domain::error::DomainError,
+    infrastructure::{
+        state::AppState,
+        tauri::dtos::{error_dtos::ErrorResponse, journal_dtos::JournalEntryResponse},
+    },
+};
+
+/// Runs a model-editing operation of `problem_id` and records it in the undo journal.
+///
+/// Commands writing through it are `async`: the write waits while another thread
+/// has a unit of work open, which must not block the main thread.
+pub(crate) fn journaled<T>(
+    state: &AppState,
+    problem_id: i64,
+    description: &str,
+    mutation: impl FnOnce() -> Result<T, DomainError>,
+) -> Result<T, ErrorResponse> {
+    let use_case = RecordMutationUseCase::new(
+        state.problem_repository.as_ref(),
+        state.journal_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    );
+
+    use_case
+        .execute(problem_id, description, mutation)
+        .map_err(ErrorResponse::from)
+}
+
+pub(crate) fn problem_id_of_input_parameter(
+    state: &AppState,
+    id: i64,
+) -> Result<i64, ErrorResponse> {
+    state
+        .input_parameter_repository
+        .get_by_id(id)
+        .map(|ip| ip.problem_id)
+        .map_err(ErrorResponse::from)
+}
+
+pub(crate) fn problem_id_of_output_parameter(
+    state: &AppState,
+    id: i64,
+) -> Result<i64, ErrorResponse> {
+    state
+        .output_parameter_repository
+        .get_by_id(id)
+        .map(|op| op.problem_id)
+        .map_err(ErrorResponse::from)
+}
+
+pub(crate) fn problem_id_of_input_value(state: &AppState, id: i64) -> Result<i64, ErrorResponse> {
+    let input_value = state.input_value_repository.get_by_id(id)?;
+    problem_id_of_input_parameter(state, input_value.input_parameter_id)
+}
+
+pub(crate) fn problem_id_of_fuzzy_output_value(
+    state: &AppState,
+    id: i64,
+) -> Result<i64, ErrorResponse> {
+    let fuzzy_output_value = state.fuzzy_output_value_repository.get_by_id(id)?;
+    problem_id_of_output_parameter(state, fuzzy_output_value.output_parameter_id)
+}
+
+pub(crate) fn problem_id_of_output_value(state: &AppState, id: i64) -> Result<i64, ErrorResponse> {
+    let output_value = state.output_value_repository.get_by_id(id)?;
+    problem_id_of_output_parameter(state, output_value.output_parameter_id)
+}
+
+#[tauri::command]
+pub fn get_journal_by_problem_id(
+    problem_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<JournalEntryResponse>, ErrorResponse> {
+    let use_case = GetJournalByProblemIdUseCase::new(state.journal_repository.as_ref());
+
+    let entries = use_case.execute(problem_id)?;
+    Ok(entries.iter().map(JournalEntryResponse::from).collect())
+}
+
+/// Reverts the last change of a problem; returns `None` when there is nothing to undo
+#[tauri::command(async)]
+pub fn undo_by_problem_id(
+    problem_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Option<JournalEntryResponse>, ErrorResponse> {
+    let use_case = UndoUseCase::new(
+        state.problem_repository.as_ref(),
+        state.journal_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    );
+
+    let entry = use_case.execute(problem_id)?;
+    Ok(entry.as_ref().map(JournalEntryResponse::from))
+}
+
+/// Re-applies the last undone change of a problem; returns `None` when there is nothing to redo
+#[tauri::command(async)]
+pub fn redo_by_problem_id(
+    problem_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Option<JournalEntryResponse>, ErrorResponse> {
+    let use_case = RedoUseCase::new(
+        state.problem_repository.as_ref(),
+        state.journal_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    );
+
+    let entry = use_case.execute(problem_id)?;
+    Ok(entry.as_ref().map(JournalEntryResponse::from))
+}
@@ -1,52 +1,154 @@
 use tauri::State;
 
 use crate::{
-    application::use_cases::output_parameter::{
-        create::CreateOutputParameterUseCase,
-        remove_by_id::RemoveOutputParameterByIdUseCase,
-        update_by_id::UpdateOutputParameterByIdUseCase,
+    application::use_cases::{
+        output_parameter::{
+            change_range::ChangeOutputParameterRangeUseCase, create::CreateOutputParameterUseCase,
+            remove_by_id::RemoveOutputParameterByIdUseCase,
+            update_by_id::UpdateOutputParameterByIdUseCase,
+            update_metadata::UpdateOutputParameterMetadataUseCase,
+            update_partition_mode::UpdateOutputParameterPartitionModeUseCase,
+        },
+        problem_snapshot::create::CreateProblemSnapshotUseCase,
     },
     infrastructure::{
         state::AppState,
+        tauri::commands::{
+            journal::{journaled, problem_id_of_output_parameter},
+            problem_snapshot::export_use_case,
+        },
+        tauri::dtos::error_dtos::ErrorResponse,
         tauri::dtos::output_parameter_dtos::{
-            CreateOutputParameterRequest, UpdateOutputParameterRequest,
+            ChangeOutputParameterRangeRequest, CreateOutputParameterRequest,
+            UpdateOutputParameterMetadataRequest, UpdateOutputParameterPartitionModeRequest,
+            UpdateOutputParameterRequest,
         },
     },
 };
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn create_output_parameter(
     create_request: CreateOutputParameterRequest,
     state: State<'_, AppState>,
-) -> Result<i64, String> {
+) -> Result<i64, ErrorResponse> {
     let use_case = CreateOutputParameterUseCase::new(state.output_parameter_repository.as_ref());
 
-    let id = use_case
-        .execute(&create_request.to_entity())
-        .map_err(|e| e.to_string())?;
-    Ok(id)
+    journaled(
+        &state,
+        create_request.problem_id,
+        "Create output parameter",
+        || use_case.execute(&create_request.to_entity()),
+    )
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn update_output_parameter_by_id(
     id: i64,
     update_request: UpdateOutputParameterRequest,
     state: State<'_, AppState>,
-) -> Result<(), String> {
-    let use_case = UpdateOutputParameterByIdUseCase::new(state.output_parameter_repository.as_ref());
-    use_case
-        .execute(id, &update_request.to_entity())
-        .map_err(|e| e.to_string())
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_output_parameter(&state, id)?;
+    let change_range = ChangeOutputParameterRangeUseCase::new(
+        state.output_parameter_repository.as_ref(),
+        state.fuzzy_output_value_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    );
+    let use_case = UpdateOutputParameterByIdUseCase::new(
+        state.output_parameter_repository.as_ref(),
+        &change_range,
+        state.unit_of_work.as_ref(),
+    );
+    journaled(&state, problem_id, "Update output parameter", || {
+        use_case.execute(id, &update_request.to_entity())
+    })
+}
+
+#[tauri::command(async)]
+pub fn change_output_parameter_range(
+    id: i64,
+    change_request: ChangeOutputParameterRangeRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_output_parameter(&state, id)?;
+    let policy = change_request.to_policy(id)?;
+    let use_case = ChangeOutputParameterRangeUseCase::new(
+        state.output_parameter_repository.as_ref(),
+        state.fuzzy_output_value_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    );
+    journaled(&state, problem_id, "Change output parameter range", || {
+        use_case.execute(id, change_request.start, change_request.end, policy)
+    })
+}
+
+#[tauri::command(async)]
+pub fn update_output_parameter_partition_mode(
+    id: i64,
+    update_request: UpdateOutputParameterPartitionModeRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_output_parameter(&state, id)?;
+    let use_case = UpdateOutputParameterPartitionModeUseCase::new(
+        state.output_parameter_repository.as_ref(),
+        state.fuzzy_output_value_repository.as_ref(),
+    );
+    journaled(
+        &state,
+        problem_id,
+        "Change output parameter partition mode",
+        || use_case.execute(id, update_request.partition_mode),
+    )
 }
 
-#[tauri::command]
-pub fn remove_output_parameter_by_id(id: i64, state: State<'_, AppState>) -> Result<(), String> {
-    let use_case = RemoveOutputParameterByIdUseCase::new(state.output_parameter_repository.as_ref());
-    use_case.execute(id).map_err(|e| e.to_string())
+#[tauri::command(async)]
+pub fn update_output_parameter_metadata(
+    id: i64,
+    update_request: UpdateOutputParameterMetadataRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_output_parameter(&state, id)?;
+    let use_case =
+        UpdateOutputParameterMetadataUseCase::new(state.output_parameter_repository.as_ref());
+    journaled(
+        &state,
+        problem_id,
+        "Change output parameter details",
+        || use_case.execute(id, &update_request.to_metadata()),
+    )
 }
 
-#[tauri::command]
-pub fn switch_output_parameters(id1: i64, id2: i64, state: State<'_, AppState>) -> Result<(), String> {
+#[tauri::command(async)]
+pub fn remove_output_parameter_by_id(
+    id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let parameter = state.output_parameter_repository.get_by_id(id)?;
+    let export = export_use_case(&state);
+    let create_snapshot =
+        CreateProblemSnapshotUseCase::new(&export, state.problem_snapshot_repository.as_ref());
+    let use_case = RemoveOutputParameterByIdUseCase::new(
+        state.output_parameter_repository.as_ref(),
+        state.test_case_repository.as_ref(),
+        &create_snapshot,
+        state.unit_of_work.as_ref(),
+    );
+    journaled(
+        &state,
+        parameter.problem_id,
+        &format!("Delete output parameter '{}'", parameter.name),
+        || use_case.execute(id),
+    )
+}
+
+#[tauri::command(async)]
+pub fn move_output_parameter(
+    id: i64,
+    position: usize,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_output_parameter(&state, id)?;
     let repository = state.output_parameter_repository.as_ref();
-    repository.switch(id1, id2).map_err(|e| e.to_string())
+    journaled(&state, problem_id, "Reorder output parameters", || {
+        repository.move_by_id(id, position)
+    })
 }
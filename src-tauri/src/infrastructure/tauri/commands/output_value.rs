@@ -2,12 +2,24 @@ use tauri::State;
 
 use crate::{
     application::use_cases::output_value::{
-        create::CreateOutputValueUseCase, 
-        get_by_problem_id::GetOutputValuesByProblemIdUseCase,
-        update_by_id::UpdateOutputValueByIdUseCase
+        create::CreateOutputValueUseCase, get_by_problem_id::GetOutputValuesByProblemIdUseCase,
+        update_by_id::UpdateOutputValueByIdUseCase, update_hedges::UpdateOutputValueHedgesUseCase,
     },
     domain::entities::output_value::OutputValue,
-    infrastructure::{state::AppState, tauri::dtos::output_value_dtos::{OutputValueResponse, UpdateOutputValueRequest}},
+    infrastructure::{
+        state::AppState,
+        tauri::{
+            commands::journal::{
+                journaled, problem_id_of_output_parameter, problem_id_of_output_value,
+            },
+            dtos::{
+                error_dtos::ErrorResponse,
+                output_value_dtos::{
+                    OutputValueResponse, UpdateOutputValueHedgesRequest, UpdateOutputValueRequest,
+                },
+            },
+        },
+    },
 };
 
 #[tauri::command]
@@ -15,30 +27,27 @@ pub async fn create_output_value(
     output_parameter_id: i64,
     input_value_ids: String,
     state: State<'_, AppState>,
-) -> Result<OutputValueResponse, String> {
-    let use_case = CreateOutputValueUseCase::new(
-        state.output_value_repository.as_ref(),
-    );
+) -> Result<OutputValueResponse, ErrorResponse> {
+    let use_case = CreateOutputValueUseCase::new(state.output_value_repository.as_ref());
 
     let new_output_value = OutputValue {
         id: 0,
         output_parameter_id,
         fuzzy_output_value_id: None,
         input_value_ids,
+        antecedent_hedges: String::new(),
+        consequent_hedge: None,
     };
 
-    let id = use_case
-        .execute(&new_output_value)
-        .map_err(|e| e.to_string())?;
+    let problem_id = problem_id_of_output_parameter(&state, output_parameter_id)?;
+    let id = journaled(&state, problem_id, "Create rule", || {
+        use_case.execute(&new_output_value)
+    })?;
 
-    let response = OutputValueResponse {
+    Ok(OutputValueResponse::from(&OutputValue {
         id,
-        output_parameter_id,
-        fuzzy_output_value_id: None,
-        input_value_ids: new_output_value.input_value_ids,
-    };
-
-    Ok(response)
+        ..new_output_value
+    }))
 }
 
 #[tauri::command]
@@ -46,35 +55,44 @@ pub async fn update_output_value_by_id(
     id: i64,
     request: UpdateOutputValueRequest,
     state: State<'_, AppState>,
-) -> Result<(), String> {
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_output_value(&state, id)?;
     let use_case = UpdateOutputValueByIdUseCase::new(state.output_value_repository.as_ref());
-    use_case
-        .execute(id, request.fuzzy_output_value_id)
-        .map_err(|e| e.to_string())?;
-    Ok(())
+    journaled(&state, problem_id, "Change rule consequent", || {
+        use_case.execute(id, request.fuzzy_output_value_id)
+    })
+}
+
+#[tauri::command]
+pub async fn update_output_value_hedges(
+    id: i64,
+    request: UpdateOutputValueHedgesRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem_id = problem_id_of_output_value(&state, id)?;
+    let use_case = UpdateOutputValueHedgesUseCase::new(state.output_value_repository.as_ref());
+    let antecedent_hedges: Vec<(i64, String)> = request
+        .antecedent_hedges
+        .into_iter()
+        .map(|h| (h.input_value_id, h.hedge))
+        .collect();
+    journaled(&state, problem_id, "Change rule hedges", || {
+        use_case.execute(id, &antecedent_hedges, request.consequent_hedge.as_deref())
+    })
 }
 
 #[tauri::command]
 pub async fn get_output_values_by_problem_id(
     problem_id: i64,
     state: State<'_, AppState>,
-) -> Result<Vec<OutputValueResponse>, String> {
-    let use_case = GetOutputValuesByProblemIdUseCase::new(
-        state.output_value_repository.as_ref(),
-    );
+) -> Result<Vec<OutputValueResponse>, ErrorResponse> {
+    let use_case = GetOutputValuesByProblemIdUseCase::new(state.output_value_repository.as_ref());
 
-    let output_values = use_case
-        .execute(problem_id)
-        .map_err(|e| e.to_string())?;
+    let output_values = use_case.execute(problem_id)?;
 
     let response: Vec<OutputValueResponse> = output_values
         .into_iter()
-        .map(|ov| OutputValueResponse {
-            id: ov.id,
-            output_parameter_id: ov.output_parameter_id,
-            fuzzy_output_value_id: ov.fuzzy_output_value_id,
-            input_value_ids: ov.input_value_ids,
-        })
+        .map(|ov| OutputValueResponse::from(&ov))
         .collect();
 
     Ok(response)
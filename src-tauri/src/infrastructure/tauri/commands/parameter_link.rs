@@ -0,0 +1,55 @@
+use tauri::State;
+
+use crate::{
+    application::use_cases::parameter_link::{
+        create::CreateParameterLinkUseCase, get_by_problem_id::GetParameterLinksByProblemIdUseCase,
+        remove_by_id::RemoveParameterLinkByIdUseCase,
+    },
+    infrastructure::{
+        state::AppState,
+        tauri::dtos::error_dtos::ErrorResponse,
+        tauri::dtos::parameter_link_dtos::{CreateParameterLinkRequest, ParameterLinkResponse},
+    },
+};
+
+/// Returns the links feeding the problem and the links it feeds
+#[tauri::command]
+pub fn get_parameter_links_by_problem_id(
+    problem_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<ParameterLinkResponse>, ErrorResponse> {
+    let use_case =
+        GetParameterLinksByProblemIdUseCase::new(state.parameter_link_repository.as_ref());
+
+    let links = use_case.execute(problem_id)?;
+    Ok(links.iter().map(ParameterLinkResponse::from).collect())
+}
+
+#[tauri::command(async)]
+pub fn create_parameter_link(
+    create_request: CreateParameterLinkRequest,
+    state: State<'_, AppState>,
+) -> Result<i64, ErrorResponse> {
+    let use_case = CreateParameterLinkUseCase::new(
+        state.parameter_link_repository.as_ref(),
+        state.input_parameter_repository.as_ref(),
+        state.output_parameter_repository.as_ref(),
+    );
+
+    use_case
+        .execute(
+            create_request.output_parameter_id,
+            create_request.input_parameter_id,
+        )
+        .map_err(ErrorResponse::from)
+}
+
+#[tauri::command(async)]
+pub fn remove_parameter_link_by_id(
+    id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let use_case = RemoveParameterLinkByIdUseCase::new(state.parameter_link_repository.as_ref());
+
+    use_case.execute(id).map_err(ErrorResponse::from)
+}
@@ -1,65 +1,173 @@
 use tauri::State;
 
 use crate::{
-    application::use_cases::{
-        problem::{
-            create::CreateProblemUseCase, export_problem::ExportProblemUseCase,
-            get_all_by_prev_problem_id::GetAllByPrevProblemIdUseCase,
-            get_full_by_id::GetFullByIdUseCase, import_problem::ImportProblemUseCase,
-            remove_by_id::RemoveByIdUseCase, update_by_id::UpdateByIdUseCase,
+    application::{
+        exported_problem::ExportedProblem,
+        use_cases::{
+            problem::{
+                clone::CloneProblemUseCase, create::CreateProblemUseCase,
+                export_problem::ExportProblemUseCase,
+                get_all_by_prev_problem_id::GetAllByPrevProblemIdUseCase,
+                get_full_by_id::GetFullByIdUseCase, import_problem::ImportProblemUseCase,
+                merge_import::MergeImportUseCase, move_problem::MoveProblemUseCase,
+                remove_by_id::RemoveByIdUseCase, update_by_id::UpdateByIdUseCase,
+                validate_import::ValidateImportUseCase,
+            },
+            problem_snapshot::create::CreateProblemSnapshotUseCase,
         },
     },
     domain::entities::image::Image,
     infrastructure::{
+        fuzzy_file::{self, FuzzyFileCompression},
         state::AppState,
+        tauri::commands::{journal::journaled, problem_snapshot::export_use_case},
         tauri::dtos::{
-            export_import_dtos::ExportedProblem,
+            error_dtos::ErrorResponse,
+            export_import_dtos::{ImportValidationResponse, MergePreviewResponse},
             problem_dtos::{
-                CreateProblemRequest, ImageUpdateAction, ProblemCreateResponse, ProblemFullResponse,
-                ProblemResponse, UpdateProblemRequest,
+                CloneProblemRequest, CreateProblemRequest, ImageUpdateAction, MoveProblemRequest,
+                ProblemCreateResponse, ProblemFullResponse, ProblemResponse, UpdateProblemRequest,
             },
         },
     },
 };
 
-#[tauri::command]
-pub fn export_problem(id: i64, state: State<'_, AppState>) -> Result<ExportedProblem, String> {
+pub(crate) fn import_use_case(state: &AppState) -> ImportProblemUseCase<'_> {
+    ImportProblemUseCase::new(
+        state.problem_repository.as_ref(),
+        state.input_parameter_repository.as_ref(),
+        state.input_value_repository.as_ref(),
+        state.output_parameter_repository.as_ref(),
+        state.fuzzy_output_value_repository.as_ref(),
+        state.output_value_repository.as_ref(),
+        state.test_case_repository.as_ref(),
+        state.unit_of_work.as_ref(),
+    )
+}
+
+#[tauri::command(async)]
+pub fn export_problem(
+    id: i64,
+    state: State<'_, AppState>,
+) -> Result<ExportedProblem, ErrorResponse> {
     let use_case = ExportProblemUseCase::new(
         state.problem_repository.as_ref(),
         state.image_repository.as_ref(),
+        state.test_case_repository.as_ref(),
     );
 
-    use_case.execute(id).map_err(|e: crate::domain::error::DomainError| e.to_string())
+    use_case.execute(id).map_err(ErrorResponse::from)
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn import_problem(
     parent_id: Option<i64>,
     data: ExportedProblem,
     state: State<'_, AppState>,
-) -> Result<(), String> {
-    let use_case = ImportProblemUseCase::new(
-        state.problem_repository.as_ref(),
-        state.input_parameter_repository.as_ref(),
-        state.input_value_repository.as_ref(),
-        state.output_parameter_repository.as_ref(),
-        state.fuzzy_output_value_repository.as_ref(),
-        state.output_value_repository.as_ref(),
-    );
+) -> Result<(), ErrorResponse> {
+    let use_case = import_use_case(&state);
+    use_case.execute(parent_id, data)?;
+
+    Ok(())
+}
+
+/// Writes a problem with its children to a `.fuzzy` file
+#[tauri::command(async)]
+pub fn export_problem_to_file(
+    id: i64,
+    path: String,
+    compression: Option<FuzzyFileCompression>,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem = export_problem(id, state)?;
+    let bytes = fuzzy_file::encode(problem, compression.unwrap_or_default())?;
+
+    std::fs::write(&path, bytes)
+        .map_err(|e| ErrorResponse::io(format!("Failed to write {}: {}", path, e)))
+}
+
+/// Reads a `.fuzzy` file of any format version without importing it
+#[tauri::command(async)]
+pub fn read_problem_file(path: String) -> Result<ExportedProblem, ErrorResponse> {
+    let bytes = std::fs::read(&path)
+        .map_err(|e| ErrorResponse::io(format!("Failed to read {}: {}", path, e)))?;
+    let file = fuzzy_file::decode(&bytes)?;
+
+    Ok(file.problem)
+}
+
+#[tauri::command(async)]
+pub fn import_problem_from_file(
+    parent_id: Option<i64>,
+    path: String,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let problem = read_problem_file(path)?;
+
+    import_problem(parent_id, problem, state)
+}
+
+fn merge_import_use_case<'a>(
+    state: &'a AppState,
+    export: &'a ExportProblemUseCase<'a>,
+    import: &'a ImportProblemUseCase<'a>,
+    create_snapshot: &'a CreateProblemSnapshotUseCase<'a>,
+) -> MergeImportUseCase<'a> {
+    MergeImportUseCase::new(export, import, create_snapshot, state.unit_of_work.as_ref())
+}
+
+/// Shows what merging `data` into an existing problem would change, without changing it
+#[tauri::command(async)]
+pub fn preview_merge_import(
+    problem_id: i64,
+    data: ExportedProblem,
+    state: State<'_, AppState>,
+) -> Result<MergePreviewResponse, ErrorResponse> {
+    let export = export_use_case(&state);
+    let import = import_use_case(&state);
+    let create_snapshot =
+        CreateProblemSnapshotUseCase::new(&export, state.problem_snapshot_repository.as_ref());
+    let use_case = merge_import_use_case(&state, &export, &import, &create_snapshot);
+
+    let preview = use_case.preview(problem_id, data)?;
+    Ok(preview.into())
+}
+
+/// Merges `data` into an existing problem, matching parameters and terms by name
+#[tauri::command(async)]
+pub fn merge_import(
+    problem_id: i64,
+    data: ExportedProblem,
+    state: State<'_, AppState>,
+) -> Result<MergePreviewResponse, ErrorResponse> {
+    let export = export_use_case(&state);
+    let import = import_use_case(&state);
+    let create_snapshot =
+        CreateProblemSnapshotUseCase::new(&export, state.problem_snapshot_repository.as_ref());
+    let use_case = merge_import_use_case(&state, &export, &import, &create_snapshot);
+
+    let preview = journaled(&state, problem_id, "Merge import", || {
+        use_case.execute(problem_id, data)
+    })?;
+    Ok(preview.into())
+}
+
+/// Checks an exported problem tree for errors and warnings without importing it
+#[tauri::command]
+pub fn validate_import(data: ExportedProblem) -> Result<ImportValidationResponse, ErrorResponse> {
+    let use_case = ValidateImportUseCase::new();
 
-    use_case.execute(parent_id, data).map_err(|e| e.to_string())
+    Ok(use_case.execute(&data).into())
 }
 
 #[tauri::command]
 pub fn get_all_problems_by_prev_problem_id(
     prev_problem_id: Option<i64>,
     state: State<'_, AppState>,
-) -> Result<Vec<ProblemResponse>, String> {
+) -> Result<Vec<ProblemResponse>, ErrorResponse> {
     let use_case = GetAllByPrevProblemIdUseCase::new(state.problem_repository.as_ref());
 
-    let problems = use_case
-        .execute(prev_problem_id)
-        .map_err(|e| e.to_string())?;
+    let problems = use_case.execute(prev_problem_id)?;
     Ok(problems.iter().map(|x| ProblemResponse::from(x)).collect())
 }
 
@@ -67,41 +175,80 @@ pub fn get_all_problems_by_prev_problem_id(
 pub fn get_full_problem_by_id(
     id: i64,
     state: State<'_, AppState>,
-) -> Result<ProblemFullResponse, String> {
+) -> Result<ProblemFullResponse, ErrorResponse> {
     let use_case = GetFullByIdUseCase::new(state.problem_repository.as_ref());
 
-    let problem = use_case.execute(id).map_err(|e| e.to_string())?;
+    let problem = use_case.execute(id)?;
     Ok(ProblemFullResponse::from(&problem))
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn create_problem(
     create_request: CreateProblemRequest,
     state: State<'_, AppState>,
-) -> Result<ProblemCreateResponse, String> {
+) -> Result<ProblemCreateResponse, ErrorResponse> {
     let use_case = CreateProblemUseCase::new(state.problem_repository.as_ref());
-    let (id, image_id) = use_case
-        .execute(&create_request.to_entity())
-        .map_err(|e| e.to_string())?;
+    let (id, image_id) =
+        use_case.execute(&create_request.to_entity(), create_request.to_image().as_ref())?;
     Ok(ProblemCreateResponse {
         id: id,
         image_id: image_id,
     })
 }
 
-#[tauri::command]
-pub fn remove_problem_by_id(id: i64, state: State<'_, AppState>) -> Result<(), String> {
+/// Deep-copies a problem and returns the id of the copy
+#[tauri::command(async)]
+pub fn clone_problem(
+    id: i64,
+    clone_request: CloneProblemRequest,
+    state: State<'_, AppState>,
+) -> Result<i64, ErrorResponse> {
+    let export = export_use_case(&state);
+    let import = import_use_case(&state);
+    let use_case = CloneProblemUseCase::new(
+        state.problem_repository.as_ref(),
+        &export,
+        &import,
+        state.unit_of_work.as_ref(),
+    );
+
+    use_case
+        .execute(
+            id,
+            clone_request.prev_problem_id,
+            clone_request.name.as_deref(),
+            clone_request.with_children,
+        )
+        .map_err(ErrorResponse::from)
+}
+
+/// Re-parents a problem with its subtree, or reorders it among its siblings
+#[tauri::command(async)]
+pub fn move_problem(
+    id: i64,
+    move_request: MoveProblemRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let use_case = MoveProblemUseCase::new(state.problem_repository.as_ref());
+
+    use_case
+        .execute(id, move_request.prev_problem_id, move_request.position)
+        .map_err(ErrorResponse::from)
+}
+
+#[tauri::command(async)]
+pub fn remove_problem_by_id(id: i64, state: State<'_, AppState>) -> Result<(), ErrorResponse> {
     let use_case = RemoveByIdUseCase::new(state.problem_repository.as_ref());
-    use_case.execute(id).map_err(|e| e.to_string())?;
+    use_case.execute(id)?;
     Ok(())
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn update_problem_by_id(
     id: i64,
     update_request: UpdateProblemRequest,
     state: State<'_, AppState>,
-) -> Result<Option<i64>, String> {
+) -> Result<Option<i64>, ErrorResponse> {
     let use_case = UpdateByIdUseCase::new(state.problem_repository.as_ref());
 
     let (delete_image, new_image) = match &update_request.image_update {
@@ -117,8 +264,7 @@ pub fn update_problem_by_id(
         ),
     };
 
-    let image_id = use_case
-        .execute(id, &update_request.to_entity(), delete_image, new_image)
-        .map_err(|e| e.to_string())?;
-    Ok(image_id)
+    journaled(&state, id, "Update problem", || {
+        use_case.execute(id, &update_request.to_entity(), delete_image, new_image)
+    })
 }
@@ -0,0 +1,106 @@
+use tauri::State;
+
+use crate::{
+    application::use_cases::{
+        problem::export_problem::ExportProblemUseCase,
+        problem_snapshot::{
+            create::CreateProblemSnapshotUseCase, diff::DiffProblemSnapshotsUseCase,
+            get_by_problem_id::GetProblemSnapshotsByProblemIdUseCase,
+            remove_by_id::RemoveProblemSnapshotByIdUseCase, restore::RestoreProblemSnapshotUseCase,
+        },
+    },
+    infrastructure::{
+        state::AppState,
+        tauri::commands::{journal::journaled, problem::import_use_case},
+        tauri::dtos::error_dtos::ErrorResponse,
+        tauri::dtos::problem_snapshot_dtos::{ProblemDiffResponse, ProblemSnapshotResponse},
+    },
+};
+
+pub(crate) fn export_use_case(state: &AppState) -> ExportProblemUseCase<'_> {
+    ExportProblemUseCase::new(
+        state.problem_repository.as_ref(),
+        state.image_repository.as_ref(),
+        state.test_case_repository.as_ref(),
+    )
+}
+
+#[tauri::command]
+pub fn get_problem_snapshots_by_problem_id(
+    problem_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<ProblemSnapshotResponse>, ErrorResponse> {
+    let use_case =
+        GetProblemSnapshotsByProblemIdUseCase::new(state.problem_snapshot_repository.as_ref());
+
+    let snapshots = use_case.execute(problem_id)?;
+    Ok(snapshots
+        .iter()
+        .map(ProblemSnapshotResponse::from)
+        .collect())
+}
+
+#[tauri::command(async)]
+pub fn create_problem_snapshot(
+    problem_id: i64,
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<i64, ErrorResponse> {
+    let export = export_use_case(&state);
+    let use_case =
+        CreateProblemSnapshotUseCase::new(&export, state.problem_snapshot_repository.as_ref());
+
+    use_case
+        .execute(problem_id, &name)
+        .map_err(ErrorResponse::from)
+}
+
+#[tauri::command(async)]
+pub fn remove_problem_snapshot_by_id(
+    id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let use_case =
+        RemoveProblemSnapshotByIdUseCase::new(state.problem_snapshot_repository.as_ref());
+
+    use_case.execute(id).map_err(ErrorResponse::from)
+}
+
+/// Restores a problem to the state saved in the snapshot
+#[tauri::command(async)]
+pub fn restore_problem_snapshot(id: i64, state: State<'_, AppState>) -> Result<(), ErrorResponse> {
+    let export = export_use_case(&state);
+    let create_snapshot =
+        CreateProblemSnapshotUseCase::new(&export, state.problem_snapshot_repository.as_ref());
+    let import = import_use_case(&state);
+    let use_case = RestoreProblemSnapshotUseCase::new(
+        state.problem_snapshot_repository.as_ref(),
+        &create_snapshot,
+        &import,
+        state.unit_of_work.as_ref(),
+    );
+
+    let snapshot = state.problem_snapshot_repository.get_by_id(id)?;
+    journaled(
+        &state,
+        snapshot.problem_id,
+        &format!("Restore snapshot '{}'", snapshot.name),
+        || use_case.execute(id),
+    )
+}
+
+/// Structural diff between two snapshots, or between a snapshot and the
+/// current definition when `new_id` is omitted
+#[tauri::command]
+pub fn diff_problem_snapshots(
+    old_id: i64,
+    new_id: Option<i64>,
+    state: State<'_, AppState>,
+) -> Result<ProblemDiffResponse, ErrorResponse> {
+    let export = export_use_case(&state);
+    let use_case =
+        DiffProblemSnapshotsUseCase::new(state.problem_snapshot_repository.as_ref(), &export);
+
+    let diff = use_case.execute(old_id, new_id)?;
+    Ok(ProblemDiffResponse::from(diff))
+}
@@ -0,0 +1,86 @@
+use tauri::State;
+
+use crate::{
+    application::use_cases::test_case::{
+        create::CreateTestCaseUseCase, get_by_problem_id::GetTestCasesByProblemIdUseCase,
+        remove_by_id::RemoveTestCaseByIdUseCase, run::RunTestCasesUseCase,
+        update_by_id::UpdateTestCaseByIdUseCase,
+    },
+    infrastructure::{
+        state::AppState,
+        tauri::dtos::error_dtos::ErrorResponse,
+        tauri::dtos::test_case_dtos::{
+            CreateTestCaseRequest, RunTestCasesRequest, TestCaseResponse, TestRunReportDto,
+            UpdateTestCaseRequest,
+        },
+    },
+};
+
+#[tauri::command]
+pub fn get_test_cases_by_problem_id(
+    problem_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<TestCaseResponse>, ErrorResponse> {
+    let use_case = GetTestCasesByProblemIdUseCase::new(state.test_case_repository.as_ref());
+
+    let test_cases = use_case.execute(problem_id)?;
+    Ok(test_cases.iter().map(TestCaseResponse::from).collect())
+}
+
+#[tauri::command(async)]
+pub fn create_test_case(
+    create_request: CreateTestCaseRequest,
+    state: State<'_, AppState>,
+) -> Result<i64, ErrorResponse> {
+    let use_case = CreateTestCaseUseCase::new(
+        state.problem_repository.as_ref(),
+        state.test_case_repository.as_ref(),
+    );
+
+    use_case
+        .execute(&create_request.to_entity())
+        .map_err(ErrorResponse::from)
+}
+
+#[tauri::command(async)]
+pub fn update_test_case_by_id(
+    id: i64,
+    update_request: UpdateTestCaseRequest,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let use_case = UpdateTestCaseByIdUseCase::new(
+        state.problem_repository.as_ref(),
+        state.test_case_repository.as_ref(),
+    );
+
+    use_case
+        .execute(id, &update_request.to_entity())
+        .map_err(ErrorResponse::from)
+}
+
+#[tauri::command(async)]
+pub fn remove_test_case_by_id(id: i64, state: State<'_, AppState>) -> Result<(), ErrorResponse> {
+    let use_case = RemoveTestCaseByIdUseCase::new(state.test_case_repository.as_ref());
+
+    use_case.execute(id).map_err(ErrorResponse::from)
+}
+
+/// Runs every stored test case of a problem and reports pass/fail with differences
+#[tauri::command(async)]
+pub fn run_test_cases(
+    request: RunTestCasesRequest,
+    state: State<'_, AppState>,
+) -> Result<TestRunReportDto, ErrorResponse> {
+    let use_case = RunTestCasesUseCase::new(
+        state.problem_repository.as_ref(),
+        state.test_case_repository.as_ref(),
+    );
+
+    let report = use_case.execute(
+        request.problem_id,
+        request.get_method(),
+        request.get_resolution(),
+    )?;
+
+    Ok(TestRunReportDto::from(report))
+}
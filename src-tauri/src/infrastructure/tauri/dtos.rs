@@ -1,9 +1,15 @@
+pub mod evaluation_history_dtos;
+pub mod error_dtos;
 pub mod export_import_dtos;
 pub mod fuzzy_inference_dtos;
 pub mod fuzzy_output_value_dtos;
 pub mod image_dtos;
 pub mod input_parameter_dtos;
 pub mod input_value_dtos;
+pub mod journal_dtos;
 pub mod output_parameter_dtos;
 pub mod output_value_dtos;
+pub mod parameter_link_dtos;
 pub mod problem_dtos;
+pub mod problem_snapshot_dtos;
+pub mod test_case_dtos;
@@ -0,0 +1,117 @@
+use serde::Serialize;
+
+use crate::domain::error::{DomainError, EntityKind, FieldError};
+
+/// Error returned by every command
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    /// Stable machine-readable code: "not_found", "validation", "data", "internal",
+    /// "cancelled" or "io"
+    pub code: &'static str,
+    /// User-facing message
+    pub message: String,
+    pub entity: Option<EntityKind>,
+    pub id: Option<i64>,
+    /// Rejected fields; empty unless `code` is "validation"
+    pub fields: Vec<FieldError>,
+}
+
+impl ErrorResponse {
+    fn new(code: &'static str, message: String) -> Self {
+        Self {
+            code,
+            message,
+            entity: None,
+            id: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// A file could not be read or written
+    pub fn io(message: String) -> Self {
+        Self::new("io", message)
+    }
+
+    /// A lock or another facility of the app itself failed
+    pub fn internal(message: String) -> Self {
+        Self::new("internal", message)
+    }
+}
+
+impl From<DomainError> for ErrorResponse {
+    /// The message leaves out the "Not found: " or "Validation error: " prefix of
+    /// the domain error; `code` already tells the kind of error
+    fn from(error: DomainError) -> Self {
+        match error {
+            DomainError::NotFound { entity, id } => Self {
+                entity: Some(entity),
+                id: Some(id),
+                ..Self::new(
+                    "not_found",
+                    format!("{} {} does not exist", entity.label(), id),
+                )
+            },
+            DomainError::Validation(msg) => Self::new("validation", msg),
+            DomainError::InvalidFields { entity, id, fields } => {
+                let message = fields
+                    .iter()
+                    .map(|e| format!("{}: {}", e.field, e.message))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                Self {
+                    entity: Some(entity),
+                    id,
+                    fields,
+                    ..Self::new("validation", message)
+                }
+            }
+            DomainError::Data(msg) => Self::new("data", msg),
+            DomainError::Internal(msg) => Self::new("internal", msg),
+            DomainError::Cancelled(msg) => Self::new("cancelled", msg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_leave_out_the_kind_of_error() {
+        let not_found = ErrorResponse::from(DomainError::not_found(EntityKind::TestCase, 7));
+        assert_eq!(not_found.code, "not_found");
+        assert_eq!(not_found.message, "Test case 7 does not exist");
+        assert_eq!(not_found.id, Some(7));
+
+        let validation =
+            ErrorResponse::from(DomainError::Validation("Name cannot be empty".to_string()));
+        assert_eq!(validation.code, "validation");
+        assert_eq!(validation.message, "Name cannot be empty");
+
+        let invalid_fields = ErrorResponse::from(DomainError::InvalidFields {
+            entity: EntityKind::TestCase,
+            id: None,
+            fields: vec![
+                FieldError::new("name", "Name cannot be empty"),
+                FieldError::new("tolerance", "Tolerance cannot be negative"),
+            ],
+        });
+        assert_eq!(invalid_fields.code, "validation");
+        assert_eq!(
+            invalid_fields.message,
+            "name: Name cannot be empty; tolerance: Tolerance cannot be negative"
+        );
+        assert_eq!(invalid_fields.fields.len(), 2);
+    }
+
+    #[test]
+    fn test_engine_errors_keep_their_entity() {
+        let engine_error = fuzzy_engine::error::DomainError::not_found(
+            fuzzy_engine::error::EntityKind::InputValue,
+            3,
+        );
+        let response = ErrorResponse::from(DomainError::from(engine_error));
+        assert_eq!(response.entity, Some(EntityKind::InputValue));
+        assert_eq!(response.message, "Input value 3 does not exist");
+    }
+}
@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application::use_cases::evaluation_history::replay::{ReplayOutputComparison, ReplayResult},
+    domain::{
+        entities::evaluation_record::{EvaluationHistoryFilter, EvaluationRecord},
+        services::fuzzification::{CategoryDegree, InputUncertainty},
+    },
+};
+
+/// Request DTO for listing evaluation history; all fields are optional
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EvaluationHistoryFilterRequest {
+    pub problem_id: Option<i64>,
+    pub method: Option<String>,
+    pub definition_hash: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+impl EvaluationHistoryFilterRequest {
+    pub fn to_filter(&self) -> EvaluationHistoryFilter {
+        EvaluationHistoryFilter {
+            problem_id: self.problem_id,
+            method: self.method.clone(),
+            definition_hash: self.definition_hash.clone(),
+            from: self.from.clone(),
+            to: self.to.clone(),
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluationRecordInputDto {
+    pub input_parameter_id: i64,
+    pub crisp_value: f32,
+    pub uncertainty: Option<InputUncertainty>,
+    pub input_value_id: Option<i64>,
+    pub category_degrees: Option<Vec<CategoryDegree>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluationRecordOutputDto {
+    pub output_parameter_id: i64,
+    pub output_parameter_name: String,
+    pub crisp_value: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluationRecordResponse {
+    pub id: i64,
+    pub problem_id: i64,
+    pub problem_name: String,
+    pub inputs: Vec<EvaluationRecordInputDto>,
+    pub method: String,
+    pub resolution: usize,
+    pub outputs: Vec<EvaluationRecordOutputDto>,
+    pub definition_hash: String,
+    pub created_at: String,
+}
+
+impl EvaluationRecordResponse {
+    pub fn from(entity: &EvaluationRecord) -> Self {
+        Self {
+            id: entity.id,
+            problem_id: entity.problem_id,
+            problem_name: entity.problem_name.to_owned(),
+            inputs: entity
+                .inputs
+                .iter()
+                .map(|i| EvaluationRecordInputDto {
+                    input_parameter_id: i.input_parameter_id,
+                    crisp_value: i.crisp_value,
+                    uncertainty: i.uncertainty,
+                    input_value_id: i.input_value_id,
+                    category_degrees: i.category_degrees.clone(),
+                })
+                .collect(),
+            method: entity.method.to_owned(),
+            resolution: entity.resolution,
+            outputs: entity
+                .outputs
+                .iter()
+                .map(|o| EvaluationRecordOutputDto {
+                    output_parameter_id: o.output_parameter_id,
+                    output_parameter_name: o.output_parameter_name.to_owned(),
+                    crisp_value: o.crisp_value,
+                })
+                .collect(),
+            definition_hash: entity.definition_hash.to_owned(),
+            created_at: entity.created_at.to_owned(),
+        }
+    }
+}
+
+/// Response DTO for replaying a history record against the current definition
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayEvaluationResponse {
+    pub record: EvaluationRecordResponse,
+    pub current_definition_hash: String,
+    pub definition_changed: bool,
+    pub outputs: Vec<ReplayOutputComparisonDto>,
+}
+
+impl From<ReplayResult> for ReplayEvaluationResponse {
+    fn from(result: ReplayResult) -> Self {
+        Self {
+            record: EvaluationRecordResponse::from(&result.record),
+            current_definition_hash: result.current_definition_hash,
+            definition_changed: result.definition_changed,
+            outputs: result.outputs.into_iter().map(|o| o.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayOutputComparisonDto {
+    pub output_parameter_id: i64,
+    pub output_parameter_name: String,
+    pub recorded_value: Option<f32>,
+    pub current_value: Option<f32>,
+    pub difference: Option<f32>,
+}
+
+impl From<ReplayOutputComparison> for ReplayOutputComparisonDto {
+    fn from(comparison: ReplayOutputComparison) -> Self {
+        Self {
+            output_parameter_id: comparison.output_parameter_id,
+            output_parameter_name: comparison.output_parameter_name,
+            recorded_value: comparison.recorded_value,
+            current_value: comparison.current_value,
+            difference: comparison.difference,
+        }
+    }
+}
@@ -1,67 +1,81 @@
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ExportedProblem {
-    pub name: String,
-    pub description: String,
-    pub is_final: bool,
-    pub image: Option<ExportedImage>,
-    pub input_parameters: Vec<ExportedInputParameter>,
-    pub output_parameters: Vec<ExportedOutputParameter>,
-    pub output_values: Vec<ExportedOutputValue>,
-    pub children: Vec<ExportedProblem>,
+use crate::{
+    application::use_cases::problem::{
+        merge_import::MergePreview,
+        validate_import::{ImportIssue, ImportIssueSeverity, ImportValidationReport},
+    },
+    domain::services::problem_merge::MergeConflict,
+    infrastructure::tauri::dtos::problem_snapshot_dtos::ProblemDiffResponse,
+};
+
+/// Response DTO for a dry-run validation of an `ExportedProblem`
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportValidationResponse {
+    /// `false` if importing would fail or leave broken references
+    pub is_valid: bool,
+    pub errors: Vec<ImportIssueDto>,
+    pub warnings: Vec<ImportIssueDto>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportIssueDto {
+    pub path: String,
+    pub message: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ExportedImage {
-    pub data: Vec<u8>,
-    pub format: String,
+impl From<ImportValidationReport> for ImportValidationResponse {
+    fn from(report: ImportValidationReport) -> Self {
+        let is_valid = !report.has_errors();
+        let (errors, warnings): (Vec<ImportIssue>, Vec<ImportIssue>) = report
+            .issues
+            .into_iter()
+            .partition(|i| i.severity == ImportIssueSeverity::Error);
+
+        Self {
+            is_valid,
+            errors: errors.into_iter().map(|i| i.into()).collect(),
+            warnings: warnings.into_iter().map(|i| i.into()).collect(),
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ExportedInputParameter {
-    pub temp_id: i64,
-    pub name: String,
-    pub start: f32,
-    pub end: f32,
-    pub values: Vec<ExportedInputValue>,
+impl From<ImportIssue> for ImportIssueDto {
+    fn from(issue: ImportIssue) -> Self {
+        Self {
+            path: issue.path,
+            message: issue.message,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ExportedInputValue {
-    pub temp_id: i64,
-    pub value: String,
-    pub a: f32,
-    pub b: f32,
-    pub c: f32,
-    pub d: f32,
-    pub is_triangle: bool,
+/// Response DTO for previewing or applying a merge-import
+#[derive(Debug, Clone, Serialize)]
+pub struct MergePreviewResponse {
+    pub diff: ProblemDiffResponse,
+    pub conflicts: Vec<MergeConflictDto>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ExportedOutputParameter {
-    pub temp_id: i64,
-    pub name: String,
-    pub start: f32,
-    pub end: f32,
-    pub values: Vec<ExportedFuzzyOutputValue>,
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeConflictDto {
+    pub subject: String,
+    pub message: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ExportedFuzzyOutputValue {
-    pub temp_id: i64,
-    pub value: String,
-    pub a: f32,
-    pub b: f32,
-    pub c: f32,
-    pub d: f32,
-    pub is_triangle: bool,
+impl From<MergePreview> for MergePreviewResponse {
+    fn from(preview: MergePreview) -> Self {
+        Self {
+            diff: preview.diff.into(),
+            conflicts: preview.conflicts.into_iter().map(|c| c.into()).collect(),
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ExportedOutputValue {
-    pub output_parameter_temp_id: i64,
-    #[serde(alias = "fuzzy_output_value_id", rename = "fuzzy_output_value_temp_id")]
-    pub fuzzy_output_value_temp_id: Option<i64>,
-    pub input_value_temp_ids: Vec<i64>,
+impl From<MergeConflict> for MergeConflictDto {
+    fn from(conflict: MergeConflict) -> Self {
+        Self {
+            subject: conflict.subject,
+            message: conflict.message,
+        }
+    }
 }
@@ -1,9 +1,17 @@
 use serde::{Deserialize, Serialize};
 
 use crate::application::use_cases::fuzzy_inference::{
-    FuzzyEvaluationInput, FuzzyEvaluationOutputResult, FuzzyEvaluationResult, FuzzifiedInputInfo,
+    BatchEvaluationResult, ChainEvaluationResult, ChainEvaluationStep, FuzzyEvaluationInput,
+    FuzzyEvaluationOutputResult, FuzzyEvaluationResult, FuzzifiedInputInfo,
+    IntervalFuzzifiedInputInfo, SweepAxis, SweepResult, SweepSurface, Type2EvaluationOutputResult,
+    Type2EvaluationResult,
+};
+use crate::domain::services::{
+    defuzzification::DefuzzificationMethod,
+    fuzzification::{CategoryDegree, InputUncertainty},
+    interval_type2::FiringInterval,
+    linguistic_approximation::{LinguisticApproximation, TermDegree},
 };
-use crate::domain::services::defuzzification::DefuzzificationMethod;
 
 /// Request DTO for fuzzy system evaluation
 #[derive(Debug, Clone, Deserialize)]
@@ -16,14 +24,7 @@ pub struct EvaluateFuzzySystemRequest {
 
 impl EvaluateFuzzySystemRequest {
     pub fn get_method(&self) -> DefuzzificationMethod {
-        match self.method.to_lowercase().as_str() {
-            "centroid" | "cog" | "coa" => DefuzzificationMethod::Centroid,
-            "bisector" | "boa" => DefuzzificationMethod::Bisector,
-            "mom" | "mean_of_maximum" => DefuzzificationMethod::MeanOfMaximum,
-            "som" | "smallest_of_maximum" => DefuzzificationMethod::SmallestOfMaximum,
-            "lom" | "largest_of_maximum" => DefuzzificationMethod::LargestOfMaximum,
-            _ => DefuzzificationMethod::Centroid, // default
-        }
+        parse_defuzzification_method(&self.method)
     }
 
     pub fn get_resolution(&self) -> usize {
@@ -36,16 +37,36 @@ impl EvaluateFuzzySystemRequest {
             .map(|i| FuzzyEvaluationInput {
                 input_parameter_id: i.input_parameter_id,
                 crisp_value: i.crisp_value,
+                uncertainty: i.uncertainty,
+                input_value_id: i.input_value_id,
+                category_degrees: i.category_degrees.clone(),
             })
             .collect()
     }
 }
 
+/// Parses a defuzzification method name as sent by the frontend
+pub fn parse_defuzzification_method(method: &str) -> DefuzzificationMethod {
+    DefuzzificationMethod::from_name(method).unwrap_or(DefuzzificationMethod::Centroid)
+}
+
 /// Input DTO for a single fuzzy input
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FuzzyInputDto {
     pub input_parameter_id: i64,
     pub crisp_value: f32,
+    /// e.g. {"shape": "triangular", "spread": 0.5} or {"shape": "gaussian", "sigma": 0.2};
+    /// omitted for exact values
+    #[serde(default)]
+    pub uncertainty: Option<InputUncertainty>,
+    /// Selected category of a categorical parameter, whose `crisp_value` is unused
+    #[serde(default)]
+    pub input_value_id: Option<i64>,
+    /// Degree per category of a categorical parameter, e.g.
+    /// [{"input_value_id": 3, "degree": 0.7}]; omitted to select the category
+    /// `input_value_id`
+    #[serde(default)]
+    pub category_degrees: Option<Vec<CategoryDegree>>,
 }
 
 /// Response DTO for fuzzy system evaluation
@@ -54,6 +75,7 @@ pub struct EvaluateFuzzySystemResponse {
     pub problem_id: i64,
     pub problem_name: String,
     pub outputs: Vec<FuzzyOutputResultDto>,
+    pub definition_hash: String,
 }
 
 impl From<FuzzyEvaluationResult> for EvaluateFuzzySystemResponse {
@@ -62,6 +84,7 @@ impl From<FuzzyEvaluationResult> for EvaluateFuzzySystemResponse {
             problem_id: result.problem_id,
             problem_name: result.problem_name,
             outputs: result.outputs.into_iter().map(|o| o.into()).collect(),
+            definition_hash: result.definition_hash,
         }
     }
 }
@@ -71,9 +94,14 @@ impl From<FuzzyEvaluationResult> for EvaluateFuzzySystemResponse {
 pub struct FuzzyOutputResultDto {
     pub output_parameter_id: i64,
     pub output_parameter_name: String,
+    pub unit: String,
     pub crisp_value: f32,
+    /// Crisp value with the parameter's precision and unit
+    pub display_value: String,
     pub fuzzified_inputs: Vec<FuzzifiedInputInfoDto>,
     pub fired_rules_count: usize,
+    pub linguistic_terms: Vec<TermDegreeDto>,
+    pub linguistic_approximation: Option<LinguisticApproximationDto>,
 }
 
 impl From<FuzzyEvaluationOutputResult> for FuzzyOutputResultDto {
@@ -81,9 +109,54 @@ impl From<FuzzyEvaluationOutputResult> for FuzzyOutputResultDto {
         Self {
             output_parameter_id: result.output_parameter_id,
             output_parameter_name: result.output_parameter_name,
+            unit: result.unit,
             crisp_value: result.crisp_value,
+            display_value: result.display_value,
             fuzzified_inputs: result.fuzzified_inputs.into_iter().map(|f| f.into()).collect(),
             fired_rules_count: result.fired_rules_count,
+            linguistic_terms: result.linguistic_terms.into_iter().map(|t| t.into()).collect(),
+            linguistic_approximation: result.linguistic_approximation.map(|a| a.into()),
+        }
+    }
+}
+
+/// Degree of a crisp output value in one output term
+#[derive(Debug, Clone, Serialize)]
+pub struct TermDegreeDto {
+    pub fuzzy_output_value_id: i64,
+    pub linguistic_term: String,
+    pub degree: f32,
+}
+
+impl From<TermDegree> for TermDegreeDto {
+    fn from(term: TermDegree) -> Self {
+        Self {
+            fuzzy_output_value_id: term.fuzzy_output_value_id,
+            linguistic_term: term.term,
+            degree: term.degree,
+        }
+    }
+}
+
+/// Best matching, possibly hedged, term for the aggregated output set
+#[derive(Debug, Clone, Serialize)]
+pub struct LinguisticApproximationDto {
+    pub fuzzy_output_value_id: i64,
+    pub linguistic_term: String,
+    pub hedge: Option<String>, // "very", "extremely", "somewhat", "slightly"
+    /// Term with the hedge, e.g. "very High"
+    pub label: String,
+    pub similarity: f32,
+}
+
+impl From<LinguisticApproximation> for LinguisticApproximationDto {
+    fn from(approximation: LinguisticApproximation) -> Self {
+        Self {
+            label: approximation.label(),
+            fuzzy_output_value_id: approximation.fuzzy_output_value_id,
+            hedge: approximation.hedge.map(|h| h.as_str().to_string()),
+            linguistic_term: approximation.term,
+            similarity: approximation.similarity,
         }
     }
 }
@@ -93,7 +166,9 @@ impl From<FuzzyEvaluationOutputResult> for FuzzyOutputResultDto {
 pub struct FuzzifiedInputInfoDto {
     pub input_parameter_id: i64,
     pub input_parameter_name: String,
+    pub unit: String,
     pub crisp_value: f32,
+    pub display_value: String,
     pub membership_degrees: Vec<MembershipDegreeDto>,
 }
 
@@ -102,7 +177,9 @@ impl From<FuzzifiedInputInfo> for FuzzifiedInputInfoDto {
         Self {
             input_parameter_id: info.input_parameter_id,
             input_parameter_name: info.input_parameter_name,
+            unit: info.unit,
             crisp_value: info.crisp_value,
+            display_value: info.display_value,
             membership_degrees: info
                 .membership_degrees
                 .into_iter()
@@ -121,3 +198,435 @@ pub struct MembershipDegreeDto {
     pub linguistic_term: String,
     pub degree: f32,
 }
+
+/// Response DTO for evaluating a chain of linked problems
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluateChainResponse {
+    pub problem_id: i64,
+    /// Results of every problem in evaluation order, the requested one last
+    pub steps: Vec<ChainEvaluationStepDto>,
+}
+
+impl From<ChainEvaluationResult> for EvaluateChainResponse {
+    fn from(result: ChainEvaluationResult) -> Self {
+        Self {
+            problem_id: result.problem_id,
+            steps: result.steps.into_iter().map(|s| s.into()).collect(),
+        }
+    }
+}
+
+/// Result of one problem of a chain with the inputs it received, linked ones included
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainEvaluationStepDto {
+    pub inputs: Vec<FuzzyInputDto>,
+    pub result: EvaluateFuzzySystemResponse,
+}
+
+impl From<ChainEvaluationStep> for ChainEvaluationStepDto {
+    fn from(step: ChainEvaluationStep) -> Self {
+        Self {
+            inputs: step
+                .inputs
+                .into_iter()
+                .map(|i| FuzzyInputDto {
+                    input_parameter_id: i.input_parameter_id,
+                    crisp_value: i.crisp_value,
+                    uncertainty: i.uncertainty,
+                    input_value_id: i.input_value_id,
+                    category_degrees: i.category_degrees,
+                })
+                .collect(),
+            result: step.result.into(),
+        }
+    }
+}
+
+/// Request DTO for interval type-2 evaluation; the defuzzification method is
+/// always the centroid of the type-reduced set
+#[derive(Debug, Clone, Deserialize)]
+pub struct EvaluateType2SystemRequest {
+    pub problem_id: i64,
+    pub inputs: Vec<FuzzyInputDto>,
+    pub resolution: Option<usize>,
+}
+
+impl EvaluateType2SystemRequest {
+    pub fn get_resolution(&self) -> usize {
+        self.resolution.unwrap_or(100)
+    }
+
+    pub fn to_inputs(&self) -> Vec<FuzzyEvaluationInput> {
+        self.inputs
+            .iter()
+            .map(|i| FuzzyEvaluationInput {
+                input_parameter_id: i.input_parameter_id,
+                crisp_value: i.crisp_value,
+                uncertainty: i.uncertainty,
+                input_value_id: i.input_value_id,
+                category_degrees: i.category_degrees.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Response DTO for interval type-2 evaluation
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluateType2SystemResponse {
+    pub problem_id: i64,
+    pub problem_name: String,
+    pub fuzzified_inputs: Vec<IntervalFuzzifiedInputInfoDto>,
+    pub outputs: Vec<Type2OutputResultDto>,
+    pub definition_hash: String,
+}
+
+impl From<Type2EvaluationResult> for EvaluateType2SystemResponse {
+    fn from(result: Type2EvaluationResult) -> Self {
+        Self {
+            problem_id: result.problem_id,
+            problem_name: result.problem_name,
+            fuzzified_inputs: result.fuzzified_inputs.into_iter().map(|f| f.into()).collect(),
+            outputs: result.outputs.into_iter().map(|o| o.into()).collect(),
+            definition_hash: result.definition_hash,
+        }
+    }
+}
+
+/// Type-reduced interval and crisp value of one output parameter
+#[derive(Debug, Clone, Serialize)]
+pub struct Type2OutputResultDto {
+    pub output_parameter_id: i64,
+    pub output_parameter_name: String,
+    pub unit: String,
+    pub left: f32,
+    pub right: f32,
+    pub crisp_value: f32,
+    /// Crisp value with the parameter's precision and unit
+    pub display_value: String,
+    pub fired_rules_count: usize,
+    pub firing_intervals: Vec<FiringIntervalDto>,
+}
+
+impl From<Type2EvaluationOutputResult> for Type2OutputResultDto {
+    fn from(result: Type2EvaluationOutputResult) -> Self {
+        Self {
+            output_parameter_id: result.output_parameter_id,
+            output_parameter_name: result.output_parameter_name,
+            unit: result.unit,
+            left: result.left,
+            right: result.right,
+            crisp_value: result.crisp_value,
+            display_value: result.display_value,
+            fired_rules_count: result.fired_rules_count,
+            firing_intervals: result.firing_intervals.into_iter().map(|f| f.into()).collect(),
+        }
+    }
+}
+
+/// Firing interval of the rules concluding in one (possibly hedged) output term
+#[derive(Debug, Clone, Serialize)]
+pub struct FiringIntervalDto {
+    pub fuzzy_output_value_id: i64,
+    pub hedge: Option<String>,
+    pub lower: f32,
+    pub upper: f32,
+}
+
+impl From<FiringInterval> for FiringIntervalDto {
+    fn from(interval: FiringInterval) -> Self {
+        Self {
+            fuzzy_output_value_id: interval.fuzzy_output_value_id,
+            hedge: interval.consequent_hedge.map(|h| h.as_str().to_string()),
+            lower: interval.lower,
+            upper: interval.upper,
+        }
+    }
+}
+
+/// Lower and upper membership degrees of an input, for debugging/display
+#[derive(Debug, Clone, Serialize)]
+pub struct IntervalFuzzifiedInputInfoDto {
+    pub input_parameter_id: i64,
+    pub input_parameter_name: String,
+    pub unit: String,
+    pub crisp_value: f32,
+    pub display_value: String,
+    pub membership_intervals: Vec<MembershipIntervalDto>,
+}
+
+impl From<IntervalFuzzifiedInputInfo> for IntervalFuzzifiedInputInfoDto {
+    fn from(info: IntervalFuzzifiedInputInfo) -> Self {
+        Self {
+            input_parameter_id: info.input_parameter_id,
+            input_parameter_name: info.input_parameter_name,
+            unit: info.unit,
+            crisp_value: info.crisp_value,
+            display_value: info.display_value,
+            membership_intervals: info
+                .membership_intervals
+                .into_iter()
+                .map(|(name, lower, upper)| MembershipIntervalDto {
+                    linguistic_term: name,
+                    lower,
+                    upper,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Membership interval DTO
+#[derive(Debug, Clone, Serialize)]
+pub struct MembershipIntervalDto {
+    pub linguistic_term: String,
+    pub lower: f32,
+    pub upper: f32,
+}
+
+/// Request DTO for evaluating a CSV table of inputs
+#[derive(Debug, Clone, Deserialize)]
+pub struct EvaluateBatchRequest {
+    /// Chosen by the frontend; identifies progress events and cancellation
+    pub job_id: String,
+    pub problem_id: i64,
+    /// Header row of input parameter names, then one row of values per evaluation;
+    /// separated by ',' or, if the header contains one, ';'
+    pub csv: String,
+    pub method: String,
+    pub resolution: Option<usize>,
+}
+
+impl EvaluateBatchRequest {
+    pub fn get_method(&self) -> DefuzzificationMethod {
+        parse_defuzzification_method(&self.method)
+    }
+
+    pub fn get_resolution(&self) -> usize {
+        self.resolution.unwrap_or(100)
+    }
+
+    pub fn delimiter(&self) -> char {
+        match self.csv.lines().next() {
+            Some(header) if header.contains(';') => ';',
+            _ => ',',
+        }
+    }
+
+    /// Column names and rows of the table; blank lines are skipped
+    pub fn parse_csv(&self) -> (Vec<String>, Vec<Vec<String>>) {
+        let delimiter = self.delimiter();
+        let mut lines = self
+            .csv
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| split_csv_line(line, delimiter));
+        let columns = lines.next().unwrap_or_default();
+        (columns, lines.collect())
+    }
+}
+
+/// Fields of one CSV line; fields may be quoted with '"', doubling quotes inside
+fn split_csv_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields.into_iter().map(|f| f.trim().to_string()).collect()
+}
+
+fn quote_csv_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains('"') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Response DTO for batch evaluation
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluateBatchResponse {
+    pub problem_id: i64,
+    pub output_parameter_ids: Vec<i64>,
+    pub output_parameter_names: Vec<String>,
+    pub rows: Vec<BatchRowDto>,
+    pub evaluated_count: usize,
+    pub failed_count: usize,
+    /// The input table with a column per output parameter and an "error" column
+    pub csv: String,
+    pub definition_hash: String,
+}
+
+impl EvaluateBatchResponse {
+    pub fn new(result: BatchEvaluationResult, delimiter: char) -> Self {
+        let header: Vec<&String> = result
+            .columns
+            .iter()
+            .chain(&result.output_parameter_names)
+            .collect();
+        let mut csv = header
+            .iter()
+            .map(|name| quote_csv_field(name, delimiter))
+            .chain(std::iter::once("error".to_string()))
+            .collect::<Vec<_>>()
+            .join(&delimiter.to_string());
+        csv.push('\n');
+        for row in &result.rows {
+            let outputs = match &row.outputs {
+                Some(outputs) => outputs.iter().map(|v| v.to_string()).collect(),
+                None => vec![String::new(); result.output_parameter_ids.len()],
+            };
+            let line = row
+                .cells
+                .iter()
+                .cloned()
+                .chain(outputs)
+                .chain(std::iter::once(row.error.clone().unwrap_or_default()))
+                .map(|field| quote_csv_field(&field, delimiter))
+                .collect::<Vec<_>>()
+                .join(&delimiter.to_string());
+            csv.push_str(&line);
+            csv.push('\n');
+        }
+
+        let failed_count = result.rows.iter().filter(|row| row.error.is_some()).count();
+        Self {
+            problem_id: result.problem_id,
+            evaluated_count: result.rows.len() - failed_count,
+            failed_count,
+            rows: result
+                .rows
+                .into_iter()
+                .map(|row| BatchRowDto {
+                    outputs: row.outputs,
+                    error: row.error,
+                })
+                .collect(),
+            output_parameter_ids: result.output_parameter_ids,
+            output_parameter_names: result.output_parameter_names,
+            csv,
+            definition_hash: result.definition_hash,
+        }
+    }
+}
+
+/// Outputs of one batch row, or why it could not be evaluated
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchRowDto {
+    pub outputs: Option<Vec<f32>>,
+    pub error: Option<String>,
+}
+
+/// Request DTO for a control curve (one axis) or surface (two axes)
+#[derive(Debug, Clone, Deserialize)]
+pub struct EvaluateSweepRequest {
+    /// Chosen by the frontend; identifies progress events and cancellation
+    pub job_id: String,
+    pub problem_id: i64,
+    pub x: SweepAxisDto,
+    pub y: Option<SweepAxisDto>,
+    /// Values of the input parameters that are not swept
+    pub fixed_inputs: Vec<FuzzyInputDto>,
+    pub method: String,
+    pub resolution: Option<usize>,
+}
+
+impl EvaluateSweepRequest {
+    pub fn get_method(&self) -> DefuzzificationMethod {
+        parse_defuzzification_method(&self.method)
+    }
+
+    pub fn get_resolution(&self) -> usize {
+        self.resolution.unwrap_or(100)
+    }
+
+    pub fn to_fixed_inputs(&self) -> Vec<FuzzyEvaluationInput> {
+        self.fixed_inputs
+            .iter()
+            .map(|i| FuzzyEvaluationInput {
+                input_parameter_id: i.input_parameter_id,
+                crisp_value: i.crisp_value,
+                uncertainty: i.uncertainty,
+                input_value_id: i.input_value_id,
+                category_degrees: i.category_degrees.clone(),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct SweepAxisDto {
+    pub input_parameter_id: i64,
+    pub steps: usize,
+}
+
+impl From<SweepAxisDto> for SweepAxis {
+    fn from(dto: SweepAxisDto) -> Self {
+        Self {
+            input_parameter_id: dto.input_parameter_id,
+            steps: dto.steps,
+        }
+    }
+}
+
+/// Response DTO for a sweep
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluateSweepResponse {
+    pub problem_id: i64,
+    pub x: Vec<f32>,
+    /// Empty for a control curve
+    pub y: Vec<f32>,
+    pub surfaces: Vec<SweepSurfaceDto>,
+    pub definition_hash: String,
+}
+
+impl From<SweepResult> for EvaluateSweepResponse {
+    fn from(result: SweepResult) -> Self {
+        Self {
+            problem_id: result.problem_id,
+            x: result.x,
+            y: result.y,
+            surfaces: result.surfaces.into_iter().map(|s| s.into()).collect(),
+            definition_hash: result.definition_hash,
+        }
+    }
+}
+
+/// Values of one output parameter over the grid, row-major: `values[j * x.len() + i]`
+#[derive(Debug, Clone, Serialize)]
+pub struct SweepSurfaceDto {
+    pub output_parameter_id: i64,
+    pub output_parameter_name: String,
+    pub unit: String,
+    pub values: Vec<f32>,
+}
+
+impl From<SweepSurface> for SweepSurfaceDto {
+    fn from(surface: SweepSurface) -> Self {
+        Self {
+            output_parameter_id: surface.output_parameter_id,
+            output_parameter_name: surface.output_parameter_name,
+            unit: surface.unit,
+            values: surface.values,
+        }
+    }
+}
+
+/// Payload of the "evaluation-progress" event emitted while a batch or sweep runs
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluationProgressEvent {
+    pub job_id: String,
+    pub completed: usize,
+    pub total: usize,
+}
@@ -1,13 +1,18 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::fuzzy_output_value::FuzzyOutputValue;
+use crate::domain::entities::{lower_membership::LowerMembership, fuzzy_output_value::FuzzyOutputValue};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateFuzzyOutputValueRequest {
     pub output_parameter_id: i64,
+    /// Breakpoints (a, b, c, d) of the new term; only used by free-form parameters,
+    /// where a term without them covers the whole range
+    #[serde(default)]
+    pub shape: Option<[f32; 4]>,
 }
 impl CreateFuzzyOutputValueRequest {
     pub fn to_entity(&self) -> FuzzyOutputValue {
+        // Breakpoints are set on creation, from `shape` or by the partition
         FuzzyOutputValue {
             id: 0,
             output_parameter_id: self.output_parameter_id,
@@ -17,6 +22,7 @@ impl CreateFuzzyOutputValueRequest {
             c: 0.,
             d: 0.,
             is_triangle: false,
+            lower: None,
         }
     }
 }
@@ -41,10 +47,17 @@ impl UpdateFuzzyOutputValueRequest {
             c: self.c,
             d: self.d,
             is_triangle: self.is_triangle,
+            lower: None,
         }
     }
 }
 
+/// Lower membership function to set on a term; `None` makes it type-1 again
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateFuzzyOutputValueLowerRequest {
+    pub lower: Option<LowerMembership>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct FuzzyOutputValueResponse {
     pub id: i64,
@@ -55,6 +68,7 @@ pub struct FuzzyOutputValueResponse {
     pub c: f32,
     pub d: f32,
     pub is_triangle: bool,
+    pub lower: Option<LowerMembership>,
 }
 impl FuzzyOutputValueResponse {
     pub fn from(entity: &FuzzyOutputValue) -> Self {
@@ -67,6 +81,7 @@ impl FuzzyOutputValueResponse {
             c: entity.c,
             d: entity.d,
             is_triangle: entity.is_triangle,
+            lower: entity.lower,
         }
     }
 }
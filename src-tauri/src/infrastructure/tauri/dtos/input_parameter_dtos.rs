@@ -1,7 +1,17 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    domain::entities::{input_parameter::InputParameter, input_value::InputValue},
+    domain::{
+        entities::{
+            input_parameter::InputParameter, input_value::InputValue, parameter_kind::ParameterKind,
+            parameter_metadata::ParameterMetadata, partition_mode::PartitionMode,
+        },
+        error::{DomainError, EntityKind, FieldError},
+        services::{
+            fuzzy_partition::{check_partition, PARTITION_TOLERANCE},
+            range_change::RangeChangePolicy,
+        },
+    },
     infrastructure::tauri::dtos::input_value_dtos::InputValueResponse,
 };
 
@@ -17,11 +27,16 @@ impl CreateInputParameterRequest {
             name: "Новый параметр".to_string(),
             start: 0.,
             end: 1.,
+            partition_mode: PartitionMode::default(),
+            kind: ParameterKind::default(),
+            metadata: ParameterMetadata::default(),
             input_values: Vec::<InputValue>::new(),
         }
     }
 }
 
+/// Renames the parameter; a different range is only taken when the terms fit it
+/// unchanged, see `change_input_parameter_range` for moving them along
 #[derive(Debug, Clone, Deserialize)]
 pub struct UpdateInputParameterRequest {
     pub name: String,
@@ -36,11 +51,64 @@ impl UpdateInputParameterRequest {
             name: self.name.to_owned(),
             start: self.start,
             end: self.end,
+            partition_mode: PartitionMode::default(),
+            kind: ParameterKind::default(),
+            metadata: ParameterMetadata::default(),
             input_values: Vec::<InputValue>::new(),
         }
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangeInputParameterRangeRequest {
+    pub start: f32,
+    pub end: f32,
+    /// "rescale", "clip" or "reject"
+    pub policy: String,
+}
+impl ChangeInputParameterRangeRequest {
+    /// `id` is the parameter whose range changes
+    pub fn to_policy(&self, id: i64) -> Result<RangeChangePolicy, DomainError> {
+        RangeChangePolicy::from_name(&self.policy).ok_or_else(|| {
+            DomainError::invalid_field(
+                EntityKind::InputParameter,
+                Some(id),
+                FieldError::new("policy", format!("Unknown range change policy '{}'", self.policy)),
+            )
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateInputParameterPartitionModeRequest {
+    pub partition_mode: PartitionMode,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateInputParameterKindRequest {
+    pub kind: ParameterKind,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateInputParameterMetadataRequest {
+    pub unit: String,
+    pub description: String,
+    /// Number of displayed decimals; `None` shows values unrounded
+    pub precision: Option<u8>,
+    /// Value an evaluation starts from
+    pub default_value: Option<f32>,
+}
+impl UpdateInputParameterMetadataRequest {
+    pub fn to_metadata(&self) -> ParameterMetadata {
+        ParameterMetadata {
+            unit: self.unit.trim().to_string(),
+            description: self.description.to_owned(),
+            precision: self.precision,
+            default_value: self.default_value,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct InputParameterResponse {
     pub id: i64,
@@ -48,16 +116,46 @@ pub struct InputParameterResponse {
     pub name: String,
     pub start: f32,
     pub end: f32,
+    pub partition_mode: PartitionMode,
+    pub kind: ParameterKind,
+    pub unit: String,
+    pub description: String,
+    pub precision: Option<u8>,
+    pub default_value: Option<f32>,
+    /// Whether the terms form a Ruspini partition of the range
+    pub partition_valid: bool,
+    /// Why they do not, if they do not
+    pub partition_issue: Option<String>,
     pub input_values: Vec<InputValueResponse>,
 }
 impl InputParameterResponse {
     pub fn from(entity: &InputParameter) -> Self {
+        let shapes: Vec<_> = entity
+            .input_values
+            .iter()
+            .map(|x| (x.a, x.b, x.c, x.d))
+            .collect();
+        // Categories do not partition a range
+        let partition_issue = match entity.kind {
+            ParameterKind::Continuous => {
+                check_partition(&shapes, entity.start, entity.end, PARTITION_TOLERANCE).err()
+            }
+            ParameterKind::Categorical => None,
+        };
         Self {
             id: entity.id,
             problem_id: entity.problem_id,
             name: entity.name.to_owned(),
             start: entity.start,
             end: entity.end,
+            partition_mode: entity.partition_mode,
+            kind: entity.kind,
+            unit: entity.metadata.unit.to_owned(),
+            description: entity.metadata.description.to_owned(),
+            precision: entity.metadata.precision,
+            default_value: entity.metadata.default_value,
+            partition_valid: partition_issue.is_none(),
+            partition_issue,
             input_values: entity
                 .input_values
                 .iter()
@@ -1,13 +1,18 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::input_value::InputValue;
+use crate::domain::entities::{lower_membership::LowerMembership, input_value::InputValue};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateInputValueRequest {
     pub input_parameter_id: i64,
+    /// Breakpoints (a, b, c, d) of the new term; only used by free-form parameters,
+    /// where a term without them covers the whole range
+    #[serde(default)]
+    pub shape: Option<[f32; 4]>,
 }
 impl CreateInputValueRequest {
     pub fn to_entity(&self) -> InputValue {
+        // Breakpoints are set on creation, from `shape` or by the partition
         InputValue {
             id: 0,
             input_parameter_id: self.input_parameter_id,
@@ -17,6 +22,7 @@ impl CreateInputValueRequest {
             c: 0.,
             d: 0.,
             is_triangle: false,
+            lower: None,
         }
     }
 }
@@ -41,10 +47,17 @@ impl UpdateInputValueRequest {
             c: self.c,
             d: self.d,
             is_triangle: self.is_triangle,
+            lower: None,
         }
     }
 }
 
+/// Lower membership function to set on a term; `None` makes it type-1 again
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateInputValueLowerRequest {
+    pub lower: Option<LowerMembership>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct InputValueResponse {
     pub id: i64,
@@ -55,6 +68,7 @@ pub struct InputValueResponse {
     pub c: f32,
     pub d: f32,
     pub is_triangle: bool,
+    pub lower: Option<LowerMembership>,
 }
 impl InputValueResponse {
     pub fn from(entity: &InputValue) -> Self {
@@ -67,6 +81,7 @@ impl InputValueResponse {
             c: entity.c,
             d: entity.d,
             is_triangle: entity.is_triangle,
+            lower: entity.lower,
         }
     }
 }
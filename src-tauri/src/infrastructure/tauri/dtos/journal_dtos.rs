@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+use crate::domain::entities::journal_entry::JournalEntry;
+
+/// Journal entry without the captured definitions
+#[derive(Debug, Clone, Serialize)]
+pub struct JournalEntryResponse {
+    pub id: i64,
+    pub problem_id: i64,
+    pub description: String,
+    pub is_undone: bool,
+    pub created_at: String,
+}
+
+impl JournalEntryResponse {
+    pub fn from(entity: &JournalEntry) -> Self {
+        Self {
+            id: entity.id,
+            problem_id: entity.problem_id,
+            description: entity.description.to_owned(),
+            is_undone: entity.is_undone,
+            created_at: entity.created_at.to_owned(),
+        }
+    }
+}
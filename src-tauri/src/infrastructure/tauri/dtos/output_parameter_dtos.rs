@@ -1,7 +1,17 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    domain::entities::{fuzzy_output_value::FuzzyOutputValue, output_parameter::OutputParameter},
+    domain::{
+        entities::{
+            fuzzy_output_value::FuzzyOutputValue, output_parameter::OutputParameter,
+            parameter_metadata::ParameterMetadata, partition_mode::PartitionMode,
+        },
+        error::{DomainError, EntityKind, FieldError},
+        services::{
+            fuzzy_partition::{check_partition, PARTITION_TOLERANCE},
+            range_change::RangeChangePolicy,
+        },
+    },
     infrastructure::tauri::dtos::fuzzy_output_value_dtos::FuzzyOutputValueResponse,
 };
 
@@ -17,11 +27,15 @@ impl CreateOutputParameterRequest {
             name: "Новый параметр".to_string(),
             start: 0.,
             end: 1.,
+            partition_mode: PartitionMode::default(),
+            metadata: ParameterMetadata::default(),
             fuzzy_output_values: Vec::<FuzzyOutputValue>::new(),
         }
     }
 }
 
+/// Renames the parameter; a different range is only taken when the terms fit it
+/// unchanged, see `change_output_parameter_range` for moving them along
 #[derive(Debug, Clone, Deserialize)]
 pub struct UpdateOutputParameterRequest {
     pub name: String,
@@ -36,11 +50,56 @@ impl UpdateOutputParameterRequest {
             name: self.name.to_owned(),
             start: self.start,
             end: self.end,
+            partition_mode: PartitionMode::default(),
+            metadata: ParameterMetadata::default(),
             fuzzy_output_values: Vec::<FuzzyOutputValue>::new(),
         }
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangeOutputParameterRangeRequest {
+    pub start: f32,
+    pub end: f32,
+    /// "rescale", "clip" or "reject"
+    pub policy: String,
+}
+impl ChangeOutputParameterRangeRequest {
+    /// `id` is the parameter whose range changes
+    pub fn to_policy(&self, id: i64) -> Result<RangeChangePolicy, DomainError> {
+        RangeChangePolicy::from_name(&self.policy).ok_or_else(|| {
+            DomainError::invalid_field(
+                EntityKind::OutputParameter,
+                Some(id),
+                FieldError::new("policy", format!("Unknown range change policy '{}'", self.policy)),
+            )
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateOutputParameterPartitionModeRequest {
+    pub partition_mode: PartitionMode,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateOutputParameterMetadataRequest {
+    pub unit: String,
+    pub description: String,
+    /// Number of displayed decimals; `None` shows values unrounded
+    pub precision: Option<u8>,
+}
+impl UpdateOutputParameterMetadataRequest {
+    pub fn to_metadata(&self) -> ParameterMetadata {
+        ParameterMetadata {
+            unit: self.unit.trim().to_string(),
+            description: self.description.to_owned(),
+            precision: self.precision,
+            default_value: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct OutputParameterResponse {
     pub id: i64,
@@ -48,16 +107,36 @@ pub struct OutputParameterResponse {
     pub name: String,
     pub start: f32,
     pub end: f32,
+    pub partition_mode: PartitionMode,
+    pub unit: String,
+    pub description: String,
+    pub precision: Option<u8>,
+    /// Whether the terms form a Ruspini partition of the range
+    pub partition_valid: bool,
+    /// Why they do not, if they do not
+    pub partition_issue: Option<String>,
     pub fuzzy_output_values: Vec<FuzzyOutputValueResponse>,
 }
 impl OutputParameterResponse {
     pub fn from(entity: &OutputParameter) -> Self {
+        let shapes: Vec<_> = entity
+            .fuzzy_output_values
+            .iter()
+            .map(|x| (x.a, x.b, x.c, x.d))
+            .collect();
+        let partition_issue = check_partition(&shapes, entity.start, entity.end, PARTITION_TOLERANCE).err();
         Self {
             id: entity.id,
             problem_id: entity.problem_id,
             name: entity.name.to_owned(),
             start: entity.start,
             end: entity.end,
+            partition_mode: entity.partition_mode,
+            unit: entity.metadata.unit.to_owned(),
+            description: entity.metadata.description.to_owned(),
+            precision: entity.metadata.precision,
+            partition_valid: partition_issue.is_none(),
+            partition_issue,
             fuzzy_output_values: entity
                 .fuzzy_output_values
                 .iter()
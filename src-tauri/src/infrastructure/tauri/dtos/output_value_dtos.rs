@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::output_value::OutputValue;
+use crate::domain::{entities::output_value::OutputValue, services::hedge::parse_antecedent_hedges};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct UpdateOutputValueRequest {
@@ -13,16 +13,32 @@ impl UpdateOutputValueRequest {
             output_parameter_id: 0,
             fuzzy_output_value_id: self.fuzzy_output_value_id,
             input_value_ids: String::new(),
+            antecedent_hedges: String::new(),
+            consequent_hedge: None,
         }
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AntecedentHedgeDto {
+    pub input_value_id: i64,
+    pub hedge: String, // "very", "extremely", "somewhat", "slightly"
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateOutputValueHedgesRequest {
+    pub antecedent_hedges: Vec<AntecedentHedgeDto>,
+    pub consequent_hedge: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct OutputValueResponse {
     pub id: i64,
     pub output_parameter_id: i64,
     pub fuzzy_output_value_id: Option<i64>,
     pub input_value_ids: String,
+    pub antecedent_hedges: Vec<AntecedentHedgeDto>,
+    pub consequent_hedge: Option<String>,
 }
 
 impl OutputValueResponse {
@@ -32,6 +48,14 @@ impl OutputValueResponse {
             output_parameter_id: entity.output_parameter_id,
             fuzzy_output_value_id: entity.fuzzy_output_value_id,
             input_value_ids: entity.input_value_ids.to_owned(),
+            antecedent_hedges: parse_antecedent_hedges(&entity.antecedent_hedges)
+                .into_iter()
+                .map(|(input_value_id, hedge)| AntecedentHedgeDto {
+                    input_value_id,
+                    hedge: hedge.as_str().to_string(),
+                })
+                .collect(),
+            consequent_hedge: entity.consequent_hedge.clone(),
         }
     }
 }
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::parameter_link::ParameterLink;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateParameterLinkRequest {
+    pub output_parameter_id: i64,
+    pub input_parameter_id: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ParameterLinkResponse {
+    pub id: i64,
+    pub output_parameter_id: i64,
+    pub input_parameter_id: i64,
+    pub source_problem_id: i64,
+    pub target_problem_id: i64,
+}
+
+impl ParameterLinkResponse {
+    pub fn from(entity: &ParameterLink) -> Self {
+        Self {
+            id: entity.id,
+            output_parameter_id: entity.output_parameter_id,
+            input_parameter_id: entity.input_parameter_id,
+            source_problem_id: entity.source_problem_id,
+            target_problem_id: entity.target_problem_id,
+        }
+    }
+}
@@ -103,16 +103,32 @@ impl CreateProblemRequest {
             input_parameters: Vec::<InputParameter>::new(),
             output_parameters: Vec::<OutputParameter>::new(),
             output_values: Vec::<OutputValue>::new(),
-            image: match &self.image {
-                None => None,
-                Some(i) => Some(Image {
-                    id: 0,
-                    image_data: i.image_data.to_owned(),
-                    image_format: i.image_format.to_owned(),
-                }),
-            },
         }
     }
+
+    pub fn to_image(&self) -> Option<Image> {
+        self.image.as_ref().map(|i| Image {
+            id: 0,
+            image_data: i.image_data.to_owned(),
+            image_format: i.image_format.to_owned(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CloneProblemRequest {
+    pub prev_problem_id: Option<i64>,
+    /// Name of the copy; the original name is kept when absent
+    pub name: Option<String>,
+    pub with_children: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MoveProblemRequest {
+    /// New parent group; the root when absent
+    pub prev_problem_id: Option<i64>,
+    /// Index among the new siblings; appended after the last one when absent
+    pub position: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -152,14 +168,6 @@ impl UpdateProblemRequest {
             input_parameters: Vec::<InputParameter>::new(),
             output_parameters: Vec::<OutputParameter>::new(),
             output_values: Vec::<OutputValue>::new(),
-            image: match &self.image_update {
-                ImageUpdateAction::Set(image) => Some(Image {
-                    id: 0,
-                    image_data: image.image_data.to_owned(),
-                    image_format: image.image_format.to_owned(),
-                }),
-                _ => None,
-            },
         }
     }
 }
@@ -0,0 +1,161 @@
+use serde::Serialize;
+
+use crate::domain::{
+    entities::{lower_membership::LowerMembership, problem_snapshot::ProblemSnapshot},
+    services::problem_diff::{
+        ChangeKind, ParameterChange, ProblemDiff, RuleChange, TermChange, TermShape,
+    },
+};
+
+/// Snapshot without its serialized definition, for listing
+#[derive(Debug, Clone, Serialize)]
+pub struct ProblemSnapshotResponse {
+    pub id: i64,
+    pub problem_id: i64,
+    pub name: String,
+    pub is_automatic: bool,
+    pub created_at: String,
+}
+
+impl ProblemSnapshotResponse {
+    pub fn from(entity: &ProblemSnapshot) -> Self {
+        Self {
+            id: entity.id,
+            problem_id: entity.problem_id,
+            name: entity.name.to_owned(),
+            is_automatic: entity.is_automatic,
+            created_at: entity.created_at.to_owned(),
+        }
+    }
+}
+
+fn change_kind_name(kind: ChangeKind) -> String {
+    match kind {
+        ChangeKind::Added => "added",
+        ChangeKind::Removed => "removed",
+        ChangeKind::Changed => "changed",
+    }
+    .to_string()
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TermShapeDto {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub is_triangle: bool,
+    pub lower: Option<LowerMembership>,
+}
+
+impl From<TermShape> for TermShapeDto {
+    fn from(shape: TermShape) -> Self {
+        Self {
+            a: shape.a,
+            b: shape.b,
+            c: shape.c,
+            d: shape.d,
+            is_triangle: shape.is_triangle,
+            lower: shape.lower,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ParameterChangeDto {
+    pub kind: String, // "added", "removed", "changed"
+    pub is_input: bool,
+    pub name: String,
+    pub old_start: Option<f32>,
+    pub old_end: Option<f32>,
+    pub new_start: Option<f32>,
+    pub new_end: Option<f32>,
+}
+
+impl From<ParameterChange> for ParameterChangeDto {
+    fn from(change: ParameterChange) -> Self {
+        Self {
+            kind: change_kind_name(change.kind),
+            is_input: change.is_input,
+            name: change.name,
+            old_start: change.old_range.map(|r| r.0),
+            old_end: change.old_range.map(|r| r.1),
+            new_start: change.new_range.map(|r| r.0),
+            new_end: change.new_range.map(|r| r.1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TermChangeDto {
+    pub kind: String,
+    pub is_input: bool,
+    pub parameter_name: String,
+    pub term_name: String,
+    pub old_shape: Option<TermShapeDto>,
+    pub new_shape: Option<TermShapeDto>,
+}
+
+impl From<TermChange> for TermChangeDto {
+    fn from(change: TermChange) -> Self {
+        Self {
+            kind: change_kind_name(change.kind),
+            is_input: change.is_input,
+            parameter_name: change.parameter_name,
+            term_name: change.term_name,
+            old_shape: change.old_shape.map(|s| s.into()),
+            new_shape: change.new_shape.map(|s| s.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleAntecedentDto {
+    pub input_parameter_name: String,
+    pub term_name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleChangeDto {
+    pub kind: String,
+    pub output_parameter_name: String,
+    pub antecedents: Vec<RuleAntecedentDto>,
+    pub old_consequent: Option<String>,
+    pub new_consequent: Option<String>,
+}
+
+impl From<RuleChange> for RuleChangeDto {
+    fn from(change: RuleChange) -> Self {
+        Self {
+            kind: change_kind_name(change.kind),
+            output_parameter_name: change.output_parameter_name,
+            antecedents: change
+                .antecedents
+                .into_iter()
+                .map(|(input_parameter_name, term_name)| RuleAntecedentDto {
+                    input_parameter_name,
+                    term_name,
+                })
+                .collect(),
+            old_consequent: change.old_consequent,
+            new_consequent: change.new_consequent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProblemDiffResponse {
+    pub parameters: Vec<ParameterChangeDto>,
+    pub terms: Vec<TermChangeDto>,
+    pub rules: Vec<RuleChangeDto>,
+}
+
+impl From<ProblemDiff> for ProblemDiffResponse {
+    fn from(diff: ProblemDiff) -> Self {
+        Self {
+            parameters: diff.parameters.into_iter().map(|p| p.into()).collect(),
+            terms: diff.terms.into_iter().map(|t| t.into()).collect(),
+            rules: diff.rules.into_iter().map(|r| r.into()).collect(),
+        }
+    }
+}
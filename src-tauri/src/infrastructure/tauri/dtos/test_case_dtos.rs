@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application::use_cases::test_case::run::{
+        TestCaseOutputResult, TestCaseResult, TestRunReport,
+    },
+    domain::{
+        entities::test_case::{TestCase, TestCaseExpectation, TestCaseInput},
+        services::defuzzification::DefuzzificationMethod,
+    },
+    infrastructure::tauri::dtos::fuzzy_inference_dtos::parse_defuzzification_method,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCaseInputDto {
+    pub input_parameter_id: i64,
+    pub crisp_value: f32,
+    /// Selected category of a categorical parameter
+    #[serde(default)]
+    pub input_value_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCaseExpectationDto {
+    pub output_parameter_id: i64,
+    pub expected_value: Option<f32>,
+    pub tolerance: Option<f32>,
+    pub expected_fuzzy_output_value_id: Option<i64>,
+}
+
+fn inputs_to_entity(inputs: &[TestCaseInputDto]) -> Vec<TestCaseInput> {
+    inputs
+        .iter()
+        .map(|i| TestCaseInput {
+            input_parameter_id: i.input_parameter_id,
+            crisp_value: i.crisp_value,
+            input_value_id: i.input_value_id,
+        })
+        .collect()
+}
+
+fn expectations_to_entity(expectations: &[TestCaseExpectationDto]) -> Vec<TestCaseExpectation> {
+    expectations
+        .iter()
+        .map(|e| TestCaseExpectation {
+            output_parameter_id: e.output_parameter_id,
+            expected_value: e.expected_value,
+            tolerance: e.tolerance.unwrap_or(0.0),
+            expected_fuzzy_output_value_id: e.expected_fuzzy_output_value_id,
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTestCaseRequest {
+    pub problem_id: i64,
+    pub name: String,
+    pub inputs: Vec<TestCaseInputDto>,
+    pub expectations: Vec<TestCaseExpectationDto>,
+}
+impl CreateTestCaseRequest {
+    pub fn to_entity(&self) -> TestCase {
+        TestCase {
+            id: 0,
+            problem_id: self.problem_id,
+            name: self.name.to_owned(),
+            inputs: inputs_to_entity(&self.inputs),
+            expectations: expectations_to_entity(&self.expectations),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateTestCaseRequest {
+    pub name: String,
+    pub inputs: Vec<TestCaseInputDto>,
+    pub expectations: Vec<TestCaseExpectationDto>,
+}
+impl UpdateTestCaseRequest {
+    pub fn to_entity(&self) -> TestCase {
+        TestCase {
+            id: 0,
+            problem_id: 0,
+            name: self.name.to_owned(),
+            inputs: inputs_to_entity(&self.inputs),
+            expectations: expectations_to_entity(&self.expectations),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TestCaseResponse {
+    pub id: i64,
+    pub problem_id: i64,
+    pub name: String,
+    pub inputs: Vec<TestCaseInputDto>,
+    pub expectations: Vec<TestCaseExpectationDto>,
+}
+impl TestCaseResponse {
+    pub fn from(entity: &TestCase) -> Self {
+        Self {
+            id: entity.id,
+            problem_id: entity.problem_id,
+            name: entity.name.to_owned(),
+            inputs: entity
+                .inputs
+                .iter()
+                .map(|i| TestCaseInputDto {
+                    input_parameter_id: i.input_parameter_id,
+                    crisp_value: i.crisp_value,
+                    input_value_id: i.input_value_id,
+                })
+                .collect(),
+            expectations: entity
+                .expectations
+                .iter()
+                .map(|e| TestCaseExpectationDto {
+                    output_parameter_id: e.output_parameter_id,
+                    expected_value: e.expected_value,
+                    tolerance: Some(e.tolerance),
+                    expected_fuzzy_output_value_id: e.expected_fuzzy_output_value_id,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Request DTO for running all test cases of a problem
+#[derive(Debug, Clone, Deserialize)]
+pub struct RunTestCasesRequest {
+    pub problem_id: i64,
+    pub method: String,
+    pub resolution: Option<usize>,
+}
+
+impl RunTestCasesRequest {
+    pub fn get_method(&self) -> DefuzzificationMethod {
+        parse_defuzzification_method(&self.method)
+    }
+
+    pub fn get_resolution(&self) -> usize {
+        self.resolution.unwrap_or(100)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TestRunReportDto {
+    pub problem_id: i64,
+    pub passed_count: usize,
+    pub failed_count: usize,
+    pub results: Vec<TestCaseResultDto>,
+}
+
+impl From<TestRunReport> for TestRunReportDto {
+    fn from(report: TestRunReport) -> Self {
+        Self {
+            problem_id: report.problem_id,
+            passed_count: report.passed_count,
+            failed_count: report.failed_count,
+            results: report.results.into_iter().map(|r| r.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TestCaseResultDto {
+    pub test_case_id: i64,
+    pub name: String,
+    pub passed: bool,
+    pub error: Option<String>,
+    pub outputs: Vec<TestCaseOutputResultDto>,
+}
+
+impl From<TestCaseResult> for TestCaseResultDto {
+    fn from(result: TestCaseResult) -> Self {
+        Self {
+            test_case_id: result.test_case_id,
+            name: result.name,
+            passed: result.passed,
+            error: result.error,
+            outputs: result.outputs.into_iter().map(|o| o.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TestCaseOutputResultDto {
+    pub output_parameter_id: i64,
+    pub output_parameter_name: String,
+    pub expected_value: Option<f32>,
+    pub tolerance: f32,
+    pub actual_value: Option<f32>,
+    pub difference: Option<f32>,
+    pub expected_term: Option<String>,
+    pub actual_term: Option<String>,
+    pub passed: bool,
+}
+
+impl From<TestCaseOutputResult> for TestCaseOutputResultDto {
+    fn from(result: TestCaseOutputResult) -> Self {
+        Self {
+            output_parameter_id: result.output_parameter_id,
+            output_parameter_name: result.output_parameter_name,
+            expected_value: result.expected_value,
+            tolerance: result.tolerance,
+            actual_value: result.actual_value,
+            difference: result.difference,
+            expected_term: result.expected_term,
+            actual_term: result.actual_term,
+            passed: result.passed,
+        }
+    }
+}
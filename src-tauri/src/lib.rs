@@ -5,8 +5,9 @@ pub mod infrastructure;
 
 use infrastructure::state::AppState;
 use infrastructure::tauri::commands::{
-    fuzzy_inference::*, fuzzy_output_value::*, image::*, input_parameter::*, input_value::*,
-    output_parameter::*, output_value::*, problem::*,
+    evaluation_history::*, fuzzy_inference::*, fuzzy_output_value::*, image::*,
+    input_parameter::*, input_value::*, journal::*, output_parameter::*, output_value::*,
+    parameter_link::*, problem::*, problem_snapshot::*, test_case::*,
 };
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -20,8 +21,16 @@ pub fn run() {
         .plugin(tauri_plugin_opener::init())
         .invoke_handler(tauri::generate_handler![
             create_problem,
+            clone_problem,
+            move_problem,
             export_problem,
             import_problem,
+            export_problem_to_file,
+            read_problem_file,
+            import_problem_from_file,
+            preview_merge_import,
+            merge_import,
+            validate_import,
             get_all_problems_by_prev_problem_id,
             remove_problem_by_id,
             update_problem_by_id,
@@ -30,23 +39,58 @@ pub fn run() {
             create_input_parameter,
             remove_input_parameter_by_id,
             update_input_parameter_by_id,
-            switch_input_parameters,
+            change_input_parameter_range,
+            update_input_parameter_partition_mode,
+            update_input_parameter_kind,
+            update_input_parameter_metadata,
+            move_input_parameter,
             create_input_value,
             remove_input_value_by_id,
             update_input_value_by_id,
-            switch_input_values,
+            move_input_value,
+            update_input_value_lower,
             create_output_parameter,
             remove_output_parameter_by_id,
             update_output_parameter_by_id,
-            switch_output_parameters,
+            change_output_parameter_range,
+            update_output_parameter_partition_mode,
+            update_output_parameter_metadata,
+            move_output_parameter,
             create_fuzzy_output_value,
             remove_fuzzy_output_value_by_id,
             update_fuzzy_output_value_by_id,
-            switch_fuzzy_output_values,
+            move_fuzzy_output_value,
+            update_fuzzy_output_value_lower,
             create_output_value,
             update_output_value_by_id,
+            update_output_value_hedges,
             get_output_values_by_problem_id,
             evaluate_fuzzy_system,
+            evaluate_chain,
+            evaluate_fuzzy_system_type2,
+            evaluate_batch,
+            evaluate_sweep,
+            cancel_evaluation,
+            get_test_cases_by_problem_id,
+            create_test_case,
+            update_test_case_by_id,
+            remove_test_case_by_id,
+            get_parameter_links_by_problem_id,
+            create_parameter_link,
+            remove_parameter_link_by_id,
+            run_test_cases,
+            get_evaluation_history,
+            remove_evaluation_record_by_id,
+            remove_evaluation_history_by_problem_id,
+            replay_evaluation,
+            get_problem_snapshots_by_problem_id,
+            create_problem_snapshot,
+            remove_problem_snapshot_by_id,
+            restore_problem_snapshot,
+            diff_problem_snapshots,
+            get_journal_by_problem_id,
+            undo_by_problem_id,
+            redo_by_problem_id,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { EvaluationHistoryFilterRequest, EvaluationRecordResponse } from "../../types/evaluation_history";
+
+export const getEvaluationHistory = async (filter?: EvaluationHistoryFilterRequest) => {
+    try {
+        return await invoke<EvaluationRecordResponse[]>("get_evaluation_history", { filter });
+    } catch (error) {
+        console.error("Failed to get evaluation history:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export const removeEvaluationHistoryByProblemId = async (problemId: number) => {
+    try {
+        return await invoke<number>("remove_evaluation_history_by_problem_id", { problemId });
+    } catch (error) {
+        console.error("Failed to clear evaluation history:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export const removeEvaluationRecordById = async (id: number) => {
+    try {
+        await invoke<void>("remove_evaluation_record_by_id", { id });
+    } catch (error) {
+        console.error("Failed to remove evaluation record:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,6 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ReplayEvaluationResponse } from "../../types/evaluation_history";
+
+export async function replayEvaluation(id: number): Promise<ReplayEvaluationResponse> {
+    return await invoke<ReplayEvaluationResponse>("replay_evaluation", { id });
+}
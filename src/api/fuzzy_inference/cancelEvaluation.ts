@@ -0,0 +1,7 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export async function cancelEvaluation(jobId: string): Promise<void> {
+    return await invoke<void>("cancel_evaluation", {
+        jobId,
+    });
+}
@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+import { EvaluateBatchRequest, EvaluateBatchResponse } from "../../types/fuzzy_inference";
+
+export async function evaluateBatch(
+    request: EvaluateBatchRequest
+): Promise<EvaluateBatchResponse> {
+    return await invoke<EvaluateBatchResponse>("evaluate_batch", {
+        request,
+    });
+}
@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+import { EvaluateChainResponse, EvaluateFuzzySystemRequest } from "../../types/fuzzy_inference";
+
+export async function evaluateChain(
+    request: EvaluateFuzzySystemRequest
+): Promise<EvaluateChainResponse> {
+    return await invoke<EvaluateChainResponse>("evaluate_chain", {
+        request,
+    });
+}
@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+import { EvaluateType2SystemRequest, EvaluateType2SystemResponse } from "../../types/fuzzy_inference";
+
+export async function evaluateFuzzySystemType2(
+    request: EvaluateType2SystemRequest
+): Promise<EvaluateType2SystemResponse> {
+    return await invoke<EvaluateType2SystemResponse>("evaluate_fuzzy_system_type2", {
+        request,
+    });
+}
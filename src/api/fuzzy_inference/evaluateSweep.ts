@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+import { EvaluateSweepRequest, EvaluateSweepResponse } from "../../types/fuzzy_inference";
+
+export async function evaluateSweep(
+    request: EvaluateSweepRequest
+): Promise<EvaluateSweepResponse> {
+    return await invoke<EvaluateSweepResponse>("evaluate_sweep", {
+        request,
+    });
+}
@@ -0,0 +1,14 @@
+import { listen, UnlistenFn } from "@tauri-apps/api/event";
+import { EvaluationProgressEvent } from "../../types/fuzzy_inference";
+
+/** Calls `handler` with the progress of the batch or sweep `jobId` until unlistened */
+export async function onEvaluationProgress(
+    jobId: string,
+    handler: (progress: EvaluationProgressEvent) => void
+): Promise<UnlistenFn> {
+    return await listen<EvaluationProgressEvent>("evaluation-progress", (event) => {
+        if (event.payload.job_id === jobId) {
+            handler(event.payload);
+        }
+    });
+}
@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+
+/** Puts the output term at `position` among its siblings (after the last one when past the end) */
+export const moveFuzzyOutputValue = async (id: number, position: number) => {
+    try {
+        await invoke("move_fuzzy_output_value", { id, position });
+    } catch (error) {
+        console.error("Failed to move output term:", error);
+        throw error;
+    }
+};
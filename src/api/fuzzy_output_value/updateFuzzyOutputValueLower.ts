@@ -0,0 +1,15 @@
+import { invoke } from "@tauri-apps/api/core";
+import { LowerMembership } from "../../types/lower_membership";
+import { UpdateFuzzyOutputValueLowerRequest } from "../../types/fuzzy_output_value";
+
+export const updateFuzzyOutputValueLower = async (id: number, lower: LowerMembership | null) => {
+    try {
+        await invoke("update_fuzzy_output_value_lower", {
+            id,
+            request: { lower } as UpdateFuzzyOutputValueLowerRequest
+        });
+    } catch (error) {
+        console.error("Failed to update term footprint:", error);
+        throw error;
+    }
+};
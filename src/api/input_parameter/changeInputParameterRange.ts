@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ChangeInputParameterRangeRequest } from "../../types/input_parameter";
+
+export const changeInputParameterRange = async (id: number, changeRequest: ChangeInputParameterRangeRequest) => {
+    try {
+        await invoke("change_input_parameter_range", { id, changeRequest });
+    } catch (error) {
+        console.error("Failed to change parameter range:", error);
+        throw error;
+    }
+};
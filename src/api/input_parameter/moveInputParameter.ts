@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+
+/** Puts the input parameter at `position` among its siblings (after the last one when past the end) */
+export const moveInputParameter = async (id: number, position: number) => {
+    try {
+        await invoke("move_input_parameter", { id, position });
+    } catch (error) {
+        console.error("Failed to move input parameter:", error);
+        throw error;
+    }
+};
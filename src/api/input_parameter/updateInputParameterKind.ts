@@ -0,0 +1,15 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ParameterKind } from "../../types/parameter_kind";
+import { UpdateInputParameterKindRequest } from "../../types/input_parameter";
+
+export const updateInputParameterKind = async (id: number, kind: ParameterKind) => {
+    try {
+        await invoke("update_input_parameter_kind", {
+            id,
+            updateRequest: { kind } as UpdateInputParameterKindRequest
+        });
+    } catch (error) {
+        console.error("Failed to change parameter kind:", error);
+        throw error;
+    }
+};
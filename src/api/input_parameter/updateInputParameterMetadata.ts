@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { UpdateInputParameterMetadataRequest } from "../../types/input_parameter";
+
+export const updateInputParameterMetadata = async (id: number, updateRequest: UpdateInputParameterMetadataRequest) => {
+    try {
+        await invoke("update_input_parameter_metadata", { id, updateRequest });
+    } catch (error) {
+        console.error("Failed to update parameter details:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,15 @@
+import { invoke } from "@tauri-apps/api/core";
+import { PartitionMode } from "../../types/partition_mode";
+import { UpdateInputParameterPartitionModeRequest } from "../../types/input_parameter";
+
+export const updateInputParameterPartitionMode = async (id: number, partitionMode: PartitionMode) => {
+    try {
+        await invoke("update_input_parameter_partition_mode", {
+            id,
+            updateRequest: { partition_mode: partitionMode } as UpdateInputParameterPartitionModeRequest
+        });
+    } catch (error) {
+        console.error("Failed to change partition mode:", error);
+        throw error;
+    }
+};
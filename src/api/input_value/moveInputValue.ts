@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+
+/** Puts the input term at `position` among its siblings (after the last one when past the end) */
+export const moveInputValue = async (id: number, position: number) => {
+    try {
+        await invoke("move_input_value", { id, position });
+    } catch (error) {
+        console.error("Failed to move input term:", error);
+        throw error;
+    }
+};
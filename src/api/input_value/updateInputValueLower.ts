@@ -0,0 +1,15 @@
+import { invoke } from "@tauri-apps/api/core";
+import { LowerMembership } from "../../types/lower_membership";
+import { UpdateInputValueLowerRequest } from "../../types/input_value";
+
+export const updateInputValueLower = async (id: number, lower: LowerMembership | null) => {
+    try {
+        await invoke("update_input_value_lower", {
+            id,
+            request: { lower } as UpdateInputValueLowerRequest
+        });
+    } catch (error) {
+        console.error("Failed to update term footprint:", error);
+        throw error;
+    }
+};
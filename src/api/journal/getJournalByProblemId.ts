@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { JournalEntryResponse } from "../../types/journal";
+
+export const getJournalByProblemId = async (problemId: number) => {
+    try {
+        return await invoke<JournalEntryResponse[]>("get_journal_by_problem_id", { problemId });
+    } catch (error) {
+        console.error("Failed to get journal:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { JournalEntryResponse } from "../../types/journal";
+
+export const redoByProblemId = async (problemId: number) => {
+    try {
+        return await invoke<JournalEntryResponse | null>("redo_by_problem_id", { problemId });
+    } catch (error) {
+        console.error("Failed to redo:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { JournalEntryResponse } from "../../types/journal";
+
+export const undoByProblemId = async (problemId: number) => {
+    try {
+        return await invoke<JournalEntryResponse | null>("undo_by_problem_id", { problemId });
+    } catch (error) {
+        console.error("Failed to undo:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ChangeOutputParameterRangeRequest } from "../../types/output_parameter";
+
+export const changeOutputParameterRange = async (id: number, changeRequest: ChangeOutputParameterRangeRequest) => {
+    try {
+        await invoke("change_output_parameter_range", { id, changeRequest });
+    } catch (error) {
+        console.error("Failed to change parameter range:", error);
+        throw error;
+    }
+};
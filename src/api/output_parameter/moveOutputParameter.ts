@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+
+/** Puts the output parameter at `position` among its siblings (after the last one when past the end) */
+export const moveOutputParameter = async (id: number, position: number) => {
+    try {
+        await invoke("move_output_parameter", { id, position });
+    } catch (error) {
+        console.error("Failed to move output parameter:", error);
+        throw error;
+    }
+};
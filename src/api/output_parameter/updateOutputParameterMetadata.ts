@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { UpdateOutputParameterMetadataRequest } from "../../types/output_parameter";
+
+export const updateOutputParameterMetadata = async (id: number, updateRequest: UpdateOutputParameterMetadataRequest) => {
+    try {
+        await invoke("update_output_parameter_metadata", { id, updateRequest });
+    } catch (error) {
+        console.error("Failed to update parameter details:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,15 @@
+import { invoke } from "@tauri-apps/api/core";
+import { PartitionMode } from "../../types/partition_mode";
+import { UpdateOutputParameterPartitionModeRequest } from "../../types/output_parameter";
+
+export const updateOutputParameterPartitionMode = async (id: number, partitionMode: PartitionMode) => {
+    try {
+        await invoke("update_output_parameter_partition_mode", {
+            id,
+            updateRequest: { partition_mode: partitionMode } as UpdateOutputParameterPartitionModeRequest
+        });
+    } catch (error) {
+        console.error("Failed to change partition mode:", error);
+        throw error;
+    }
+};
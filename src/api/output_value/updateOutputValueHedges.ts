@@ -0,0 +1,22 @@
+import { invoke } from "@tauri-apps/api/core";
+import { AntecedentHedge, UpdateOutputValueHedgesRequest } from "../../types/output_value";
+import { LinguisticHedge } from "../../types/fuzzy_inference";
+
+export const updateOutputValueHedges = async (
+    id: number,
+    antecedent_hedges: AntecedentHedge[],
+    consequent_hedge: LinguisticHedge | null
+) => {
+    try {
+        await invoke("update_output_value_hedges", {
+            id,
+            request: {
+                antecedent_hedges,
+                consequent_hedge
+            } as UpdateOutputValueHedgesRequest
+        });
+    } catch (error) {
+        console.error("Failed to update rule hedges:", error);
+        throw error;
+    }
+};
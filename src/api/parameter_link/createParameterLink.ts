@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { CreateParameterLinkRequest } from "../../types/parameter_link";
+
+export const createParameterLink = async (createRequest: CreateParameterLinkRequest) => {
+    try {
+        return await invoke<number>("create_parameter_link", { createRequest });
+    } catch (error) {
+        console.error("Failed to create parameter link:", error);
+        throw error;
+    }
+};
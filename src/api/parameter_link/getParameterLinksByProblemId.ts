@@ -0,0 +1,13 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ParameterLinkResponse } from "../../types/parameter_link";
+
+export const getParameterLinksByProblemId = async (
+    problemId: number
+): Promise<ParameterLinkResponse[]> => {
+    try {
+        return await invoke<ParameterLinkResponse[]>("get_parameter_links_by_problem_id", { problemId });
+    } catch (error) {
+        console.error("Failed to get parameter links:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export const removeParameterLinkById = async (id: number) => {
+    try {
+        return await invoke<void>("remove_parameter_link_by_id", { id });
+    } catch (error) {
+        console.error("Failed to remove parameter link:", error);
+        throw error;
+    }
+};
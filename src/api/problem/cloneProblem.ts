@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { CloneProblemRequest } from "../../types/problem";
+
+export const cloneProblem = async (id: number, cloneRequest: CloneProblemRequest) => {
+    try {
+        return await invoke<number>("clone_problem", { id, cloneRequest });
+    } catch (error) {
+        console.error("Failed to clone problem:", error);
+        throw error;
+    }
+};
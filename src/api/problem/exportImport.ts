@@ -1,13 +1,9 @@
 import { save, open } from '@tauri-apps/plugin-dialog';
-import { writeTextFile, readTextFile } from '@tauri-apps/plugin-fs';
 import { invoke } from '@tauri-apps/api/core';
-import { ExportedProblem } from '../../types/export_import';
+import { ExportedProblem, FuzzyFileCompression } from '../../types/export_import';
 
-export const exportProblemById = async (id: number, name?: string) => {
+export const exportProblemById = async (id: number, name?: string, compression: FuzzyFileCompression = 'none') => {
     try {
-        const data = await invoke<ExportedProblem>('export_problem', { id });
-        const json = JSON.stringify(data, null, 2);
-        
         const path = await save({
             filters: [{
                 name: 'Fuzzy DB Problem',
@@ -17,7 +13,7 @@ export const exportProblemById = async (id: number, name?: string) => {
         });
         
         if (path) {
-            await writeTextFile(path, json);
+            await invoke('export_problem_to_file', { id, path, compression });
             return true;
         }
         return false;
@@ -41,10 +37,7 @@ export const importProblemToParent = async (parentId: number | null, onFinish?:
             const filePath = result as string; // multiple: false returns string | null (in recent versions, or just string)
             // Note: Types might imply string | string[] depending on version. Casting safely.
             
-            const text = await readTextFile(filePath);
-            const data = JSON.parse(text) as ExportedProblem;
-            
-            await invoke('import_problem', { parentId, data });
+            await invoke('import_problem_from_file', { parentId, path: filePath });
             if (onFinish) onFinish();
             return true;
         }
@@ -54,3 +47,12 @@ export const importProblemToParent = async (parentId: number | null, onFinish?:
          throw e;
      }
 }
+
+export const readProblemFile = async (path: string) => {
+    try {
+        return await invoke<ExportedProblem>('read_problem_file', { path });
+    } catch (e) {
+        console.error("Failed to read problem file:", e);
+        throw e;
+    }
+}
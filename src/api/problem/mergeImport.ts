@@ -0,0 +1,20 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ExportedProblem, MergePreviewResponse } from "../../types/export_import";
+
+export const previewMergeImport = async (problemId: number, data: ExportedProblem) => {
+    try {
+        return await invoke<MergePreviewResponse>("preview_merge_import", { problemId, data });
+    } catch (error) {
+        console.error("Failed to preview merge import:", error);
+        throw error;
+    }
+};
+
+export const mergeImport = async (problemId: number, data: ExportedProblem) => {
+    try {
+        return await invoke<MergePreviewResponse>("merge_import", { problemId, data });
+    } catch (error) {
+        console.error("Failed to merge import:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { MoveProblemRequest } from "../../types/problem";
+
+export const moveProblem = async (id: number, moveRequest: MoveProblemRequest) => {
+    try {
+        return await invoke<void>("move_problem", { id, moveRequest });
+    } catch (error) {
+        console.error("Failed to move problem:", error);
+        throw error;
+    }
+};
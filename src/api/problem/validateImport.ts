@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ExportedProblem, ImportValidationResponse } from "../../types/export_import";
+
+export const validateImport = async (data: ExportedProblem) => {
+    try {
+        return await invoke<ImportValidationResponse>("validate_import", { data });
+    } catch (error) {
+        console.error("Failed to validate import:", error);
+        throw error;
+    }
+};
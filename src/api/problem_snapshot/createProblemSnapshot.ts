@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export const createProblemSnapshot = async (problemId: number, name: string) => {
+    try {
+        return await invoke<number>("create_problem_snapshot", { problemId, name });
+    } catch (error) {
+        console.error("Failed to create problem snapshot:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,7 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ProblemDiffResponse } from "../../types/problem_snapshot";
+
+/** Omit `newId` to compare the snapshot with the current definition */
+export async function diffProblemSnapshots(oldId: number, newId?: number): Promise<ProblemDiffResponse> {
+    return await invoke<ProblemDiffResponse>("diff_problem_snapshots", { oldId, newId });
+}
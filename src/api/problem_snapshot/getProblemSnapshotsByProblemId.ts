@@ -0,0 +1,13 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ProblemSnapshotResponse } from "../../types/problem_snapshot";
+
+export const getProblemSnapshotsByProblemId = async (
+    problemId: number
+): Promise<ProblemSnapshotResponse[]> => {
+    try {
+        return await invoke<ProblemSnapshotResponse[]>("get_problem_snapshots_by_problem_id", { problemId });
+    } catch (error) {
+        console.error("Failed to get problem snapshots:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export const removeProblemSnapshotById = async (id: number) => {
+    try {
+        await invoke<void>("remove_problem_snapshot_by_id", { id });
+    } catch (error) {
+        console.error("Failed to remove problem snapshot:", error);
+        throw error;
+    }
+};
@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export const restoreProblemSnapshot = async (id: number) => {
+    try {
+        await invoke<void>("restore_problem_snapshot", { id });
+    } catch (error) {
+        console.error("Failed to restore problem snapshot:", error);
+        throw error;
+    }
+};
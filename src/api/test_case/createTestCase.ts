@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { CreateTestCaseRequest } from "../../types/test_case";
+
+export const createTestCase = async (createRequest: CreateTestCaseRequest): Promise<number> => {
+    try {
+        return await invoke<number>("create_test_case", { createRequest });
+    } catch (error) {
+        console.error("Failed to create test case:", error);
+        throw error;
+    }
+};
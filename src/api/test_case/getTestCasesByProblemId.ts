@@ -0,0 +1,13 @@
+import { invoke } from "@tauri-apps/api/core";
+import { TestCaseResponse } from "../../types/test_case";
+
+export const getTestCasesByProblemId = async (
+    problemId: number
+): Promise<TestCaseResponse[]> => {
+    try {
+        return await invoke<TestCaseResponse[]>("get_test_cases_by_problem_id", { problemId });
+    } catch (error) {
+        console.error("Failed to get test cases:", error);
+        throw error;
+    }
+};
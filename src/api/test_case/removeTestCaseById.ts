@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export const removeTestCaseById = async (id: number) => {
+    try {
+        await invoke<void>("remove_test_case_by_id", { id });
+    } catch (error) {
+        console.error("Failed to remove test case:", error);
+        throw error;
+    }
+};
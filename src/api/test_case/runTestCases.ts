@@ -0,0 +1,6 @@
+import { invoke } from "@tauri-apps/api/core";
+import { RunTestCasesRequest, TestRunReportDto } from "../../types/test_case";
+
+export async function runTestCases(request: RunTestCasesRequest): Promise<TestRunReportDto> {
+    return await invoke<TestRunReportDto>("run_test_cases", { request });
+}
@@ -0,0 +1,11 @@
+import { invoke } from "@tauri-apps/api/core";
+import { UpdateTestCaseRequest } from "../../types/test_case";
+
+export const updateTestCaseById = async (id: number, updateRequest: UpdateTestCaseRequest) => {
+    try {
+        await invoke<void>("update_test_case_by_id", { id, updateRequest });
+    } catch (error) {
+        console.error("Failed to update test case:", error);
+        throw error;
+    }
+};
@@ -8,6 +8,7 @@ import {
     FuzzyInputDto,
 } from '../../types/fuzzy_inference';
 import { InputParameterResponse } from '../../types/input_parameter';
+import { errorMessage } from '../../types/error';
 
 interface FuzzyEvaluationProps {
     problemId: number;
@@ -25,11 +26,20 @@ const FuzzyEvaluation: React.FC<FuzzyEvaluationProps> = ({
     const [error, setError] = useState<string | null>(null);
     const [loading, setLoading] = useState(false);
 
-    // Initialize input values with midpoints
+    // Initialize input values with defaults, falling back to midpoints;
+    // categorical parameters hold the id of the selected category
+    // (the default one, or the first)
     useEffect(() => {
         const initialValues: Record<number, number> = {};
         inputParameters.forEach((param) => {
-            initialValues[param.id] = (param.start + param.end) / 2;
+            if (param.kind === 'categorical') {
+                const category = param.input_values[param.default_value ?? 0] ?? param.input_values[0];
+                if (category) {
+                    initialValues[param.id] = category.id;
+                }
+            } else {
+                initialValues[param.id] = param.default_value ?? (param.start + param.end) / 2;
+            }
         });
         setInputValues(initialValues);
     }, [inputParameters]);
@@ -47,11 +57,13 @@ const FuzzyEvaluation: React.FC<FuzzyEvaluationProps> = ({
         setResult(null);
 
         try {
+            const categorical = new Set(
+                inputParameters.filter((param) => param.kind === 'categorical').map((param) => param.id)
+            );
             const inputs: FuzzyInputDto[] = Object.entries(inputValues).map(
-                ([paramId, value]) => ({
-                    input_parameter_id: parseInt(paramId),
-                    crisp_value: value,
-                })
+                ([paramId, value]) => categorical.has(parseInt(paramId))
+                    ? { input_parameter_id: parseInt(paramId), crisp_value: 0, input_value_id: value }
+                    : { input_parameter_id: parseInt(paramId), crisp_value: value }
             );
 
             const response = await evaluateFuzzySystem({
@@ -63,7 +75,7 @@ const FuzzyEvaluation: React.FC<FuzzyEvaluationProps> = ({
 
             setResult(response);
         } catch (err) {
-            setError(err instanceof Error ? err.message : String(err));
+            setError(errorMessage(err));
         } finally {
             setLoading(false);
         }
@@ -86,20 +98,39 @@ const FuzzyEvaluation: React.FC<FuzzyEvaluationProps> = ({
                     {inputParameters.map((param) => (
                         <div key={param.id} className={classes.InputCard}>
                             <label className={classes.InputLabel}>{param.name}</label>
-                            <span className={classes.InputRange}>
-                                Диапазон: [{param.start.toFixed(2)}, {param.end.toFixed(2)}]
-                            </span>
-                            <div className={classes.InputField}>
-                                <input
-                                    type="number"
-                                    className={classes.NumberInput}
-                                    value={inputValues[param.id] ?? ''}
-                                    onChange={(e) => handleInputChange(param.id, e.target.value)}
-                                    min={param.start}
-                                    max={param.end}
-                                    step={(param.end - param.start) / 100}
-                                />
-                            </div>
+                            {param.kind === 'categorical' ? (
+                                <div className={classes.InputField}>
+                                    <select
+                                        className={classes.MethodSelect}
+                                        value={inputValues[param.id] ?? ''}
+                                        onChange={(e) => handleInputChange(param.id, e.target.value)}
+                                    >
+                                        {param.input_values.map((iv) => (
+                                            <option key={iv.id} value={iv.id}>
+                                                {iv.value}
+                                            </option>
+                                        ))}
+                                    </select>
+                                </div>
+                            ) : (
+                                <>
+                                    <span className={classes.InputRange}>
+                                        Диапазон: [{param.start.toFixed(2)}, {param.end.toFixed(2)}]
+                                        {param.unit && ` ${param.unit}`}
+                                    </span>
+                                    <div className={classes.InputField}>
+                                        <input
+                                            type="number"
+                                            className={classes.NumberInput}
+                                            value={inputValues[param.id] ?? ''}
+                                            onChange={(e) => handleInputChange(param.id, e.target.value)}
+                                            min={param.start}
+                                            max={param.end}
+                                            step={(param.end - param.start) / 100}
+                                        />
+                                    </div>
+                                </>
+                            )}
                             {param.input_values.length > 0 && (
                                 <div className={classes.MembershipList}>
                                     {param.input_values.map((iv) => (
@@ -156,7 +187,7 @@ const FuzzyEvaluation: React.FC<FuzzyEvaluationProps> = ({
                                         {output.output_parameter_name}
                                     </span>
                                     <span className={classes.OutputValue}>
-                                        {output.crisp_value.toFixed(4)}
+                                        {output.display_value}
                                     </span>
                                 </div>
                                 <div className={classes.OutputDetails}>
@@ -175,7 +206,7 @@ const FuzzyEvaluation: React.FC<FuzzyEvaluationProps> = ({
                                             className={classes.FuzzifiedInput}
                                         >
                                             <div className={classes.FuzzifiedInputName}>
-                                                {fi.input_parameter_name} = {fi.crisp_value.toFixed(4)}
+                                                {fi.input_parameter_name} = {fi.display_value}
                                             </div>
                                             <div className={classes.MembershipList}>
                                                 {fi.membership_degrees
@@ -4,13 +4,15 @@ import { InputParameterResponse } from '../../../types/input_parameter';
 import AccentButton from '../../../ui/buttons/AccentButton/AccentButton';
 import { createInputValue } from '../../../api/input_value/createInputValue';
 import { updateInputParameterById } from '../../../api/input_parameter/updateInputParameter';
+import { changeInputParameterRange } from '../../../api/input_parameter/changeInputParameterRange';
 import { removeInputParameterById } from '../../../api/input_parameter/removeInputParameterById';
 import InputValueUnit from './InputValueUnit/InputValueUnit';
 import { InputValueResponse } from '../../../types/input_value';
 import FuzzyGraph from '../../../components/FuzzyGraph/FuzzyGraph';
 import { updateInputValueById } from '../../../api/input_value/updateInputValueById';
 import { removeInputValueById } from '../../../api/input_value/removeInputValueById';
-import { switchInputValues } from '../../../api/input_value/switchInputValues';
+import { moveInputValue } from '../../../api/input_value/moveInputValue';
+import { updateInputParameterKind } from '../../../api/input_parameter/updateInputParameterKind';
 import classes from './InputParameterCard.module.css';
 
 interface InputParameterCardProps {
@@ -73,8 +75,11 @@ const InputParameterCard: React.FC<InputParameterCardProps> = ({
         }
     }, [localInputValues, setInputParameter]);
 
-    // Sort input values by 'a' for consistent ordering with graph
-    const sortedInputValues = [...localInputValues].sort((a, b) => a.a - b.a);
+    // Terms come in their stored order; a Ruspini partition is also sorted by 'a'
+    // for consistent ordering with graph
+    const sortedInputValues = inputParameter.partition_mode === "free_form"
+        ? localInputValues
+        : [...localInputValues].sort((a, b) => a.a - b.a);
 
     // Update a single term and sync adjacent terms using Ruspini partition rules:
     // A.c = B.a, A.d = B.b (overlapping terms)
@@ -185,63 +190,35 @@ const InputParameterCard: React.FC<InputParameterCardProps> = ({
         });
     }, [refetchData]);
 
-    // Обработчик switch (перемещение терма)
-    const handleSwitch = useCallback((id1: number, id2: number) => {
-        switchInputValues(id1, id2, () => {
-            refetchData();
-        });
+    // Move a term to another position; a Ruspini term takes over the shape found there
+    const handleMove = useCallback((id: number, position: number) => {
+        moveInputValue(id, position).then(refetchData);
     }, [refetchData]);
 
     return (
         <ParameterCard
             name={inputParameter.name}
             setParameter={(name: string, start: number, end: number) => {
-                const startChanged = start !== inputParameter.start;
-                const endChanged = end !== inputParameter.end;
-                
-                updateInputParameterById(inputParameter.id, {
+                const rangeChanged = start !== inputParameter.start || end !== inputParameter.end;
+                const rename = () => updateInputParameterById(inputParameter.id, {
                     name: name,
                     start: start,
                     end: end
-                }, () => { 
+                }, () => {
                     setInputParameter({ ...inputParameter, name: name, start: start, end: end });
-                    
-                    // Обновить граничные термы при изменении start/end
-                    const sorted = [...localInputValues].sort((a, b) => a.a - b.a);
-                    if (sorted.length > 0) {
-                        let needsRefetch = false;
-                        if (startChanged) {
-                            // Обновить первую терму: a и b должны быть равны start
-                            const firstTerm = sorted[0];
-                            updateInputValueById(firstTerm.id, {
-                                value: firstTerm.value,
-                                a: start,
-                                b: start,
-                                c: firstTerm.c,
-                                d: firstTerm.d,
-                                is_triangle: firstTerm.is_triangle,
-                            }, () => {});
-                            needsRefetch = true;
-                        }
-                        if (endChanged) {
-                            // Обновить последнюю терму: c и d должны быть равны end
-                            const lastTerm = sorted[sorted.length - 1];
-                            updateInputValueById(lastTerm.id, {
-                                value: lastTerm.value,
-                                a: lastTerm.a,
-                                b: lastTerm.b,
-                                c: end,
-                                d: end,
-                                is_triangle: lastTerm.is_triangle,
-                            }, () => {});
-                            needsRefetch = true;
-                        }
-                        if (needsRefetch) {
-                            // Обновить данные после изменения термов
-                            setTimeout(() => refetchData(), 100);
-                        }
+                    // The terms were rescaled to the new range on the backend
+                    if (rangeChanged) {
+                        refetchData();
                     }
                 });
+
+                if (rangeChanged) {
+                    changeInputParameterRange(inputParameter.id, { start, end, policy: "rescale" })
+                        .then(rename)
+                        .catch(refetchData);
+                } else {
+                    rename();
+                }
             }}
             start={inputParameter.start}
             end={inputParameter.end}
@@ -253,7 +230,9 @@ const InputParameterCard: React.FC<InputParameterCardProps> = ({
             canSwitchUp={canSwitchUp}
             canSwitchDown={canSwitchDown}
         >
-            <FuzzyGraph start={inputParameter.start} end={inputParameter.end} units={sortedInputValues} />
+            {/* Categories have no shapes worth plotting */}
+            {inputParameter.kind !== "categorical" &&
+                <FuzzyGraph start={inputParameter.start} end={inputParameter.end} units={sortedInputValues} />}
             {
                 sortedInputValues.map((inputValue, index) =>
                     <InputValueUnit
@@ -266,8 +245,9 @@ const InputParameterCard: React.FC<InputParameterCardProps> = ({
                         isLast={index === sortedInputValues.length - 1}
                         onValueChange={(updated: InputValueResponse) => handleTermChange(updated)}
                         onDelete={() => handleDeleteTerm(inputValue.id)}
-                        onMoveUp={index > 0 ? () => handleSwitch(inputValue.id, sortedInputValues[index - 1].id) : undefined}
-                        onMoveDown={index < sortedInputValues.length - 1 ? () => handleSwitch(inputValue.id, sortedInputValues[index + 1].id) : undefined}
+                        onMoveUp={index > 0 ? () => handleMove(inputValue.id, index - 1) : undefined}
+                        onMoveDown={index < sortedInputValues.length - 1 ? () => handleMove(inputValue.id, index + 1) : undefined}
+                        isCategory={inputParameter.kind === "categorical"}
                     />
                 )
             }
@@ -278,7 +258,13 @@ const InputParameterCard: React.FC<InputParameterCardProps> = ({
                             refetchData();
                         }
                     )
-                }>Добавить терм</AccentButton>
+                }>{inputParameter.kind === "categorical" ? "Добавить категорию" : "Добавить терм"}</AccentButton>
+                <AccentButton onClick={
+                    () => updateInputParameterKind(
+                        inputParameter.id,
+                        inputParameter.kind === "categorical" ? "continuous" : "categorical"
+                    ).then(refetchData)
+                }>{inputParameter.kind === "categorical" ? "Сделать числовым" : "Сделать категориальным"}</AccentButton>
             </div>
         </ParameterCard>
     );
@@ -20,6 +20,8 @@ interface InputValueUnitProps {
     onDelete: () => void;
     onMoveUp?: () => void;
     onMoveDown?: () => void;
+    /** Categories have only a name; their breakpoints are not shown */
+    isCategory?: boolean;
 }
 
 const InputValueUnit: React.FC<InputValueUnitProps> = ({
@@ -33,6 +35,7 @@ const InputValueUnit: React.FC<InputValueUnitProps> = ({
     onDelete,
     onMoveUp,
     onMoveDown,
+    isCategory = false,
 }) => {
     const color = COLORS[index % COLORS.length];
     
@@ -228,7 +231,7 @@ const InputValueUnit: React.FC<InputValueUnitProps> = ({
                     </button>
                 </div>
             </div>
-            <div className={classes.Parameters}>
+            {!isCategory && <div className={classes.Parameters}>
                 <div className={classes.ParameterRow}>
                     <span className={classes.ParameterLabel}>a:</span>
                     <input
@@ -281,7 +284,7 @@ const InputValueUnit: React.FC<InputValueUnitProps> = ({
                         title={isLast ? "Фиксировано (конец диапазона)" : "Правая граница (c < d, = next.b)"}
                     />
                 </div>
-            </div>
+            </div>}
         </div>
     );
 };
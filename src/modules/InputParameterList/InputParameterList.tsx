@@ -1,7 +1,7 @@
 import { InputParameterResponse } from '../../types/input_parameter';
 import classes from './InputParameter.module.css';
 import InputParameterCard from './InputParameterCard/InputParameterCard';
-import { switchInputParameters } from '../../api/input_parameter/switchInputParameters';
+import { moveInputParameter } from '../../api/input_parameter/moveInputParameter';
 
 interface InputParameterListProps {
     inputParameters: Array<InputParameterResponse>,
@@ -18,17 +18,9 @@ const InputParameterList: React.FC<InputParameterListProps> = ({
     openCards,
     setOpenCards,
 }) => {
-    // Обработчики для перемещения параметров
-    const handleSwitchUp = (id1: number, id2: number) => {
-        switchInputParameters(id1, id2, () => {
-            refetchData();
-        });
-    };
-
-    const handleSwitchDown = (id1: number, id2: number) => {
-        switchInputParameters(id1, id2, () => {
-            refetchData();
-        });
+    // Обработчик перемещения параметров
+    const handleMove = (id: number, position: number) => {
+        moveInputParameter(id, position).then(refetchData);
     };
 
     return (
@@ -50,8 +42,8 @@ const InputParameterList: React.FC<InputParameterListProps> = ({
                         refetchData={refetchData}
                         isOpen={openCards[inputParameter.id] ?? true}
                         setIsOpen={(open) => setOpenCards({ ...openCards, [inputParameter.id]: open })}
-                        switchUpCallback={index > 0 ? () => handleSwitchUp(inputParameter.id, inputParameters[index - 1].id) : undefined}
-                        switchDownCallback={index < inputParameters.length - 1 ? () => handleSwitchDown(inputParameter.id, inputParameters[index + 1].id) : undefined}
+                        switchUpCallback={index > 0 ? () => handleMove(inputParameter.id, index - 1) : undefined}
+                        switchDownCallback={index < inputParameters.length - 1 ? () => handleMove(inputParameter.id, index + 1) : undefined}
                         canSwitchUp={index > 0}
                         canSwitchDown={index < inputParameters.length - 1}
                         key={inputParameter.id}
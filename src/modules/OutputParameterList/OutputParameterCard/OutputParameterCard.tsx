@@ -4,13 +4,14 @@ import { OutputParameterResponse } from '../../../types/output_parameter';
 import AccentButton from '../../../ui/buttons/AccentButton/AccentButton';
 import { createFuzzyOutputValue } from '../../../api/fuzzy_output_value/createFuzzyOutputValue';
 import { updateOutputParameterById } from '../../../api/output_parameter/updateOutputParameterById';
+import { changeOutputParameterRange } from '../../../api/output_parameter/changeOutputParameterRange';
 import { removeOutputParameterById } from '../../../api/output_parameter/removeOutputParameterById';
 import FuzzyOutputValueUnit from './FuzzyOutputValueUnit/FuzzyOutputValueUnit';
 import { FuzzyOutputValueResponse } from '../../../types/fuzzy_output_value';
 import FuzzyGraph from '../../../components/FuzzyGraph/FuzzyGraph';
 import { updateFuzzyOutputValueById } from '../../../api/fuzzy_output_value/updateFuzzyOutputValueById';
 import { removeFuzzyOutputValueById } from '../../../api/fuzzy_output_value/removeFuzzyOutputValueById';
-import { switchFuzzyOutputValues } from '../../../api/fuzzy_output_value/switchFuzzyOutputValues';
+import { moveFuzzyOutputValue } from '../../../api/fuzzy_output_value/moveFuzzyOutputValue';
 import classes from './OutputParameterCard.module.css';
 
 interface OutputParameterCardProps {
@@ -73,8 +74,11 @@ const OutputParameterCard: React.FC<OutputParameterCardProps> = ({
         }
     }, [localOutputValues, setOutputParameter]);
 
-    // Sort fuzzy output values by 'a' for consistent ordering with graph
-    const sortedFuzzyOutputValues = [...localOutputValues].sort((a, b) => a.a - b.a);
+    // Terms come in their stored order; a Ruspini partition is also sorted by 'a'
+    // for consistent ordering with graph
+    const sortedFuzzyOutputValues = outputParameter.partition_mode === "free_form"
+        ? localOutputValues
+        : [...localOutputValues].sort((a, b) => a.a - b.a);
 
     // Update a single term and sync adjacent terms using Ruspini partition rules:
     // Same logic as input parameters - overlapping trapezoidal membership functions
@@ -184,63 +188,35 @@ const OutputParameterCard: React.FC<OutputParameterCardProps> = ({
         });
     }, [refetchData]);
 
-    // Switch two terms (swap their a,b,c,d values, not IDs or names)
-    const handleSwitch = useCallback((id1: number, id2: number) => {
-        switchFuzzyOutputValues(id1, id2, () => {
-            refetchData();
-        });
+    // Move a term to another position; a Ruspini term takes over the shape found there
+    const handleMove = useCallback((id: number, position: number) => {
+        moveFuzzyOutputValue(id, position).then(refetchData);
     }, [refetchData]);
 
     return (
         <ParameterCard
             name={outputParameter.name}
             setParameter={(name: string, start: number, end: number) => {
-                const startChanged = start !== outputParameter.start;
-                const endChanged = end !== outputParameter.end;
-                
-                updateOutputParameterById(outputParameter.id, {
+                const rangeChanged = start !== outputParameter.start || end !== outputParameter.end;
+                const rename = () => updateOutputParameterById(outputParameter.id, {
                     name: name,
                     start: start,
                     end: end
-                }, () => { 
+                }, () => {
                     setOutputParameter({ ...outputParameter, name: name, start: start, end: end });
-                    
-                    // Обновить граничные термы при изменении start/end
-                    const sorted = [...localOutputValues].sort((a, b) => a.a - b.a);
-                    if (sorted.length > 0) {
-                        let needsRefetch = false;
-                        if (startChanged) {
-                            // Обновить первую терму: a и b должны быть равны start
-                            const firstTerm = sorted[0];
-                            updateFuzzyOutputValueById(firstTerm.id, {
-                                value: firstTerm.value,
-                                a: start,
-                                b: start,
-                                c: firstTerm.c,
-                                d: firstTerm.d,
-                                is_triangle: firstTerm.is_triangle,
-                            }, () => {});
-                            needsRefetch = true;
-                        }
-                        if (endChanged) {
-                            // Обновить последнюю терму: c и d должны быть равны end
-                            const lastTerm = sorted[sorted.length - 1];
-                            updateFuzzyOutputValueById(lastTerm.id, {
-                                value: lastTerm.value,
-                                a: lastTerm.a,
-                                b: lastTerm.b,
-                                c: end,
-                                d: end,
-                                is_triangle: lastTerm.is_triangle,
-                            }, () => {});
-                            needsRefetch = true;
-                        }
-                        if (needsRefetch) {
-                            // Обновить данные после изменения термов
-                            setTimeout(() => refetchData(), 100);
-                        }
+                    // The terms were rescaled to the new range on the backend
+                    if (rangeChanged) {
+                        refetchData();
                     }
                 });
+
+                if (rangeChanged) {
+                    changeOutputParameterRange(outputParameter.id, { start, end, policy: "rescale" })
+                        .then(rename)
+                        .catch(refetchData);
+                } else {
+                    rename();
+                }
             }}
             start={outputParameter.start}
             end={outputParameter.end}
@@ -265,8 +241,8 @@ const OutputParameterCard: React.FC<OutputParameterCardProps> = ({
                         isLast={index === sortedFuzzyOutputValues.length - 1}
                         onValueChange={(updated: FuzzyOutputValueResponse) => handleTermChange(updated)}
                         onDelete={() => handleDeleteTerm(fuzzyOutputValue.id)}
-                        onMoveUp={index > 0 ? () => handleSwitch(fuzzyOutputValue.id, sortedFuzzyOutputValues[index - 1].id) : undefined}
-                        onMoveDown={index < sortedFuzzyOutputValues.length - 1 ? () => handleSwitch(fuzzyOutputValue.id, sortedFuzzyOutputValues[index + 1].id) : undefined}
+                        onMoveUp={index > 0 ? () => handleMove(fuzzyOutputValue.id, index - 1) : undefined}
+                        onMoveDown={index < sortedFuzzyOutputValues.length - 1 ? () => handleMove(fuzzyOutputValue.id, index + 1) : undefined}
                     />
                 )
             }
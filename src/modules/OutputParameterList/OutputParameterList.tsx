@@ -1,7 +1,7 @@
 import { OutputParameterResponse } from '../../types/output_parameter';
 import classes from './OutputParameter.module.css';
 import OutputParameterCard from './OutputParameterCard/OutputParameterCard';
-import { switchOutputParameters } from '../../api/output_parameter/switchOutputParameters';
+import { moveOutputParameter } from '../../api/output_parameter/moveOutputParameter';
 
 interface OutputParameterListProps {
     outputParameters: Array<OutputParameterResponse>,
@@ -18,17 +18,9 @@ const OutputParameterList: React.FC<OutputParameterListProps> = ({
     openCards,
     setOpenCards,
 }) => {
-    // Обработчики для перемещения параметров
-    const handleSwitchUp = (id1: number, id2: number) => {
-        switchOutputParameters(id1, id2, () => {
-            refetchData();
-        });
-    };
-
-    const handleSwitchDown = (id1: number, id2: number) => {
-        switchOutputParameters(id1, id2, () => {
-            refetchData();
-        });
+    // Обработчик перемещения параметров
+    const handleMove = (id: number, position: number) => {
+        moveOutputParameter(id, position).then(refetchData);
     };
 
     return (
@@ -50,8 +42,8 @@ const OutputParameterList: React.FC<OutputParameterListProps> = ({
                         refetchData={refetchData}
                         isOpen={openCards[outputParameter.id] ?? true}
                         setIsOpen={(open) => setOpenCards({ ...openCards, [outputParameter.id]: open })}
-                        switchUpCallback={index > 0 ? () => handleSwitchUp(outputParameter.id, outputParameters[index - 1].id) : undefined}
-                        switchDownCallback={index < outputParameters.length - 1 ? () => handleSwitchDown(outputParameter.id, outputParameters[index + 1].id) : undefined}
+                        switchUpCallback={index > 0 ? () => handleMove(outputParameter.id, index - 1) : undefined}
+                        switchDownCallback={index < outputParameters.length - 1 ? () => handleMove(outputParameter.id, index + 1) : undefined}
                         canSwitchUp={index > 0}
                         canSwitchDown={index < outputParameters.length - 1}
                         key={outputParameter.id}
@@ -0,0 +1,40 @@
+export type ErrorCode = 'not_found' | 'validation' | 'data' | 'internal' | 'cancelled' | 'io';
+
+export type EntityKind =
+    | 'problem'
+    | 'input_parameter'
+    | 'input_value'
+    | 'output_parameter'
+    | 'fuzzy_output_value'
+    | 'output_value'
+    | 'image'
+    | 'test_case'
+    | 'evaluation_record'
+    | 'problem_snapshot'
+    | 'journal_entry'
+    | 'parameter_link';
+
+export type FieldError = {
+    field: string;
+    message: string;
+}
+
+/** Error every command rejects with */
+export type ErrorResponse = {
+    code: ErrorCode;
+    message: string;
+    entity: EntityKind | null;
+    id: number | null;
+    fields: FieldError[];
+}
+
+export const isErrorResponse = (error: unknown): error is ErrorResponse =>
+    typeof error === 'object' && error !== null && 'code' in error && 'message' in error;
+
+/** User-facing message of anything a command or the frontend may throw */
+export const errorMessage = (error: unknown): string => {
+    if (isErrorResponse(error)) {
+        return error.message;
+    }
+    return error instanceof Error ? error.message : String(error);
+}
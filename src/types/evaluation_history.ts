@@ -0,0 +1,51 @@
+import { InputUncertainty } from "./fuzzy_inference";
+
+export type EvaluationHistoryFilterRequest = {
+    problem_id?: number;
+    method?: string;
+    definition_hash?: string;
+    from?: string;
+    to?: string;
+    limit?: number;
+    offset?: number;
+}
+
+export type EvaluationRecordInputDto = {
+    input_parameter_id: number;
+    crisp_value: number;
+    uncertainty: InputUncertainty | null;
+    input_value_id: number | null;
+}
+
+export type EvaluationRecordOutputDto = {
+    output_parameter_id: number;
+    output_parameter_name: string;
+    crisp_value: number;
+}
+
+export type EvaluationRecordResponse = {
+    id: number;
+    problem_id: number;
+    problem_name: string;
+    inputs: EvaluationRecordInputDto[];
+    method: string;
+    resolution: number;
+    outputs: EvaluationRecordOutputDto[];
+    definition_hash: string;
+    created_at: string;
+}
+
+export type ReplayOutputComparisonDto = {
+    output_parameter_id: number;
+    output_parameter_name: string;
+    recorded_value: number | null;
+    current_value: number | null;
+    difference: number | null;
+}
+
+export type ReplayEvaluationResponse = {
+    record: EvaluationRecordResponse;
+    current_definition_hash: string;
+    definition_changed: boolean;
+    outputs: ReplayOutputComparisonDto[];
+}
@@ -1,11 +1,27 @@
+import { LowerMembership } from "./lower_membership";
+import { ParameterKind } from "./parameter_kind";
+import { PartitionMode } from "./partition_mode";
+import { ProblemDiffResponse } from "./problem_snapshot";
+
+export type FuzzyFileCompression = 'none' | 'gzip' | 'zstd';
+
+export type FuzzyFile = {
+    format_version: number;
+    generator: string;
+    exported_at: string;
+    problem: ExportedProblem;
+}
+
 export type ExportedProblem = {
     name: string;
     description: string;
     is_final: boolean;
-    image: { data: number[]; format: string } | null;
+    // base64; files written before format version 2 hold an array of bytes
+    image: { data: string | number[]; format: string } | null;
     input_parameters: ExportedInputParameter[];
     output_parameters: ExportedOutputParameter[];
     output_values: ExportedOutputValue[];
+    test_cases?: ExportedTestCase[];
     children: ExportedProblem[];
 }
 
@@ -14,6 +30,12 @@ export type ExportedInputParameter = {
     name: string;
     start: number;
     end: number;
+    partition_mode?: PartitionMode;
+    kind?: ParameterKind;
+    unit?: string;
+    description?: string;
+    precision?: number | null;
+    default_value?: number | null;
     values: ExportedInputValue[];
 }
 
@@ -25,6 +47,7 @@ export type ExportedInputValue = {
     c: number;
     d: number;
     is_triangle: boolean;
+    lower?: LowerMembership | null;
 }
 
 export type ExportedOutputParameter = {
@@ -32,6 +55,11 @@ export type ExportedOutputParameter = {
     name: string;
     start: number;
     end: number;
+    partition_mode?: PartitionMode;
+    unit?: string;
+    description?: string;
+    precision?: number | null;
+    default_value?: number | null;
     values: ExportedFuzzyOutputValue[];
 }
 
@@ -43,10 +71,48 @@ export type ExportedFuzzyOutputValue = {
     c: number;
     d: number;
     is_triangle: boolean;
+    lower?: LowerMembership | null;
 }
 
 export type ExportedOutputValue = {
     output_parameter_temp_id: number;
     fuzzy_output_value_temp_id: number | null;
     input_value_temp_ids: number[];
+    antecedent_hedges?: {
+        input_value_temp_id: number;
+        hedge: string;
+    }[];
+    consequent_hedge?: string | null;
+}
+
+export type ExportedTestCase = {
+    name: string;
+    inputs: { input_parameter_temp_id: number; crisp_value: number }[];
+    expectations: {
+        output_parameter_temp_id: number;
+        expected_value: number | null;
+        tolerance: number;
+        expected_fuzzy_output_value_temp_id: number | null;
+    }[];
+}
+
+export type ImportIssueDto = {
+    path: string;
+    message: string;
+}
+
+export type ImportValidationResponse = {
+    is_valid: boolean;
+    errors: ImportIssueDto[];
+    warnings: ImportIssueDto[];
+}
+
+export type MergeConflictDto = {
+    subject: string;
+    message: string;
+}
+
+export type MergePreviewResponse = {
+    diff: ProblemDiffResponse;
+    conflicts: MergeConflictDto[];
 }
@@ -1,8 +1,25 @@
 // Types for fuzzy inference evaluation
 
+export type InputUncertainty =
+    | { shape: 'triangular'; spread: number }
+    | { shape: 'gaussian'; sigma: number };
+
+/** Degree supplied for one category of a categorical parameter */
+export type CategoryDegree = {
+    input_value_id: number;
+    degree: number;
+};
+
 export type FuzzyInputDto = {
     input_parameter_id: number;
+    /** Unused for categorical parameters */
     crisp_value: number;
+    /** Omitted for exact values */
+    uncertainty?: InputUncertainty | null;
+    /** Selected category of a categorical parameter */
+    input_value_id?: number | null;
+    /** Degree per category of a categorical parameter; omitted to select the category input_value_id */
+    category_degrees?: CategoryDegree[] | null;
 };
 
 export type EvaluateFuzzySystemRequest = {
@@ -20,22 +37,176 @@ export type MembershipDegreeDto = {
 export type FuzzifiedInputInfoDto = {
     input_parameter_id: number;
     input_parameter_name: string;
+    unit: string;
     crisp_value: number;
+    /** Crisp value with the parameter's precision and unit, e.g. "21.5 °C" */
+    display_value: string;
     membership_degrees: MembershipDegreeDto[];
 };
 
+export type TermDegreeDto = {
+    fuzzy_output_value_id: number;
+    linguistic_term: string;
+    degree: number;
+};
+
+export type LinguisticHedge = 'very' | 'extremely' | 'somewhat' | 'slightly';
+
+export type LinguisticApproximationDto = {
+    fuzzy_output_value_id: number;
+    linguistic_term: string;
+    hedge: LinguisticHedge | null;
+    label: string;
+    similarity: number;
+};
+
 export type FuzzyOutputResultDto = {
     output_parameter_id: number;
     output_parameter_name: string;
+    unit: string;
     crisp_value: number;
+    /** Crisp value with the parameter's precision and unit, e.g. "21.5 °C" */
+    display_value: string;
     fuzzified_inputs: FuzzifiedInputInfoDto[];
     fired_rules_count: number;
+    linguistic_terms: TermDegreeDto[];
+    linguistic_approximation: LinguisticApproximationDto | null;
 };
 
 export type EvaluateFuzzySystemResponse = {
     problem_id: number;
     problem_name: string;
     outputs: FuzzyOutputResultDto[];
+    definition_hash: string;
+};
+
+export type ChainEvaluationStepDto = {
+    inputs: FuzzyInputDto[];
+    result: EvaluateFuzzySystemResponse;
+};
+
+export type EvaluateChainResponse = {
+    problem_id: number;
+    steps: ChainEvaluationStepDto[];
+};
+
+export type EvaluateType2SystemRequest = {
+    problem_id: number;
+    inputs: FuzzyInputDto[];
+    resolution?: number;
+};
+
+export type MembershipIntervalDto = {
+    linguistic_term: string;
+    lower: number;
+    upper: number;
+};
+
+export type IntervalFuzzifiedInputInfoDto = {
+    input_parameter_id: number;
+    input_parameter_name: string;
+    unit: string;
+    crisp_value: number;
+    /** Crisp value with the parameter's precision and unit, e.g. "21.5 °C" */
+    display_value: string;
+    membership_intervals: MembershipIntervalDto[];
+};
+
+export type FiringIntervalDto = {
+    fuzzy_output_value_id: number;
+    hedge: LinguisticHedge | null;
+    lower: number;
+    upper: number;
+};
+
+export type Type2OutputResultDto = {
+    output_parameter_id: number;
+    output_parameter_name: string;
+    unit: string;
+    /** Type-reduced (Karnik–Mendel) centroid interval */
+    left: number;
+    right: number;
+    /** Midpoint of the type-reduced interval */
+    crisp_value: number;
+    display_value: string;
+    fired_rules_count: number;
+    firing_intervals: FiringIntervalDto[];
+};
+
+export type EvaluateType2SystemResponse = {
+    problem_id: number;
+    problem_name: string;
+    fuzzified_inputs: IntervalFuzzifiedInputInfoDto[];
+    outputs: Type2OutputResultDto[];
+    definition_hash: string;
+};
+
+export type EvaluateBatchRequest = {
+    /** Chosen by the caller; tags progress events and is passed to cancelEvaluation */
+    job_id: string;
+    problem_id: number;
+    /** Header of input parameter names, then a row of values per evaluation; ',' or ';' separated */
+    csv: string;
+    method: DefuzzificationMethod;
+    resolution?: number;
+};
+
+export type BatchRowDto = {
+    outputs: number[] | null;
+    error: string | null;
+};
+
+export type EvaluateBatchResponse = {
+    problem_id: number;
+    output_parameter_ids: number[];
+    output_parameter_names: string[];
+    rows: BatchRowDto[];
+    evaluated_count: number;
+    failed_count: number;
+    /** Input table with the outputs and an "error" column appended */
+    csv: string;
+    definition_hash: string;
+};
+
+export type SweepAxisDto = {
+    input_parameter_id: number;
+    /** Ignored for categorical parameters, which are swept over their categories */
+    steps: number;
+};
+
+export type EvaluateSweepRequest = {
+    job_id: string;
+    problem_id: number;
+    x: SweepAxisDto;
+    y?: SweepAxisDto | null;
+    /** Values of the inputs that are not swept */
+    fixed_inputs: FuzzyInputDto[];
+    method: DefuzzificationMethod;
+    resolution?: number;
+};
+
+export type SweepSurfaceDto = {
+    output_parameter_id: number;
+    output_parameter_name: string;
+    unit: string;
+    /** Row-major: the value at (x[i], y[j]) is values[j * x.length + i] */
+    values: number[];
+};
+
+export type EvaluateSweepResponse = {
+    problem_id: number;
+    x: number[];
+    /** Empty for a control curve */
+    y: number[];
+    surfaces: SweepSurfaceDto[];
+    definition_hash: string;
+};
+
+/** Payload of the "evaluation-progress" event */
+export type EvaluationProgressEvent = {
+    job_id: string;
+    completed: number;
+    total: number;
 };
 
 export type DefuzzificationMethod = 'centroid' | 'bisector' | 'mom' | 'som' | 'lom';
@@ -1,5 +1,9 @@
+import { LowerMembership } from "./lower_membership";
+
 export type CreateFuzzyOutputValueRequest = {
     output_parameter_id: number;
+    /** Breakpoints [a, b, c, d]; only used by free-form parameters */
+    shape?: [number, number, number, number];
 }
 
 export type UpdateFuzzyOutputValueRequest = {
@@ -11,6 +15,10 @@ export type UpdateFuzzyOutputValueRequest = {
     is_triangle: boolean;
 }
 
+export type UpdateFuzzyOutputValueLowerRequest = {
+    lower: LowerMembership | null;
+}
+
 export type FuzzyOutputValueResponse = {
     id: number;
     output_parameter_id: number;
@@ -20,4 +28,5 @@ export type FuzzyOutputValueResponse = {
     c: number;
     d: number;
     is_triangle: boolean;
+    lower: LowerMembership | null;
 }
\ No newline at end of file
@@ -1,4 +1,6 @@
 import { InputValueResponse } from "./input_value";
+import { ParameterKind } from "./parameter_kind";
+import { PartitionMode } from "./partition_mode";
 
 export type CreateInputParameterRequest = {
     problem_id: number;
@@ -10,11 +12,47 @@ export type UpdateInputParameterRequest = {
     end: number;
 }
 
+/** What happens to the terms when the range changes */
+export type RangeChangePolicy = "rescale" | "clip" | "reject";
+
+export type ChangeInputParameterRangeRequest = {
+    start: number;
+    end: number;
+    policy: RangeChangePolicy;
+}
+
+export type UpdateInputParameterPartitionModeRequest = {
+    partition_mode: PartitionMode;
+}
+
+export type UpdateInputParameterKindRequest = {
+    kind: ParameterKind;
+}
+
+export type UpdateInputParameterMetadataRequest = {
+    /** Physical unit, e.g. "°C"; empty if dimensionless */
+    unit: string;
+    description: string;
+    /** Number of displayed decimals; null shows values unrounded */
+    precision: number | null;
+    /** Value an evaluation starts from; a category index for categorical parameters */
+    default_value: number | null;
+}
+
 export type InputParameterResponse = {
     id: number;
     problem_id: number;
     name: string;
     start: number;
     end: number;
+    partition_mode: PartitionMode;
+    kind: ParameterKind;
+    unit: string;
+    description: string;
+    precision: number | null;
+    default_value: number | null;
+    /** Whether the terms form a Ruspini partition of the range */
+    partition_valid: boolean;
+    partition_issue: string | null;
     input_values: Array<InputValueResponse>;
 }
\ No newline at end of file
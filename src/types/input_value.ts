@@ -1,5 +1,9 @@
+import { LowerMembership } from "./lower_membership";
+
 export type CreateInputValueRequest = {
     input_parameter_id: number;
+    /** Breakpoints [a, b, c, d]; only used by free-form parameters */
+    shape?: [number, number, number, number];
 }
 
 export type UpdateInputValueRequest = {
@@ -11,6 +15,10 @@ export type UpdateInputValueRequest = {
     is_triangle: boolean;
 }
 
+export type UpdateInputValueLowerRequest = {
+    lower: LowerMembership | null;
+}
+
 export type InputValueResponse = {
     id: number;
     input_parameter_id: number;
@@ -20,4 +28,5 @@ export type InputValueResponse = {
     c: number;
     d: number;
     is_triangle: boolean;
+    lower: LowerMembership | null;
 }
\ No newline at end of file
@@ -0,0 +1,7 @@
+export type JournalEntryResponse = {
+    id: number;
+    problem_id: number;
+    description: string;
+    is_undone: boolean;
+    created_at: string;
+}
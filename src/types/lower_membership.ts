@@ -0,0 +1,10 @@
+/** Lower membership function of an interval type-2 term; the term's own shape is the upper one */
+export type LowerMembership = {
+    a: number;
+    b: number;
+    c: number;
+    d: number;
+    is_triangle: boolean;
+    /** Peak degree, in (0, 1] */
+    height: number;
+}
\ No newline at end of file
@@ -1,4 +1,6 @@
 import { FuzzyOutputValueResponse } from "./fuzzy_output_value";
+import { PartitionMode } from "./partition_mode";
+import { RangeChangePolicy } from "./input_parameter";
 
 export type CreateOutputParameterRequest = {
     problem_id: number;
@@ -10,11 +12,36 @@ export type UpdateOutputParameterRequest = {
     end: number;
 }
 
+export type ChangeOutputParameterRangeRequest = {
+    start: number;
+    end: number;
+    policy: RangeChangePolicy;
+}
+
+export type UpdateOutputParameterPartitionModeRequest = {
+    partition_mode: PartitionMode;
+}
+
+export type UpdateOutputParameterMetadataRequest = {
+    /** Physical unit, e.g. "°C"; empty if dimensionless */
+    unit: string;
+    description: string;
+    /** Number of displayed decimals; null shows values unrounded */
+    precision: number | null;
+}
+
 export type OutputParameterResponse = {
     id: number;
     problem_id: number;
     name: string;
     start: number;
     end: number;
+    partition_mode: PartitionMode;
+    unit: string;
+    description: string;
+    precision: number | null;
+    /** Whether the terms form a Ruspini partition of the range */
+    partition_valid: boolean;
+    partition_issue: string | null;
     fuzzy_output_values: Array<FuzzyOutputValueResponse>;
 }
\ No newline at end of file
@@ -1,10 +1,24 @@
+import { LinguisticHedge } from "./fuzzy_inference";
+
 export type UpdateOutputValueRequest = {
     fuzzy_output_value_id : number | null;
 }
 
+export type AntecedentHedge = {
+    input_value_id: number;
+    hedge: LinguisticHedge;
+}
+
+export type UpdateOutputValueHedgesRequest = {
+    antecedent_hedges: AntecedentHedge[];
+    consequent_hedge: LinguisticHedge | null;
+}
+
 export type OutputValueResponse = {
     id: number;
     output_parameter_id: number;
     fuzzy_output_value_id: number | null;
     input_value_ids: string;
+    antecedent_hedges: AntecedentHedge[];
+    consequent_hedge: LinguisticHedge | null;
 }
\ No newline at end of file
@@ -0,0 +1,2 @@
+/** Whether an input parameter takes a number ("continuous") or one of its terms as a category ("categorical") */
+export type ParameterKind = "continuous" | "categorical";
@@ -0,0 +1,12 @@
+export type ParameterLinkResponse = {
+    id: number;
+    output_parameter_id: number;
+    input_parameter_id: number;
+    source_problem_id: number;
+    target_problem_id: number;
+}
+
+export type CreateParameterLinkRequest = {
+    output_parameter_id: number;
+    input_parameter_id: number;
+}
@@ -0,0 +1,2 @@
+/** Whether term edits keep a Ruspini partition ("ruspini") or store terms as given ("free_form") */
+export type PartitionMode = "ruspini" | "free_form";
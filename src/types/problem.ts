@@ -36,6 +36,17 @@ export type CreateProblemRequest = {
     image: CreateImageRequest | null;
 }
 
+export type CloneProblemRequest = {
+    prev_problem_id: number | null;
+    name: string | null;
+    with_children: boolean;
+}
+
+export type MoveProblemRequest = {
+    prev_problem_id: number | null;
+    position: number | null;
+}
+
 export type CreateProblemResponse = {
     id: number;
     image_id: number | null;
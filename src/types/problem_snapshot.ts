@@ -0,0 +1,58 @@
+import { LowerMembership } from "./lower_membership";
+
+export type ProblemSnapshotResponse = {
+    id: number;
+    problem_id: number;
+    name: string;
+    is_automatic: boolean;
+    created_at: string;
+}
+
+export type ChangeKind = 'added' | 'removed' | 'changed';
+
+export type TermShapeDto = {
+    a: number;
+    b: number;
+    c: number;
+    d: number;
+    is_triangle: boolean;
+    lower: LowerMembership | null;
+}
+
+export type ParameterChangeDto = {
+    kind: ChangeKind;
+    is_input: boolean;
+    name: string;
+    old_start: number | null;
+    old_end: number | null;
+    new_start: number | null;
+    new_end: number | null;
+}
+
+export type TermChangeDto = {
+    kind: ChangeKind;
+    is_input: boolean;
+    parameter_name: string;
+    term_name: string;
+    old_shape: TermShapeDto | null;
+    new_shape: TermShapeDto | null;
+}
+
+export type RuleAntecedentDto = {
+    input_parameter_name: string;
+    term_name: string;
+}
+
+export type RuleChangeDto = {
+    kind: ChangeKind;
+    output_parameter_name: string;
+    antecedents: RuleAntecedentDto[];
+    old_consequent: string | null;
+    new_consequent: string | null;
+}
+
+export type ProblemDiffResponse = {
+    parameters: ParameterChangeDto[];
+    terms: TermChangeDto[];
+    rules: RuleChangeDto[];
+}
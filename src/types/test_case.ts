@@ -0,0 +1,67 @@
+export type TestCaseInputDto = {
+    input_parameter_id: number;
+    crisp_value: number;
+    /** Selected category of a categorical parameter */
+    input_value_id?: number | null;
+}
+
+export type TestCaseExpectationDto = {
+    output_parameter_id: number;
+    expected_value: number | null;
+    tolerance: number | null;
+    expected_fuzzy_output_value_id: number | null;
+}
+
+export type CreateTestCaseRequest = {
+    problem_id: number;
+    name: string;
+    inputs: TestCaseInputDto[];
+    expectations: TestCaseExpectationDto[];
+}
+
+export type UpdateTestCaseRequest = {
+    name: string;
+    inputs: TestCaseInputDto[];
+    expectations: TestCaseExpectationDto[];
+}
+
+export type TestCaseResponse = {
+    id: number;
+    problem_id: number;
+    name: string;
+    inputs: TestCaseInputDto[];
+    expectations: TestCaseExpectationDto[];
+}
+
+export type RunTestCasesRequest = {
+    problem_id: number;
+    method: 'centroid' | 'bisector' | 'mom' | 'som' | 'lom';
+    resolution?: number;
+}
+
+export type TestCaseOutputResultDto = {
+    output_parameter_id: number;
+    output_parameter_name: string;
+    expected_value: number | null;
+    tolerance: number;
+    actual_value: number | null;
+    difference: number | null;
+    expected_term: string | null;
+    actual_term: string | null;
+    passed: boolean;
+}
+
+export type TestCaseResultDto = {
+    test_case_id: number;
+    name: string;
+    passed: boolean;
+    error: string | null;
+    outputs: TestCaseOutputResultDto[];
+}
+
+export type TestRunReportDto = {
+    problem_id: number;
+    passed_count: number;
+    failed_count: number;
+    results: TestCaseResultDto[];
+}